@@ -0,0 +1,126 @@
+use crate::api::{self, BanzukeEntry, BanzukeResponse, Basho, HeadToHeadMatch, SumoApi};
+
+/// Honours that the stats endpoint doesn't count, worked out from a rikishi's bouts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Achievements {
+    pub rikishi_id: u32,
+    /// Wins as a maegashira over a yokozuna, not counting fusen
+    pub kinboshi: u32,
+    /// Runner-up finishes, and how many of the rikishi's basho could be checked: only
+    /// basho whose results and banzuke are already cached are, so the count is a floor
+    pub jun_yusho: (u32, u32),
+}
+
+/// Gold stars in `matches`, the bout history of `rikishi_id`.
+pub fn kinboshi<'a>(rikishi_id: u32, matches: impl IntoIterator<Item = &'a HeadToHeadMatch>) -> u32 {
+    let tier = |rank: &str| crate::rank::Rank::parse(rank).map(|r| r.tier);
+    matches
+        .into_iter()
+        .filter(|m| m.winner_id == Some(rikishi_id) && m.kimarite.as_deref() != Some("fusen"))
+        .filter(|m| {
+            let (own, opponent) = if m.east_id == rikishi_id { (&m.east_rank, &m.west_rank) } else { (&m.west_rank, &m.east_rank) };
+            tier(own) == Some(crate::rank::Tier::Maegashira) && tier(opponent) == Some(crate::rank::Tier::Yokozuna)
+        })
+        .count() as u32
+}
+
+/// Whether `rikishi_id` finished runner-up in `banzuke`: not the yusho winner, with the
+/// most wins of everyone else over the division's `days`.
+pub fn runner_up(rikishi_id: u32, winner_id: u32, banzuke: &[BanzukeEntry], days: u8) -> bool {
+    let others = banzuke.iter().filter(|e| e.rikishi_id != winner_id);
+    let best = others.clone().map(|e| e.win_loss_absent(days).0).max();
+    rikishi_id != winner_id
+        && others
+            .clone()
+            .find(|e| e.rikishi_id == rikishi_id)
+            .is_some_and(|e| Some(e.win_loss_absent(days).0) == best)
+}
+
+/// Count jun-yusho over the basho and divisions `matches` were fought in, reading only
+/// cached basho results and banzuke. `hidden_basho` is left out, for replay and
+/// spoiler-free mode.
+pub fn jun_yusho(api: &SumoApi, rikishi_id: u32, matches: &[HeadToHeadMatch], hidden_basho: Option<&str>) -> (u32, u32) {
+    let mut basho: Vec<(&str, &str)> = matches.iter().map(|m| (m.basho_id.as_str(), m.division.as_str())).collect();
+    basho.sort_unstable();
+    basho.dedup();
+    let (mut found, mut checked) = (0, 0);
+    for (basho_id, division) in basho.into_iter().filter(|(id, _)| Some(*id) != hidden_basho) {
+        let Some(info) = api.get_cached::<Basho>(&api::basho_path(basho_id)) else {
+            continue;
+        };
+        let winner = info.yusho.iter().flatten().find(|y| y.division.eq_ignore_ascii_case(division));
+        let Some((winner, banzuke)) = winner.zip(api.get_cached::<BanzukeResponse>(&api::banzuke_path(basho_id, division))) else {
+            continue;
+        };
+        let entries: Vec<BanzukeEntry> = banzuke.east.into_iter().chain(banzuke.west).collect();
+        checked += 1;
+        if runner_up(rikishi_id, winner.rikishi_id, &entries, crate::max_day_for_division(division)) {
+            found += 1;
+        }
+    }
+    (found, checked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{kinboshi, runner_up};
+    use crate::api::{BanzukeEntry, HeadToHeadMatch, MatchRecord};
+
+    fn bout(east: (u32, &str), west: (u32, &str), winner: u32, kimarite: &str) -> HeadToHeadMatch {
+        HeadToHeadMatch {
+            basho_id: "202501".to_string(),
+            division: "Makuuchi".to_string(),
+            day: 1,
+            match_no: 1,
+            east_id: east.0,
+            east_shikona: String::new(),
+            east_rank: east.1.to_string(),
+            west_id: west.0,
+            west_shikona: String::new(),
+            west_rank: west.1.to_string(),
+            kimarite: Some(kimarite.to_string()),
+            winner_id: Some(winner),
+            winner_en: None,
+            winner_jp: None,
+        }
+    }
+
+    fn entry(id: u32, wins: usize) -> BanzukeEntry {
+        let result = |i| MatchRecord {
+            result: if i < wins { "win" } else { "loss" }.to_string(),
+            opponent_shikona_en: String::new(),
+            opponent_shikona_jp: String::new(),
+            kimarite: None,
+        };
+        BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: id,
+            shikona_en: "".into(),
+            shikona_jp: None,
+            rank_value: id,
+            rank: String::new(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: Some((0..15).map(result).collect()),
+        }
+    }
+
+    #[test]
+    fn counts_kinboshi_and_runner_up_finishes() {
+        let matches = [
+            bout((1, "Maegashira 1 East"), (9, "Yokozuna 1 East"), 1, "oshidashi"),
+            bout((9, "Yokozuna 1 East"), (1, "Maegashira 2 West"), 1, "hatakikomi"),
+            bout((1, "Maegashira 1 East"), (9, "Yokozuna 1 East"), 1, "fusen"),
+            bout((1, "Maegashira 1 East"), (9, "Yokozuna 1 East"), 9, "yorikiri"),
+            bout((1, "Komusubi 1 East"), (9, "Yokozuna 1 East"), 1, "yorikiri"),
+        ];
+        assert_eq!(kinboshi(1, &matches), 2);
+
+        let banzuke = [entry(9, 14), entry(1, 12), entry(2, 12), entry(3, 11)];
+        assert!(runner_up(1, 9, &banzuke, 15));
+        assert!(runner_up(2, 9, &banzuke, 15));
+        assert!(!runner_up(3, 9, &banzuke, 15));
+        assert!(!runner_up(9, 9, &banzuke, 15));
+    }
+}
@@ -1,3 +1,14 @@
+use serde::de::DeserializeOwned;
+use crate::cache::DiskCache;
+use crate::config::{ApiConfig, DEFAULT_USER_AGENT};
+use crate::queue::{self, Queue};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION};
+use reqwest::StatusCode;
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use serde::{Deserialize, Serialize};
 use chrono::Datelike;
 
@@ -45,6 +56,14 @@ impl Basho {
             .and_then(|s| s.split('T').next())
             .and_then(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
     }
+
+    /// Parse the end date string (if present) into a NaiveDate without time information.
+    pub fn end_date_naive(&self) -> Option<chrono::NaiveDate> {
+        self.end_date
+            .as_deref()
+            .and_then(|s| s.split('T').next())
+            .and_then(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -62,11 +81,81 @@ pub struct BanzukeEntry {
     #[serde(rename = "rikishiID")]
     pub rikishi_id: u32,
     #[serde(rename = "shikonaEn")]
-    pub shikona_en: String,
+    pub shikona_en: Arc<str>,
+    #[serde(rename = "shikonaJp", default)]
+    pub shikona_jp: Option<String>,
     #[serde(rename = "rankValue")]
     pub rank_value: u32,
     pub rank: String,
     pub record: Option<Vec<MatchRecord>>,
+    /// The API's own tally of the basho so far, where it gives one
+    #[serde(default)]
+    pub wins: Option<u8>,
+    #[serde(default)]
+    pub losses: Option<u8>,
+    #[serde(default)]
+    pub absences: Option<u8>,
+}
+
+/// Whether a day's result was a win, fusen included; `None` for absences and days not
+/// yet fought.
+pub fn outcome(result: &str) -> Option<bool> {
+    match result.trim() {
+        "win" | "fusen-win" | "○" | "□" => Some(true),
+        "loss" | "fusen-loss" | "●" | "■" => Some(false),
+        _ => None,
+    }
+}
+
+impl BanzukeEntry {
+    // The API's wins and losses, when the first `days` days take in every result it has
+    fn reported(&self, days: u8) -> Option<(u8, u8)> {
+        let hidden = self.record.as_ref().is_some_and(|r| r.iter().skip(days as usize).any(|m| !m.result.is_empty()));
+        if hidden {
+            return None;
+        }
+        Some((self.wins?, self.losses?))
+    }
+
+    /// Wins and losses over the first `days` days: the API's tally when that covers the
+    /// same days, otherwise counted from the record.
+    pub fn wins_losses(&self, days: u8) -> (u8, u8) {
+        if let Some(tally) = self.reported(days) {
+            return tally;
+        }
+        let Some(records) = &self.record else {
+            return (0, 0);
+        };
+        records.iter().take(days as usize).filter_map(|r| outcome(&r.result)).fold((0, 0), |(w, l), won| {
+            if won { (w + 1, l) } else { (w, l + 1) }
+        })
+    }
+
+    /// Count wins, losses and absences over the first `total_days` days of the record.
+    /// Fusen results count as wins and losses; absences are the API's count or else the
+    /// days left over.
+    pub fn win_loss_absent(&self, total_days: u8) -> (u8, u8, u8) {
+        if self.record.is_none() && self.reported(total_days).is_none() {
+            return (0, 0, 0);
+        }
+        let (w, l) = self.wins_losses(total_days);
+        let a = match self.absences {
+            Some(a) if self.reported(total_days).is_some() => a,
+            _ => total_days.saturating_sub(w).saturating_sub(l),
+        };
+        (w, l, a)
+    }
+
+    /// Current run of consecutive results over the first `total_days` days, as
+    /// `(won, length)`. Fusen results count; days without a result end the run.
+    pub fn streak(&self, total_days: u8) -> Option<(bool, u8)> {
+        let records = self.record.as_ref()?;
+        let played = &records[..records.len().min(total_days as usize)];
+        let mut outcomes = played.iter().rev().skip_while(|r| r.result.is_empty()).map(|r| outcome(&r.result));
+        let won = outcomes.next()??;
+        let len = 1 + outcomes.take_while(|o| *o == Some(won)).count();
+        Some((won, len as u8))
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -102,13 +191,13 @@ pub struct TorikumiEntry {
     #[serde(rename = "eastId")]
     pub east_id: u32,
     #[serde(rename = "eastShikona")]
-    pub east_shikona: String,
+    pub east_shikona: Arc<str>,
     #[serde(rename = "eastRank")]
     pub east_rank: String,
     #[serde(rename = "westId")]
     pub west_id: u32,
     #[serde(rename = "westShikona")]
-    pub west_shikona: String,
+    pub west_shikona: Arc<str>,
     #[serde(rename = "westRank")]
     pub west_rank: String,
     pub kimarite: Option<String>,
@@ -142,6 +231,38 @@ pub struct RikishiDetails {
     pub debut: Option<String>,
 }
 
+/// Career totals from `/api/rikishi/{id}/stats`.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+pub struct RikishiStats {
+    pub basho: u32,
+    #[serde(rename = "totalMatches")]
+    pub total_matches: u32,
+    #[serde(rename = "totalWins")]
+    pub total_wins: u32,
+    #[serde(rename = "totalLosses")]
+    pub total_losses: u32,
+    #[serde(rename = "totalAbsences")]
+    pub total_absences: u32,
+    pub yusho: u32,
+    #[serde(rename = "yushoByDivision")]
+    pub yusho_by_division: HashMap<String, u32>,
+    /// Special prizes by name, e.g. "Shukun-sho"
+    pub sansho: HashMap<String, u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RikishiListResponse {
+    pub records: Option<Vec<RikishiDetails>>,
+}
+
+/// One page of a rikishi's bouts from `/api/rikishi/{id}/matches`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RikishiMatchesResponse {
+    pub total: u32,
+    pub records: Option<Vec<HeadToHeadMatch>>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct HeadToHeadResponse {
     #[serde(rename = "kimariteLosses")]
@@ -156,6 +277,7 @@ pub struct HeadToHeadResponse {
     pub total: u32,
 }
 
+/// A bout as the matches endpoints list it.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct HeadToHeadMatch {
     #[serde(rename = "bashoId")]
@@ -185,54 +307,584 @@ pub struct HeadToHeadMatch {
     pub winner_jp: Option<String>,
 }
 
+impl HeadToHeadMatch {
+    /// Shikona `rikishi_id` fought under in this bout, if they were in it.
+    pub fn shikona_of(&self, rikishi_id: u32) -> Option<&str> {
+        if self.east_id == rikishi_id {
+            Some(&self.east_shikona)
+        } else if self.west_id == rikishi_id {
+            Some(&self.west_shikona)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `rikishi_id` won the bout, going by the winner's ID; `None` while it is
+    /// undecided or when the winner is neither side.
+    pub fn won_by(&self, rikishi_id: u32) -> Option<bool> {
+        let opponent = match rikishi_id {
+            id if id == self.east_id => self.west_id,
+            id if id == self.west_id => self.east_id,
+            _ => return None,
+        };
+        match self.winner_id? {
+            w if w == rikishi_id => Some(true),
+            w if w == opponent => Some(false),
+            _ => None,
+        }
+    }
+}
+
+// Longest part of a response body quoted in a decode error
+const BODY_EXCERPT_CHARS: usize = 300;
+
+/// How long to wait according to `Retry-After` (seconds or an HTTP date), falling back to
+/// `X-RateLimit-Reset` (seconds, or a Unix time when larger than a day).
+fn retry_after(headers: &HeaderMap, now: chrono::DateTime<chrono::Utc>) -> Option<Duration> {
+    let header = |name: &str| headers.get(name)?.to_str().ok().map(str::trim);
+    if let Some(value) = header("retry-after") {
+        if let Ok(secs) = value.parse::<u64>() {
+            return Some(Duration::from_secs(secs));
+        }
+        if let Ok(date) = chrono::DateTime::parse_from_rfc2822(value) {
+            return Some((date.with_timezone(&chrono::Utc) - now).to_std().unwrap_or_default());
+        }
+    }
+    let reset = header("x-ratelimit-reset")?.parse::<i64>().ok()?;
+    let secs = if reset > 86_400 { reset - now.timestamp() } else { reset };
+    Some(Duration::from_secs(secs.max(0) as u64))
+}
+
+/// Request paths of the basho endpoints, as used for recordings and the disk cache.
+pub fn basho_path(basho_id: &str) -> String {
+    format!("/api/basho/{}", basho_id)
+}
+
+pub fn banzuke_path(basho_id: &str, division: &str) -> String {
+    format!("/api/basho/{}/banzuke/{}", basho_id, division)
+}
+
+pub fn torikumi_path(basho_id: &str, division: &str, day: u8) -> String {
+    format!("/api/basho/{}/torikumi/{}/{}", basho_id, division, day)
+}
+
+// File name of a recorded response, e.g. `api_basho_202509_banzuke_Makuuchi.json`
+pub(crate) fn recording_name(path: &str) -> String {
+    let name: String = path
+        .trim_start_matches('/')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    format!("{}.json", name)
+}
+
+fn parse_body<T: DeserializeOwned>(body: &str) -> Result<T, String> {
+    let mut deserializer = serde_json::Deserializer::from_str(body);
+    serde_path_to_error::deserialize(&mut deserializer).map_err(|e| {
+        let mut excerpt: String = body.chars().take(BODY_EXCERPT_CHARS).collect();
+        if excerpt.len() < body.len() {
+            excerpt.push('…');
+        }
+        format!("{} at `{}`; body: {}", e.inner(), e.path(), excerpt)
+    })
+}
+
+/// Where API responses come from: the network, the network with every response also
+/// saved to a directory, or a directory of saved responses.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Traffic {
+    Network,
+    Record(PathBuf),
+    Playback(PathBuf),
+}
+
+// Back-off used when a 429/503 response doesn't say how long to wait
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// The API answered 429 Too Many Requests or 503 Service Unavailable; no requests are
+/// sent until `retry_after` has passed.
+#[derive(Debug)]
+pub struct RateLimited {
+    pub retry_after: Duration,
+}
+
+impl std::fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rate limited by the API, retry in {}s", self.retry_after.as_secs())
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+// Consecutive failures that trip the circuit breaker, and how long it stays open
+const FAILURES_TO_OPEN: u32 = 3;
+const COOL_DOWN: Duration = Duration::from_secs(30);
+
+/// Requests keep failing and the circuit breaker is open: nothing is sent until the
+/// cool-down has passed, and there is no cached response to fall back on.
+#[derive(Debug)]
+pub struct Offline {
+    pub retry_in: Duration,
+}
+
+impl std::fmt::Display for Offline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "API unreachable, retrying in {}s", self.retry_in.as_secs().max(1))
+    }
+}
+
+impl std::error::Error for Offline {}
+
+/// The API answered 404 Not Found, e.g. for a basho ID that doesn't exist.
+#[derive(Debug)]
+pub struct NotFound {
+    pub path: String,
+}
+
+impl std::fmt::Display for NotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} not found", self.path)
+    }
+}
+
+impl std::error::Error for NotFound {}
+
+// Counts consecutive connection failures and server errors. Once there are
+// FAILURES_TO_OPEN of them the circuit opens for COOL_DOWN; after that a single
+// request is let through, and any success closes it again.
+#[derive(Debug, Default)]
+struct Breaker {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl Breaker {
+    fn record(&mut self, ok: bool, now: Instant) {
+        if ok {
+            *self = Self::default();
+            return;
+        }
+        self.failures += 1;
+        if self.failures >= FAILURES_TO_OPEN {
+            self.open_until = Some(now + COOL_DOWN);
+        }
+    }
+
+    fn open_for(&self, now: Instant) -> Option<Duration> {
+        let left = self.open_until?.saturating_duration_since(now);
+        (!left.is_zero()).then_some(left)
+    }
+
+    fn tripped(&self) -> bool {
+        self.failures >= FAILURES_TO_OPEN
+    }
+}
+
+// Requests that make up the connection health, and the average latency that counts as slow
+const HEALTH_WINDOW: usize = 10;
+const SLOW_RESPONSE: Duration = Duration::from_secs(3);
+
+/// How the API has been answering lately, shown in the footer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Health {
+    Online,
+    /// Some recent requests failed or responses are slow
+    Degraded,
+    /// The circuit breaker is open
+    Offline,
+}
+
+impl Health {
+    fn from_recent(recent: &VecDeque<(bool, Duration)>, tripped: bool) -> Option<Self> {
+        if tripped {
+            return Some(Health::Offline);
+        }
+        if recent.is_empty() {
+            return None;
+        }
+        let average = recent.iter().map(|(_, latency)| *latency).sum::<Duration>() / recent.len() as u32;
+        if recent.iter().any(|(ok, _)| !ok) || average > SLOW_RESPONSE {
+            Some(Health::Degraded)
+        } else {
+            Some(Health::Online)
+        }
+    }
+}
+
+// Bytes of response bodies kept in memory for the session
+const MEMORY_CACHE_BYTES: usize = 32 * 1024 * 1024;
+
+// Last good body for each path, as big as `max_bytes` in all; the least recently used
+// bodies are dropped first and ones bigger than the whole budget aren't kept
+struct Responses {
+    // Body and when it was last used, by path
+    bodies: HashMap<String, (String, u64)>,
+    size: usize,
+    max_bytes: usize,
+    clock: u64,
+}
+
+impl Default for Responses {
+    fn default() -> Self {
+        Self::new(MEMORY_CACHE_BYTES)
+    }
+}
+
+impl Responses {
+    fn new(max_bytes: usize) -> Self {
+        Self { bodies: HashMap::new(), size: 0, max_bytes, clock: 0 }
+    }
+
+    fn get(&mut self, path: &str) -> Option<String> {
+        self.clock += 1;
+        let (body, used) = self.bodies.get_mut(path)?;
+        *used = self.clock;
+        Some(body.clone())
+    }
+
+    fn insert(&mut self, path: String, body: String) {
+        if let Some((old, _)) = self.bodies.remove(&path) {
+            self.size -= old.len();
+        }
+        if body.len() > self.max_bytes {
+            return;
+        }
+        self.clock += 1;
+        self.size += body.len();
+        self.bodies.insert(path, (body, self.clock));
+        while self.size > self.max_bytes {
+            let Some(oldest) = self.bodies.iter().min_by_key(|(_, (_, used))| *used).map(|(path, _)| path.clone()) else {
+                break;
+            };
+            if let Some((body, _)) = self.bodies.remove(&oldest) {
+                self.size -= body.len();
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Lookups {
+    rikishi: HashMap<u32, RikishiDetails>,
+    // By (rikishi, opponent)
+    head_to_head: HashMap<(u32, u32), HeadToHeadResponse>,
+}
+
+#[derive(Clone)]
 pub struct SumoApi {
     client: reqwest::Client,
     base_url: String,
+    traffic: Traffic,
+    // Shared by all clones: no requests before this instant
+    paused_until: Arc<Mutex<Option<Instant>>>,
+    breaker: Arc<Mutex<Breaker>>,
+    // Last good body for each path, served while the API is unreachable
+    cache: Arc<Mutex<Responses>>,
+    // Outcome and latency of the last HEALTH_WINDOW requests
+    recent: Arc<Mutex<VecDeque<(bool, Duration)>>>,
+    // Responses kept between runs, the fallback when the API can't be reached
+    disk: Option<DiskCache>,
+    // Rikishi details and head-to-head records already fetched, so popups reopen at once
+    lookups: Arc<Mutex<Lookups>>,
+    // Requests sent at once, the user's before prefetching and analytics
+    queue: Queue,
+    // Log each request to stderr; only while nothing else owns the terminal
+    trace: Arc<AtomicBool>,
 }
 
 impl SumoApi {
-    pub fn new() -> Self {
-        Self {
-            client: reqwest::Client::new(),
-            base_url: "https://www.sumo-api.com".to_string(),
+    /// A client for the configured base URL, sending the configured API key and bearer
+    /// token with every request.
+    pub fn with_config(config: &ApiConfig) -> anyhow::Result<Self> {
+        let mut headers = HeaderMap::new();
+        if let Some(key) = config.key() {
+            let name = HeaderName::from_bytes(config.key_header.as_bytes())
+                .map_err(|_| anyhow::anyhow!("invalid API key header name \"{}\"", config.key_header))?;
+            let mut value = HeaderValue::from_str(&key).map_err(|_| anyhow::anyhow!("API key contains invalid characters"))?;
+            value.set_sensitive(true);
+            headers.insert(name, value);
+        }
+        if let Some(token) = config.token() {
+            let mut value = HeaderValue::from_str(&format!("Bearer {}", token))
+                .map_err(|_| anyhow::anyhow!("API token contains invalid characters"))?;
+            value.set_sensitive(true);
+            headers.insert(AUTHORIZATION, value);
         }
+
+        // Compressed responses (Accept-Encoding: gzip, br) make the large banzuke and
+        // torikumi payloads much smaller; reqwest decompresses them transparently
+        let client = reqwest::Client::builder()
+            .gzip(true)
+            .brotli(true)
+            // Fail rather than hang on a dead network, so the circuit breaker can open
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(30))
+            .default_headers(headers)
+            .user_agent(config.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT))
+            .build()?;
+        Ok(Self {
+            client,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            traffic: Traffic::Network,
+            paused_until: Arc::default(),
+            breaker: Arc::default(),
+            cache: Arc::default(),
+            recent: Arc::default(),
+            disk: None,
+            lookups: Arc::default(),
+            queue: Queue::new(config.max_concurrent_requests),
+            trace: Arc::default(),
+        })
     }
 
-    pub async fn get_basho(&self, basho_id: &str) -> anyhow::Result<Basho> {
-        let url = format!("{}/api/basho/{}", self.base_url, basho_id);
+    /// Time left before requests resume after the API asked us to back off.
+    pub fn rate_limited_for(&self) -> Option<Duration> {
+        let until = (*self.paused_until.lock().unwrap())?;
+        let left = until.saturating_duration_since(Instant::now());
+        (!left.is_zero()).then_some(left)
+    }
+
+    /// Connection health from recent request outcomes and latency; `None` until a
+    /// request has been sent.
+    pub fn health(&self) -> Option<Health> {
+        let tripped = self.breaker.lock().unwrap().tripped();
+        Health::from_recent(&self.recent.lock().unwrap(), tripped)
+    }
+
+    fn record_outcome(&self, ok: bool, latency: Duration) {
+        let mut recent = self.recent.lock().unwrap();
+        if recent.len() == HEALTH_WINDOW {
+            recent.pop_front();
+        }
+        recent.push_back((ok, latency));
+    }
+
+    /// Keep every response in `cache` and fall back to it when a request fails.
+    pub fn with_disk_cache(mut self, cache: DiskCache) -> Self {
+        self.disk = Some(cache);
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Log every network request, its outcome and latency to stderr (`-vv`).
+    pub fn set_trace(&self, on: bool) {
+        self.trace.store(on, Ordering::Relaxed);
+    }
+
+    /// The same client without the disk cache, for requests that must reach the API.
+    pub fn without_disk_cache(mut self) -> Self {
+        self.disk = None;
+        self
+    }
+
+    pub fn disk_cache(&self) -> Option<&DiskCache> {
+        self.disk.as_ref()
+    }
+
+    /// Record responses to, or play them back from, a directory.
+    pub fn with_traffic(mut self, traffic: Traffic) -> Self {
+        self.traffic = traffic;
+        self
+    }
+
+    /// Fetch `path` and decode the JSON body. Decode errors name the field that failed
+    /// and quote the start of the body, since the API occasionally changes the shape
+    /// of its responses.
+    async fn get_json<T: DeserializeOwned>(&self, path: &str) -> anyhow::Result<T> {
+        let (body, status) = match &self.traffic {
+            Traffic::Playback(dir) => {
+                let file = dir.join(recording_name(path));
+                let body = std::fs::read_to_string(&file)
+                    .map_err(|e| anyhow::anyhow!("no recorded response for {} in {}: {}", path, file.display(), e))?;
+                (body, "recorded".to_string())
+            }
+            Traffic::Network | Traffic::Record(_) => {
+                let _slot = self.queue.acquire(queue::current()).await;
+                if let Some(retry_after) = self.rate_limited_for() {
+                    return Err(RateLimited { retry_after }.into());
+                }
+                let open_for = self.breaker.lock().unwrap().open_for(Instant::now());
+                if let Some(retry_in) = open_for {
+                    return self
+                        .cached(path)
+                        .or_else(|| self.stored(path))
+                        .unwrap_or_else(|| Err(Offline { retry_in }.into()));
+                }
+                let started = Instant::now();
+                let fetched = self.fetch(path).await;
+                self.record_outcome(fetched.is_ok(), started.elapsed());
+                if self.trace.load(Ordering::Relaxed) {
+                    let outcome = match &fetched {
+                        Ok((_, status)) => status.to_string(),
+                        Err(e) => e.to_string(),
+                    };
+                    eprintln!("  GET {} -> {} ({} ms)", path, outcome, started.elapsed().as_millis());
+                }
+                let (body, status) = match fetched {
+                    Ok(fetched) => {
+                        self.breaker.lock().unwrap().record(true, Instant::now());
+                        fetched
+                    }
+                    Err(e) if e.is::<RateLimited>() => return Err(e),
+                    Err(e) => {
+                        let tripped = {
+                            let mut breaker = self.breaker.lock().unwrap();
+                            breaker.record(false, Instant::now());
+                            breaker.tripped()
+                        };
+                        // Once the API looks down, show the last data we had rather than an
+                        // error; data saved on disk (e.g. prefetched) is used straight away
+                        return match self.cached(path) {
+                            Some(cached) if tripped => cached,
+                            _ => self.stored(path).unwrap_or(Err(e)),
+                        };
+                    }
+                };
+                if status == StatusCode::NOT_FOUND.to_string() {
+                    return Err(NotFound { path: path.to_string() }.into());
+                }
+                if let Traffic::Record(dir) = &self.traffic {
+                    std::fs::create_dir_all(dir)?;
+                    std::fs::write(dir.join(recording_name(path)), &body)?;
+                }
+                let value = parse_body(&body).map_err(|e| anyhow::anyhow!("unexpected response from {} ({}): {}", path, status, e))?;
+                if let Some(disk) = &self.disk {
+                    // A full disk shouldn't fail the request; the cache is only a fallback
+                    let _ = disk.put(path, &body);
+                }
+                self.cache.lock().unwrap().insert(path.to_string(), body);
+                return Ok(value);
+            }
+        };
+        parse_body(&body).map_err(|e| anyhow::anyhow!("unexpected response from {} ({}): {}", path, status, e))
+    }
+
+    // Send the request. Connection errors and 5xx responses count against the circuit
+    // breaker; 429/503 pause requests instead.
+    async fn fetch(&self, path: &str) -> anyhow::Result<(String, String)> {
+        let url = format!("{}{}", self.base_url, path);
         let response = self.client.get(&url).send().await?;
-        let basho = response.json::<Basho>().await?;
+        if matches!(response.status(), StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE) {
+            let retry_after = retry_after(response.headers(), chrono::Utc::now()).unwrap_or(DEFAULT_RETRY_AFTER);
+            *self.paused_until.lock().unwrap() = Some(Instant::now() + retry_after);
+            return Err(RateLimited { retry_after }.into());
+        }
+        if response.status().is_server_error() {
+            anyhow::bail!("{} returned {}", path, response.status());
+        }
+        let status = response.status().to_string();
+        let body = response.text().await?;
+        Ok((body, status))
+    }
+
+    // The last good response for `path`, decoded again
+    fn cached<T: DeserializeOwned>(&self, path: &str) -> Option<anyhow::Result<T>> {
+        let body = self.cache.lock().unwrap().get(path)?;
+        Some(parse_body(&body).map_err(|e| anyhow::anyhow!("unexpected cached response from {}: {}", path, e)))
+    }
+
+    /// The last response for `path` this session or in the disk cache, without touching
+    /// the network.
+    pub fn get_cached<T: DeserializeOwned>(&self, path: &str) -> Option<T> {
+        self.cached(path).or_else(|| self.stored(path))?.ok()
+    }
+
+    // The response for `path` saved in the disk cache, decoded
+    fn stored<T: DeserializeOwned>(&self, path: &str) -> Option<anyhow::Result<T>> {
+        let body = self.disk.as_ref()?.get(path)?;
+        Some(parse_body(&body).map_err(|e| anyhow::anyhow!("unexpected cached response from {}: {}", path, e)))
+    }
+
+    pub async fn get_basho(&self, basho_id: &str) -> anyhow::Result<Basho> {
+        let path = basho_path(basho_id);
+        let basho = self.get_json::<Basho>(&path).await?;
         Ok(basho)
     }
 
     pub async fn get_banzuke(&self, basho_id: &str, division: &str) -> anyhow::Result<BanzukeResponse> {
-        let url = format!("{}/api/basho/{}/banzuke/{}", self.base_url, basho_id, division);
-        let response = self.client.get(&url).send().await?;
-        let banzuke = response.json::<BanzukeResponse>().await?;
+        let path = banzuke_path(basho_id, division);
+        let banzuke = self.get_json::<BanzukeResponse>(&path).await?;
         Ok(banzuke)
     }
 
     pub async fn get_torikumi(&self, basho_id: &str, division: &str, day: u8) -> anyhow::Result<TorikumiResponse> {
-        let url = format!("{}/api/basho/{}/torikumi/{}/{}", self.base_url, basho_id, division, day);
-        let response = self.client.get(&url).send().await?;
-        let torikumi = response.json::<TorikumiResponse>().await?;
+        let path = torikumi_path(basho_id, division, day);
+        let torikumi = self.get_json::<TorikumiResponse>(&path).await?;
         Ok(torikumi)
     }
 
+    // A lookup saved on disk recently enough to reuse without a request
+    fn recent<T: DeserializeOwned>(&self, path: &str) -> Option<T> {
+        parse_body(&self.disk.as_ref()?.get_recent(path)?).ok()
+    }
+
+    /// Fetch a rikishi's profile, or reuse it when fetched before this session or
+    /// recently enough on disk.
     pub async fn get_rikishi(&self, rikishi_id: u32) -> anyhow::Result<RikishiDetails> {
-        let url = format!("{}/api/rikishi/{}", self.base_url, rikishi_id);
-        let response = self.client.get(&url).send().await?;
-        let rikishi = response.json::<RikishiDetails>().await?;
+        if let Some(rikishi) = self.lookups.lock().unwrap().rikishi.get(&rikishi_id) {
+            return Ok(rikishi.clone());
+        }
+        let path = format!("/api/rikishi/{}", rikishi_id);
+        let rikishi = match self.recent(&path) {
+            Some(rikishi) => rikishi,
+            None => self.get_json::<RikishiDetails>(&path).await?,
+        };
+        self.lookups.lock().unwrap().rikishi.insert(rikishi_id, rikishi.clone());
         Ok(rikishi)
     }
 
+    pub async fn get_rikishi_stats(&self, rikishi_id: u32) -> anyhow::Result<RikishiStats> {
+        let path = format!("/api/rikishi/{}/stats", rikishi_id);
+        let stats = self.get_json::<RikishiStats>(&path).await?;
+        Ok(stats)
+    }
+
+    /// Fetch profiles of all active rikishi (used for heya, shusshin and age columns).
+    pub async fn get_rikishi_list(&self) -> anyhow::Result<Vec<RikishiDetails>> {
+        let list = self.get_json::<RikishiListResponse>("/api/rikishis?limit=1000").await?;
+        Ok(list.records.unwrap_or_default())
+    }
+
+    /// Fetch every bout between two rikishi, or reuse them like [`Self::get_rikishi`].
     pub async fn get_head_to_head(&self, rikishi_id: u32, opponent_id: u32) -> anyhow::Result<HeadToHeadResponse> {
-        let url = format!("{}/api/rikishi/{}/matches/{}", self.base_url, rikishi_id, opponent_id);
-        let response = self.client.get(&url).send().await?;
-        let head_to_head = response.json::<HeadToHeadResponse>().await?;
+        if let Some(h2h) = self.lookups.lock().unwrap().head_to_head.get(&(rikishi_id, opponent_id)) {
+            return Ok(h2h.clone());
+        }
+        let path = format!("/api/rikishi/{}/matches/{}", rikishi_id, opponent_id);
+        let head_to_head = match self.recent(&path) {
+            Some(h2h) => h2h,
+            None => self.get_json::<HeadToHeadResponse>(&path).await?,
+        };
+        self.lookups.lock().unwrap().head_to_head.insert((rikishi_id, opponent_id), head_to_head.clone());
         Ok(head_to_head)
     }
 
+    /// Drop the reused head-to-head records of two rikishi, after they meet again.
+    pub fn forget_head_to_head(&self, rikishi_id: u32, opponent_id: u32) {
+        let mut lookups = self.lookups.lock().unwrap();
+        lookups.head_to_head.remove(&(rikishi_id, opponent_id));
+        lookups.head_to_head.remove(&(opponent_id, rikishi_id));
+    }
+
+    /// Fetch every bout of a rikishi's career, newest first, a page at a time.
+    pub async fn get_rikishi_matches(&self, rikishi_id: u32) -> anyhow::Result<Vec<HeadToHeadMatch>> {
+        const PAGE: usize = 1000;
+        let mut matches = Vec::new();
+        loop {
+            let path = format!("/api/rikishi/{}/matches?limit={}&skip={}", rikishi_id, PAGE, matches.len());
+            let page = self.get_json::<RikishiMatchesResponse>(&path).await?;
+            let records = page.records.unwrap_or_default();
+            let last = records.len() < PAGE || matches.len() + records.len() >= page.total as usize;
+            matches.extend(records);
+            if last {
+                return Ok(matches);
+            }
+        }
+    }
+
     /// Get the current basho ID based on today's date.
     ///
     /// This is deterministic and does not probe the network. It selects the most
@@ -258,6 +910,17 @@ impl SumoApi {
         }
     }
 
+    /// Get the venue a basho is held at from the month
+    pub fn get_basho_venue(month: u32) -> &'static str {
+        match month {
+            1 | 5 | 9 => "Ryogoku Kokugikan, Tokyo",
+            3 => "Edion Arena, Osaka",
+            7 => "IG Arena, Nagoya",
+            11 => "Fukuoka Kokusai Center, Fukuoka",
+            _ => "Unknown",
+        }
+    }
+
     /// Format basho ID as human readable date
     pub fn format_basho_date(basho_id: &str) -> String {
         if basho_id.len() != 6 {
@@ -307,20 +970,19 @@ impl SumoApi {
         // Same month: try to use API start date; if that fails, approximate as second Sunday.
         match self.get_basho(basho_id).await {
             Ok(basho) => {
-                if let Some(s) = basho.start_date.as_deref() {
-                    if s.len() >= 10 {
-                        if let Ok(start_date) = chrono::NaiveDate::parse_from_str(&s[..10], "%Y-%m-%d") {
-                            let days_since_start = (now - start_date).num_days();
-                            let day = if days_since_start < 0 {
-                                1
-                            } else if days_since_start > 14 {
-                                15
-                            } else {
-                                (days_since_start + 1) as u8
-                            };
-                            return Ok(day);
-                        }
-                    }
+                if let Some(s) = basho.start_date.as_deref()
+                    && s.len() >= 10
+                    && let Ok(start_date) = chrono::NaiveDate::parse_from_str(&s[..10], "%Y-%m-%d")
+                {
+                    let days_since_start = (now - start_date).num_days();
+                    let day = if days_since_start < 0 {
+                        1
+                    } else if days_since_start > 14 {
+                        15
+                    } else {
+                        (days_since_start + 1) as u8
+                    };
+                    return Ok(day);
                 }
                 // Fall through to approximation if parsing failed or missing data
             }
@@ -346,6 +1008,9 @@ impl SumoApi {
     }
 }
 
+/// Months in which a honbasho is held.
+pub const BASHO_MONTHS: [u32; 6] = [1, 3, 5, 7, 9, 11];
+
 /// Compute the most recent basho (year, month) for a given year and month.
 /// Basho months are fixed: 1, 3, 5, 7, 9, 11.
 fn most_recent_basho_ym(year: i32, month: u32) -> (i32, u32) {
@@ -356,8 +1021,7 @@ fn most_recent_basho_ym(year: i32, month: u32) -> (i32, u32) {
     }
 
     // Otherwise, pick the greatest basho month <= current month
-    let candidates = [1u32, 3, 5, 7, 9, 11];
-    if let Some(&m) = candidates.iter().filter(|&&m| m <= month).max() {
+    if let Some(&m) = BASHO_MONTHS.iter().filter(|&&m| m <= month).max() {
         (year, m)
     } else {
         // This should never happen for real calendar months, but keep a safe fallback
@@ -365,19 +1029,29 @@ fn most_recent_basho_ym(year: i32, month: u32) -> (i32, u32) {
     }
 }
 
+/// Id of the basho held before `basho_id`, e.g. "202501" -> "202411".
+pub fn previous_basho_id(basho_id: &str) -> Option<String> {
+    let year: i32 = basho_id.get(0..4)?.parse().ok()?;
+    let month: u32 = basho_id.get(4..6)?.parse().ok()?;
+    match BASHO_MONTHS.iter().rev().find(|&&m| m < month) {
+        Some(m) => Some(format!("{}{:02}", year, m)),
+        None => Some(format!("{}{:02}", year - 1, BASHO_MONTHS[BASHO_MONTHS.len() - 1])),
+    }
+}
+
 /// Approximate the basho start date as the second Sunday of a given month.
-fn approximate_basho_start(year: i32, month: u32) -> Option<chrono::NaiveDate> {
+pub fn approximate_basho_start(year: i32, month: u32) -> Option<chrono::NaiveDate> {
     let first = chrono::NaiveDate::from_ymd_opt(year, month, 1)?;
     let first_weekday_from_sun = first.weekday().num_days_from_sunday(); // 0..=6
     let days_to_first_sunday = (7 - first_weekday_from_sun) % 7; // 0..=6
-    let first_sunday_day = 1 + days_to_first_sunday as u32;
+    let first_sunday_day = 1 + days_to_first_sunday;
     let second_sunday_day = first_sunday_day + 7;
     chrono::NaiveDate::from_ymd_opt(year, month, second_sunday_day)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{most_recent_basho_ym, approximate_basho_start};
+    use super::{most_recent_basho_ym, approximate_basho_start, parse_body, recording_name, retry_after, previous_basho_id, ApiConfig, BanzukeEntry, Basho, Breaker, Health, Offline, MatchRecord, Responses, SumoApi, Traffic, TorikumiResponse};
 
     #[test]
     fn october_maps_to_september() {
@@ -410,4 +1084,215 @@ mod tests {
         let d = approximate_basho_start(2025, 9).unwrap();
         assert_eq!(d.to_string(), "2025-09-14");
     }
+
+    #[test]
+    fn win_loss_absent_counts_only_requested_days() {
+        let result = |r: &str| MatchRecord {
+            result: r.to_string(),
+            opponent_shikona_en: String::new(),
+            opponent_shikona_jp: String::new(),
+            kimarite: None,
+        };
+        let entry = BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: 1,
+            shikona_en: "Hoshoryu".into(),
+            shikona_jp: None,
+            rank_value: 101,
+            rank: "Yokozuna 1 East".to_string(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: Some(vec![result("win"), result("loss"), result("win"), result("absent")]),
+        };
+        assert_eq!(entry.win_loss_absent(15), (2, 1, 12));
+        assert_eq!(entry.win_loss_absent(2), (1, 1, 0));
+    }
+
+    #[test]
+    fn wins_losses_prefers_the_api_tally() {
+        let result = |r: &str| MatchRecord {
+            result: r.to_string(),
+            opponent_shikona_en: String::new(),
+            opponent_shikona_jp: String::new(),
+            kimarite: None,
+        };
+        let mut entry = BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: 1,
+            shikona_en: "Hoshoryu".into(),
+            shikona_jp: None,
+            rank_value: 101,
+            rank: "Yokozuna 1 East".to_string(),
+            record: Some(vec![result("win"), result("fusen-loss"), result("fusen-win"), result("absent"), result("")]),
+            wins: None,
+            losses: None,
+            absences: None,
+        };
+        // Fusen counts, and results that only look like wins or losses don't
+        assert_eq!(entry.wins_losses(15), (2, 1));
+        entry.record.as_mut().unwrap()[4] = result("winless?");
+        assert_eq!(entry.wins_losses(15), (2, 1));
+
+        (entry.wins, entry.losses, entry.absences) = (Some(2), Some(1), Some(2));
+        assert_eq!(entry.win_loss_absent(5), (2, 1, 2));
+        // Only part of the basho is revealed, so the tally doesn't apply
+        assert_eq!(entry.wins_losses(2), (1, 1));
+    }
+
+    #[test]
+    fn streak_counts_trailing_results() {
+        let result = |r: &str| MatchRecord {
+            result: r.to_string(),
+            opponent_shikona_en: String::new(),
+            opponent_shikona_jp: String::new(),
+            kimarite: None,
+        };
+        let entry = BanzukeEntry {
+            side: "West".to_string(),
+            rikishi_id: 2,
+            shikona_en: "Onosato".into(),
+            shikona_jp: None,
+            rank_value: 201,
+            rank: "Ozeki 1 West".to_string(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: Some(vec![result("loss"), result("win"), result("fusen-win"), result("loss"), result("")]),
+        };
+        assert_eq!(entry.streak(3), Some((true, 2)));
+        assert_eq!(entry.streak(5), Some((false, 1)));
+        assert_eq!(entry.streak(0), None);
+    }
+
+    #[test]
+    fn previous_basho_wraps_year() {
+        assert_eq!(previous_basho_id("202501").as_deref(), Some("202411"));
+        assert_eq!(previous_basho_id("202509").as_deref(), Some("202507"));
+    }
+
+    #[test]
+    fn decode_errors_name_the_field() {
+        let body = r#"{"torikumi":[{"id":"x","bashoId":"202509","division":"Makuuchi","day":"three"}]}"#;
+        let error = parse_body::<TorikumiResponse>(body).unwrap_err();
+        assert!(error.contains("at `torikumi[0].day`"), "{}", error);
+        assert!(error.contains("body: {\"torikumi\""), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn playback_serves_recorded_responses() {
+        let dir = std::env::temp_dir().join(format!("sumo-playback-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("api_basho_202509.json"), r#"{"date":"202509","location":"Tokyo"}"#).unwrap();
+
+        let api = SumoApi::with_config(&ApiConfig::default()).unwrap().with_traffic(Traffic::Playback(dir.clone()));
+        let basho = api.get_basho("202509").await.unwrap();
+        let missing = api.get_basho("202511").await;
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(basho.location.as_deref(), Some("Tokyo"));
+        assert!(missing.unwrap_err().to_string().contains("no recorded response for /api/basho/202511"));
+    }
+
+    #[tokio::test]
+    async fn lookups_are_reused_until_forgotten() {
+        let dir = std::env::temp_dir().join(format!("sumo-lookups-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let h2h = dir.join(recording_name("/api/rikishi/1/matches/2"));
+        std::fs::write(dir.join(recording_name("/api/rikishi/1")), r#"{"id":1,"shikonaEn":"Onosato","shikonaJp":"大の里"}"#).unwrap();
+        std::fs::write(&h2h, r#"{"total":0,"rikishiWins":0,"opponentWins":0,"matches":[]}"#).unwrap();
+
+        let api = SumoApi::with_config(&ApiConfig::default()).unwrap().with_traffic(Traffic::Playback(dir.clone()));
+        api.get_rikishi(1).await.unwrap();
+        api.get_head_to_head(1, 2).await.unwrap();
+        std::fs::remove_file(dir.join(recording_name("/api/rikishi/1"))).unwrap();
+        std::fs::remove_file(&h2h).unwrap();
+        let rikishi = api.get_rikishi(1).await;
+        let reused = api.get_head_to_head(1, 2).await;
+        api.forget_head_to_head(2, 1);
+        let forgotten = api.get_head_to_head(1, 2).await;
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(rikishi.unwrap().shikona_en, "Onosato");
+        assert!(reused.is_ok());
+        assert!(forgotten.is_err());
+    }
+
+    #[test]
+    fn retry_after_accepts_seconds_and_dates() {
+        use reqwest::header::HeaderMap;
+        use std::time::Duration;
+        let now = chrono::DateTime::parse_from_rfc3339("2025-09-20T07:00:00Z").unwrap().with_timezone(&chrono::Utc);
+        let headers = |name: &'static str, value: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(name, value.parse().unwrap());
+            headers
+        };
+        assert_eq!(retry_after(&headers("retry-after", "120"), now), Some(Duration::from_secs(120)));
+        assert_eq!(retry_after(&headers("retry-after", "Sat, 20 Sep 2025 07:00:30 GMT"), now), Some(Duration::from_secs(30)));
+        assert_eq!(retry_after(&headers("x-ratelimit-reset", "1758351645"), now), Some(Duration::from_secs(45)));
+        assert_eq!(retry_after(&HeaderMap::new(), now), None);
+    }
+
+    #[test]
+    fn breaker_opens_after_repeated_failures_and_closes_on_success() {
+        use std::time::{Duration, Instant};
+        let now = Instant::now();
+        let mut breaker = Breaker::default();
+        breaker.record(false, now);
+        breaker.record(false, now);
+        assert_eq!(breaker.open_for(now), None);
+        breaker.record(false, now);
+        assert!(breaker.tripped());
+        assert_eq!(breaker.open_for(now), Some(Duration::from_secs(30)));
+        assert_eq!(breaker.open_for(now + Duration::from_secs(30)), None);
+        breaker.record(true, now);
+        assert!(!breaker.tripped());
+    }
+
+    #[test]
+    fn memory_cache_drops_least_recently_used_bodies() {
+        let mut responses = Responses::new(10);
+        responses.insert("a".to_string(), "1234".to_string());
+        responses.insert("b".to_string(), "1234".to_string());
+        assert!(responses.get("a").is_some());
+        // "b" was used longest ago, so it makes room for "c"
+        responses.insert("c".to_string(), "1234".to_string());
+        assert_eq!((responses.get("a").is_some(), responses.get("b"), responses.size), (true, None, 8));
+        // Replacing a body counts only the new one; a body over the budget isn't kept
+        responses.insert("a".to_string(), "12".to_string());
+        responses.insert("d".to_string(), "12345678901".to_string());
+        assert_eq!((responses.get("d"), responses.size), (None, 6));
+    }
+
+    #[tokio::test]
+    async fn unreachable_api_serves_cached_responses() {
+        let config = ApiConfig { base_url: "http://127.0.0.1:1".to_string(), ..ApiConfig::default() };
+        let api = SumoApi::with_config(&config).unwrap();
+        api.cache.lock().unwrap().insert("/api/basho/202509".to_string(), r#"{"location":"Tokyo"}"#.to_string());
+
+        assert!(api.get_json::<Basho>("/api/basho/202509").await.is_err());
+        assert!(api.get_json::<Basho>("/api/basho/202509").await.is_err());
+        assert_eq!(api.health(), Some(Health::Degraded));
+        let basho = api.get_json::<Basho>("/api/basho/202509").await.unwrap();
+        assert_eq!(basho.location.as_deref(), Some("Tokyo"));
+        assert_eq!(api.health(), Some(Health::Offline));
+        // While the circuit is open nothing is sent: uncached paths fail straight away
+        assert!(api.get_json::<Basho>("/api/basho/202511").await.unwrap_err().is::<Offline>());
+    }
+
+    #[test]
+    fn health_reflects_failures_and_latency() {
+        use std::collections::VecDeque;
+        use std::time::Duration;
+        let fast = Duration::from_millis(200);
+        let mut recent = VecDeque::new();
+        assert_eq!(Health::from_recent(&recent, false), None);
+        recent.extend([(true, fast), (true, fast)]);
+        assert_eq!(Health::from_recent(&recent, false), Some(Health::Online));
+        recent.push_back((true, Duration::from_secs(10)));
+        assert_eq!(Health::from_recent(&recent, false), Some(Health::Degraded));
+        recent.pop_back();
+        recent.push_back((false, fast));
+        assert_eq!(Health::from_recent(&recent, false), Some(Health::Degraded));
+        assert_eq!(Health::from_recent(&recent, true), Some(Health::Offline));
+    }
 }
@@ -0,0 +1,157 @@
+use crate::api::{BanzukeEntry, MatchRecord, TorikumiEntry};
+use crate::config::{BanzukeColumn, TorikumiColumn};
+use crate::keymap::Action;
+use crate::tui::{self, App, AppView, Selection};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::time::{Duration, Instant};
+
+// Rikishi in the synthetic banzuke, about a full Jonidan
+const RIKISHI: u32 = 240;
+
+/// Render `frames` frames of each view into an off-screen `width`x`height` terminal
+/// with a synthetic full Jonidan banzuke and day of bouts, moving the selection down a
+/// row each frame, and report frame times.
+pub fn run(frames: usize, width: u16, height: u16) -> anyhow::Result<String> {
+    let mut app = synthetic_app();
+    let mut terminal = Terminal::new(TestBackend::new(width, height))?;
+    let mut report = format!(
+        "{} frames per view at {}x{}, {} rikishi, {} bouts\n",
+        frames,
+        width,
+        height,
+        RIKISHI,
+        RIKISHI / 2
+    );
+
+    for (name, view, action) in [
+        ("torikumi", AppView::Torikumi, Action::ViewTorikumi),
+        ("banzuke", AppView::Banzuke, Action::ViewBanzuke),
+    ] {
+        app.perform(action);
+        *app.selection_mut() = Selection::default();
+        let rows = if view == AppView::Torikumi { RIKISHI / 2 } else { RIKISHI } as usize;
+        let mut times = Vec::with_capacity(frames);
+        for _ in 0..frames {
+            // Switching views keeps the selection, so start over from the top by hand
+            if app.selection().index + 1 >= rows {
+                *app.selection_mut() = Selection::default();
+            } else {
+                app.perform(Action::Down);
+            }
+            let start = Instant::now();
+            terminal.draw(|f| tui::ui(f, &mut app))?;
+            times.push(start.elapsed());
+        }
+        report.push_str(&format!("{:<9} {}\n", name, summarize(&mut times)));
+    }
+    Ok(report)
+}
+
+// "mean 1.23ms  p50 1.10ms  p95 2.00ms  max 3.45ms"
+fn summarize(times: &mut [Duration]) -> String {
+    if times.is_empty() {
+        return "no frames".to_string();
+    }
+    times.sort();
+    let mean = times.iter().sum::<Duration>() / times.len() as u32;
+    let percentile = |p: usize| times[(times.len() - 1) * p / 100];
+    let ms = |d: Duration| format!("{:.2}ms", d.as_secs_f64() * 1000.0);
+    format!(
+        "mean {}  p50 {}  p95 {}  max {}",
+        ms(mean),
+        ms(percentile(50)),
+        ms(percentile(95)),
+        ms(times[times.len() - 1])
+    )
+}
+
+// An app with every torikumi and banzuke column shown and made-up data loaded
+fn synthetic_app() -> App {
+    let mut app = App::new("202509".to_string(), "Jonidan".to_string(), 7);
+    app.torikumi_columns = vec![
+        TorikumiColumn::MatchNo,
+        TorikumiColumn::East,
+        TorikumiColumn::West,
+        TorikumiColumn::RankDiff,
+        TorikumiColumn::WinProb,
+        TorikumiColumn::HeadToHead,
+        TorikumiColumn::Edge,
+        TorikumiColumn::Kimarite,
+    ];
+    app.banzuke_columns = vec![
+        BanzukeColumn::Rank,
+        BanzukeColumn::Shikona,
+        BanzukeColumn::ShikonaJp,
+        BanzukeColumn::Record,
+        BanzukeColumn::Streak,
+        BanzukeColumn::RankChange,
+    ];
+
+    let side = |id: u32| if id.is_multiple_of(2) { "East" } else { "West" };
+    let rank = |id: u32| format!("Jonidan {} {}", id / 2 + 1, side(id));
+    let shikona = |id: u32| format!("Synthetic{}yama", id);
+    let banzuke = (0..RIKISHI)
+        .map(|id| BanzukeEntry {
+            side: side(id).to_string(),
+            rikishi_id: id,
+            shikona_en: shikona(id).into(),
+            shikona_jp: Some(format!("合成{}山", id)),
+            rank_value: id,
+            rank: rank(id),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: Some(
+                (0..6)
+                    .map(|day| MatchRecord {
+                        result: if (id + day) % 3 == 0 { "loss" } else { "win" }.to_string(),
+                        opponent_shikona_en: shikona((id + day + 1) % RIKISHI),
+                        opponent_shikona_jp: String::new(),
+                        kimarite: Some("yorikiri".to_string()),
+                    })
+                    .collect(),
+            ),
+        })
+        .collect();
+    app.set_banzuke(banzuke);
+
+    let torikumi = (0..RIKISHI / 2)
+        .map(|bout| {
+            let (east, west) = (bout * 2, bout * 2 + 1);
+            // Leave the last few bouts undecided
+            let decided = bout < RIKISHI / 2 - 10;
+            TorikumiEntry {
+                id: format!("202509-7-{}", bout + 1),
+                basho_id: "202509".to_string(),
+                division: "Jonidan".to_string(),
+                day: 7,
+                match_no: (bout + 1) as u8,
+                east_id: east,
+                east_shikona: shikona(east).into(),
+                east_rank: rank(east),
+                west_id: west,
+                west_shikona: shikona(west).into(),
+                west_rank: rank(west),
+                kimarite: decided.then(|| "oshidashi".to_string()),
+                winner_id: decided.then_some(east),
+                winner_en: decided.then(|| shikona(east)),
+                winner_jp: None,
+            }
+        })
+        .collect();
+    app.set_torikumi(torikumi);
+    app
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+
+    #[test]
+    fn reports_both_views() {
+        let report = run(3, 100, 30).unwrap();
+        assert!(report.contains("torikumi  mean"), "{}", report);
+        assert!(report.contains("banzuke   mean"), "{}", report);
+    }
+}
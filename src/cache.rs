@@ -0,0 +1,338 @@
+use crate::config::CacheConfig;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+/// Bumped whenever the entry layout changes; entries of any other version are ignored
+/// and removed.
+const SCHEMA_VERSION: u32 = 1;
+
+/// One cached response, with what is needed to tell whether it can still be trusted.
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    schema: u32,
+    /// Request path the body answers; file names are sanitised and could collide
+    path: String,
+    fetched_at: DateTime<Utc>,
+    body: String,
+}
+
+/// What `sumo cache stats` reports.
+#[derive(Debug, Default, PartialEq)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub bytes: u64,
+    /// Entries past the configured age, removed the next time they are read
+    pub expired: usize,
+    /// Unreadable entries or ones written by another version
+    pub invalid: usize,
+    pub oldest: Option<DateTime<Utc>>,
+    pub newest: Option<DateTime<Utc>>,
+}
+
+/// API responses kept on disk between runs, one file per request path. Filled by every
+/// successful request and by `sumo prefetch`, and read when the API can't be reached.
+/// Reading an entry marks it used; once the cache outgrows `max_bytes` the least
+/// recently used entries are removed. Responses bigger than the whole cache are not kept.
+#[derive(Clone, Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    max_age: Option<Duration>,
+    lookup_max_age: Option<Duration>,
+    // Bytes in the directory, counted on the first write and kept up to date after, so
+    // writes don't have to list the directory
+    size: Arc<Mutex<Option<u64>>>,
+}
+
+impl DiskCache {
+    pub fn new(dir: impl Into<PathBuf>, config: &CacheConfig) -> Self {
+        Self {
+            dir: dir.into(),
+            max_bytes: config.max_size_mb.saturating_mul(1024 * 1024),
+            max_age: (config.max_age_days > 0).then(|| Duration::from_secs(config.max_age_days * 24 * 3600)),
+            lookup_max_age: (config.lookup_max_age_hours > 0).then(|| Duration::from_secs(config.lookup_max_age_hours * 3600)),
+            size: Arc::default(),
+        }
+    }
+
+    /// The configured directory, else `responses` in the platform cache directory (see
+    /// [`crate::paths::cache_dir`]).
+    pub fn default_location(config: &CacheConfig) -> Option<Self> {
+        let dir = match &config.dir {
+            Some(dir) => dir.clone(),
+            None => crate::paths::cache_dir()?.join("responses"),
+        };
+        Some(Self::new(dir, config))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+
+    fn file(&self, path: &str) -> PathBuf {
+        self.dir.join(crate::api::recording_name(path))
+    }
+
+    fn is_expired(&self, entry: &Entry, now: DateTime<Utc>) -> bool {
+        self.max_age
+            .is_some_and(|max_age| (now - entry.fetched_at).to_std().is_ok_and(|age| age > max_age))
+    }
+
+    /// The stored body for a request path. Entries that are unreadable, from another
+    /// schema version, for a different path or too old are removed instead.
+    pub fn get(&self, path: &str) -> Option<String> {
+        let file = self.file(path);
+        let contents = std::fs::read_to_string(&file).ok()?;
+        match serde_json::from_str::<Entry>(&contents) {
+            Ok(entry) if entry.schema == SCHEMA_VERSION && entry.path == path && !self.is_expired(&entry, Utc::now()) => {
+                // The modification time doubles as the last-used time for eviction
+                let _ = File::options().append(true).open(&file).and_then(|f| f.set_modified(SystemTime::now()));
+                Some(entry.body)
+            }
+            Ok(entry) if entry.path != path => None,
+            _ => {
+                self.remove(&file, contents.len() as u64);
+                None
+            }
+        }
+    }
+
+    /// The stored body for a request path if it was fetched within `lookup_max_age_hours`,
+    /// recent enough to use instead of asking the API.
+    pub fn get_recent(&self, path: &str) -> Option<String> {
+        let max_age = self.lookup_max_age?;
+        let entry = serde_json::from_str::<Entry>(&std::fs::read_to_string(self.file(path)).ok()?).ok()?;
+        let recent = (Utc::now() - entry.fetched_at).to_std().is_ok_and(|age| age <= max_age);
+        if recent { self.get(path) } else { None }
+    }
+
+    /// Store a response body, then evict if the cache has grown too big. Written to a
+    /// temporary file first, so an interrupted write never leaves a truncated entry behind.
+    pub fn put(&self, path: &str, body: &str) -> std::io::Result<()> {
+        self.put_at(path, body, Utc::now())
+    }
+
+    fn put_at(&self, path: &str, body: &str, fetched_at: DateTime<Utc>) -> std::io::Result<()> {
+        let entry = Entry { schema: SCHEMA_VERSION, path: path.to_string(), fetched_at, body: body.to_string() };
+        let contents = serde_json::to_string(&entry)?;
+        let len = contents.len() as u64;
+        // It would push every other entry out and still not fit
+        if len > self.max_bytes {
+            return Ok(());
+        }
+        std::fs::create_dir_all(&self.dir)?;
+        let file = self.file(path);
+        let replaced = std::fs::metadata(&file).map_or(0, |meta| meta.len());
+        let partial = file.with_extension("part");
+        std::fs::write(&partial, contents)?;
+        std::fs::rename(&partial, &file)?;
+
+        let mut size = self.size.lock().unwrap();
+        let total = match *size {
+            Some(total) => (total + len).saturating_sub(replaced),
+            None => self.scan_size()?,
+        };
+        *size = Some(self.evict(total));
+        Ok(())
+    }
+
+    // Bytes taken by the files in the directory
+    fn scan_size(&self) -> std::io::Result<u64> {
+        Ok(std::fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok()?.metadata().ok())
+            .filter(|meta| meta.is_file())
+            .map(|meta| meta.len())
+            .sum())
+    }
+
+    // Remove an entry of `len` bytes, best effort, keeping the running size in step
+    fn remove(&self, file: &Path, len: u64) {
+        if std::fs::remove_file(file).is_ok()
+            && let Some(total) = self.size.lock().unwrap().as_mut()
+        {
+            *total = total.saturating_sub(len);
+        }
+    }
+
+    // Remove least recently used entries until the `total` bytes in the cache fit in
+    // `max_bytes`, returning what is left. Entries that can't be removed are skipped.
+    fn evict(&self, mut total: u64) -> u64 {
+        if total <= self.max_bytes {
+            return total;
+        }
+        let Ok(dir) = std::fs::read_dir(&self.dir) else {
+            return total;
+        };
+        let mut files: Vec<(SystemTime, u64, PathBuf)> = dir
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let meta = entry.metadata().ok()?;
+                meta.is_file().then(|| (meta.modified().unwrap_or(SystemTime::UNIX_EPOCH), meta.len(), entry.path()))
+            })
+            .collect();
+        files.sort_by_key(|(used, _, _)| *used);
+        for (_, len, file) in files {
+            if total <= self.max_bytes {
+                break;
+            }
+            if std::fs::remove_file(&file).is_ok() {
+                total -= len.min(total);
+            }
+        }
+        total
+    }
+
+    /// IDs of the basho with any cached response, newest first. Read from the file names
+    /// alone, so expired entries still count.
+    pub fn basho_ids(&self) -> Vec<String> {
+        let Ok(dir) = std::fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut ids: Vec<String> = dir
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                let id = name.strip_prefix("api_basho_")?.get(..6)?;
+                id.bytes().all(|b| b.is_ascii_digit()).then(|| id.to_string())
+            })
+            .collect();
+        ids.sort_by(|a, b| b.cmp(a));
+        ids.dedup();
+        ids
+    }
+
+    /// Count and size the cached entries.
+    pub fn stats(&self) -> std::io::Result<CacheStats> {
+        let mut stats = CacheStats::default();
+        let dir = match std::fs::read_dir(&self.dir) {
+            Ok(dir) => dir,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(stats),
+            Err(e) => return Err(e),
+        };
+        let now = Utc::now();
+        for entry in dir {
+            let entry = entry?;
+            if !entry.metadata()?.is_file() {
+                continue;
+            }
+            stats.entries += 1;
+            stats.bytes += entry.metadata()?.len();
+            let parsed = std::fs::read_to_string(entry.path())
+                .ok()
+                .and_then(|contents| serde_json::from_str::<Entry>(&contents).ok())
+                .filter(|e| e.schema == SCHEMA_VERSION);
+            let Some(cached) = parsed else {
+                stats.invalid += 1;
+                continue;
+            };
+            if self.is_expired(&cached, now) {
+                stats.expired += 1;
+            }
+            stats.oldest = Some(stats.oldest.map_or(cached.fetched_at, |t| t.min(cached.fetched_at)));
+            stats.newest = Some(stats.newest.map_or(cached.fetched_at, |t| t.max(cached.fetched_at)));
+        }
+        Ok(stats)
+    }
+}
+
+/// `sumo cache stats` output.
+pub fn render_stats(cache: &DiskCache, stats: &CacheStats) -> String {
+    let megabytes = |bytes: u64| bytes as f64 / (1024.0 * 1024.0);
+    let time = |t: Option<DateTime<Utc>>| t.map_or_else(|| "-".to_string(), |t| t.format("%Y-%m-%d %H:%M UTC").to_string());
+    format!(
+        "Directory: {}\nEntries:   {} ({} expired, {} invalid)\nSize:      {:.1} MB of {:.0} MB\nOldest:    {}\nNewest:    {}\n",
+        cache.dir().display(),
+        stats.entries,
+        stats.expired,
+        stats.invalid,
+        megabytes(stats.bytes),
+        megabytes(cache.max_bytes()),
+        time(stats.oldest),
+        time(stats.newest),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DiskCache;
+    use crate::config::CacheConfig;
+    use chrono::{Duration, Utc};
+
+    fn temp_cache(name: &str, config: &CacheConfig) -> DiskCache {
+        let dir = std::env::temp_dir().join(format!("sumo-cache-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        DiskCache::new(dir, config)
+    }
+
+    #[test]
+    fn stores_bodies_by_request_path() {
+        let cache = temp_cache("paths", &CacheConfig::default());
+        assert_eq!(cache.get("/api/basho/202501"), None);
+        cache.put("/api/basho/202501", "{}").unwrap();
+        cache.put("/api/basho/202501/torikumi/Makuuchi/1", "[]").unwrap();
+        assert_eq!(cache.get("/api/basho/202501").as_deref(), Some("{}"));
+        assert_eq!(cache.get("/api/basho/202501/torikumi/Makuuchi/1").as_deref(), Some("[]"));
+        assert_eq!(cache.basho_ids(), vec!["202501".to_string()]);
+
+        // Corrupt and outdated entries are dropped rather than served
+        std::fs::write(cache.file("/api/basho/202501"), "{\"schema\":0}").unwrap();
+        assert_eq!(cache.get("/api/basho/202501"), None);
+        assert!(!cache.file("/api/basho/202501").exists());
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn expires_old_entries_and_evicts_the_least_recently_used() {
+        let config = CacheConfig { max_size_mb: 0, max_age_days: 30, ..CacheConfig::default() };
+        let mut cache = temp_cache("limits", &config);
+        cache.max_bytes = u64::MAX;
+        cache.put_at("/api/basho/202401", "old", Utc::now() - Duration::days(31)).unwrap();
+        cache.put("/api/basho/202501", "new").unwrap();
+        let stats = cache.stats().unwrap();
+        assert_eq!((stats.entries, stats.expired, stats.invalid), (2, 1, 0));
+        assert_eq!(cache.get("/api/basho/202401"), None);
+        assert_eq!(cache.get("/api/basho/202501").as_deref(), Some("new"));
+
+        // Room for two entries and a bit: reading the older one keeps it over the untouched one
+        let entry_len = std::fs::metadata(cache.file("/api/basho/202501")).unwrap().len();
+        cache.max_bytes = entry_len * 5 / 2;
+        cache.put("/api/basho/202505", "one").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert!(cache.get("/api/basho/202501").is_some());
+        cache.put("/api/basho/202507", "two").unwrap();
+        assert!(cache.get("/api/basho/202501").is_some());
+        assert_eq!(cache.get("/api/basho/202505"), None);
+        assert!(cache.get("/api/basho/202507").is_some());
+
+        // A response bigger than the whole cache is not kept, and pushes nothing out
+        cache.put("/api/basho/202509", &"x".repeat(entry_len as usize * 3)).unwrap();
+        assert_eq!(cache.get("/api/basho/202509"), None);
+        assert!(cache.get("/api/basho/202501").is_some());
+        assert!(cache.get("/api/basho/202507").is_some());
+        assert_eq!(*cache.size.lock().unwrap(), Some(cache.stats().unwrap().bytes));
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn recent_lookups_are_reused_within_their_age() {
+        let config = CacheConfig { lookup_max_age_hours: 24, ..CacheConfig::default() };
+        let cache = temp_cache("lookups", &config);
+        cache.put_at("/api/rikishi/1", "old", Utc::now() - Duration::hours(25)).unwrap();
+        cache.put("/api/rikishi/2", "new").unwrap();
+        assert_eq!(cache.get_recent("/api/rikishi/1"), None);
+        assert_eq!(cache.get_recent("/api/rikishi/2").as_deref(), Some("new"));
+        // Still there as a fallback for when the API is down
+        assert_eq!(cache.get("/api/rikishi/1").as_deref(), Some("old"));
+        // Off by default
+        let off = DiskCache::new(cache.dir(), &CacheConfig::default());
+        assert_eq!(off.get_recent("/api/rikishi/2"), None);
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+}
@@ -1,4 +1,7 @@
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Deserialize;
+use crate::exit::ErrorFormat;
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -11,16 +14,220 @@ pub struct Args {
     #[arg(short, long)]
     pub day: Option<u8>,
 
-    /// Division to show
-    #[arg(long, default_value = "makuuchi")]
-    pub division: Division,
+    /// Division to show (defaults to the configured division, then makuuchi)
+    #[arg(long)]
+    pub division: Option<Division>,
+
+    /// Basho, division and day in one, e.g. "202411 juryo d9" or "hatsu 2024 makuuchi";
+    /// overrides --basho, --division and --day
+    #[arg(long, value_name = "TARGET")]
+    pub goto: Option<String>,
 
     /// Show banzuke instead of daily results
     #[arg(long)]
     pub banzuke: bool,
+
+    /// Replay a finished basho day by day, starting at day 1 unless --day is given
+    #[arg(long)]
+    pub replay: bool,
+
+    /// Auto-advance the replay every N seconds (implies --replay)
+    #[arg(long, value_name = "SECS")]
+    pub replay_interval: Option<u64>,
+
+    /// Spoiler-free mode: hide winners, kimarite and records from this day onwards
+    #[arg(long, value_name = "DAY")]
+    pub spoiler_free: Option<u8>,
+
+    /// Live mode: periodically refresh the current day
+    #[arg(long)]
+    pub live: bool,
+
+    /// Reopen the basho, division, day and view shown when the TUI last exited; flags
+    /// given alongside it take precedence
+    #[arg(long)]
+    pub resume: bool,
+
+    /// Save every API response to this directory
+    #[arg(long, value_name = "DIR", conflicts_with = "playback")]
+    pub record: Option<PathBuf>,
+
+    /// Serve API responses saved with --record from this directory instead of the network
+    #[arg(long, value_name = "DIR")]
+    pub playback: Option<PathBuf>,
+
+    /// Show more startup output: -v adds where settings came from and load times,
+    /// -vv also logs every API request
+    #[arg(short, long, action = clap::ArgAction::Count, conflicts_with = "quiet")]
+    pub verbose: u8,
+
+    /// Print nothing but errors before the TUI starts and after it exits
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// How fatal errors are printed to stderr; `json` prints one object with the error
+    /// kind, exit code and message
+    #[arg(long, value_enum, default_value = "text", value_name = "FORMAT")]
+    pub errors: ErrorFormat,
+
+    /// Path to the config file (defaults to config.toml in the platform config directory)
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Drive the app with a script instead of the keyboard and print its state after each
+    /// step as a line of JSON. Each line of the script is an action by its `[keys]` name,
+    /// `key NAME` or `type TEXT`; `-` reads the script from stdin
+    #[arg(long, value_name = "SCRIPT", conflicts_with = "format")]
+    pub headless: Option<PathBuf>,
+
+    /// Output format; anything other than `tui` prints a report and exits. When stdout
+    /// is not a terminal, `tui` falls back to `text`
+    #[arg(long, value_enum, default_value = "tui")]
+    pub format: OutputFormat,
+
+    /// Fields to include with --format json, comma-separated (e.g. east,west,winner,kimarite)
+    #[arg(long, value_delimiter = ',', value_name = "FIELDS", requires = "format")]
+    pub fields: Vec<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl Args {
+    pub fn verbosity(&self) -> Verbosity {
+        match (self.quiet, self.verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            (false, _) => Verbosity::Debug,
+        }
+    }
+}
+
+/// How much is printed to stderr outside the TUI.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Errors only
+    Quiet,
+    /// Load progress and warnings
+    Normal,
+    /// Also where the basho, day and settings came from, and load times
+    Verbose,
+    /// Also every API request
+    Debug,
 }
 
-#[derive(Clone, Debug, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Interactive terminal UI
+    Tui,
+    /// Self-contained HTML page of the day's torikumi and standings, or of the
+    /// traditional banzuke with --banzuke
+    Html,
+    /// JSON array of the day's torikumi, or of the banzuke with --banzuke
+    Json,
+    /// The day's torikumi as aligned plain text, one bout per line, or the banzuke in the
+    /// traditional East | rank | West layout with --banzuke
+    Text,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Export the basho schedule for a year as an iCalendar (.ics) file
+    Ical {
+        /// Calendar year (defaults to the current year)
+        #[arg(long)]
+        year: Option<i32>,
+
+        /// Write the calendar to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Download a whole basho (banzuke and all fifteen days) into the disk cache for
+    /// offline use; an interrupted run resumes where it stopped
+    Prefetch {
+        /// Basho ID in YYYYMM format (defaults to the current basho)
+        #[arg(long)]
+        basho: Option<String>,
+
+        /// Divisions to fetch, comma-separated, or `all` (defaults to the configured
+        /// division, then makuuchi)
+        #[arg(long, value_delimiter = ',', value_name = "DIVISIONS")]
+        divisions: Vec<String>,
+    },
+
+    /// Inspect the on-disk response cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheCommand,
+    },
+
+    /// Check the API connection, cache directory, terminal and config, and print a
+    /// report to include with bug reports
+    Doctor,
+
+    /// Print the current basho's day, the configured division's leaders and the
+    /// favorites' results today, for a tmux status bar or waybar module
+    Status {
+        /// Everything on one line, parts separated by " | "
+        #[arg(long)]
+        one_line: bool,
+
+        /// Reuse the last status printed when it is younger than this many seconds
+        #[arg(long, value_name = "SECS", default_value_t = crate::statusline::DEFAULT_MAX_AGE_SECS)]
+        max_age: u64,
+    },
+
+    /// Write the day's results, yusho race and notable bouts as a plain-text digest, to
+    /// stdout, a file, or mailed through the configured sendmail command
+    Digest {
+        /// Basho ID in YYYYMM format (defaults to the current basho)
+        #[arg(long)]
+        basho: Option<String>,
+
+        /// Division (defaults to the configured division, then makuuchi)
+        #[arg(long)]
+        division: Option<Division>,
+
+        /// Day of the basho (defaults to the current day)
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Write the digest to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Mail the digest to `to` in the config's `[digest]` section
+        #[arg(long)]
+        mail: bool,
+    },
+
+    /// Render frames off-screen with a large synthetic dataset and report frame times
+    #[command(hide = true)]
+    Bench {
+        /// Frames to render per view
+        #[arg(long, default_value_t = 500)]
+        frames: usize,
+
+        /// Terminal width in columns
+        #[arg(long, default_value_t = 160)]
+        width: u16,
+
+        /// Terminal height in rows
+        #[arg(long, default_value_t = 50)]
+        height: u16,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommand {
+    /// Show the number, size and age of the cached responses
+    Stats,
+}
+
+#[derive(Clone, Debug, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Division {
     Makuuchi,
     Juryo,
@@ -42,3 +249,27 @@ impl std::fmt::Display for Division {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Args, Verbosity};
+    use clap::Parser;
+
+    #[test]
+    fn verbosity_flags() {
+        let verbosity = |args: &[&str]| Args::try_parse_from([&["sumo"], args].concat()).map(|a| a.verbosity());
+        assert_eq!(verbosity(&[]).unwrap(), Verbosity::Normal);
+        assert_eq!(verbosity(&["-q"]).unwrap(), Verbosity::Quiet);
+        assert_eq!(verbosity(&["-v"]).unwrap(), Verbosity::Verbose);
+        assert_eq!(verbosity(&["-vv"]).unwrap(), Verbosity::Debug);
+        assert_eq!(verbosity(&["-v", "-v", "-v"]).unwrap(), Verbosity::Debug);
+        assert!(verbosity(&["-q", "-v"]).is_err());
+    }
+
+    #[test]
+    fn fields_need_a_format() {
+        let parse = |args: &[&str]| Args::try_parse_from([&["sumo"], args].concat());
+        assert!(parse(&["--fields", "east,west"]).is_err());
+        assert_eq!(parse(&["--format", "json", "--fields", "east,west"]).unwrap().fields, ["east", "west"]);
+    }
+}
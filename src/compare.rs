@@ -0,0 +1,150 @@
+use crate::api::{HeadToHeadMatch, HeadToHeadResponse, RikishiDetails, RikishiStats, SumoApi};
+use futures::future::join_all;
+use std::collections::HashMap;
+
+/// Most rikishi the comparison view shows side by side.
+pub const MAX_COMPARED: usize = 4;
+
+/// Profiles and mutual head-to-heads fetched for the comparison view (career stats
+/// are shared with the records view and live in `App::career_stats`). Kept for the
+/// whole session, so marking another rikishi only fetches what is new.
+#[derive(Default)]
+pub struct Comparison {
+    pub profiles: HashMap<u32, RikishiDetails>,
+    // (rikishi, opponent) -> their bouts, as returned for the rikishi
+    pub head_to_head: HashMap<(u32, u32), HeadToHeadResponse>,
+}
+
+/// What still has to be fetched to compare a set of rikishi.
+#[derive(Debug, Default, PartialEq)]
+pub struct Missing {
+    pub profiles: Vec<u32>,
+    pub stats: Vec<u32>,
+    pub pairs: Vec<(u32, u32)>,
+}
+
+impl Missing {
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty() && self.stats.is_empty() && self.pairs.is_empty()
+    }
+}
+
+/// Results of one `fetch`; requests that failed are reported in `errors`.
+#[derive(Default)]
+pub struct Fetched {
+    pub profiles: Vec<RikishiDetails>,
+    pub stats: Vec<(u32, RikishiStats)>,
+    pub head_to_head: Vec<((u32, u32), HeadToHeadResponse)>,
+    pub errors: Vec<String>,
+}
+
+impl Comparison {
+    pub fn missing(&self, ids: &[u32], career_stats: &HashMap<u32, RikishiStats>) -> Missing {
+        let pairs = ids
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| ids[i + 1..].iter().map(move |b| (*a, *b)))
+            .filter(|(a, b)| !self.head_to_head.contains_key(&(*a, *b)) && !self.head_to_head.contains_key(&(*b, *a)))
+            .collect();
+        Missing {
+            profiles: ids.iter().copied().filter(|id| !self.profiles.contains_key(id)).collect(),
+            stats: ids.iter().copied().filter(|id| !career_stats.contains_key(id)).collect(),
+            pairs,
+        }
+    }
+
+    pub fn merge(&mut self, fetched: Fetched, career_stats: &mut HashMap<u32, RikishiStats>) {
+        self.profiles.extend(fetched.profiles.into_iter().map(|p| (p.id, p)));
+        career_stats.extend(fetched.stats);
+        self.head_to_head.extend(fetched.head_to_head);
+    }
+
+    /// Wins and losses of `rikishi` against `opponent`, counting only the bouts `shown`
+    /// lets through (hidden results stay hidden).
+    pub fn record_against(&self, rikishi: u32, opponent: u32, shown: impl Fn(&HeadToHeadMatch) -> bool) -> Option<(u32, u32)> {
+        let h2h = self
+            .head_to_head
+            .get(&(rikishi, opponent))
+            .or_else(|| self.head_to_head.get(&(opponent, rikishi)))?;
+        let mut record = (0, 0);
+        for m in h2h.matches.iter().filter(|m| shown(m)) {
+            match m.winner_id {
+                Some(winner) if winner == rikishi => record.0 += 1,
+                Some(winner) if winner == opponent => record.1 += 1,
+                _ => {}
+            }
+        }
+        Some(record)
+    }
+}
+
+/// Fetch everything in `missing` concurrently.
+pub async fn fetch(api: &SumoApi, missing: Missing) -> Fetched {
+    let profiles = join_all(missing.profiles.iter().map(|id| api.get_rikishi(*id)));
+    let stats = join_all(missing.stats.iter().map(|id| api.get_rikishi_stats(*id)));
+    let pairs = join_all(missing.pairs.iter().map(|(a, b)| api.get_head_to_head(*a, *b)));
+    let (profiles, stats, pairs) = futures::join!(profiles, stats, pairs);
+
+    let mut fetched = Fetched::default();
+    for result in profiles {
+        match result {
+            Ok(profile) => fetched.profiles.push(profile),
+            Err(e) => fetched.errors.push(e.to_string()),
+        }
+    }
+    for (id, result) in missing.stats.into_iter().zip(stats) {
+        match result {
+            Ok(stats) => fetched.stats.push((id, stats)),
+            Err(e) => fetched.errors.push(e.to_string()),
+        }
+    }
+    for (pair, result) in missing.pairs.into_iter().zip(pairs) {
+        match result {
+            Ok(h2h) => fetched.head_to_head.push((pair, h2h)),
+            Err(e) => fetched.errors.push(e.to_string()),
+        }
+    }
+    fetched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Comparison, Missing};
+    use crate::api::{HeadToHeadMatch, HeadToHeadResponse};
+    use std::collections::HashMap;
+
+    fn bout(basho_id: &str, winner: u32) -> HeadToHeadMatch {
+        HeadToHeadMatch {
+            basho_id: basho_id.to_string(),
+            division: "Makuuchi".to_string(),
+            day: 1,
+            match_no: 1,
+            east_id: 1,
+            east_shikona: "A".to_string(),
+            east_rank: "Y1e".to_string(),
+            west_id: 2,
+            west_shikona: "B".to_string(),
+            west_rank: "O1e".to_string(),
+            kimarite: Some("yorikiri".to_string()),
+            winner_id: Some(winner),
+            winner_en: None,
+            winner_jp: None,
+        }
+    }
+
+    #[test]
+    fn fetches_only_what_is_missing_and_counts_both_sides() {
+        let mut comparison = Comparison::default();
+        let matches = vec![bout("202501", 1), bout("202503", 2), bout("202505", 1)];
+        let h2h = HeadToHeadResponse { kimarite_losses: None, kimarite_wins: None, matches, opponent_wins: 1, rikishi_wins: 2, total: 3 };
+        comparison.head_to_head.insert((1, 2), h2h);
+
+        assert_eq!(
+            comparison.missing(&[2, 1, 3], &HashMap::new()),
+            Missing { profiles: vec![2, 1, 3], stats: vec![2, 1, 3], pairs: vec![(2, 3), (1, 3)] }
+        );
+        assert_eq!(comparison.record_against(1, 2, |_| true), Some((2, 1)));
+        assert_eq!(comparison.record_against(2, 1, |m| m.basho_id != "202505"), Some((1, 1)));
+        assert_eq!(comparison.record_against(1, 3, |_| true), None);
+    }
+}
@@ -0,0 +1,527 @@
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use crate::cli::Division;
+use crate::keymap::{Action, KeyList};
+use crate::theme::{ThemeColors, ThemeName};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// User configuration loaded from `config.toml`. Every field has a default so a
+/// missing file or a partial file is always valid.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Config {
+    pub refresh: RefreshConfig,
+    /// Division to open when neither `--division` nor `--resume` picks one
+    pub division: Option<Division>,
+    /// Units shown first for height and weight
+    pub units: Units,
+    /// Favorite rikishi by English shikona; the first nine are bound to Alt-1..Alt-9
+    pub favorites: Vec<String>,
+    /// Interface language (`en` or `ja`); defaults to the `LANG` environment
+    pub locale: Option<String>,
+    /// How long vowels are written in names and terms
+    pub romanization: crate::romaji::Romanization,
+    /// Show ranks in kanji (東前頭筆頭) in the banzuke and rikishi details
+    pub kanji_ranks: bool,
+    /// How basho and birth dates are written
+    pub date_format: crate::dates::DateFormat,
+    /// Show notices as static status text rather than popups that come and go
+    pub reduced_motion: bool,
+    pub torikumi: TorikumiConfig,
+    pub banzuke: BanzukeConfig,
+    pub navigation: NavigationConfig,
+    /// Built-in color theme
+    pub theme: ThemeName,
+    /// Per-role color overrides
+    pub colors: ThemeColors,
+    /// Styles for rikishi matching a rule, in the torikumi and banzuke
+    pub highlight: Vec<crate::highlight::HighlightRule>,
+    /// Key remapping: action name to one key or a list of keys
+    pub keys: HashMap<Action, KeyList>,
+    pub updates: UpdatesConfig,
+    pub hooks: HooksConfig,
+    pub digest: DigestConfig,
+    pub api: ApiConfig,
+    pub cache: CacheConfig,
+}
+
+/// Measurement units for rikishi details; the other system is shown in brackets.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Units {
+    #[default]
+    Metric,
+    Imperial,
+}
+
+/// Torikumi table layout.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct TorikumiConfig {
+    /// Columns to show, left to right
+    pub columns: Vec<TorikumiColumn>,
+    /// Order of the bouts when the TUI starts
+    pub order: TorikumiOrder,
+    /// Bouts shown when the TUI starts
+    pub filter: TorikumiFilter,
+}
+
+impl Default for TorikumiConfig {
+    fn default() -> Self {
+        Self {
+            columns: vec![TorikumiColumn::East, TorikumiColumn::West, TorikumiColumn::Kimarite],
+            order: TorikumiOrder::Match,
+            filter: TorikumiFilter::All,
+        }
+    }
+}
+
+/// Banzuke table layout.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BanzukeConfig {
+    /// Columns to show, left to right
+    pub columns: Vec<BanzukeColumn>,
+}
+
+impl Default for BanzukeConfig {
+    fn default() -> Self {
+        Self {
+            columns: vec![BanzukeColumn::Rank, BanzukeColumn::Shikona, BanzukeColumn::Record],
+        }
+    }
+}
+
+/// How the selection moves through lists, in every view.
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(default)]
+pub struct NavigationConfig {
+    /// Moving on from the last row goes to the first, and from the first to the last
+    pub wrap: bool,
+    /// Holding a movement key moves further with each repeat
+    pub accelerate: bool,
+    /// Where the selected row is kept as the list scrolls
+    pub scroll: ScrollMode,
+}
+
+/// Where the selected row sits in a scrolling list.
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScrollMode {
+    /// Scroll only once the selection reaches the edge of the window
+    #[default]
+    Edge,
+    /// Keep the selection in the middle of the window where the list allows
+    Center,
+}
+
+/// A column of the banzuke table, as named in `[banzuke] columns`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BanzukeColumn {
+    Rank,
+    Shikona,
+    ShikonaJp,
+    Record,
+    Streak,
+    Heya,
+    Shusshin,
+    Age,
+    RankChange,
+    Recent,
+}
+
+impl BanzukeColumn {
+    /// Whether the column needs rikishi profiles beyond what the banzuke carries.
+    pub fn needs_profile(self) -> bool {
+        matches!(self, BanzukeColumn::Heya | BanzukeColumn::Shusshin | BanzukeColumn::Age)
+    }
+}
+
+/// A column of the torikumi table, as named in `[torikumi] columns`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TorikumiColumn {
+    East,
+    West,
+    Kimarite,
+    MatchNo,
+    RankDiff,
+    WinProb,
+    HeadToHead,
+    Edge,
+}
+
+/// Order of the torikumi table, as named in `[torikumi] order`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TorikumiOrder {
+    /// Match order, musubi-no-ichiban last
+    #[default]
+    Match,
+    /// Musubi-no-ichiban first
+    Reverse,
+    /// By the higher rank in each bout, top rank first
+    Rank,
+}
+
+impl TorikumiOrder {
+    pub fn next(self) -> Self {
+        match self {
+            TorikumiOrder::Match => TorikumiOrder::Reverse,
+            TorikumiOrder::Reverse => TorikumiOrder::Rank,
+            TorikumiOrder::Rank => TorikumiOrder::Match,
+        }
+    }
+
+    /// i18n key of the order's display name.
+    pub fn label_key(self) -> &'static str {
+        match self {
+            TorikumiOrder::Match => "order.match",
+            TorikumiOrder::Reverse => "order.reverse",
+            TorikumiOrder::Rank => "order.rank",
+        }
+    }
+}
+
+/// Bouts the torikumi table shows, as named in `[torikumi] filter`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TorikumiFilter {
+    #[default]
+    All,
+    /// Bouts with a yokozuna, ozeki, sekiwake or komusubi on either side
+    Sanyaku,
+    /// Bouts between two maegashira
+    Maegashira,
+    /// Bouts with one of the favorites on either side
+    Favorites,
+    /// Bouts without a shown result yet
+    Unfinished,
+}
+
+impl TorikumiFilter {
+    pub fn next(self) -> Self {
+        match self {
+            TorikumiFilter::All => TorikumiFilter::Sanyaku,
+            TorikumiFilter::Sanyaku => TorikumiFilter::Maegashira,
+            TorikumiFilter::Maegashira => TorikumiFilter::Favorites,
+            TorikumiFilter::Favorites => TorikumiFilter::Unfinished,
+            TorikumiFilter::Unfinished => TorikumiFilter::All,
+        }
+    }
+
+    /// i18n key of the filter's display name.
+    pub fn label_key(self) -> &'static str {
+        match self {
+            TorikumiFilter::All => "filter.all",
+            TorikumiFilter::Sanyaku => "filter.sanyaku",
+            TorikumiFilter::Maegashira => "filter.maegashira",
+            TorikumiFilter::Favorites => "filter.favorites",
+            TorikumiFilter::Unfinished => "filter.unfinished",
+        }
+    }
+}
+
+/// Live-mode polling settings.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RefreshConfig {
+    /// Start with live auto-refresh enabled
+    pub enabled: bool,
+    /// Seconds between refreshes while bouts are being held
+    pub interval_secs: u64,
+    /// Seconds between refreshes when no basho is running
+    pub idle_interval_secs: u64,
+    /// Seconds without a key press, outside bout hours, before polling stops until the
+    /// next key (0 never stops)
+    pub low_power_after_secs: u64,
+}
+
+impl Default for RefreshConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: 60,
+            idle_interval_secs: 30 * 60,
+            low_power_after_secs: 10 * 60,
+        }
+    }
+}
+
+/// Shell commands run on live-mode events. Each gets the event's data in `SUMO_*`
+/// environment variables and as JSON on stdin.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Run for every new bout result
+    pub on_result: Option<String>,
+    /// Run for a new result involving one of the favorites
+    pub on_favorite_result: Option<String>,
+    /// Run once every bout of the day has a result
+    pub on_day_complete: Option<String>,
+}
+
+/// How `sumo digest --mail` sends the digest.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DigestConfig {
+    /// Command the message is piped to, headers included
+    pub sendmail: String,
+    /// Recipient addresses, comma-separated
+    pub to: Option<String>,
+    /// Sender address; left to the sendmail command when unset
+    pub from: Option<String>,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self { sendmail: "sendmail -t".to_string(), to: None, from: None }
+    }
+}
+
+/// Sumo API connection settings.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ApiConfig {
+    /// Base URL of the API, e.g. a private mirror
+    pub base_url: String,
+    /// API key sent in the `key_header` header; the `SUMO_API_KEY` environment variable takes precedence
+    pub key: Option<String>,
+    pub key_header: String,
+    /// Bearer token sent in the `Authorization` header; the `SUMO_API_TOKEN` environment variable takes precedence
+    pub token: Option<String>,
+    /// User-Agent sent with API requests instead of `sumo-tui/<version>`
+    pub user_agent: Option<String>,
+    /// Requests sent at once; more wait, the ones the user is waiting on first
+    pub max_concurrent_requests: usize,
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "https://www.sumo-api.com".to_string(),
+            key: None,
+            key_header: "X-API-Key".to_string(),
+            token: None,
+            user_agent: None,
+            max_concurrent_requests: 6,
+        }
+    }
+}
+
+impl ApiConfig {
+    /// The API key from the environment or the config file.
+    pub fn key(&self) -> Option<String> {
+        env_var("SUMO_API_KEY").or_else(|| self.key.clone())
+    }
+
+    /// The bearer token from the environment or the config file.
+    pub fn token(&self) -> Option<String> {
+        env_var("SUMO_API_TOKEN").or_else(|| self.token.clone())
+    }
+}
+
+/// Limits of the on-disk response cache.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// Directory for cached responses instead of the platform cache directory
+    pub dir: Option<PathBuf>,
+    /// Size in megabytes above which the least recently used entries are removed
+    pub max_size_mb: u64,
+    /// Days a cached response is trusted for; 0 keeps entries until evicted for space
+    pub max_age_days: u64,
+    /// Hours rikishi details and head-to-head records saved here are reused without
+    /// asking the API; 0 only reuses them within a session
+    pub lookup_max_age_hours: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self { dir: None, max_size_mb: 200, max_age_days: 90, lookup_max_age_hours: 0 }
+    }
+}
+
+/// User-Agent sent when none is configured.
+pub const DEFAULT_USER_AGENT: &str = concat!("sumo-tui/", env!("CARGO_PKG_VERSION"), " (+https://github.com/yu-eric/sumo)");
+
+// An environment variable's value, trimmed; unset and blank are the same
+fn env_var(name: &str) -> Option<String> {
+    trimmed(std::env::var(name).ok())
+}
+
+fn trimmed(value: Option<String>) -> Option<String> {
+    value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+}
+
+/// New-version check settings.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct UpdatesConfig {
+    /// Look up the latest release on crates.io at startup (at most once a day)
+    pub check: bool,
+}
+
+impl Default for UpdatesConfig {
+    fn default() -> Self {
+        Self { check: true }
+    }
+}
+
+impl Config {
+    /// Default location of the config file: `$SUMO_CONFIG`, else `config.toml` in the
+    /// platform config directory (see [`crate::paths::config_dir`]).
+    pub fn default_path() -> Option<PathBuf> {
+        if let Some(path) = env_var("SUMO_CONFIG") {
+            return Some(PathBuf::from(path));
+        }
+        Some(crate::paths::config_dir()?.join("config.toml"))
+    }
+
+    /// Load the config from `path`, or from the default location when `None`.
+    /// A missing file yields the default configuration.
+    pub fn load(path: Option<&Path>) -> anyhow::Result<Self> {
+        let path = match path {
+            Some(p) => p.to_path_buf(),
+            None => match Self::default_path() {
+                Some(p) => p,
+                None => return Ok(Self::default()),
+            },
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| anyhow::anyhow!("invalid config {}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(anyhow::anyhow!("could not read config {}: {}", path.display(), e)),
+        }
+    }
+}
+
+impl Config {
+    /// Override settings with `SUMO_*` environment variables. They sit between the
+    /// config file and the command line: flags still win.
+    pub fn apply_env(&mut self) -> anyhow::Result<()> {
+        self.apply_env_from(|name| std::env::var(name).ok())
+    }
+
+    fn apply_env_from(&mut self, env: impl Fn(&str) -> Option<String>) -> anyhow::Result<()> {
+        let var = |name: &'static str| trimmed(env(name)).map(|v| (name, v));
+        if let Some((name, v)) = var("SUMO_DIVISION") {
+            self.division = Some(env_value(name, &v)?);
+        }
+        if let Some((_, v)) = var("SUMO_LOCALE") {
+            self.locale = Some(v);
+        }
+        if let Some((name, v)) = var("SUMO_ROMANIZATION") {
+            self.romanization = env_value(name, &v)?;
+        }
+        if let Some((name, v)) = var("SUMO_UNITS") {
+            self.units = env_value(name, &v)?;
+        }
+        if let Some((name, v)) = var("SUMO_THEME") {
+            self.theme = env_value(name, &v)?;
+        }
+        if let Some((_, v)) = var("SUMO_FAVORITES") {
+            self.favorites = v.split(',').map(str::trim).filter(|f| !f.is_empty()).map(str::to_string).collect();
+        }
+        if let Some((name, v)) = var("SUMO_LIVE") {
+            self.refresh.enabled = match v.to_ascii_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => true,
+                "0" | "false" | "no" | "off" => false,
+                _ => anyhow::bail!("invalid {}={:?}: expected true or false", name, v),
+            };
+        }
+        if let Some((name, v)) = var("SUMO_REFRESH_INTERVAL") {
+            self.refresh.interval_secs = v.parse().map_err(|e| anyhow::anyhow!("invalid {}={:?}: {}", name, v, e))?;
+        }
+        if let Some((_, v)) = var("SUMO_API_URL") {
+            self.api.base_url = v;
+        }
+        if let Some((_, v)) = var("SUMO_USER_AGENT") {
+            self.api.user_agent = Some(v);
+        }
+        if let Some((_, v)) = var("SUMO_CACHE_DIR") {
+            self.cache.dir = Some(PathBuf::from(v));
+        }
+        Ok(())
+    }
+}
+
+// Parse an environment variable the way the same value would be read from the config file
+fn env_value<T: DeserializeOwned>(name: &str, value: &str) -> anyhow::Result<T> {
+    toml::Value::String(value.to_string())
+        .try_into()
+        .map_err(|e| anyhow::anyhow!("invalid {}={:?}: {}", name, value, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BanzukeColumn, Config, TorikumiColumn};
+
+    #[test]
+    fn partial_file_keeps_defaults() {
+        let config: Config = toml::from_str("[refresh]\ninterval_secs = 30\n").unwrap();
+        assert_eq!(config.refresh.interval_secs, 30);
+        assert_eq!(config.refresh.idle_interval_secs, 1800);
+        assert!(!config.refresh.enabled);
+        assert!(config.favorites.is_empty());
+        assert!(config.updates.check);
+    }
+
+    #[test]
+    fn favorites_keep_order() {
+        let config: Config = toml::from_str("favorites = [\"Onosato\", \"Hoshoryu\"]\n").unwrap();
+        assert_eq!(config.favorites, vec!["Onosato", "Hoshoryu"]);
+    }
+
+    #[test]
+    fn torikumi_columns_parse_in_order() {
+        let config: Config = toml::from_str("[torikumi]\ncolumns = [\"match_no\", \"west\", \"east\", \"win_prob\"]\n").unwrap();
+        assert_eq!(
+            config.torikumi.columns,
+            vec![TorikumiColumn::MatchNo, TorikumiColumn::West, TorikumiColumn::East, TorikumiColumn::WinProb]
+        );
+        assert_eq!(Config::default().torikumi.columns.len(), 3);
+    }
+
+    #[test]
+    fn banzuke_columns_parse() {
+        let config: Config = toml::from_str("[banzuke]\ncolumns = [\"rank\", \"shikona_jp\", \"rank_change\"]\n").unwrap();
+        assert_eq!(
+            config.banzuke.columns,
+            vec![BanzukeColumn::Rank, BanzukeColumn::ShikonaJp, BanzukeColumn::RankChange]
+        );
+    }
+
+    #[test]
+    fn api_section_keeps_default_header() {
+        let config: Config = toml::from_str("[api]\nbase_url = \"https://mirror.example\"\nkey = \"secret\"\n").unwrap();
+        assert_eq!(config.api.base_url, "https://mirror.example");
+        assert_eq!(config.api.key.as_deref(), Some("secret"));
+        assert_eq!(config.api.key_header, "X-API-Key");
+    }
+
+    #[test]
+    fn environment_overrides_the_file() {
+        let mut config: Config = toml::from_str("division = \"juryo\"\n[api]\nbase_url = \"https://mirror.example\"\n").unwrap();
+        let env = |name: &str| match name {
+            "SUMO_DIVISION" => Some("makushita".to_string()),
+            "SUMO_API_URL" => Some("http://localhost:8080".to_string()),
+            "SUMO_FAVORITES" => Some("Onosato, Hoshoryu".to_string()),
+            "SUMO_LIVE" => Some("yes".to_string()),
+            "SUMO_THEME" => Some(" ".to_string()),
+            _ => None,
+        };
+        config.apply_env_from(env).unwrap();
+        assert!(matches!(config.division, Some(crate::cli::Division::Makushita)));
+        assert_eq!(config.api.base_url, "http://localhost:8080");
+        assert_eq!(config.favorites, vec!["Onosato", "Hoshoryu"]);
+        assert!(config.refresh.enabled);
+        assert_eq!(config.theme, crate::theme::ThemeName::default());
+
+        let bad = |name: &str| (name == "SUMO_UNITS").then(|| "furlongs".to_string());
+        assert!(config.apply_env_from(bad).unwrap_err().to_string().starts_with("invalid SUMO_UNITS=\"furlongs\""));
+    }
+}
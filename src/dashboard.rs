@@ -0,0 +1,130 @@
+use crate::api::{BanzukeEntry, SumoApi, TorikumiEntry};
+use futures::future::join_all;
+
+/// A favorite rikishi as the dashboard shows them: where they are on this basho's banzuke
+/// and their bout on the loaded day.
+#[derive(Debug, Clone)]
+pub struct Tracked {
+    /// The favorite as configured
+    pub name: String,
+    /// Division and banzuke entry, when they are on this basho's banzuke
+    pub placing: Option<(String, BanzukeEntry)>,
+    /// Their bout on the loaded day, if they have one
+    pub bout: Option<TorikumiEntry>,
+}
+
+impl Tracked {
+    /// Opponent's shikona and rank in the day's bout.
+    pub fn opponent(&self) -> Option<(&str, &str)> {
+        let ((_, entry), bout) = (self.placing.as_ref()?, self.bout.as_ref()?);
+        Some(if bout.east_id == entry.rikishi_id {
+            (&bout.west_shikona, &bout.west_rank)
+        } else {
+            (&bout.east_shikona, &bout.east_rank)
+        })
+    }
+
+    /// Whether they won the day's bout, once it has a result.
+    pub fn won(&self) -> Option<bool> {
+        let ((_, entry), bout) = (self.placing.as_ref()?, self.bout.as_ref()?);
+        bout.winner_id.map(|winner| winner == entry.rikishi_id)
+    }
+}
+
+/// Find each favorite on the first division's banzuke that has them, matching shikona
+/// without regard to case, and attach their bout from the day's torikumi.
+pub fn locate(favorites: &[String], banzuke: &[(String, Vec<BanzukeEntry>)], torikumi: &[TorikumiEntry]) -> Vec<Tracked> {
+    favorites
+        .iter()
+        .map(|name| {
+            let placing = banzuke.iter().find_map(|(division, entries)| {
+                let entry = entries.iter().find(|e| e.shikona_en.eq_ignore_ascii_case(name))?;
+                Some((division.clone(), entry.clone()))
+            });
+            let bout = placing.as_ref().and_then(|(_, entry)| {
+                torikumi.iter().find(|b| b.east_id == entry.rikishi_id || b.west_id == entry.rikishi_id).cloned()
+            });
+            Tracked { name: name.clone(), placing, bout }
+        })
+        .collect()
+}
+
+/// Load the banzuke of every division, then the day's torikumi of the divisions the
+/// favorites are in.
+pub async fn fetch(api: &SumoApi, basho_id: &str, day: u8, divisions: &[&str], favorites: &[String]) -> anyhow::Result<Vec<Tracked>> {
+    let responses = join_all(divisions.iter().map(|division| api.get_banzuke(basho_id, division))).await;
+    let mut banzuke = Vec::new();
+    for (division, response) in divisions.iter().zip(responses) {
+        let response = response?;
+        banzuke.push((division.to_string(), response.east.into_iter().chain(response.west).collect::<Vec<_>>()));
+    }
+
+    let placed = locate(favorites, &banzuke, &[]);
+    let mut wanted: Vec<&str> = Vec::new();
+    for (division, _) in placed.iter().filter_map(|t| t.placing.as_ref()) {
+        if !wanted.contains(&division.as_str()) {
+            wanted.push(division);
+        }
+    }
+    let responses = join_all(wanted.iter().map(|division| api.get_torikumi(basho_id, division, day))).await;
+    let mut torikumi = Vec::new();
+    for response in responses {
+        torikumi.extend(response?.torikumi.unwrap_or_default());
+    }
+    Ok(locate(favorites, &banzuke, &torikumi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::locate;
+    use crate::api::{BanzukeEntry, TorikumiEntry};
+
+    fn entry(id: u32, shikona: &str, rank: &str) -> BanzukeEntry {
+        BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: id,
+            shikona_en: shikona.into(),
+            shikona_jp: None,
+            rank_value: 0,
+            rank: rank.to_string(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: None,
+        }
+    }
+
+    #[test]
+    fn finds_favorites_in_any_division() {
+        let banzuke = vec![
+            ("Makuuchi".to_string(), vec![entry(1, "Onosato", "Ozeki 1 East"), entry(2, "Hoshoryu", "Yokozuna 1 East")]),
+            ("Juryo".to_string(), vec![entry(3, "Enho", "Juryo 14 West")]),
+        ];
+        let torikumi = vec![TorikumiEntry {
+            id: "202501-8-1".to_string(),
+            basho_id: "202501".to_string(),
+            division: "Makuuchi".to_string(),
+            day: 8,
+            match_no: 20,
+            east_id: 2,
+            east_shikona: "Hoshoryu".into(),
+            east_rank: "Yokozuna 1 East".to_string(),
+            west_id: 1,
+            west_shikona: "Onosato".into(),
+            west_rank: "Ozeki 1 East".to_string(),
+            kimarite: Some("yorikiri".to_string()),
+            winner_id: Some(1),
+            winner_en: Some("Onosato".to_string()),
+            winner_jp: None,
+        }];
+        let favorites = ["onosato", "Enho", "Hakuho"].map(String::from);
+        let tracked = locate(&favorites, &banzuke, &torikumi);
+
+        assert_eq!(tracked[0].placing.as_ref().map(|(d, e)| (d.as_str(), e.rikishi_id)), Some(("Makuuchi", 1)));
+        assert_eq!(tracked[0].opponent(), Some(("Hoshoryu", "Yokozuna 1 East")));
+        assert_eq!(tracked[0].won(), Some(true));
+        assert_eq!(tracked[1].placing.as_ref().map(|(d, _)| d.as_str()), Some("Juryo"));
+        assert_eq!((tracked[1].opponent(), tracked[1].won()), (None, None));
+        assert!(tracked[2].placing.is_none());
+    }
+}
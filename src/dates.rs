@@ -0,0 +1,137 @@
+use crate::i18n::{locale, Locale};
+use chrono::{Datelike, NaiveDate};
+use serde::Deserialize;
+use std::sync::OnceLock;
+
+/// How basho and birth dates are written, as set by `date_format` in the config.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DateFormat {
+    /// January 2025, 1996-08-24
+    #[default]
+    Gregorian,
+    /// Japanese era years: 令和7年1月, or Reiwa 7 in English
+    Era,
+    /// Gregorian followed by the era form in brackets
+    Both,
+}
+
+static FORMAT: OnceLock<DateFormat> = OnceLock::new();
+
+/// Set the date format for the rest of the process. Only the first call has an effect.
+pub fn set_format(format: DateFormat) {
+    let _ = FORMAT.set(format);
+}
+
+pub fn format() -> DateFormat {
+    FORMAT.get().copied().unwrap_or_default()
+}
+
+struct Era {
+    kanji: &'static str,
+    romaji: &'static str,
+    start: (i32, u32, u32),
+}
+
+// Newest first
+const ERAS: &[Era] = &[
+    Era { kanji: "令和", romaji: "Reiwa", start: (2019, 5, 1) },
+    Era { kanji: "平成", romaji: "Heisei", start: (1989, 1, 8) },
+    Era { kanji: "昭和", romaji: "Showa", start: (1926, 12, 25) },
+    Era { kanji: "大正", romaji: "Taisho", start: (1912, 7, 30) },
+];
+
+// The era `date` falls in and its year within it (the first year is 1)
+fn era_year(date: NaiveDate) -> Option<(&'static Era, i32)> {
+    let era = ERAS.iter().find(|era| {
+        let (y, m, d) = era.start;
+        NaiveDate::from_ymd_opt(y, m, d).is_some_and(|start| date >= start)
+    })?;
+    Some((era, date.year() - era.start.0 + 1))
+}
+
+// "令和7年" (the first year is 元年) or "Reiwa 7"
+fn era_year_text(era: &Era, year: i32, locale: Locale) -> String {
+    match (locale, year) {
+        (Locale::Ja, 1) => format!("{}元年", era.kanji),
+        (Locale::Ja, _) => format!("{}{}年", era.kanji, year),
+        (Locale::En, _) => format!("{} {}", era.romaji, year),
+    }
+}
+
+// `gregorian` and `era` combined as `format` asks; the Gregorian form alone when there is
+// no era form
+fn combine(gregorian: String, era: Option<String>, format: DateFormat) -> String {
+    match (format, era) {
+        (DateFormat::Era, Some(era)) => era,
+        (DateFormat::Both, Some(era)) => format!("{} ({})", gregorian, era),
+        _ => gregorian,
+    }
+}
+
+fn format_basho_date(basho_id: &str, format: DateFormat, locale: Locale) -> String {
+    // A basho is held in the middle of its month, which decides the era when it changed
+    let date = basho_id
+        .get(0..4)
+        .zip(basho_id.get(4..6))
+        .filter(|_| basho_id.len() == 6)
+        .and_then(|(year, month)| NaiveDate::from_ymd_opt(year.parse().ok()?, month.parse().ok()?, 15));
+    let Some(date) = date else {
+        return basho_id.to_string();
+    };
+    let (gregorian, era) = match locale {
+        Locale::En => (
+            crate::api::SumoApi::format_basho_date(basho_id),
+            era_year(date).map(|(era, year)| format!("{} {}", date.format("%B"), era_year_text(era, year, locale))),
+        ),
+        Locale::Ja => (
+            format!("{}年{}月", date.year(), date.month()),
+            era_year(date).map(|(era, year)| format!("{}{}月", era_year_text(era, year, locale), date.month())),
+        ),
+    };
+    combine(gregorian, era, format)
+}
+
+fn format_date(text: &str, format: DateFormat, locale: Locale) -> String {
+    let day = text.split('T').next().unwrap_or(text);
+    let Ok(date) = NaiveDate::parse_from_str(day, "%Y-%m-%d") else {
+        return day.to_string();
+    };
+    let era = era_year(date).map(|(era, year)| match locale {
+        Locale::Ja => format!("{}{}月{}日", era_year_text(era, year, locale), date.month(), date.day()),
+        Locale::En => format!("{}, {}", date.format("%B %-d"), era_year_text(era, year, locale)),
+    });
+    combine(day.to_string(), era, format)
+}
+
+/// Year and month of a basho ID (YYYYMM) in the configured format and language.
+pub fn basho_date(basho_id: &str) -> String {
+    format_basho_date(basho_id, format(), locale())
+}
+
+/// A date from the API ("1996-08-24T00:00:00") without its time, in the configured format
+/// and language.
+pub fn date(text: &str) -> String {
+    format_date(text, format(), locale())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_basho_date, format_date, DateFormat};
+    use crate::i18n::Locale;
+
+    #[test]
+    fn writes_era_years() {
+        assert_eq!(format_basho_date("202501", DateFormat::Era, Locale::Ja), "令和7年1月");
+        assert_eq!(format_basho_date("202501", DateFormat::Both, Locale::En), "January 2025 (January Reiwa 7)");
+        // Reiwa began on 1 May 2019, between the March and May basho
+        assert_eq!(format_basho_date("201903", DateFormat::Era, Locale::Ja), "平成31年3月");
+        assert_eq!(format_basho_date("201905", DateFormat::Era, Locale::Ja), "令和元年5月");
+        assert_eq!(format_basho_date("202501", DateFormat::Gregorian, Locale::En), "January 2025");
+
+        assert_eq!(format_date("1996-08-24T00:00:00", DateFormat::Gregorian, Locale::Ja), "1996-08-24");
+        assert_eq!(format_date("1996-08-24T00:00:00", DateFormat::Era, Locale::Ja), "平成8年8月24日");
+        assert_eq!(format_date("1989-01-07", DateFormat::Both, Locale::En), "1989-01-07 (January 7, Showa 64)");
+        assert_eq!(format_date("unknown", DateFormat::Era, Locale::En), "unknown");
+    }
+}
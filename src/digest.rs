@@ -0,0 +1,146 @@
+use crate::config::DigestConfig;
+use crate::i18n::{t, tf};
+use crate::romaji::romanize;
+use crate::summary::DaySummary;
+use crate::tui::App;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+
+/// A day of the basho written up for reading away from the terminal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Digest {
+    pub subject: String,
+    pub body: String,
+}
+
+// A heading with the summary's lines under it, or "None"
+fn section(out: &mut String, key: &'static str, lines: Vec<String>) {
+    out.push('\n');
+    out.push_str(t(key));
+    out.push('\n');
+    if lines.is_empty() {
+        out.push_str(&format!("  {}\n", t("summary.none")));
+    }
+    for line in lines {
+        out.push_str(&format!("  {}\n", line));
+    }
+}
+
+/// The loaded day as a digest: every result, the yusho race, then the upsets, kinboshi
+/// and kimarite of the day.
+pub fn compose(app: &App) -> Digest {
+    let torikumi = app.torikumi.as_deref().unwrap_or_default();
+    let banzuke = app.banzuke.as_deref().unwrap_or_default();
+    let summary = DaySummary::compute(app.day, torikumi, banzuke, &app.record_map);
+
+    let mut body = crate::sheet::render_day_text(app);
+    let undecided = torikumi.iter().filter(|b| b.winner_id.is_none()).count();
+    if undecided > 0 && undecided < torikumi.len() {
+        body.push_str(&format!("\n{}\n", tf("digest.undecided", &[&undecided])));
+    }
+    body.push_str(&format!("\n{}{}{}{}\n", t("summary.bouts"), summary.bouts, t("summary.fusen"), summary.fusen));
+
+    let race = summary.race.iter().map(|c| format!("{}-{}  {}", c.wins, c.losses, romanize(&c.shikona)));
+    section(&mut body, "summary.race", race.collect());
+    let upsets = summary.upsets.iter().map(|u| {
+        format!(
+            "{} ({}){}{} ({})",
+            romanize(&u.winner),
+            romanize(&u.winner_rank),
+            t("summary.beat"),
+            romanize(&u.loser),
+            romanize(&u.loser_rank)
+        )
+    });
+    section(&mut body, "summary.upsets", upsets.collect());
+    let kinboshi = summary.kinboshi.iter().map(|(winner, yokozuna)| format!("{}{}{}", romanize(winner), t("summary.beat"), romanize(yokozuna)));
+    section(&mut body, "summary.kinboshi", kinboshi.collect());
+    let kimarite = summary.kimarite.iter().map(|(kimarite, count)| format!("{}: {}", kimarite, count));
+    section(&mut body, "summary.kimarite", kimarite.collect());
+
+    let month = app.basho_id.get(4..6).and_then(|m| m.parse().ok()).unwrap_or(0);
+    let subject = tf("digest.subject", &[&crate::i18n::basho_name(month), &romanize(&app.division), &app.day]);
+    Digest { subject, body }
+}
+
+// A header value as an RFC 2047 encoded word when it isn't plain ASCII
+fn encode_header(value: &str) -> String {
+    if value.bytes().all(|b| b.is_ascii_graphic() || b == b' ') {
+        return value.to_string();
+    }
+    let encoded: String = value
+        .bytes()
+        .map(|b| match b {
+            b' ' => "_".to_string(),
+            b if b.is_ascii_alphanumeric() => (b as char).to_string(),
+            b => format!("={:02X}", b),
+        })
+        .collect();
+    format!("=?UTF-8?Q?{}?=", encoded)
+}
+
+/// The digest as a mail message for `to`, headers first.
+pub fn message(digest: &Digest, to: &str, from: Option<&str>) -> String {
+    let mut message = format!("To: {}\n", to);
+    if let Some(from) = from {
+        message.push_str(&format!("From: {}\n", from));
+    }
+    message.push_str(&format!("Subject: {}\n", encode_header(&digest.subject)));
+    message.push_str("MIME-Version: 1.0\nContent-Type: text/plain; charset=utf-8\nContent-Transfer-Encoding: 8bit\n\n");
+    message.push_str(&digest.body);
+    message
+}
+
+/// Pipe the digest as a mail message to the configured sendmail command and wait for it.
+pub async fn send(config: &DigestConfig, digest: &Digest) -> anyhow::Result<()> {
+    let Some(to) = config.to.as_deref().filter(|to| !to.trim().is_empty()) else {
+        anyhow::bail!("--mail needs a recipient: set `to` in the [digest] section of the config");
+    };
+    let mut child = crate::hooks::shell(&config.sendmail)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("{}: {}", config.sendmail, e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(message(digest, to, config.from.as_deref()).as_bytes()).await?;
+    }
+    let status = child.wait().await?;
+    if !status.success() {
+        anyhow::bail!("{} failed ({})", config.sendmail, status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compose, message, Digest};
+    use crate::api::TorikumiEntry;
+    use crate::tui::App;
+
+    #[test]
+    fn digest_lists_results_and_events() {
+        let bout: TorikumiEntry = serde_json::from_value(serde_json::json!({
+            "id": "202509-3-1", "bashoId": "202509", "division": "Makuuchi", "day": 3, "matchNo": 1,
+            "eastId": 1, "eastShikona": "Hoshoryu", "eastRank": "Yokozuna 1 East",
+            "westId": 2, "westShikona": "Ura", "westRank": "Maegashira 3 West",
+            "kimarite": "hatakikomi", "winnerId": 2, "winnerEn": "Ura", "winnerJp": null,
+        }))
+        .unwrap();
+        let mut app = App::new("202509".to_string(), "Makuuchi".to_string(), 3);
+        app.set_torikumi(vec![bout]);
+
+        let digest = compose(&app);
+        assert_eq!(digest.subject, "Aki Basho Makuuchi Day 3");
+        assert!(digest.body.contains("● | ○ Ura"));
+        assert!(digest.body.contains("Kinboshi\n  Ura beat Hoshoryu\n"));
+        assert!(digest.body.contains("Most common kimarite\n  hatakikomi: 1\n"));
+    }
+
+    #[test]
+    fn mail_headers_come_first() {
+        let digest = Digest { subject: "秋場所 Day 3".to_string(), body: "Results\n".to_string() };
+        let mail = message(&digest, "me@example.com", None);
+        assert!(mail.starts_with("To: me@example.com\nSubject: =?UTF-8?Q?=E7=A7=8B=E5=A0=B4=E6=89=80_Day_3?=\n"));
+        assert!(mail.ends_with("8bit\n\nResults\n"));
+    }
+}
@@ -0,0 +1,246 @@
+use crate::api::SumoApi;
+use crate::cache::DiskCache;
+use crate::theme::{self, ColorSupport};
+use std::io::IsTerminal;
+use std::path::Path;
+use std::time::Instant;
+
+// Smallest terminal the TUI lays out properly in
+const MIN_COLUMNS: u16 = 80;
+const MIN_ROWS: u16 = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Ok,
+    Warn,
+    Fail,
+}
+
+/// The outcome of one diagnostic check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+}
+
+impl Check {
+    fn new(name: &'static str, status: Status, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Run every check. `config_error` is the error from loading the config file, which
+/// `sumo doctor` reports instead of failing on.
+pub async fn run(
+    api: &SumoApi,
+    config_path: Option<&Path>,
+    config_error: Option<&anyhow::Error>,
+    cache: Option<&DiskCache>,
+) -> Vec<Check> {
+    vec![
+        check_config(config_path, config_error),
+        check_api(api).await,
+        check_cache(cache),
+        check_terminal(),
+        check_colors(),
+        check_unicode(),
+    ]
+}
+
+fn check_config(path: Option<&Path>, error: Option<&anyhow::Error>) -> Check {
+    match (path, error) {
+        (_, Some(e)) => Check::new("Config", Status::Fail, format!("{:#}", e)),
+        (Some(path), None) if path.exists() => {
+            Check::new("Config", Status::Ok, path.display().to_string())
+        }
+        (Some(path), None) => Check::new(
+            "Config",
+            Status::Ok,
+            format!("{} not found, using defaults", path.display()),
+        ),
+        (None, None) => Check::new(
+            "Config",
+            Status::Warn,
+            "no config directory found, using defaults",
+        ),
+    }
+}
+
+async fn check_api(api: &SumoApi) -> Check {
+    // A cached response would hide a dead connection
+    let api = api.clone().without_disk_cache();
+    let basho_id = api.get_current_basho_id().await;
+    let started = Instant::now();
+    match api.get_basho(&basho_id).await {
+        Ok(_) => Check::new(
+            "API",
+            Status::Ok,
+            format!(
+                "{} answered in {} ms",
+                api.base_url(),
+                started.elapsed().as_millis()
+            ),
+        ),
+        Err(e) => Check::new("API", Status::Fail, format!("{}: {:#}", api.base_url(), e)),
+    }
+}
+
+fn check_cache(cache: Option<&DiskCache>) -> Check {
+    let Some(cache) = cache else {
+        return Check::new(
+            "Cache",
+            Status::Warn,
+            "disabled: no cache directory, or --playback in use",
+        );
+    };
+    let probe = cache.dir().join(".doctor");
+    let writable = std::fs::create_dir_all(cache.dir())
+        .and_then(|()| std::fs::write(&probe, b"ok"))
+        .and_then(|()| std::fs::remove_file(&probe));
+    match (writable, cache.stats()) {
+        (Err(e), _) => Check::new(
+            "Cache",
+            Status::Fail,
+            format!("{} is not writable: {}", cache.dir().display(), e),
+        ),
+        (Ok(()), Ok(stats)) if stats.invalid > 0 => Check::new(
+            "Cache",
+            Status::Warn,
+            format!(
+                "{} ({} entries, {} unreadable)",
+                cache.dir().display(),
+                stats.entries,
+                stats.invalid
+            ),
+        ),
+        (Ok(()), Ok(stats)) => Check::new(
+            "Cache",
+            Status::Ok,
+            format!("{} ({} entries)", cache.dir().display(), stats.entries),
+        ),
+        (Ok(()), Err(e)) => Check::new(
+            "Cache",
+            Status::Fail,
+            format!("{} could not be read: {}", cache.dir().display(), e),
+        ),
+    }
+}
+
+fn check_terminal() -> Check {
+    if !std::io::stdout().is_terminal() {
+        return Check::new(
+            "Terminal",
+            Status::Warn,
+            "stdout is not a terminal; sumo prints plain text instead of the TUI",
+        );
+    }
+    match crossterm::terminal::size() {
+        Ok((columns, rows)) if columns < MIN_COLUMNS || rows < MIN_ROWS => Check::new(
+            "Terminal",
+            Status::Warn,
+            format!(
+                "{}x{}, smaller than the {}x{} the TUI is laid out for",
+                columns, rows, MIN_COLUMNS, MIN_ROWS
+            ),
+        ),
+        Ok((columns, rows)) => Check::new("Terminal", Status::Ok, format!("{}x{}", columns, rows)),
+        Err(e) => Check::new(
+            "Terminal",
+            Status::Fail,
+            format!("could not read the size: {}", e),
+        ),
+    }
+}
+
+fn check_colors() -> Check {
+    if theme::no_color_requested() {
+        return Check::new(
+            "Colors",
+            Status::Ok,
+            "NO_COLOR is set; the monochrome theme is used",
+        );
+    }
+    match ColorSupport::detect() {
+        ColorSupport::TrueColor => Check::new("Colors", Status::Ok, "24-bit"),
+        ColorSupport::Ansi256 => Check::new("Colors", Status::Ok, "256 colors"),
+        ColorSupport::Ansi16 => Check::new(
+            "Colors",
+            Status::Warn,
+            "16 colors; set COLORTERM=truecolor if the terminal supports more",
+        ),
+    }
+}
+
+fn check_unicode() -> Check {
+    let var = |name| std::env::var(name).ok();
+    if unicode_locale(
+        var("LC_ALL").as_deref(),
+        var("LC_CTYPE").as_deref(),
+        var("LANG").as_deref(),
+    ) {
+        Check::new("Unicode", Status::Ok, "UTF-8 locale")
+    } else {
+        Check::new(
+            "Unicode",
+            Status::Warn,
+            "locale is not UTF-8; box drawing and Japanese names may not display",
+        )
+    }
+}
+
+// Whether the effective character encoding is UTF-8: LC_ALL overrides LC_CTYPE, which
+// overrides LANG; the first one set decides
+fn unicode_locale(lc_all: Option<&str>, lc_ctype: Option<&str>, lang: Option<&str>) -> bool {
+    [lc_all, lc_ctype, lang]
+        .into_iter()
+        .flatten()
+        .find(|v| !v.is_empty())
+        .is_some_and(|locale| {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
+
+/// One line per check, `✓`, `!` or `✗` first.
+pub fn render(checks: &[Check]) -> String {
+    let width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    checks
+        .iter()
+        .map(|check| {
+            let mark = match check.status {
+                Status::Ok => "✓",
+                Status::Warn => "!",
+                Status::Fail => "✗",
+            };
+            format!("{} {:<width$}  {}\n", mark, check.name, check.detail)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Check, Status, render, unicode_locale};
+
+    #[test]
+    fn reports_each_check_on_a_line() {
+        let checks = vec![
+            Check::new("Config", Status::Ok, "defaults"),
+            Check::new("API", Status::Fail, "unreachable"),
+            Check::new("Unicode", Status::Warn, "locale is not UTF-8"),
+        ];
+        assert_eq!(
+            render(&checks),
+            "✓ Config   defaults\n✗ API      unreachable\n! Unicode  locale is not UTF-8\n"
+        );
+
+        assert!(unicode_locale(None, None, Some("en_US.UTF-8")));
+        assert!(unicode_locale(Some("ja_JP.utf8"), None, Some("C")));
+        assert!(!unicode_locale(Some("C"), None, Some("en_US.UTF-8")));
+        assert!(!unicode_locale(Some(""), None, None));
+    }
+}
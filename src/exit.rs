@@ -0,0 +1,126 @@
+use crate::api::{NotFound, Offline, RateLimited};
+use clap::ValueEnum;
+
+/// What went wrong, which decides the process exit code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Failure {
+    /// Anything not covered below
+    Other,
+    /// Invalid command line arguments
+    BadArguments,
+    /// The API couldn't be reached, timed out, failed or is rate limiting us
+    Network,
+    /// The basho (or other resource) doesn't exist
+    NotFound,
+    /// The basho exists but has no data for the request yet
+    NoData,
+}
+
+impl Failure {
+    pub fn code(self) -> i32 {
+        match self {
+            Failure::Other => 1,
+            Failure::BadArguments => 2,
+            Failure::Network => 3,
+            Failure::NotFound => 4,
+            Failure::NoData => 5,
+        }
+    }
+
+    /// Name used in `--errors json` output.
+    pub fn name(self) -> &'static str {
+        match self {
+            Failure::Other => "error",
+            Failure::BadArguments => "bad_arguments",
+            Failure::Network => "network",
+            Failure::NotFound => "not_found",
+            Failure::NoData => "no_data",
+        }
+    }
+}
+
+/// An error that already knows which failure it is.
+#[derive(Debug)]
+pub struct Exit {
+    pub failure: Failure,
+    pub message: String,
+}
+
+impl Exit {
+    pub fn error(failure: Failure, message: impl Into<String>) -> anyhow::Error {
+        Exit { failure, message: message.into() }.into()
+    }
+}
+
+impl std::fmt::Display for Exit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Exit {}
+
+/// Classify an error by the first cause in its chain that says what went wrong.
+pub fn classify(error: &anyhow::Error) -> Failure {
+    for cause in error.chain() {
+        if let Some(exit) = cause.downcast_ref::<Exit>() {
+            return exit.failure;
+        }
+        if cause.is::<NotFound>() {
+            return Failure::NotFound;
+        }
+        if cause.is::<Offline>() || cause.is::<RateLimited>() || cause.is::<reqwest::Error>() {
+            return Failure::Network;
+        }
+        if cause.is::<clap::Error>() {
+            return Failure::BadArguments;
+        }
+    }
+    Failure::Other
+}
+
+/// How errors are printed to stderr, as picked by `--errors`.
+#[derive(Clone, Copy, Debug, PartialEq, Default, ValueEnum)]
+pub enum ErrorFormat {
+    /// A sentence for people
+    #[default]
+    Text,
+    /// One JSON object per error: `{"error": "network", "code": 3, "message": "..."}`
+    Json,
+}
+
+/// The line printed for a fatal error.
+pub fn render(error: &anyhow::Error, failure: Failure, format: ErrorFormat) -> String {
+    match format {
+        ErrorFormat::Text => format!("Error: {:#}", error),
+        ErrorFormat::Json => serde_json::json!({
+            "error": failure.name(),
+            "code": failure.code(),
+            "message": format!("{:#}", error).trim_end(),
+        })
+        .to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify, render, ErrorFormat, Exit, Failure};
+    use crate::api::{NotFound, Offline};
+    use std::time::Duration;
+
+    #[test]
+    fn classifies_errors_through_context() {
+        let offline = anyhow::Error::from(Offline { retry_in: Duration::from_secs(5) }).context("loading basho 202501");
+        assert_eq!(classify(&offline), Failure::Network);
+        let missing = anyhow::Error::from(NotFound { path: "/api/basho/209901".to_string() });
+        assert_eq!(classify(&missing), Failure::NotFound);
+        assert_eq!(classify(&anyhow::anyhow!("disk full")), Failure::Other);
+
+        let no_data = Exit::error(Failure::NoData, "no torikumi for day 3 yet");
+        assert_eq!(
+            render(&no_data, classify(&no_data), ErrorFormat::Json),
+            r#"{"error":"no_data","code":5,"message":"no torikumi for day 3 yet"}"#
+        );
+        assert_eq!(render(&no_data, Failure::NoData, ErrorFormat::Text), "Error: no torikumi for day 3 yet");
+    }
+}
@@ -0,0 +1,83 @@
+use crate::api::HeadToHeadMatch;
+use std::collections::HashMap;
+
+// Winning techniques listed for each basho
+const TOP_KIMARITE: usize = 3;
+
+/// How a rikishi's basho went up to a given day, from their bout history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BashoForm {
+    pub basho_id: String,
+    /// Rank in their bouts that basho; `None` when they had none by then
+    pub rank: Option<String>,
+    pub wins: u32,
+    pub losses: u32,
+    /// Winning techniques, most used first
+    pub kimarite: Vec<(String, u32)>,
+}
+
+/// The record of `rikishi_id` in `basho_id` over days 1 to `through_day`, from `matches`
+/// (their bout history).
+pub fn basho_form(rikishi_id: u32, matches: &[HeadToHeadMatch], basho_id: &str, through_day: u8) -> BashoForm {
+    let bouts: Vec<&HeadToHeadMatch> = matches
+        .iter()
+        .filter(|m| m.basho_id == basho_id && m.day <= through_day && (m.east_id == rikishi_id || m.west_id == rikishi_id))
+        .collect();
+    let rank = bouts.first().map(|m| if m.east_id == rikishi_id { m.east_rank.clone() } else { m.west_rank.clone() });
+    let wins: Vec<&&HeadToHeadMatch> = bouts.iter().filter(|m| m.winner_id == Some(rikishi_id)).collect();
+    let losses = bouts.iter().filter(|m| m.winner_id.is_some_and(|w| w != rikishi_id)).count();
+
+    let mut kimarite: HashMap<&str, u32> = HashMap::new();
+    for technique in wins.iter().filter_map(|m| m.kimarite.as_deref()).filter(|k| !k.is_empty()) {
+        *kimarite.entry(technique).or_insert(0) += 1;
+    }
+    let mut kimarite: Vec<(String, u32)> = kimarite.into_iter().map(|(k, n)| (k.to_string(), n)).collect();
+    kimarite.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    kimarite.truncate(TOP_KIMARITE);
+
+    BashoForm { basho_id: basho_id.to_string(), rank, wins: wins.len() as u32, losses: losses as u32, kimarite }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::basho_form;
+    use crate::api::HeadToHeadMatch;
+
+    fn bout(basho_id: &str, day: u8, winner: u32, kimarite: &str) -> HeadToHeadMatch {
+        HeadToHeadMatch {
+            basho_id: basho_id.to_string(),
+            division: "Makuuchi".to_string(),
+            day,
+            match_no: 1,
+            east_id: 1,
+            east_shikona: "Onosato".to_string(),
+            east_rank: if basho_id == "202501" { "Ozeki 1 East" } else { "Sekiwake 1 East" }.to_string(),
+            west_id: 2,
+            west_shikona: String::new(),
+            west_rank: String::new(),
+            kimarite: Some(kimarite.to_string()),
+            winner_id: Some(winner),
+            winner_en: None,
+            winner_jp: None,
+        }
+    }
+
+    #[test]
+    fn counts_a_basho_up_to_the_day() {
+        let matches = [
+            bout("202501", 1, 1, "yorikiri"),
+            bout("202501", 2, 2, "hatakikomi"),
+            bout("202411", 1, 1, "oshidashi"),
+            bout("202411", 2, 1, "yorikiri"),
+            bout("202411", 3, 1, "oshidashi"),
+            bout("202411", 4, 2, "uwatenage"),
+        ];
+        let now = basho_form(1, &matches, "202501", 2);
+        assert_eq!((now.rank.as_deref(), now.wins, now.losses), (Some("Ozeki 1 East"), 1, 1));
+        let before = basho_form(1, &matches, "202411", 2);
+        assert_eq!((before.rank.as_deref(), before.wins, before.losses), (Some("Sekiwake 1 East"), 2, 0));
+        assert_eq!(before.kimarite, [("oshidashi".to_string(), 1), ("yorikiri".to_string(), 1)]);
+        assert_eq!(basho_form(1, &matches, "202411", 15).kimarite[0], ("oshidashi".to_string(), 2));
+        assert_eq!(basho_form(1, &matches, "202409", 15).rank, None);
+    }
+}
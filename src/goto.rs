@@ -0,0 +1,147 @@
+use crate::i18n::{t, tf};
+
+/// Where a goto string such as `202411 juryo d9` or `hatsu 2024 makuuchi` points. Parts
+/// left out of the string stay as they are.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Goto {
+    pub basho_id: Option<String>,
+    /// One of [`crate::tui::DIVISIONS`]
+    pub division: Option<&'static str>,
+    pub day: Option<u8>,
+}
+
+/// Why a goto string couldn't be read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    Empty,
+    /// A word that is no basho, year, division or day
+    Unknown(String),
+    /// A year without the basho in it
+    YearOnly(i32),
+    /// A basho ID or year and month that names no basho
+    NoSuchBasho(String),
+    /// A day outside 1-15, or 16 for a playoff
+    BadDay(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Error::Empty => t("goto.empty").to_string(),
+            Error::Unknown(word) => tf("goto.unknown", &[word]),
+            Error::YearOnly(year) => tf("goto.year_only", &[year]),
+            Error::NoSuchBasho(basho) => tf("goto.no_basho", &[basho]),
+            Error::BadDay(day) => tf("goto.bad_day", &[day]),
+        };
+        f.write_str(&message)
+    }
+}
+
+// Names of each basho, by month: its nickname, where it is held and the month itself
+const BASHO_NAMES: &[(u32, &[&str])] = &[
+    (1, &["hatsu", "january", "jan", "初場所", "初"]),
+    (3, &["haru", "osaka", "march", "mar", "春場所", "春"]),
+    (5, &["natsu", "may", "夏場所", "夏"]),
+    (7, &["nagoya", "july", "jul", "名古屋場所", "名古屋"]),
+    (9, &["aki", "september", "sep", "sept", "秋場所", "秋"]),
+    (11, &["kyushu", "fukuoka", "november", "nov", "九州場所", "九州"]),
+];
+
+// Short names of the divisions, after the full ones
+const DIVISION_NAMES: &[(&str, &[&str])] = &[
+    ("Makuuchi", &["makuuchi", "m", "maku", "幕内"]),
+    ("Juryo", &["juryo", "j", "十両"]),
+    ("Makushita", &["makushita", "ms", "幕下"]),
+    ("Sandanme", &["sandanme", "sd", "三段目"]),
+    ("Jonidan", &["jonidan", "jd", "序二段"]),
+    ("Jonokuchi", &["jonokuchi", "jk", "序ノ口"]),
+];
+
+fn basho_month(word: &str) -> Option<u32> {
+    BASHO_NAMES.iter().find(|(_, names)| names.contains(&word)).map(|(month, _)| *month)
+}
+
+fn division(word: &str) -> Option<&'static str> {
+    DIVISION_NAMES.iter().find(|(_, names)| names.contains(&word)).map(|(name, _)| *name)
+}
+
+fn basho_id(year: i32, month: u32) -> Result<String, Error> {
+    let id = format!("{}{:02}", year, month);
+    if year < 1958 || month.is_multiple_of(2) || month > 11 {
+        return Err(Error::NoSuchBasho(id));
+    }
+    Ok(id)
+}
+
+/// Read a goto string, in any order and case: a basho as `YYYYMM`, or a basho name or
+/// month with an optional year (`year` when left out), a division, and a day as `d9`,
+/// `day 9` or a bare number up to 16, the playoff.
+pub fn parse(text: &str, year: i32) -> Result<Goto, Error> {
+    let text = text.to_lowercase();
+    let mut words = text.split(|c: char| c.is_whitespace() || c == ',').filter(|w| !w.is_empty());
+    let mut goto = Goto::default();
+    let (mut basho_year, mut month) = (None, None);
+    let day_number = |digits: &str| match digits.parse::<u8>() {
+        Ok(day) if (1..=crate::PLAYOFF_DAY).contains(&day) => Ok(day),
+        _ => Err(Error::BadDay(digits.to_string())),
+    };
+
+    while let Some(word) = words.next() {
+        let word = word.trim_end_matches("-basho").trim_end_matches("basho");
+        let digits = !word.is_empty() && word.bytes().all(|b| b.is_ascii_digit());
+        if word.is_empty() {
+            // "basho" on its own, as in "hatsu basho"
+        } else if digits && word.len() == 6 {
+            let (y, m) = (word[..4].parse().unwrap_or(0), word[4..].parse().unwrap_or(0));
+            goto.basho_id = Some(basho_id(y, m)?);
+        } else if digits && word.len() == 4 {
+            basho_year = word.parse().ok();
+        } else if digits {
+            goto.day = Some(day_number(word)?);
+        } else if let Some(m) = basho_month(word) {
+            month = Some(m);
+        } else if let Some(d) = division(word) {
+            goto.division = Some(d);
+        } else if word == "day" || word == "d" {
+            let next = words.next().ok_or_else(|| Error::Unknown(word.to_string()))?;
+            goto.day = Some(day_number(next)?);
+        } else if let Some(rest) = word.strip_prefix("day").or_else(|| word.strip_prefix('d'))
+            && rest.bytes().all(|b| b.is_ascii_digit())
+        {
+            goto.day = Some(day_number(rest)?);
+        } else {
+            return Err(Error::Unknown(word.to_string()));
+        }
+    }
+
+    match (month, basho_year) {
+        (Some(month), year_given) => goto.basho_id = Some(basho_id(year_given.unwrap_or(year), month)?),
+        (None, Some(year)) if goto.basho_id.is_none() => return Err(Error::YearOnly(year)),
+        (None, _) => {}
+    }
+    if goto == Goto::default() {
+        return Err(Error::Empty);
+    }
+    Ok(goto)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Error, Goto};
+
+    #[test]
+    fn reads_compound_targets() {
+        let goto = |basho: Option<&str>, division, day| Goto { basho_id: basho.map(str::to_string), division, day };
+        assert_eq!(parse("202411 juryo d9", 2025), Ok(goto(Some("202411"), Some("Juryo"), Some(9))));
+        assert_eq!(parse("Hatsu 2024 Makuuchi", 2025), Ok(goto(Some("202401"), Some("Makuuchi"), None)));
+        assert_eq!(parse("kyushu-basho day 3", 2025), Ok(goto(Some("202511"), None, Some(3))));
+        assert_eq!(parse("ms 5", 2025), Ok(goto(None, Some("Makushita"), Some(5))));
+        assert_eq!(parse("九州場所 2023 十両", 2025), Ok(goto(Some("202311"), Some("Juryo"), None)));
+
+        assert_eq!(parse("", 2025), Err(Error::Empty));
+        assert_eq!(parse("2024 juryo", 2025), Err(Error::YearOnly(2024)));
+        assert_eq!(parse("202412", 2025), Err(Error::NoSuchBasho("202412".to_string())));
+        assert_eq!(parse("d20", 2025), Err(Error::BadDay("20".to_string())));
+        assert_eq!(parse("juryo tomorrow", 2025), Err(Error::Unknown("tomorrow".to_string())));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::keymap::{parse_key, Action};
+use crate::tui::{App, AppView, InputMode, Popup, PopupStack};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Serialize;
+
+/// One line of a headless script.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    /// A normal-mode action by its `[keys]` name, e.g. `next_day` or `view_banzuke`
+    Action(Action),
+    /// `key Enter`, `key Ctrl-P`: a key pressed wherever the app is, prompts included
+    Key(KeyEvent),
+    /// `type 202411 juryo d9`: the text typed a character at a time
+    Type(String),
+}
+
+// "Ctrl-P", "Alt-1" or a key name as the `[keys]` config reads it
+fn parse_key_event(name: &str) -> Option<KeyEvent> {
+    let (modifiers, key) = match name.split_once('-') {
+        Some((modifier, key)) if !key.is_empty() && modifier.eq_ignore_ascii_case("ctrl") => (KeyModifiers::CONTROL, key),
+        Some((modifier, key)) if !key.is_empty() && modifier.eq_ignore_ascii_case("alt") => (KeyModifiers::ALT, key),
+        _ => (KeyModifiers::NONE, name),
+    };
+    let code = match parse_key(key)? {
+        KeyCode::Char(c) if modifiers == KeyModifiers::CONTROL => KeyCode::Char(c.to_ascii_lowercase()),
+        code => code,
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// Read a headless script: one step per line, blank lines and lines starting with `#`
+/// skipped. Errors name the line.
+pub fn parse(script: &str) -> Result<Vec<Step>, String> {
+    let mut steps = Vec::new();
+    for (number, line) in script.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (command, rest) = line.split_once(' ').map_or((line, ""), |(c, r)| (c, r.trim()));
+        let step = match command {
+            "key" => parse_key_event(rest).map(Step::Key),
+            "type" => Some(Step::Type(rest.to_string())),
+            name => toml::Value::String(name.to_string()).try_into().ok().filter(|_| rest.is_empty()).map(Step::Action),
+        };
+        steps.push(step.ok_or_else(|| format!("line {}: unknown step \"{}\"", number + 1, line))?);
+    }
+    Ok(steps)
+}
+
+/// Run one step through the same handlers the TUI feeds key presses to.
+pub fn apply(app: &mut App, step: &Step) {
+    match step {
+        Step::Action(action) => app.perform(*action),
+        Step::Key(key) => app.on_key_event(*key),
+        Step::Type(text) => {
+            for c in text.chars() {
+                app.on_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+            }
+        }
+    }
+}
+
+/// What a headless run prints after each step, one JSON object per line.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct State {
+    pub view: &'static str,
+    pub basho_id: String,
+    pub division: String,
+    pub day: u8,
+    /// Row selected in the current view's table
+    pub selected_index: usize,
+    /// The selected bout ("East - West") or banzuke rikishi
+    pub selected: Option<String>,
+    /// The popup on top of the others
+    pub popup: Option<&'static str>,
+    /// Open prompt, if any, and what has been typed into it
+    pub prompt: Option<&'static str>,
+    pub input: String,
+    pub error: Option<String>,
+    pub status: Option<String>,
+    pub quit: bool,
+}
+
+fn view_name(view: &AppView) -> &'static str {
+    match view {
+        AppView::Torikumi => "torikumi",
+        AppView::Banzuke => "banzuke",
+        AppView::BashoInfo => "info",
+        AppView::Compare => "compare",
+        AppView::Stats => "stats",
+        AppView::Records => "records",
+        AppView::Schedule => "schedule",
+        AppView::Journal => "journal",
+        AppView::Dashboard => "dashboard",
+        AppView::Matchups => "matchups",
+        AppView::YushoHistory => "yusho_history",
+        AppView::Settings => "settings",
+    }
+}
+
+fn prompt_name(mode: &InputMode) -> Option<&'static str> {
+    match mode {
+        InputMode::Normal => None,
+        InputMode::EditingDay => Some("day"),
+        InputMode::SelectingDivision => Some("division"),
+        InputMode::EditingBasho => Some("basho"),
+        InputMode::EditingSpoilerDay => Some("spoiler_day"),
+        InputMode::EnteringCommand => Some("command"),
+        InputMode::FindingBout => Some("find_bout"),
+        InputMode::JumpingToRank => Some("jump_to_rank"),
+        InputMode::WritingNote => Some("note"),
+        InputMode::Switching => Some("switcher"),
+        InputMode::EnteringGoto => Some("goto"),
+        InputMode::BindingKey => Some("bind_key"),
+    }
+}
+
+impl State {
+    pub fn from_app(app: &App) -> Self {
+        let selected = match app.current_view {
+            AppView::Torikumi => app.selected_bout().map(|b| format!("{} - {}", b.east_shikona, b.west_shikona)),
+            AppView::Banzuke => app.banzuke.as_ref().and_then(|b| b.get(app.selection().index)).map(|e| e.shikona_en.to_string()),
+            _ => None,
+        };
+        Self {
+            view: view_name(&app.current_view),
+            basho_id: app.basho_id.clone(),
+            division: app.division.clone(),
+            day: app.day,
+            selected_index: app.selection().index,
+            popup: PopupStack::open(app).top().map(Popup::name),
+            selected,
+            prompt: prompt_name(&app.input_mode),
+            input: app.input_buffer.clone(),
+            error: app.input_error.clone(),
+            status: app.status_message.clone(),
+            quit: app.should_quit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply, parse, State, Step};
+    use crate::keymap::Action;
+    use crate::tui::App;
+
+    #[test]
+    fn scripts_drive_the_app_like_keys() {
+        let script = "# jump to another basho\ngoto\ntype 202411 juryo d9\nkey Enter\n\nview_banzuke\nkey Ctrl-P\n";
+        let steps = parse(script).unwrap();
+        assert_eq!(steps[0], Step::Action(Action::Goto));
+        assert_eq!(steps.len(), 5);
+        assert_eq!(parse("goto\nfly_away").unwrap_err(), "line 2: unknown step \"fly_away\"");
+        assert!(parse("key Hyper-X").is_err());
+
+        let mut app = App::new("202501".into(), "Makuuchi".into(), 3);
+        let mut states = steps.iter().map(|step| {
+            apply(&mut app, step);
+            State::from_app(&app)
+        });
+        let typing = states.nth(1).unwrap();
+        assert_eq!((typing.prompt, typing.input.as_str()), (Some("goto"), "202411 juryo d9"));
+        let moved = states.next().unwrap();
+        assert_eq!((moved.basho_id.as_str(), moved.division.as_str(), moved.day, moved.prompt), ("202411", "Juryo", 9, None));
+        assert_eq!(states.next().unwrap().view, "banzuke");
+        assert_eq!(states.next().unwrap().prompt, Some("switcher"));
+    }
+}
@@ -0,0 +1,156 @@
+use crate::api::RikishiDetails;
+use crate::rank::Rank;
+use crate::theme::deserialize_color;
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Deserializer};
+
+/// A `[[highlight]]` rule from the config. Rikishi matching every condition the rule
+/// gives are drawn in its style in the torikumi and banzuke; a rule without conditions
+/// matches nobody.
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct HighlightRule {
+    /// English shikona, in any case
+    pub shikona: Option<String>,
+    /// Heya, with or without "-beya"
+    pub heya: Option<String>,
+    /// One rank ("O", "M1e") or a range from one rank down to another ("S-K", "M1-M5")
+    #[serde(deserialize_with = "deserialize_rank_pattern")]
+    pub rank: Option<RankPattern>,
+    /// Part of the shusshin (place of origin), e.g. "Mongolia" or "Aomori"
+    pub origin: Option<String>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub color: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub background: Option<Color>,
+    pub bold: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RankPattern {
+    /// The rank's tier, and its number and side where given
+    One(Rank),
+    /// Every rank from the first down to the second, both sides
+    Range(Rank, Rank),
+}
+
+impl RankPattern {
+    fn parse(text: &str) -> Option<Self> {
+        match text.split_once('-') {
+            Some((from, to)) => Some(RankPattern::Range(Rank::parse(from)?, Rank::parse(to)?)),
+            None => Rank::parse(text).map(RankPattern::One),
+        }
+    }
+
+    fn matches(self, rank: &Rank) -> bool {
+        match self {
+            RankPattern::One(pattern) => rank.matches(&pattern),
+            RankPattern::Range(from, to) => (from.ordinal()..=to.ordinal()).contains(&rank.ordinal()),
+        }
+    }
+}
+
+fn deserialize_rank_pattern<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<RankPattern>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    RankPattern::parse(&value)
+        .map(Some)
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid rank \"{}\"", value)))
+}
+
+// "Miyagino-beya", "miyagino" and "Miyagino" all name the same heya
+fn heya_name(heya: &str) -> String {
+    let heya = heya.trim().to_lowercase();
+    heya.trim_end_matches("beya").trim_end_matches(['-', ' ']).to_string()
+}
+
+impl HighlightRule {
+    /// Whether the rule can only be decided with the rikishi's profile.
+    pub fn needs_profile(&self) -> bool {
+        self.heya.is_some() || self.origin.is_some()
+    }
+
+    fn matches(&self, shikona: &str, rank: &str, profile: Option<&RikishiDetails>) -> bool {
+        if self.shikona.is_none() && self.heya.is_none() && self.rank.is_none() && self.origin.is_none() {
+            return false;
+        }
+        self.shikona.as_ref().is_none_or(|s| s.trim().eq_ignore_ascii_case(shikona))
+            && self.heya.as_ref().is_none_or(|h| {
+                profile.and_then(|p| p.heya.as_deref()).is_some_and(|heya| heya_name(heya) == heya_name(h))
+            })
+            && self.rank.is_none_or(|pattern| Rank::parse(rank).is_some_and(|r| pattern.matches(&r)))
+            && self.origin.as_ref().is_none_or(|o| {
+                profile
+                    .and_then(|p| p.shusshin.as_deref())
+                    .is_some_and(|shusshin| shusshin.to_lowercase().contains(&o.trim().to_lowercase()))
+            })
+    }
+
+    fn style(&self) -> Style {
+        let mut style = Style::default();
+        if let Some(color) = self.color {
+            style = style.fg(color);
+        }
+        if let Some(color) = self.background {
+            style = style.bg(color);
+        }
+        if self.bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        style
+    }
+}
+
+/// The combined style of every rule the rikishi matches, later rules drawn over earlier
+/// ones, or `None` when none match. Heya and origin only match once `profile` is known.
+pub fn style(rules: &[HighlightRule], shikona: &str, rank: &str, profile: Option<&RikishiDetails>) -> Option<Style> {
+    rules
+        .iter()
+        .filter(|rule| rule.matches(shikona, rank, profile))
+        .map(HighlightRule::style)
+        .reduce(|style, over| style.patch(over))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{style, HighlightRule};
+    use crate::api::RikishiDetails;
+    use crate::config::Config;
+    use ratatui::style::{Color, Modifier};
+
+    #[test]
+    fn rules_match_on_every_condition_given() {
+        let config: Config = toml::from_str(
+            "[[highlight]]\nheya = \"Miyagino-beya\"\ncolor = \"magenta\"\n\n\
+             [[highlight]]\nrank = \"Y-K\"\nbold = true\n\n\
+             [[highlight]]\nshikona = \"hakuoho\"\norigin = \"hokkaido\"\ncolor = \"cyan\"\n",
+        )
+        .unwrap();
+        let rules = config.highlight;
+        let profile = |heya: &str, shusshin: &str| RikishiDetails {
+            id: 1,
+            sumodb_id: None,
+            nsk_id: None,
+            shikona_en: String::new(),
+            shikona_jp: String::new(),
+            current_rank: None,
+            heya: Some(heya.to_string()),
+            birth_date: None,
+            shusshin: Some(shusshin.to_string()),
+            height: None,
+            weight: None,
+            debut: None,
+        };
+        let miyagino = profile("Miyagino", "Hokkaido, Sapporo");
+
+        let hakuoho = style(&rules, "Hakuoho", "Komusubi 1 West", Some(&miyagino)).unwrap();
+        assert_eq!(hakuoho.fg, Some(Color::Cyan));
+        assert!(hakuoho.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(style(&rules, "Hokuseiho", "Maegashira 5 East", Some(&miyagino)).unwrap().fg, Some(Color::Magenta));
+        // Heya and origin can't match before the profiles are loaded
+        assert_eq!(style(&rules, "Hakuoho", "Maegashira 1 East", None), None);
+        assert_eq!(style(&rules, "Onosato", "Maegashira 1 East", Some(&profile("Nishonoseki", "Ishikawa"))), None);
+
+        assert!(toml::from_str::<HighlightRule>("rank = \"Q3\"\n").is_err());
+        assert_eq!(style(&[HighlightRule { bold: true, ..Default::default() }], "Onosato", "Ozeki 1 East", None), None);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::api::TorikumiEntry;
+use crate::config::HooksConfig;
+use crate::tui::App;
+use serde_json::json;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+
+/// Run the configured commands for results that came in with a live refresh, given
+/// the day's bouts before the refresh. Returns errors from commands that couldn't start.
+pub fn run_live_hooks(hooks: &HooksConfig, app: &App, previous: &[TorikumiEntry]) -> Vec<String> {
+    let current = app.torikumi.as_deref().unwrap_or_default();
+    let mut errors = Vec::new();
+    for bout in crate::live::new_results(previous, current) {
+        if let Some(command) = &hooks.on_result {
+            errors.extend(spawn(command, "result", app, Some(bout)).err());
+        }
+        if let Some(command) = &hooks.on_favorite_result
+            && favorite_in(app, bout).is_some()
+        {
+            errors.extend(spawn(command, "favorite_result", app, Some(bout)).err());
+        }
+    }
+    let same_day = previous.first().is_some_and(|b| b.day == app.day);
+    if let Some(command) = &hooks.on_day_complete
+        && same_day
+        && !crate::live::day_complete(previous)
+        && crate::live::day_complete(current)
+    {
+        errors.extend(spawn(command, "day_complete", app, None).err());
+    }
+    errors
+}
+
+// The first favorite fighting in `bout`
+fn favorite_in<'a>(app: &'a App, bout: &TorikumiEntry) -> Option<&'a String> {
+    app.favorites
+        .iter()
+        .find(|name| bout.east_shikona.eq_ignore_ascii_case(name) || bout.west_shikona.eq_ignore_ascii_case(name))
+}
+
+/// Environment variables and JSON document handed to a hook command.
+fn payload(event: &str, app: &App, bout: Option<&TorikumiEntry>) -> (Vec<(&'static str, String)>, serde_json::Value) {
+    let mut env = vec![
+        ("SUMO_EVENT", event.to_string()),
+        ("SUMO_BASHO", app.basho_id.clone()),
+        ("SUMO_DIVISION", app.division.clone()),
+        ("SUMO_DAY", app.day.to_string()),
+    ];
+    let mut document = json!({
+        "event": event,
+        "basho_id": app.basho_id,
+        "division": app.division,
+        "day": app.day,
+    });
+    match bout {
+        Some(bout) => {
+            env.extend([
+                ("SUMO_EAST", bout.east_shikona.to_string()),
+                ("SUMO_EAST_RANK", bout.east_rank.clone()),
+                ("SUMO_WEST", bout.west_shikona.to_string()),
+                ("SUMO_WEST_RANK", bout.west_rank.clone()),
+                ("SUMO_WINNER", bout.winner_en.clone().unwrap_or_default()),
+                ("SUMO_KIMARITE", bout.kimarite.clone().unwrap_or_default()),
+            ]);
+            if let Some(favorite) = favorite_in(app, bout) {
+                env.push(("SUMO_FAVORITE", favorite.clone()));
+            }
+            document["bout"] = json!(bout);
+        }
+        None => document["torikumi"] = json!(app.torikumi.as_deref().unwrap_or_default()),
+    }
+    (env, document)
+}
+
+/// `command` run through the platform's shell.
+pub fn shell(command: &str) -> tokio::process::Command {
+    if cfg!(windows) {
+        let mut process = tokio::process::Command::new("cmd");
+        process.arg("/C").arg(command);
+        process
+    } else {
+        let mut process = tokio::process::Command::new("sh");
+        process.arg("-c").arg(command);
+        process
+    }
+}
+
+// Start the command through the shell without waiting for it; its output is discarded
+// so it can't draw over the TUI
+fn spawn(command: &str, event: &str, app: &App, bout: Option<&TorikumiEntry>) -> Result<(), String> {
+    let (env, document) = payload(event, app, bout);
+    let mut child = shell(command)
+        .envs(env)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("{}: {}", command, e))?;
+    let mut stdin = child.stdin.take();
+    tokio::spawn(async move {
+        if let Some(stdin) = stdin.as_mut() {
+            let _ = stdin.write_all(document.to_string().as_bytes()).await;
+        }
+        drop(stdin);
+        let _ = child.wait().await;
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::payload;
+    use crate::api::TorikumiEntry;
+    use crate::tui::App;
+
+    #[test]
+    fn payload_describes_the_bout() {
+        let bout: TorikumiEntry = serde_json::from_value(serde_json::json!({
+            "id": "202509-3-1", "bashoId": "202509", "division": "Makuuchi", "day": 3, "matchNo": 1,
+            "eastId": 1, "eastShikona": "Hoshoryu", "eastRank": "Yokozuna 1 East",
+            "westId": 2, "westShikona": "Onosato", "westRank": "Ozeki 1 West",
+            "kimarite": "yorikiri", "winnerId": 2, "winnerEn": "Onosato", "winnerJp": null,
+        }))
+        .unwrap();
+        let mut app = App::new("202509".to_string(), "Makuuchi".to_string(), 3);
+        app.favorites = vec!["onosato".to_string()];
+
+        let (env, document) = payload("favorite_result", &app, Some(&bout));
+        let var = |name: &str| env.iter().find(|(k, _)| *k == name).map(|(_, v)| v.as_str());
+        assert_eq!(var("SUMO_DAY"), Some("3"));
+        assert_eq!(var("SUMO_WINNER"), Some("Onosato"));
+        assert_eq!(var("SUMO_FAVORITE"), Some("onosato"));
+        assert_eq!(document["bout"]["kimarite"], "yorikiri");
+        assert_eq!(document["event"], "favorite_result");
+    }
+}
@@ -0,0 +1,222 @@
+use crate::api::{BanzukeEntry, SumoApi};
+use crate::romaji::romanize;
+use crate::sheet;
+use crate::tui::App;
+
+const STYLE: &str = "\
+body { font-family: system-ui, sans-serif; margin: 2rem auto; max-width: 60rem; color: #222; }
+h1 { font-size: 1.5rem; margin-bottom: 0.25rem; }
+h2 { font-size: 1.2rem; margin-top: 2rem; border-bottom: 2px solid #c9a227; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: 0.3rem 0.6rem; border-bottom: 1px solid #ddd; }
+th { background: #f4ecd0; }
+td.winner { background: #2e7d32; color: #fff; font-weight: bold; }
+td.east { text-align: right; }
+td.rank { text-align: center; color: #555; }
+tr.sanyaku td { font-weight: bold; }
+p.empty { color: #666; }
+footer { margin-top: 2rem; font-size: 0.8rem; color: #888; }
+";
+
+/// Render a self-contained HTML page with the loaded day's torikumi and the division
+/// standings, with records up to the end of that day.
+pub fn render_day_report(app: &App) -> String {
+    let basho_month: u32 = app.basho_id.get(4..6).and_then(|m| m.parse().ok()).unwrap_or(0);
+    let basho_name = SumoApi::get_basho_name(basho_month);
+    let basho_date = SumoApi::format_basho_date(&app.basho_id);
+    let title = romanize(&format!("{} - {} {} - Day {}", basho_name, basho_date, app.division, app.day)).into_owned();
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{}</title>\n", escape(&title)));
+    out.push_str(&format!("<style>\n{}</style>\n</head>\n<body>\n", STYLE));
+    out.push_str(&format!("<h1>{}</h1>\n", escape(&title)));
+
+    // Record entries up to the loaded day, of those replay or spoiler-free mode reveal
+    let through_day = |entry: &BanzukeEntry| app.bouts_through(entry, app.day).min(app.revealed_for(entry));
+    let banzuke = app.banzuke.as_deref().unwrap_or_default();
+    let record = |rikishi_id: u32| {
+        banzuke.iter().find(|e| e.rikishi_id == rikishi_id).map_or((0, 0), |e| e.wins_losses(through_day(e)))
+    };
+
+    // Torikumi
+    out.push_str("<h2>Daily Matches</h2>\n");
+    match app.torikumi.as_deref() {
+        Some(torikumi) if !torikumi.is_empty() => {
+            out.push_str("<table>\n<tr><th>East</th><th>West</th><th>Kimarite</th></tr>\n");
+            for m in torikumi {
+                let (ew, el) = record(m.east_id);
+                let (ww, wl) = record(m.west_id);
+                let east = romanize(&format!("{} ({}) ({}-{})", m.east_shikona, m.east_rank, ew, el)).into_owned();
+                let west = romanize(&format!("{} ({}) ({}-{})", m.west_shikona, m.west_rank, ww, wl)).into_owned();
+                // Replay and spoiler-free mode leave hidden results out
+                let winner = if app.bout_hidden(m) { None } else { m.winner_id };
+                let east_class = if winner == Some(m.east_id) { " class=\"winner\"" } else { "" };
+                let west_class = if winner == Some(m.west_id) { " class=\"winner\"" } else { "" };
+                let kimarite = if app.bout_hidden(m) { "" } else { m.kimarite.as_deref().unwrap_or("") };
+                out.push_str(&format!(
+                    "<tr><td{}>{}</td><td{}>{}</td><td>{}</td></tr>\n",
+                    east_class,
+                    escape(&east),
+                    west_class,
+                    escape(&west),
+                    escape(kimarite),
+                ));
+            }
+            out.push_str("</table>\n");
+        }
+        _ => out.push_str("<p class=\"empty\">No matches available for the selected day.</p>\n"),
+    }
+
+    // Standings
+    out.push_str("<h2>Standings</h2>\n");
+    match banzuke {
+        [] => out.push_str("<p class=\"empty\">No banzuke available.</p>\n"),
+        _ => {
+            out.push_str("<table>\n<tr><th>Rank</th><th>Wrestler</th><th>Result</th></tr>\n");
+            for entry in banzuke {
+                let (wins, losses, absent) = entry.win_loss_absent(through_day(entry));
+                out.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}-{}-{}</td></tr>\n",
+                    escape(&romanize(&entry.rank)),
+                    escape(&romanize(&entry.shikona_en)),
+                    wins,
+                    losses,
+                    absent,
+                ));
+            }
+            out.push_str("</table>\n");
+        }
+    }
+
+    out.push_str(&format!(
+        "<footer>Generated {} from sumo-api.com</footer>\n</body>\n</html>\n",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M UTC")
+    ));
+    out
+}
+
+/// Render a self-contained HTML page with the banzuke in the traditional layout: East,
+/// rank and West side by side, sanyaku at the top.
+pub fn render_banzuke(app: &App) -> String {
+    let basho_month: u32 = app.basho_id.get(4..6).and_then(|m| m.parse().ok()).unwrap_or(0);
+    let basho_name = SumoApi::get_basho_name(basho_month);
+    let basho_date = SumoApi::format_basho_date(&app.basho_id);
+    let title = romanize(&format!("{} - {} {} Banzuke", basho_name, basho_date, app.division)).into_owned();
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{}</title>\n", escape(&title)));
+    out.push_str(&format!("<style>\n{}</style>\n</head>\n<body>\n", STYLE));
+    out.push_str(&format!("<h1>{}</h1>\n", escape(&title)));
+
+    match app.banzuke.as_deref() {
+        Some(banzuke) if !banzuke.is_empty() => {
+            out.push_str("<table>\n<tr><th class=\"east\">East</th><th class=\"rank\">Rank</th><th>West</th></tr>\n");
+            for row in sheet::rows(banzuke) {
+                out.push_str(&format!(
+                    "<tr{}><td class=\"east\">{}</td><td class=\"rank\">{}</td><td>{}</td></tr>\n",
+                    if row.sanyaku { " class=\"sanyaku\"" } else { "" },
+                    escape(row.east.as_deref().unwrap_or("")),
+                    escape(&row.rank),
+                    escape(row.west.as_deref().unwrap_or("")),
+                ));
+            }
+            out.push_str("</table>\n");
+        }
+        _ => out.push_str("<p class=\"empty\">No banzuke available.</p>\n"),
+    }
+
+    out.push_str(&format!(
+        "<footer>Generated {} from sumo-api.com</footer>\n</body>\n</html>\n",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M UTC")
+    ));
+    out
+}
+
+// Escape text for inclusion in HTML element content and attribute values
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, render_day_report};
+    use crate::api::{BanzukeEntry, MatchRecord};
+    use crate::tui::App;
+
+    #[test]
+    fn escapes_markup() {
+        assert_eq!(escape("<b>\"A&B\"</b>"), "&lt;b&gt;&quot;A&amp;B&quot;&lt;/b&gt;");
+    }
+
+    #[test]
+    fn empty_day_renders_placeholders() {
+        let app = App::new("202509".to_string(), "Makuuchi".to_string(), 3);
+        let html = render_day_report(&app);
+        assert!(html.contains("<h1>Aki Basho - September 2025 Makuuchi - Day 3</h1>"));
+        assert!(html.contains("No matches available"));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn hidden_results_stay_out_of_the_report() {
+        let mut app = App::new("202509".to_string(), "Makuuchi".to_string(), 3);
+        app.set_torikumi(vec![serde_json::from_value(serde_json::json!({
+            "id": "202509-3-1", "bashoId": "202509", "division": "Makuuchi", "day": 3, "matchNo": 1,
+            "eastId": 1, "eastShikona": "Hoshoryu", "eastRank": "Yokozuna 1 East",
+            "westId": 2, "westShikona": "Onosato", "westRank": "Ozeki 1 West",
+            "kimarite": "yorikiri", "winnerId": 2, "winnerEn": "Onosato", "winnerJp": null,
+        }))
+        .unwrap()]);
+        assert!(render_day_report(&app).contains("<td class=\"winner\">Onosato (Ozeki 1 West) (0-0)</td><td>yorikiri</td>"));
+
+        app.set_spoiler_from(Some(3));
+        let html = render_day_report(&app);
+        assert!(html.contains("<td>Onosato (Ozeki 1 West) (0-0)</td><td></td>"));
+        assert!(!html.contains("class=\"winner\">"));
+    }
+
+    #[test]
+    fn standings_stop_at_the_loaded_day() {
+        let standing = |division: &str, day| {
+            let mut app = App::new("202509".to_string(), division.to_string(), day);
+            let result = |result: &str| MatchRecord {
+                result: result.to_string(),
+                opponent_shikona_en: "Onosato".to_string(),
+                opponent_shikona_jp: String::new(),
+                kimarite: None,
+            };
+            app.set_banzuke(vec![BanzukeEntry {
+                side: "East".to_string(),
+                rikishi_id: 1,
+                shikona_en: "Hoshoryu".into(),
+                shikona_jp: None,
+                rank_value: 1,
+                rank: "Yokozuna 1 East".to_string(),
+                wins: None,
+                losses: None,
+                absences: None,
+                record: Some(vec![result("win"), result("loss"), result("win"), result("win")]),
+            }]);
+            app.set_bout_days(app.bout_days_key(), [(1, vec![1, 4, 5, 8])].into());
+            let html = render_day_report(&app);
+            html.lines().find(|l| l.contains("Hoshoryu")).map(str::to_string)
+        };
+        assert_eq!(standing("Makuuchi", 2).as_deref(), Some("<tr><td>Yokozuna 1 East</td><td>Hoshoryu</td><td>1-1-0</td></tr>"));
+        // Below Juryo the bouts fall on days 1, 4, 5 and 8
+        assert_eq!(standing("Makushita", 4).as_deref(), Some("<tr><td>Yokozuna 1 East</td><td>Hoshoryu</td><td>1-1-0</td></tr>"));
+        assert_eq!(standing("Makushita", 15).as_deref(), Some("<tr><td>Yokozuna 1 East</td><td>Hoshoryu</td><td>3-1-3</td></tr>"));
+    }
+}
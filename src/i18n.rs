@@ -0,0 +1,977 @@
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+/// Interface language.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Locale {
+    En,
+    Ja,
+}
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+impl Locale {
+    /// Parse a locale tag such as `ja`, `ja_JP.UTF-8` or `en-US`.
+    pub fn parse(tag: &str) -> Option<Self> {
+        let lang = tag.split(['_', '-', '.', '@']).next()?.to_ascii_lowercase();
+        match lang.as_str() {
+            "ja" => Some(Locale::Ja),
+            "en" | "c" | "posix" => Some(Locale::En),
+            _ => None,
+        }
+    }
+
+    /// Pick the locale from the configured value, then the usual environment variables,
+    /// defaulting to English.
+    pub fn detect(configured: Option<&str>) -> Self {
+        configured
+            .and_then(Self::parse)
+            .or_else(|| Self::from_env(&|var| std::env::var(var).ok()))
+            .unwrap_or(Locale::En)
+    }
+
+    // The first supported locale named by the locale variables, read through `env`.
+    // Values naming other languages are skipped; LANGUAGE may list several, split by ':'.
+    fn from_env(env: &dyn Fn(&str) -> Option<String>) -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANGUAGE", "LANG"]
+            .iter()
+            .filter_map(|var| env(var))
+            .find_map(|value| value.split(':').find_map(Self::parse))
+    }
+}
+
+/// Set the interface language for the rest of the process. Only the first call has an effect.
+pub fn set_locale(locale: Locale) {
+    let _ = LOCALE.set(locale);
+}
+
+pub fn locale() -> Locale {
+    LOCALE.get().copied().unwrap_or(Locale::En)
+}
+
+/// Look up a UI string by key, falling back to English and then to the key itself.
+pub fn t(key: &'static str) -> &'static str {
+    lookup(locale(), key)
+}
+
+/// Look up a UI string and substitute each `{}` placeholder with the next argument.
+pub fn tf(key: &'static str, args: &[&dyn std::fmt::Display]) -> String {
+    fill(t(key), args)
+}
+
+fn lookup(locale: Locale, key: &'static str) -> &'static str {
+    let table = match locale {
+        Locale::En => EN,
+        Locale::Ja => JA,
+    };
+    table
+        .iter()
+        .chain(EN.iter())
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| *v)
+        .unwrap_or(key)
+}
+
+fn fill(template: &str, args: &[&dyn std::fmt::Display]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+    while let Some(pos) = rest.find("{}") {
+        out.push_str(&rest[..pos]);
+        match args.next() {
+            Some(arg) => out.push_str(&arg.to_string()),
+            None => out.push_str("{}"),
+        }
+        rest = &rest[pos + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Display name of a division (the API name is still used for requests).
+pub fn division_name(division: &str) -> String {
+    let key = match division.to_ascii_lowercase().as_str() {
+        "makuuchi" => "division.makuuchi",
+        "juryo" => "division.juryo",
+        "makushita" => "division.makushita",
+        "sandanme" => "division.sandanme",
+        "jonidan" => "division.jonidan",
+        "jonokuchi" => "division.jonokuchi",
+        _ => return division.to_string(),
+    };
+    crate::romaji::romanize(t(key)).into_owned()
+}
+
+/// Display name of the basho held in `month`.
+pub fn basho_name(month: u32) -> Cow<'static, str> {
+    match locale() {
+        Locale::En => crate::romaji::romanize(crate::api::SumoApi::get_basho_name(month)),
+        Locale::Ja => Cow::Borrowed(match month {
+            1 => "初場所",
+            3 => "春場所",
+            5 => "夏場所",
+            7 => "名古屋場所",
+            9 => "秋場所",
+            11 => "九州場所",
+            _ => "不明な場所",
+        }),
+    }
+}
+
+const EN: &[(&str, &str)] = &[
+    ("app.title", "Sumo TUI"),
+    ("header.day", "Day {}/{}"),
+    ("header.playoff", "Playoff"),
+    ("adjusted.future", "Day {} requested: basho has not started, showing day {}"),
+    ("adjusted.finished", "Day {} requested: basho is over, showing day {}"),
+    ("adjusted.division", "Day {} requested: {} runs to day {}"),
+    ("statusline.day", "{} D{}"),
+    ("statusline.final", "{} final"),
+    ("statusline.upcoming", "{} starts in {} days"),
+    ("statusline.favorites", "fav: {}"),
+    ("header.upcoming", "(Upcoming)"),
+    ("header.replay", "Replay Day {}/{}"),
+    ("header.auto", "(auto)"),
+    ("header.live", "● LIVE"),
+    ("header.offline", "⚠ OFFLINE (showing cached data)"),
+    ("header.spoilers", "[spoilers hidden from Day {}]"),
+    ("footer.quit", "Quit"),
+    ("footer.torikumi", "Torikumi"),
+    ("footer.banzuke", "Banzuke"),
+    ("footer.info", "Info"),
+    ("footer.day", "Day"),
+    ("footer.division", "Division"),
+    ("footer.basho", "Basho"),
+    ("footer.help", "Help"),
+    ("footer.update", " sumo {} is available (you have {}) "),
+    ("footer.online", "online"),
+    ("footer.degraded", "degraded"),
+    ("footer.offline", "offline"),
+    ("input.title", "Input"),
+    ("goto.empty", "Nothing to go to"),
+    ("goto.unknown", "\"{}\" is no basho, division or day"),
+    ("goto.year_only", "Which basho of {}?"),
+    ("goto.no_basho", "There is no basho {}"),
+    ("goto.bad_day", "There is no day {}"),
+    ("switcher.title", "Go to"),
+    ("switcher.hint", "{} matches · ↑/↓ choose · Enter go · Esc cancel"),
+    ("switcher.no_match", "Nothing matches"),
+    ("switcher.rikishi", "Rikishi"),
+    ("switcher.basho", "Basho"),
+    ("switcher.division", "Division"),
+    ("switcher.action", "Action"),
+    ("switcher.command", "Command"),
+    ("input.day", "Day"),
+    ("input.day_grid_hint", "Arrows or digits to pick, Enter to load, Esc to cancel"),
+    ("input.basho", "Basho (YYYYMM, e.g., 202501)"),
+    ("input.spoiler_day", "Hide results from day (empty to show all)"),
+    ("input.confirm", "Press Enter to confirm, Esc to cancel"),
+    ("input.invalid", "Invalid day/basho"),
+    ("input.invalid_day", "Invalid day"),
+    ("input.day_range", "{} bouts run on days 1-{}"),
+    ("input.checking_day", "Checking day {}…"),
+    ("input.no_torikumi", "No {} torikumi for day {} yet"),
+    ("input.command", "Run command: {}"),
+    ("input.unknown_command", "Unknown command"),
+    ("input.find_bout", "Find a rikishi's bout today (shikona)"),
+    ("input.note", "Note for day {}"),
+    ("input.rank", "Jump to rank (e.g. M10, K, J3w)"),
+    ("input.goto", "Go to (e.g. 202411 juryo d9, hatsu 2024 makuuchi)"),
+    ("input.unknown_rank", "No such rank on this banzuke"),
+    ("loading.title", "Please wait"),
+    ("loading.reloading", "Reloading data for {} {}..."),
+    ("loading.details", "Loading..."),
+    ("loading.retry", "{} to retry, {} to close"),
+    ("torikumi.title", "Daily Matches"),
+    ("torikumi.none", "No matches available for the selected day."),
+    ("torikumi.not_started", "This basho has not started yet."),
+    ("torikumi.loading", "Loading torikumi data..."),
+    ("torikumi.decided", "{}/{} decided"),
+    ("torikumi.complete", "All bouts decided"),
+    ("torikumi.east", "East"),
+    ("torikumi.west", "West"),
+    ("torikumi.kimarite", "Kimarite"),
+    ("torikumi.match_no", "No."),
+    ("torikumi.rank_diff", "Rank Diff"),
+    ("torikumi.win_prob", "Win Prob"),
+    ("torikumi.head_to_head", "H2H"),
+    ("torikumi.edge", "Edge"),
+    ("torikumi.east_short", "E"),
+    ("torikumi.west_short", "W"),
+    ("torikumi.hidden", "Hidden"),
+    ("torikumi.estimate", "~{} JST"),
+    ("torikumi.estimate_local", "~{} JST ({} local)"),
+    ("banzuke.title", "Banzuke"),
+    ("banzuke.rank", "Rank"),
+    ("banzuke.wrestler", "Wrestler"),
+    ("banzuke.result", "Result"),
+    ("banzuke.shikona_jp", "Shikona (JP)"),
+    ("banzuke.streak", "Streak"),
+    ("banzuke.streak_wins", "W{}"),
+    ("banzuke.streak_losses", "L{}"),
+    ("banzuke.heya", "Heya"),
+    ("banzuke.shusshin", "Shusshin"),
+    ("banzuke.age", "Age"),
+    ("banzuke.rank_change", "Change"),
+    ("banzuke.recent", "Last 3"),
+    ("banzuke.loading", "Loading banzuke data..."),
+    ("info.title", "Basho Information"),
+    ("info.start", "Start Date: "),
+    ("info.end", "End Date: "),
+    ("info.unknown", "Unknown"),
+    ("info.yusho", "Yusho Winners:"),
+    ("info.division", "  Division: "),
+    ("info.winner", "  Winner: "),
+    ("info.loading", "Loading basho information..."),
+    ("compare.title", "Comparison"),
+    ("compare.empty", "Mark up to {} rikishi in the banzuke with {} to compare them here"),
+    ("compare.rank", "Rank"),
+    ("compare.record", "Record"),
+    ("compare.heya", "Heya"),
+    ("compare.age", "Age"),
+    ("compare.height", "Height"),
+    ("compare.weight", "Weight"),
+    ("compare.career", "Career"),
+    ("compare.basho", "Basho"),
+    ("compare.yusho", "Yusho"),
+    ("compare.versus", "vs {}"),
+    ("stats.title", "{} Statistics"),
+    ("stats.rikishi", "Rikishi: "),
+    ("stats.average_age", "Average age: "),
+    ("stats.average_height", "Average height: "),
+    ("stats.average_weight", "Average weight: "),
+    ("stats.heya", "Heya"),
+    ("stats.kimarite", "Kimarite this basho"),
+    ("stats.kimarite_categories", "Kimarite by type"),
+    ("kimarite.push_thrust", "Push/thrust"),
+    ("kimarite.force_out", "Force-out"),
+    ("kimarite.throw", "Throw"),
+    ("kimarite.leg_trip", "Leg trip"),
+    ("kimarite.special", "Special"),
+    ("stats.records", "Records"),
+    ("stats.extremes", "Extremes ({}: back to breakdowns)"),
+    ("stats.show_extremes", " {}: extremes "),
+    ("records.title", "{} Records"),
+    ("records.title_loading", "{} Records (career stats for {}/{} rikishi loaded)"),
+    ("records.yusho", "Most career yusho"),
+    ("records.wins", "Most career wins"),
+    ("records.kinboshi", "Most kinboshi"),
+    ("records.win_rate", "Best career win rate"),
+    ("records.streak", "Longest career winning streak"),
+    ("schedule.heading", "Schedule"),
+    ("schedule.title", "{} ({}) - {}"),
+    ("schedule.missing", "This rikishi is not on the loaded banzuke"),
+    ("journal.title", "Journal - {}"),
+    ("journal.empty", "No notes for this basho yet. Press {} to write one for the loaded day."),
+    ("journal.day", "Day {}"),
+    ("journal.day_heading", "Day"),
+    ("journal.written", "Written"),
+    ("journal.note", "Note"),
+    ("dashboard.title", "Favorites - Day {}"),
+    ("dashboard.no_favorites", "No favorites yet. Add them with favorites in the config file."),
+    ("dashboard.loading", "Loading every division's banzuke..."),
+    ("dashboard.not_ranked", "Not on this basho's banzuke"),
+    ("dashboard.no_bout", "No bout today"),
+    ("dashboard.division", "Division"),
+    ("dashboard.opponent", "Today"),
+    ("matchups.heading", "{} Matchups"),
+    ("matchups.title", "{} Matchups - {}: {} met, {} not yet"),
+    ("matchups.legend", " ○ win  ● loss  □ fusen win  ■ fusen loss  ◇ today  ? hidden  · not met "),
+    ("settings.title", "Settings ({})"),
+    ("settings.no_file", "not saved"),
+    ("settings.setting", "Setting"),
+    ("settings.value", "Value"),
+    ("settings.theme", "Color theme"),
+    ("settings.units", "Units"),
+    ("settings.refresh", "Live refresh interval"),
+    ("settings.seconds", "{} s"),
+    ("settings.division", "Division at startup"),
+    ("settings.key", "Key: {}"),
+    ("settings.press_key", "press a key (Esc cancels)"),
+    ("settings.hint", " {} changes the selected setting and saves it "),
+    ("yusho_history.title", "Yusho history: {}"),
+    ("yusho_history.hint", "{}: next division"),
+    ("yusho_history.loading", "Loading yusho history..."),
+    ("yusho_history.basho", "Basho"),
+    ("yusho_history.unavailable", "Could not load"),
+    ("yusho_history.none", "No yusho"),
+    ("projection.title", "Possible remaining opponents of {}"),
+    ("projection.none", "No plausible opponents left"),
+    ("projection.never_met", "never met"),
+    ("projection.rank", "near in rank"),
+    ("projection.race", "in the race"),
+    ("projection.both", "{}, {}"),
+    ("projection.head_to_head", "H2H"),
+    ("projection.why", "Why"),
+    ("schedule.day", "Day"),
+    ("schedule.opponent", "Opponent"),
+    ("schedule.rank", "Rank"),
+    ("schedule.result", "Result"),
+    ("schedule.win", "Win"),
+    ("schedule.loss", "Loss"),
+    ("schedule.fusen_win", "Win (fusen)"),
+    ("schedule.fusen_loss", "Loss (fusen)"),
+    ("schedule.scheduled", "Scheduled"),
+    ("summary.title", "Day {} Summary"),
+    ("summary.bouts", "Bouts: "),
+    ("summary.fusen", "   Fusen: "),
+    ("summary.upsets", "Biggest upsets"),
+    ("summary.beat", " beat "),
+    ("summary.kinboshi", "Kinboshi"),
+    ("summary.kimarite", "Most common kimarite"),
+    ("summary.race", "Yusho race"),
+    ("summary.none", "None"),
+    ("digest.subject", "{} {} Day {}"),
+    ("digest.undecided", "{} bouts still to be decided"),
+    ("stats.heaviest", "Heaviest"),
+    ("stats.lightest", "Lightest"),
+    ("stats.tallest", "Tallest"),
+    ("stats.shortest", "Shortest"),
+    ("stats.oldest", "Oldest"),
+    ("stats.youngest", "Youngest"),
+    ("stats.longest_serving", "Longest serving (debut)"),
+    ("stats.years", "{} years"),
+    ("help.title", "Help"),
+    ("help.heading", "Sumo TUI Help"),
+    ("help.navigation", "Navigation:"),
+    ("help.up", "Move up"),
+    ("help.down", "Move down"),
+    ("help.prev_page", "Previous page"),
+    ("help.next_page", "Next page"),
+    ("help.prev_section", "Banzuke: previous rank tier"),
+    ("help.next_section", "Banzuke: next rank tier"),
+    ("help.jump_to_rank", "Banzuke: jump to a rank (e.g. M10, O, Ms12w)"),
+    ("help.goto", "Go to a basho, division and day at once"),
+    ("help.details", "View details (rikishi in banzuke, head-to-head in torikumi, extremes in stats)"),
+    ("help.view_torikumi", "View daily matches (torikumi)"),
+    ("help.view_banzuke", "View rankings (banzuke)"),
+    ("help.view_info", "View basho information"),
+    ("help.view_compare", "View the rikishi comparison"),
+    ("help.toggle_compare", "Mark the selected rikishi for comparison"),
+    ("help.view_stats", "View division statistics"),
+    ("help.view_records", "View career records"),
+    ("help.view_schedule", "Show the selected rikishi's bouts this basho"),
+    ("help.view_journal", "Show your notes on this basho"),
+    ("help.view_dashboard", "Show every favorite's rank, bout and record"),
+    ("help.view_matchups", "Show who has fought whom in the division this basho"),
+    ("help.view_yusho_history", "Show yusho winners of recent basho by division"),
+    ("help.view_settings", "Change settings and save them to the config file"),
+    ("help.find_bout", "Find a rikishi's bout today in any division"),
+    ("help.day_summary", "Summary of the loaded day once its results are in"),
+    ("help.favorite", "Jump to a favorite's bout or banzuke row"),
+    ("help.switcher", "Quick switcher: rikishi, basho, divisions, views and commands"),
+    ("help.switch_data", "Switch Data:"),
+    ("help.change_day", "Change day (1-15)"),
+    ("help.change_division", "Change division"),
+    ("help.change_basho", "Change basho (YYYYMM format)"),
+    ("help.replay", "Replay (finished basho):"),
+    ("help.replay_toggle", "Toggle replay mode (starts at day 1)"),
+    ("help.replay_next", "Next replay day"),
+    ("help.replay_prev", "Previous replay day"),
+    ("help.replay_auto", "Toggle auto-advance"),
+    ("help.spoilers", "Spoilers:"),
+    ("help.journal", "Journal:"),
+    ("help.spoiler_day", "Hide results from a day onwards (spoiler-free mode)"),
+    ("help.reveal_one", "Reveal the selected bout"),
+    ("help.reveal_all", "Reveal all bouts of the day"),
+    ("help.other", "Other:"),
+    ("help.toggle_help", "Toggle this help"),
+    ("help.live", "Toggle live auto-refresh"),
+    ("help.theme", "Cycle color theme"),
+    ("help.order", "Cycle torikumi order: match, reverse, by rank"),
+    ("help.cycle_filter", "Cycle torikumi filter: all, sanyaku, maegashira, favorites, unfinished"),
+    ("help.jump_to_unfinished", "Jump to the first bout without a result"),
+    ("help.screenshot", "Save a screenshot (.ans and .txt)"),
+    ("help.run_command", "Run a script command"),
+    ("help.inspect", "Show the raw API data behind the selection or popup"),
+    ("help.add_note", "Write a note for the loaded day"),
+    ("help.delete_note", "Delete the selected note (journal)"),
+    ("help.quit", "Quit application"),
+    ("help.suspend", "Suspend to the shell (fg to resume)"),
+    ("help.close", "Close help/cancel input/close details"),
+    ("help.divisions", "Divisions: Makuuchi, Juryo, Makushita, Sandanme, Jonidan, Jonokuchi"),
+    ("help.months", "Basho months: 01, 03, 05, 07, 09, 11"),
+    ("help.filter", "Help: search {}"),
+    ("help.no_matches", "No matching keys"),
+    ("help.search_hint", " / search · ↑/↓ PgUp/PgDn scroll · Esc close "),
+    ("division.title", "Division"),
+    ("division.select", "Select Division"),
+    ("division.hint", "Use ↑↓ to select, Enter to confirm, Esc to cancel"),
+    ("division.makuuchi", "Makuuchi"),
+    ("division.juryo", "Juryo"),
+    ("division.makushita", "Makushita"),
+    ("division.sandanme", "Sandanme"),
+    ("division.jonidan", "Jonidan"),
+    ("division.jonokuchi", "Jonokuchi"),
+    ("tier.yokozuna", "Yokozuna"),
+    ("tier.ozeki", "Ozeki"),
+    ("tier.sekiwake", "Sekiwake"),
+    ("tier.komusubi", "Komusubi"),
+    ("tier.maegashira", "Maegashira"),
+    ("rikishi.title", "Rikishi Information"),
+    ("rikishi.heading", "Rikishi Details"),
+    ("rikishi.shikona_en", "Shikona (English): "),
+    ("rikishi.shikona_jp", "Shikona (Japanese): "),
+    ("rikishi.rank", "Current Rank: "),
+    ("rikishi.heya", "Heya: "),
+    ("rikishi.birth_date", "Birth Date: "),
+    ("rikishi.age", " (Age: {})"),
+    ("rikishi.birthplace", "Birthplace: "),
+    ("rikishi.height", "Height: "),
+    ("rikishi.weight", "Weight: "),
+    ("rikishi.debut", "Debut: "),
+    ("rikishi.achievements", "Achievements"),
+    ("rikishi.achievements_loading", "  Loading career..."),
+    ("rikishi.yusho", "Yusho: "),
+    ("rikishi.jun_yusho", "Jun-yusho: "),
+    ("rikishi.jun_yusho_count", "{} (in {} cached basho)"),
+    ("rikishi.sansho", "Sansho: "),
+    ("rikishi.kinboshi", "Kinboshi: "),
+    ("rikishi.none", "none"),
+    ("rikishi.rivals", "Most faced"),
+    ("rikishi.rivals_loading", "  Loading bout history..."),
+    ("rikishi.rivals_none", "  No bouts yet"),
+    ("rikishi.rival_record", "{}-{} in {} bouts"),
+    ("rikishi.rivals_hint", "↑/↓ pick an opponent, Enter for the full head-to-head, {} this basho vs the last, Esc to close"),
+    ("rikishi.hint", "{} this basho vs the last, Esc to close"),
+    ("rikishi.previous_heading", "This basho vs the last, through day {}"),
+    ("rikishi.previous_rank", "Rank"),
+    ("rikishi.previous_record", "Record"),
+    ("rikishi.previous_kimarite", "Won by"),
+    ("rikishi.previous_absent", "did not fight"),
+    ("common.close", "Press Esc to close"),
+    ("h2h.title", "Match History"),
+    ("h2h.heading", "Head-to-Head Record"),
+    ("h2h.total", "Total Matches: "),
+    ("h2h.wins", "{} Wins: "),
+    ("h2h.winning", "Winning Techniques:"),
+    ("h2h.losing", "Losing Techniques:"),
+    ("h2h.recent", "Recent Matches:"),
+    ("h2h.match_day", "{} Day {}: "),
+    ("h2h.by", " by "),
+    ("h2h.more", "... and {} more"),
+    ("status.replay_final", "Replay reached the final day"),
+    ("status.replay_on", "Replay mode: ]/[ step days, t toggles auto-advance"),
+    ("status.replay_off", "Replay mode off"),
+    ("status.replay_unavailable", "Replay is only available for finished basho"),
+    ("status.favorite_empty", "No favorite in slot {}"),
+    ("status.favorite_missing", "{} is not in the {} banzuke"),
+    ("status.favorite_unranked", "{} is not on this basho's banzuke"),
+    ("status.spoiler_off", "Spoiler-free mode off"),
+    ("status.spoiler_on", "Hiding results from day {} onwards"),
+    ("status.live_on", "Live mode on"),
+    ("status.live_off", "Live mode off"),
+    ("status.reloaded", "Reloaded {} {} Day {}"),
+    ("status.reloaded_auto", "Reloaded {} {} Day {} (auto-selected)"),
+    ("status.reload_failed", "Failed to reload data: {}"),
+    ("status.live_updated", "Live: updated {} (next at {})"),
+    ("status.live_failed", "Live refresh failed: {}"),
+    ("status.screenshot_saved", "Saved screenshot to {}"),
+    ("status.screenshot_failed", "Failed to save screenshot: {}"),
+    ("status.inspect_hidden", "Not shown while results are hidden"),
+    ("status.inspect_failed", "Could not show the data: {}"),
+    ("status.inspect_nothing", "Nothing to inspect here"),
+    ("inspect.title", "API data: {}"),
+    ("inspect.hint", "↑/↓ PgUp/PgDn scroll · Esc close"),
+    ("status.theme", "Theme: {}"),
+    ("status.order", "Torikumi order: {}"),
+    ("status.filter", "Torikumi showing: {}"),
+    ("status.rikishi_failed", "Failed to load rikishi details: {}"),
+    ("status.h2h_failed", "Failed to load head-to-head data: {}"),
+    ("status.rivals_failed", "Failed to load bout history: {}"),
+    ("status.compare_added", "Added {} to the comparison ({}/{})"),
+    ("status.compare_removed", "Removed {} from the comparison ({}/{})"),
+    ("status.compare_full", "Up to {} rikishi can be compared"),
+    ("status.compare_cleared", "Comparison cleared"),
+    ("status.compare_from_banzuke", "Mark rikishi for comparison in the banzuke"),
+    ("status.compare_failed", "Failed to load comparison data: {}"),
+    ("status.profiles_failed", "Failed to load rikishi profiles: {}"),
+    ("status.career_stats_failed", "Failed to load career stats for {} rikishi: {}"),
+    ("status.schedule_from_banzuke", "Pick a rikishi in the banzuke to see their schedule"),
+    ("status.finding_bout", "Looking for {} on day {}..."),
+    ("status.bout_found", "Found {} in {}"),
+    ("status.no_bout", "No bout for {} on day {}"),
+    ("status.find_bout_failed", "Bout lookup failed: {}"),
+    ("status.dashboard_failed", "Could not load the favorites: {}"),
+    ("status.summary_incomplete", "Day {} results are not complete yet"),
+    ("status.summary_hidden", "Reveal the day's results to see its summary"),
+    ("status.note_added", "Note saved for day {}"),
+    ("status.note_deleted", "Note deleted"),
+    ("status.journal_save_failed", "Could not save the journal: {}"),
+    ("status.journal_unavailable", "The journal could not be loaded, so notes are not saved"),
+    ("status.setting_saved", "Saved to {}"),
+    ("status.setting_save_failed", "Setting not saved: {}"),
+    ("status.settings_unavailable", "No config file location, so settings are not saved"),
+    ("toast.day_complete", "Day {} is complete. Press {} for the summary"),
+    ("status.no_commands", "No script commands registered"),
+    ("status.script", "Script: {}"),
+    ("status.hook_failed", "Hook command failed to start: {}"),
+    ("status.rate_limited", "Rate limited by the API, resuming in {}s"),
+    ("status.rate_limit_over", "Rate limit over, requests resumed"),
+    ("theme.default", "Default"),
+    ("theme.high_contrast", "High contrast"),
+    ("theme.monochrome", "Monochrome"),
+    ("order.match", "match order"),
+    ("order.reverse", "musubi-no-ichiban first"),
+    ("order.rank", "by rank"),
+    ("filter.all", "all bouts"),
+    ("filter.sanyaku", "sanyaku bouts"),
+    ("filter.maegashira", "maegashira bouts"),
+    ("filter.favorites", "favorites' bouts"),
+    ("filter.unfinished", "unfinished bouts"),
+    ("onboarding.welcome", "Welcome to sumo! A few questions to set things up (press Enter to keep the default).\n"),
+    ("onboarding.division", "Preferred division"),
+    ("onboarding.units", "Units for height and weight"),
+    ("onboarding.theme", "Color theme"),
+    ("onboarding.favorites", "Favorite rikishi, comma-separated English shikona (blank for none)"),
+    ("onboarding.saved", "Settings saved to {}. Edit the file to change them later."),
+    ("onboarding.save_failed", "Could not save settings to {}: {}"),
+];
+
+const JA: &[(&str, &str)] = &[
+    ("app.title", "大相撲 TUI"),
+    ("header.day", "{}/{}日目"),
+    ("header.playoff", "優勝決定戦"),
+    ("adjusted.future", "{}日目は未開催のため{}日目を表示しています"),
+    ("adjusted.finished", "場所が終了したため{}日目ではなく{}日目を表示しています"),
+    ("adjusted.division", "{}日目は範囲外です: {}は{}日目までです"),
+    ("statusline.day", "{} {}日目"),
+    ("statusline.final", "{} 終了"),
+    ("statusline.upcoming", "{} 初日まで{}日"),
+    ("statusline.favorites", "注目: {}"),
+    ("header.upcoming", "(開催前)"),
+    ("header.replay", "再生 {}/{}日目"),
+    ("header.auto", "(自動)"),
+    ("header.live", "● ライブ"),
+    ("header.offline", "⚠ オフライン (キャッシュを表示中)"),
+    ("header.spoilers", "[{}日目以降の結果を非表示]"),
+    ("footer.quit", "終了"),
+    ("footer.torikumi", "取組"),
+    ("footer.banzuke", "番付"),
+    ("footer.info", "情報"),
+    ("footer.day", "日"),
+    ("footer.division", "階級"),
+    ("footer.basho", "場所"),
+    ("footer.help", "ヘルプ"),
+    ("footer.update", " sumo {} が利用可能です（現在 {}） "),
+    ("footer.online", "オンライン"),
+    ("footer.degraded", "不安定"),
+    ("footer.offline", "オフライン"),
+    ("input.title", "入力"),
+    ("goto.empty", "移動先がありません"),
+    ("goto.unknown", "「{}」は場所・階級・日目として読めません"),
+    ("goto.year_only", "{}年のどの場所ですか？"),
+    ("goto.no_basho", "{}場所はありません"),
+    ("goto.bad_day", "{}日目はありません"),
+    ("switcher.title", "移動"),
+    ("switcher.hint", "{}件 · ↑/↓ 選択 · Enter 移動 · Esc 取消"),
+    ("switcher.no_match", "該当なし"),
+    ("switcher.rikishi", "力士"),
+    ("switcher.basho", "場所"),
+    ("switcher.division", "階級"),
+    ("switcher.action", "操作"),
+    ("switcher.command", "コマンド"),
+    ("input.day", "日"),
+    ("input.day_grid_hint", "矢印キーか数字で選択、Enterで表示、Escで取消"),
+    ("input.basho", "場所 (YYYYMM、例: 202501)"),
+    ("input.spoiler_day", "結果を隠す開始日 (空欄ですべて表示)"),
+    ("input.confirm", "Enterで確定、Escで取消"),
+    ("input.invalid", "日または場所が無効です"),
+    ("input.invalid_day", "日が無効です"),
+    ("input.day_range", "{}の取組は1〜{}日目です"),
+    ("input.checking_day", "{}日目を確認中…"),
+    ("input.no_torikumi", "{}の{}日目の取組はまだありません"),
+    ("input.command", "実行するコマンド: {}"),
+    ("input.unknown_command", "不明なコマンドです"),
+    ("input.find_bout", "今日の取組を探す力士 (四股名)"),
+    ("input.note", "{}日目のメモ"),
+    ("input.rank", "移動する地位 (例: M10、K、J3w、前頭十枚目)"),
+    ("input.goto", "移動先 (例: 202411 十両 d9、初場所 2024 幕内)"),
+    ("input.unknown_rank", "この番付にない地位です"),
+    ("loading.title", "お待ちください"),
+    ("loading.reloading", "{} {} のデータを再読み込み中..."),
+    ("loading.details", "読み込み中..."),
+    ("loading.retry", "{}で再試行、{}で閉じる"),
+    ("torikumi.title", "取組"),
+    ("torikumi.none", "この日の取組はありません。"),
+    ("torikumi.not_started", "この場所はまだ始まっていません。"),
+    ("torikumi.loading", "取組を読み込み中..."),
+    ("torikumi.decided", "{}/{}番終了"),
+    ("torikumi.complete", "全取組終了"),
+    ("torikumi.east", "東"),
+    ("torikumi.west", "西"),
+    ("torikumi.kimarite", "決まり手"),
+    ("torikumi.match_no", "番"),
+    ("torikumi.rank_diff", "番付差"),
+    ("torikumi.win_prob", "勝率予想"),
+    ("torikumi.head_to_head", "対戦成績"),
+    ("torikumi.edge", "対戦優位"),
+    ("torikumi.east_short", "東"),
+    ("torikumi.west_short", "西"),
+    ("torikumi.hidden", "非表示"),
+    ("torikumi.estimate", "~{} JST"),
+    ("torikumi.estimate_local", "~{} JST (現地 {})"),
+    ("banzuke.title", "番付"),
+    ("banzuke.rank", "地位"),
+    ("banzuke.wrestler", "力士"),
+    ("banzuke.result", "成績"),
+    ("banzuke.shikona_jp", "四股名"),
+    ("banzuke.streak", "連続"),
+    ("banzuke.streak_wins", "{}連勝"),
+    ("banzuke.streak_losses", "{}連敗"),
+    ("banzuke.heya", "部屋"),
+    ("banzuke.shusshin", "出身"),
+    ("banzuke.age", "年齢"),
+    ("banzuke.rank_change", "昇降"),
+    ("banzuke.recent", "直近3場所"),
+    ("banzuke.loading", "番付を読み込み中..."),
+    ("info.title", "場所情報"),
+    ("info.start", "初日: "),
+    ("info.end", "千秋楽: "),
+    ("info.unknown", "不明"),
+    ("info.yusho", "優勝:"),
+    ("info.division", "  階級: "),
+    ("info.winner", "  優勝力士: "),
+    ("info.loading", "場所情報を読み込み中..."),
+    ("compare.title", "比較"),
+    ("compare.empty", "最大 {} 人の力士を番付で {} キーで選ぶと、ここで比較できます"),
+    ("compare.rank", "番付"),
+    ("compare.record", "成績"),
+    ("compare.heya", "部屋"),
+    ("compare.age", "年齢"),
+    ("compare.height", "身長"),
+    ("compare.weight", "体重"),
+    ("compare.career", "通算成績"),
+    ("compare.basho", "場所数"),
+    ("compare.yusho", "優勝"),
+    ("compare.versus", "対 {}"),
+    ("stats.title", "{} 統計"),
+    ("stats.rikishi", "力士数: "),
+    ("stats.average_age", "平均年齢: "),
+    ("stats.average_height", "平均身長: "),
+    ("stats.average_weight", "平均体重: "),
+    ("stats.heya", "部屋"),
+    ("stats.kimarite", "今場所の決まり手"),
+    ("stats.kimarite_categories", "決まり手の種類"),
+    ("kimarite.push_thrust", "押し・突き"),
+    ("kimarite.force_out", "寄り"),
+    ("kimarite.throw", "投げ・捻り"),
+    ("kimarite.leg_trip", "掛け・足取り"),
+    ("kimarite.special", "特殊技・非技"),
+    ("stats.records", "成績分布"),
+    ("stats.extremes", "番付の記録 ({}: 内訳に戻る)"),
+    ("stats.show_extremes", " {}: 番付の記録 "),
+    ("records.title", "{} 記録"),
+    ("records.title_loading", "{} 記録 (通算成績 {}/{} 人分を読み込み済み)"),
+    ("records.yusho", "通算優勝回数"),
+    ("records.wins", "通算勝利数"),
+    ("records.kinboshi", "金星獲得数"),
+    ("records.win_rate", "通算勝率"),
+    ("records.streak", "通算最長連勝"),
+    ("schedule.heading", "日程"),
+    ("schedule.title", "{} ({}) - {}"),
+    ("schedule.missing", "この力士は読み込んだ番付にいません"),
+    ("journal.title", "日記 - {}"),
+    ("journal.empty", "この場所のメモはまだありません。{} で表示中の日のメモを書けます。"),
+    ("journal.day", "{}日目"),
+    ("journal.day_heading", "日"),
+    ("journal.written", "記入日時"),
+    ("journal.note", "メモ"),
+    ("dashboard.title", "お気に入り - {}日目"),
+    ("dashboard.no_favorites", "お気に入りがありません。設定ファイルの favorites で追加できます。"),
+    ("dashboard.loading", "全階級の番付を読み込み中..."),
+    ("dashboard.not_ranked", "今場所の番付にいません"),
+    ("dashboard.no_bout", "今日は取組なし"),
+    ("dashboard.division", "階級"),
+    ("dashboard.opponent", "今日の相手"),
+    ("matchups.heading", "{} 対戦表"),
+    ("matchups.title", "{} 対戦表 - {}: 対戦済み {}、未対戦 {}"),
+    ("matchups.legend", " ○ 勝ち  ● 負け  □ 不戦勝  ■ 不戦敗  ◇ 今日  ? 非表示  · 未対戦 "),
+    ("settings.title", "設定 ({})"),
+    ("settings.no_file", "保存されません"),
+    ("settings.setting", "設定"),
+    ("settings.value", "値"),
+    ("settings.theme", "配色テーマ"),
+    ("settings.units", "単位"),
+    ("settings.refresh", "ライブ更新の間隔"),
+    ("settings.seconds", "{} 秒"),
+    ("settings.division", "起動時の階級"),
+    ("settings.key", "キー: {}"),
+    ("settings.press_key", "キーを押してください (Esc で取消)"),
+    ("settings.hint", " {} で選択中の設定を変更して保存 "),
+    ("yusho_history.title", "優勝力士: {}"),
+    ("yusho_history.hint", "{}: 次の階級"),
+    ("yusho_history.loading", "優勝力士を読み込み中..."),
+    ("yusho_history.basho", "場所"),
+    ("yusho_history.unavailable", "読み込めません"),
+    ("yusho_history.none", "優勝なし"),
+    ("projection.title", "{}の残りの対戦候補"),
+    ("projection.none", "残りの対戦候補はいません"),
+    ("projection.never_met", "初対戦"),
+    ("projection.rank", "番付が近い"),
+    ("projection.race", "優勝争い"),
+    ("projection.both", "{}、{}"),
+    ("projection.head_to_head", "対戦成績"),
+    ("projection.why", "理由"),
+    ("schedule.day", "日"),
+    ("schedule.opponent", "対戦相手"),
+    ("schedule.rank", "番付"),
+    ("schedule.result", "結果"),
+    ("schedule.win", "勝ち"),
+    ("schedule.loss", "負け"),
+    ("schedule.fusen_win", "不戦勝"),
+    ("schedule.fusen_loss", "不戦敗"),
+    ("schedule.scheduled", "予定"),
+    ("summary.title", "{}日目のまとめ"),
+    ("summary.bouts", "取組数: "),
+    ("summary.fusen", "   不戦: "),
+    ("summary.upsets", "主な番狂わせ"),
+    ("summary.beat", " 勝 "),
+    ("summary.kinboshi", "金星"),
+    ("summary.kimarite", "多かった決まり手"),
+    ("summary.race", "優勝争い"),
+    ("summary.none", "なし"),
+    ("digest.subject", "{} {} {}日目"),
+    ("digest.undecided", "未決着の取組: {}番"),
+    ("stats.heaviest", "最重量"),
+    ("stats.lightest", "最軽量"),
+    ("stats.tallest", "最長身"),
+    ("stats.shortest", "最短身"),
+    ("stats.oldest", "最年長"),
+    ("stats.youngest", "最年少"),
+    ("stats.longest_serving", "最古参 (初土俵)"),
+    ("stats.years", "{} 歳"),
+    ("help.title", "ヘルプ"),
+    ("help.heading", "大相撲 TUI ヘルプ"),
+    ("help.navigation", "移動:"),
+    ("help.up", "上へ移動"),
+    ("help.down", "下へ移動"),
+    ("help.prev_page", "前のページ"),
+    ("help.next_page", "次のページ"),
+    ("help.prev_section", "番付: 前の地位へ"),
+    ("help.next_section", "番付: 次の地位へ"),
+    ("help.jump_to_rank", "番付: 地位へ移動 (例: M10、O、Ms12w)"),
+    ("help.goto", "場所・階級・日目をまとめて指定して移動"),
+    ("help.details", "詳細を表示 (番付では力士、取組では対戦成績、統計では番付の記録)"),
+    ("help.view_torikumi", "取組を表示"),
+    ("help.view_banzuke", "番付を表示"),
+    ("help.view_info", "場所情報を表示"),
+    ("help.view_compare", "力士の比較を表示"),
+    ("help.toggle_compare", "選択中の力士を比較に追加/削除"),
+    ("help.view_stats", "階級の統計を表示"),
+    ("help.view_records", "通算記録を表示"),
+    ("help.view_schedule", "選択中の力士の今場所の取組を表示"),
+    ("help.view_journal", "この場所のメモを表示"),
+    ("help.view_dashboard", "お気に入り力士の番付・取組・成績を一覧表示"),
+    ("help.view_matchups", "今場所の階級内の対戦表を表示"),
+    ("help.view_yusho_history", "階級別の最近の優勝力士を表示"),
+    ("help.view_settings", "設定を変更して設定ファイルに保存"),
+    ("help.find_bout", "力士の今日の取組を全階級から探す"),
+    ("help.day_summary", "結果がそろった日のまとめを表示"),
+    ("help.favorite", "お気に入り力士の取組・番付へ移動"),
+    ("help.switcher", "クイック切り替え: 力士・場所・階級・画面・コマンド"),
+    ("help.switch_data", "データ切り替え:"),
+    ("help.change_day", "日を変更 (1-15)"),
+    ("help.change_division", "階級を変更"),
+    ("help.change_basho", "場所を変更 (YYYYMM形式)"),
+    ("help.replay", "再生 (終了した場所):"),
+    ("help.replay_toggle", "再生モードの切り替え (初日から)"),
+    ("help.replay_next", "次の日"),
+    ("help.replay_prev", "前の日"),
+    ("help.replay_auto", "自動送りの切り替え"),
+    ("help.spoilers", "ネタバレ防止:"),
+    ("help.journal", "日記:"),
+    ("help.spoiler_day", "指定した日以降の結果を隠す"),
+    ("help.reveal_one", "選択した取組の結果を表示"),
+    ("help.reveal_all", "その日の全取組の結果を表示"),
+    ("help.other", "その他:"),
+    ("help.toggle_help", "このヘルプを表示/非表示"),
+    ("help.live", "自動更新の切り替え"),
+    ("help.theme", "配色テーマの切り替え"),
+    ("help.order", "取組順の切り替え: 取組順、逆順、番付順"),
+    ("help.cycle_filter", "取組の絞り込み切り替え: すべて、三役、前頭、お気に入り、未決着"),
+    ("help.jump_to_unfinished", "最初の未決着の取組へ移動"),
+    ("help.screenshot", "スクリーンショットを保存 (.ans と .txt)"),
+    ("help.run_command", "スクリプトのコマンドを実行"),
+    ("help.inspect", "選択中の項目やポップアップの API データを表示"),
+    ("help.add_note", "表示中の日のメモを書く"),
+    ("help.delete_note", "選択中のメモを削除 (日記)"),
+    ("help.quit", "終了"),
+    ("help.suspend", "一時停止 (fg で再開)"),
+    ("help.close", "ヘルプ・入力・詳細を閉じる"),
+    ("help.divisions", "階級: 幕内、十両、幕下、三段目、序二段、序ノ口"),
+    ("help.months", "本場所の月: 01, 03, 05, 07, 09, 11"),
+    ("help.filter", "ヘルプ: 検索 {}"),
+    ("help.no_matches", "一致するキーはありません"),
+    ("help.search_hint", " / 検索 · ↑/↓ PgUp/PgDn スクロール · Esc 閉じる "),
+    ("division.title", "階級"),
+    ("division.select", "階級を選択"),
+    ("division.hint", "↑↓で選択、Enterで確定、Escで取消"),
+    ("division.makuuchi", "幕内"),
+    ("division.juryo", "十両"),
+    ("division.makushita", "幕下"),
+    ("division.sandanme", "三段目"),
+    ("division.jonidan", "序二段"),
+    ("division.jonokuchi", "序ノ口"),
+    ("tier.yokozuna", "横綱"),
+    ("tier.ozeki", "大関"),
+    ("tier.sekiwake", "関脇"),
+    ("tier.komusubi", "小結"),
+    ("tier.maegashira", "前頭"),
+    ("rikishi.title", "力士情報"),
+    ("rikishi.heading", "力士の詳細"),
+    ("rikishi.shikona_en", "四股名 (英語): "),
+    ("rikishi.shikona_jp", "四股名: "),
+    ("rikishi.rank", "現在の地位: "),
+    ("rikishi.heya", "部屋: "),
+    ("rikishi.birth_date", "生年月日: "),
+    ("rikishi.age", " ({}歳)"),
+    ("rikishi.birthplace", "出身地: "),
+    ("rikishi.height", "身長: "),
+    ("rikishi.weight", "体重: "),
+    ("rikishi.debut", "初土俵: "),
+    ("rikishi.achievements", "主な成績"),
+    ("rikishi.achievements_loading", "  成績を読み込み中..."),
+    ("rikishi.yusho", "優勝: "),
+    ("rikishi.jun_yusho", "準優勝: "),
+    ("rikishi.jun_yusho_count", "{} (キャッシュ済みの{}場所中)"),
+    ("rikishi.sansho", "三賞: "),
+    ("rikishi.kinboshi", "金星: "),
+    ("rikishi.none", "なし"),
+    ("rikishi.rivals", "よく対戦する力士"),
+    ("rikishi.rivals_loading", "  取組履歴を読み込み中..."),
+    ("rikishi.rivals_none", "  取組なし"),
+    ("rikishi.rival_record", "{}勝{}敗 ({}番)"),
+    ("rikishi.rivals_hint", "↑/↓で力士を選択、Enterで対戦成績、{}で前場所と比較、Escで閉じる"),
+    ("rikishi.hint", "{}で前場所と比較、Escで閉じる"),
+    ("rikishi.previous_heading", "今場所と前場所 ({}日目まで)"),
+    ("rikishi.previous_rank", "地位"),
+    ("rikishi.previous_record", "成績"),
+    ("rikishi.previous_kimarite", "決まり手"),
+    ("rikishi.previous_absent", "出場なし"),
+    ("common.close", "Escで閉じる"),
+    ("h2h.title", "対戦成績"),
+    ("h2h.heading", "対戦成績"),
+    ("h2h.total", "対戦数: "),
+    ("h2h.wins", "{} 勝数: "),
+    ("h2h.winning", "勝ちの決まり手:"),
+    ("h2h.losing", "負けの決まり手:"),
+    ("h2h.recent", "最近の対戦:"),
+    ("h2h.match_day", "{} {}日目: "),
+    ("h2h.by", " 決まり手 "),
+    ("h2h.more", "... ほか{}番"),
+    ("status.replay_final", "千秋楽まで再生しました"),
+    ("status.replay_on", "再生モード: ]/[ で日送り、t で自動送り"),
+    ("status.replay_off", "再生モード終了"),
+    ("status.replay_unavailable", "再生は終了した場所のみ利用できます"),
+    ("status.favorite_empty", "スロット{}にお気に入りがありません"),
+    ("status.favorite_missing", "{}は{}の番付にいません"),
+    ("status.favorite_unranked", "{}は今場所の番付にいません"),
+    ("status.spoiler_off", "ネタバレ防止を解除しました"),
+    ("status.spoiler_on", "{}日目以降の結果を隠しています"),
+    ("status.live_on", "自動更新オン"),
+    ("status.live_off", "自動更新オフ"),
+    ("status.reloaded", "{} {} {}日目を読み込みました"),
+    ("status.reloaded_auto", "{} {} {}日目を読み込みました (自動選択)"),
+    ("status.reload_failed", "データの再読み込みに失敗しました: {}"),
+    ("status.live_updated", "自動更新: {} 更新 (次回 {})"),
+    ("status.live_failed", "自動更新に失敗しました: {}"),
+    ("status.screenshot_saved", "スクリーンショットを保存しました: {}"),
+    ("status.screenshot_failed", "スクリーンショットの保存に失敗しました: {}"),
+    ("status.inspect_hidden", "結果を隠している間は表示できません"),
+    ("status.inspect_failed", "データを表示できません: {}"),
+    ("status.inspect_nothing", "ここには表示するデータがありません"),
+    ("inspect.title", "API データ: {}"),
+    ("inspect.hint", "↑/↓ PgUp/PgDn でスクロール · Esc で閉じる"),
+    ("status.theme", "テーマ: {}"),
+    ("status.order", "取組の並び: {}"),
+    ("status.filter", "取組の表示: {}"),
+    ("status.rikishi_failed", "力士情報の読み込みに失敗しました: {}"),
+    ("status.h2h_failed", "対戦成績の読み込みに失敗しました: {}"),
+    ("status.rivals_failed", "取組履歴の読み込みに失敗しました: {}"),
+    ("status.compare_added", "{} を比較に追加しました ({}/{})"),
+    ("status.compare_removed", "{} を比較から外しました ({}/{})"),
+    ("status.compare_full", "比較できるのは {} 人までです"),
+    ("status.compare_cleared", "比較をクリアしました"),
+    ("status.compare_from_banzuke", "比較する力士は番付で選んでください"),
+    ("status.compare_failed", "比較データの読み込みに失敗しました: {}"),
+    ("status.profiles_failed", "力士プロフィールの読み込みに失敗しました: {}"),
+    ("status.career_stats_failed", "{} 人の通算成績の読み込みに失敗しました: {}"),
+    ("status.schedule_from_banzuke", "日程を見る力士を番付で選んでください"),
+    ("status.finding_bout", "{}の{}日目の取組を検索中..."),
+    ("status.bout_found", "{}は{}の取組にいます"),
+    ("status.no_bout", "{}の{}日目の取組はありません"),
+    ("status.find_bout_failed", "取組の検索に失敗しました: {}"),
+    ("status.dashboard_failed", "お気に入りを読み込めませんでした: {}"),
+    ("status.summary_incomplete", "{}日目の結果はまだそろっていません"),
+    ("status.summary_hidden", "まとめを見るには結果を表示してください"),
+    ("status.note_added", "{}日目のメモを保存しました"),
+    ("status.note_deleted", "メモを削除しました"),
+    ("status.journal_save_failed", "日記を保存できませんでした: {}"),
+    ("status.journal_unavailable", "日記を読み込めなかったため、メモは保存されません"),
+    ("status.setting_saved", "{} に保存しました"),
+    ("status.setting_save_failed", "設定を保存できません: {}"),
+    ("status.settings_unavailable", "設定ファイルの場所がないため、設定は保存されません"),
+    ("toast.day_complete", "{}日目の取組が終わりました。{}でまとめを表示"),
+    ("status.no_commands", "スクリプトのコマンドは登録されていません"),
+    ("status.script", "スクリプト: {}"),
+    ("status.hook_failed", "フックのコマンドを起動できませんでした: {}"),
+    ("status.rate_limited", "API のレート制限中です。{} 秒後に再開します"),
+    ("status.rate_limit_over", "レート制限が解除され、通信を再開しました"),
+    ("theme.default", "標準"),
+    ("theme.high_contrast", "ハイコントラスト"),
+    ("theme.monochrome", "モノクロ"),
+    ("order.match", "取組順"),
+    ("order.reverse", "結びの一番から"),
+    ("order.rank", "番付順"),
+    ("filter.all", "すべての取組"),
+    ("filter.sanyaku", "三役の取組"),
+    ("filter.maegashira", "前頭同士の取組"),
+    ("filter.favorites", "お気に入りの取組"),
+    ("filter.unfinished", "未決着の取組"),
+    ("onboarding.welcome", "sumo へようこそ！いくつかの質問で初期設定を行います（Enter で既定値のまま）。\n"),
+    ("onboarding.division", "最初に表示する階級"),
+    ("onboarding.units", "身長・体重の単位"),
+    ("onboarding.theme", "配色テーマ"),
+    ("onboarding.favorites", "お気に入り力士（英語の四股名をカンマ区切り、なしなら空欄）"),
+    ("onboarding.saved", "設定を {} に保存しました。後で変更する場合はこのファイルを編集してください。"),
+    ("onboarding.save_failed", "設定を {} に保存できませんでした: {}"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{fill, lookup, Locale, EN, JA};
+
+    #[test]
+    fn parses_locale_tags() {
+        assert_eq!(Locale::parse("ja_JP.UTF-8"), Some(Locale::Ja));
+        assert_eq!(Locale::parse("en-US"), Some(Locale::En));
+        assert_eq!(Locale::parse("C"), Some(Locale::En));
+        assert_eq!(Locale::parse("de_DE"), None);
+    }
+
+    #[test]
+    fn skips_unsupported_environment_locales() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(var, _)| *var == name).map(|(_, value)| value.to_string())
+        };
+        assert_eq!(Locale::from_env(&env(&[("LANGUAGE", "de"), ("LANG", "ja_JP.UTF-8")])), Some(Locale::Ja));
+        assert_eq!(Locale::from_env(&env(&[("LC_ALL", ""), ("LANGUAGE", "de:ja"), ("LANG", "en_US.UTF-8")])), Some(Locale::Ja));
+        assert_eq!(Locale::from_env(&env(&[("LC_MESSAGES", "en_GB"), ("LANG", "ja_JP.UTF-8")])), Some(Locale::En));
+        assert_eq!(Locale::from_env(&env(&[("LANG", "fr_FR.UTF-8")])), None);
+    }
+
+    #[test]
+    fn fills_placeholders_in_order() {
+        assert_eq!(fill("{} {} Day {}", &[&"202501", &"Makuuchi", &5]), "202501 Makuuchi Day 5");
+        assert_eq!(fill("{}日目", &[]), "{}日目");
+    }
+
+    #[test]
+    fn japanese_covers_every_english_key() {
+        for (key, _) in EN {
+            assert!(JA.iter().any(|(k, _)| k == key), "missing ja translation for {}", key);
+        }
+        assert_eq!(lookup(Locale::Ja, "torikumi.east"), "東");
+    }
+}
@@ -0,0 +1,131 @@
+use crate::api::{approximate_basho_start, SumoApi, BASHO_MONTHS};
+use chrono::NaiveDate;
+
+/// Number of days in a honbasho for the top two divisions.
+const BASHO_DAYS: u8 = 15;
+
+/// A basho with the date its first day falls on.
+pub struct ScheduledBasho {
+    pub basho_id: String,
+    pub month: u32,
+    pub start_date: NaiveDate,
+}
+
+/// Build the schedule for all six basho of a year.
+///
+/// Start dates come from the API when available and fall back to the
+/// second-Sunday approximation for basho the API does not know about yet.
+pub async fn basho_schedule(api: &SumoApi, year: i32) -> Vec<ScheduledBasho> {
+    let mut schedule = Vec::new();
+    for month in BASHO_MONTHS {
+        let basho_id = format!("{}{:02}", year, month);
+        let api_start = match api.get_basho(&basho_id).await {
+            Ok(basho) => basho.start_date_naive(),
+            Err(_) => None,
+        };
+        let start_date = match api_start.or_else(|| approximate_basho_start(year, month)) {
+            Some(date) => date,
+            None => continue,
+        };
+        schedule.push(ScheduledBasho { basho_id, month, start_date });
+    }
+    schedule
+}
+
+/// Render the schedule as an iCalendar document with one all-day event per basho day.
+/// Senshuraku (the final day) is marked with a distinct summary, category and priority.
+pub fn render_calendar(schedule: &[ScheduledBasho], stamp: chrono::DateTime<chrono::Utc>) -> String {
+    let dtstamp = stamp.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//sumo//Basho Schedule//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "X-WR-CALNAME:Sumo Basho".to_string(),
+    ];
+
+    for basho in schedule {
+        let name = SumoApi::get_basho_name(basho.month);
+        let venue = SumoApi::get_basho_venue(basho.month);
+
+        for day in 1..=BASHO_DAYS {
+            let date = basho.start_date + chrono::Duration::days(i64::from(day) - 1);
+            let next = date + chrono::Duration::days(1);
+            let senshuraku = day == BASHO_DAYS;
+
+            let summary = if senshuraku {
+                format!("{} Day {} (Senshuraku)", name, day)
+            } else {
+                format!("{} Day {}", name, day)
+            };
+
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:{}-day{}@sumo", basho.basho_id, day));
+            lines.push(format!("DTSTAMP:{}", dtstamp));
+            lines.push(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+            lines.push(format!("DTEND;VALUE=DATE:{}", next.format("%Y%m%d")));
+            lines.push(format!("SUMMARY:{}", escape_text(&summary)));
+            lines.push(format!("LOCATION:{}", escape_text(venue)));
+            lines.push("TRANSP:TRANSPARENT".to_string());
+            if senshuraku {
+                lines.push("CATEGORIES:Sumo,Senshuraku".to_string());
+                lines.push("PRIORITY:1".to_string());
+            } else {
+                lines.push("CATEGORIES:Sumo".to_string());
+            }
+            lines.push("END:VEVENT".to_string());
+        }
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    // iCalendar requires CRLF line endings, including after the last line
+    let mut out = lines.join("\r\n");
+    out.push_str("\r\n");
+    out
+}
+
+// Escape characters that have special meaning in iCalendar TEXT values
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_text, render_calendar, ScheduledBasho};
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    fn september_2025() -> Vec<ScheduledBasho> {
+        vec![ScheduledBasho {
+            basho_id: "202509".to_string(),
+            month: 9,
+            start_date: NaiveDate::from_ymd_opt(2025, 9, 14).unwrap(),
+        }]
+    }
+
+    #[test]
+    fn one_event_per_day() {
+        let stamp = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let ics = render_calendar(&september_2025(), stamp);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 15);
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250914"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn senshuraku_is_highlighted() {
+        let stamp = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let ics = render_calendar(&september_2025(), stamp);
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250928\r\nDTEND;VALUE=DATE:20250929\r\nSUMMARY:Aki Basho Day 15 (Senshuraku)"));
+        assert_eq!(ics.matches("PRIORITY:1").count(), 1);
+    }
+
+    #[test]
+    fn escapes_commas() {
+        assert_eq!(escape_text("Ryogoku Kokugikan, Tokyo"), "Ryogoku Kokugikan\\, Tokyo");
+    }
+}
@@ -0,0 +1,94 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A note written while following a basho, attached to one of its days.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct JournalEntry {
+    pub basho_id: String,
+    pub day: u8,
+    /// When the note was written
+    pub written: DateTime<Local>,
+    pub text: String,
+}
+
+/// The user's notes across every basho, kept in `journal.toml`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Journal {
+    #[serde(default, rename = "entry")]
+    pub entries: Vec<JournalEntry>,
+}
+
+impl Journal {
+    /// `journal.toml` in the platform state directory (see [`crate::paths::state_dir`]).
+    pub fn default_path() -> Option<PathBuf> {
+        Some(crate::paths::state_dir()?.join("journal.toml"))
+    }
+
+    /// Read the journal, or start an empty one when the file doesn't exist yet.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|e| anyhow::anyhow!("invalid journal {}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(anyhow::anyhow!("could not read journal {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Write the journal through a temporary file, so a crash mid-write can't lose the
+    /// notes already there.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let partial = path.with_extension("part");
+        std::fs::write(&partial, toml::to_string(self)?)?;
+        std::fs::rename(&partial, path)?;
+        Ok(())
+    }
+
+    pub fn add(&mut self, basho_id: &str, day: u8, text: &str, written: DateTime<Local>) {
+        self.entries.push(JournalEntry { basho_id: basho_id.to_string(), day, written, text: text.trim().to_string() });
+    }
+
+    /// Notes for one basho by day, in the order they were written within a day.
+    pub fn for_basho(&self, basho_id: &str) -> Vec<&JournalEntry> {
+        let mut entries: Vec<&JournalEntry> = self.entries.iter().filter(|e| e.basho_id == basho_id).collect();
+        entries.sort_by_key(|e| (e.day, e.written));
+        entries
+    }
+
+    /// Remove a note, returning it if it was there.
+    pub fn remove(&mut self, entry: &JournalEntry) -> Option<JournalEntry> {
+        let index = self.entries.iter().position(|e| e == entry)?;
+        Some(self.entries.remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Journal;
+    use chrono::{Local, TimeZone};
+
+    #[test]
+    fn lists_a_bashos_notes_by_day_and_round_trips() {
+        let at = |hour| Local.with_ymd_and_hms(2025, 1, 20, hour, 0, 0).unwrap();
+        let mut journal = Journal::default();
+        journal.add("202501", 8, "Onosato injured? ", at(18));
+        journal.add("202501", 2, "Hoshoryu looks sharp", at(19));
+        journal.add("202411", 15, "Kotozakura yusho", at(17));
+        journal.add("202501", 8, "Limping on the hanamichi", at(17));
+
+        let notes: Vec<(u8, &str)> = journal.for_basho("202501").iter().map(|e| (e.day, e.text.as_str())).collect();
+        assert_eq!(notes, [(2, "Hoshoryu looks sharp"), (8, "Limping on the hanamichi"), (8, "Onosato injured?")]);
+
+        let path = std::env::temp_dir().join(format!("sumo-journal-test-{}.toml", std::process::id()));
+        journal.save(&path).unwrap();
+        assert_eq!(Journal::load(&path).unwrap(), journal);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(Journal::load(&path).unwrap(), Journal::default());
+
+        let first = journal.for_basho("202411")[0].clone();
+        assert_eq!(journal.remove(&first), Some(first));
+        assert!(journal.for_basho("202411").is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+use crate::tui::App;
+use serde_json::{json, Map, Value};
+
+/// Fields of a torikumi row, in default output order.
+pub const TORIKUMI_FIELDS: &[&str] = &[
+    "match_no", "east", "east_id", "east_rank", "east_record",
+    "west", "west_id", "west_rank", "west_record", "winner", "winner_id", "kimarite",
+];
+
+/// Fields of a banzuke row, in default output order.
+pub const BANZUKE_FIELDS: &[&str] = &["rank", "side", "id", "shikona", "shikona_jp", "wins", "losses", "absent"];
+
+/// Render the loaded day's torikumi, or the banzuke when `banzuke` is set, as a JSON
+/// array of objects. `fields` picks and orders the keys of each object; by default all
+/// fields are included.
+pub fn render(app: &App, banzuke: bool, fields: &[String]) -> anyhow::Result<String> {
+    let available = if banzuke { BANZUKE_FIELDS } else { TORIKUMI_FIELDS };
+    if let Some(unknown) = fields.iter().find(|f| !available.contains(&f.as_str())) {
+        anyhow::bail!("unknown field \"{}\"; available fields are {}", unknown, available.join(", "));
+    }
+    let rows = if banzuke { banzuke_rows(app) } else { torikumi_rows(app) };
+    let rows: Vec<Value> = rows
+        .into_iter()
+        .map(|mut row| {
+            if fields.is_empty() {
+                return Value::Object(row);
+            }
+            let selected: Map<String, Value> = fields
+                .iter()
+                .map(|f| (f.clone(), row.remove(f).unwrap_or(Value::Null)))
+                .collect();
+            Value::Object(selected)
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&rows)?)
+}
+
+fn torikumi_rows(app: &App) -> Vec<Map<String, Value>> {
+    let banzuke = app.banzuke.as_deref().unwrap_or_default();
+    let record = |id: u32| {
+        let entry = banzuke.iter().find(|e| e.rikishi_id == id);
+        let (wins, losses) = entry.map_or((0, 0), |e| e.wins_losses(app.revealed_for(e)));
+        format!("{}-{}", wins, losses)
+    };
+    app.torikumi
+        .iter()
+        .flatten()
+        .map(|bout| {
+            // Hidden results are left null in replay and spoiler-free mode
+            let hidden = app.bout_hidden(bout);
+            let row = json!({
+                "match_no": bout.match_no,
+                "east": bout.east_shikona,
+                "east_id": bout.east_id,
+                "east_rank": bout.east_rank,
+                "east_record": record(bout.east_id),
+                "west": bout.west_shikona,
+                "west_id": bout.west_id,
+                "west_rank": bout.west_rank,
+                "west_record": record(bout.west_id),
+                "winner": if hidden { None } else { bout.winner_en.as_ref() },
+                "winner_id": if hidden { None } else { bout.winner_id },
+                "kimarite": if hidden { None } else { bout.kimarite.as_ref() },
+            });
+            into_map(row)
+        })
+        .collect()
+}
+
+fn banzuke_rows(app: &App) -> Vec<Map<String, Value>> {
+    app.banzuke
+        .iter()
+        .flatten()
+        .map(|entry| {
+            let (wins, losses, absent) = entry.win_loss_absent(app.revealed_for(entry));
+            let row = json!({
+                "rank": entry.rank,
+                "side": entry.side,
+                "id": entry.rikishi_id,
+                "shikona": entry.shikona_en,
+                "shikona_jp": entry.shikona_jp,
+                "wins": wins,
+                "losses": losses,
+                "absent": absent,
+            });
+            into_map(row)
+        })
+        .collect()
+}
+
+fn into_map(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use crate::tui::App;
+
+    #[test]
+    fn selected_fields_keep_their_order() {
+        let mut app = App::new("202509".to_string(), "Makuuchi".to_string(), 3);
+        app.set_torikumi(vec![serde_json::from_value(serde_json::json!({
+            "id": "202509-3-1", "bashoId": "202509", "division": "Makuuchi", "day": 3, "matchNo": 1,
+            "eastId": 1, "eastShikona": "Hoshoryu", "eastRank": "Yokozuna 1 East",
+            "westId": 2, "westShikona": "Onosato", "westRank": "Ozeki 1 West",
+            "kimarite": "yorikiri", "winnerId": 2, "winnerEn": "Onosato", "winnerJp": null,
+        }))
+        .unwrap()]);
+
+        let fields = vec!["winner".to_string(), "east".to_string(), "kimarite".to_string()];
+        let output = render(&app, false, &fields).unwrap();
+        let compact: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(compact.to_string(), r#"[{"winner":"Onosato","east":"Hoshoryu","kimarite":"yorikiri"}]"#);
+        assert!(render(&app, false, &["shikona".to_string()]).is_err());
+
+        // Spoiler-free mode hides the day's results
+        app.set_spoiler_from(Some(3));
+        let hidden: serde_json::Value = serde_json::from_str(&render(&app, false, &fields).unwrap()).unwrap();
+        assert_eq!(hidden.to_string(), r#"[{"winner":null,"east":"Hoshoryu","kimarite":null}]"#);
+    }
+
+    #[test]
+    fn banzuke_records_count_revealed_bouts() {
+        let mut app = App::new("202509".to_string(), "Makushita".to_string(), 7);
+        let result = |result: &str| serde_json::json!({"result": result, "opponentShikonaEn": "", "opponentShikonaJp": ""});
+        app.set_banzuke(vec![serde_json::from_value(serde_json::json!({
+            "side": "East", "rikishiID": 1, "shikonaEn": "Hoshoryu", "rankValue": 1, "rank": "Makushita 1 East",
+            "record": [result("win"), result("loss"), result("win"), result("win"), result("win"), result("loss"), result("win")],
+        }))
+        .unwrap()]);
+        let fields = ["wins", "losses", "absent"].map(str::to_string);
+        let record = |app: &App| render(app, true, &fields).unwrap().split_whitespace().collect::<String>();
+        assert_eq!(record(&app), r#"[{"wins":5,"losses":2,"absent":0}]"#);
+
+        // Spoiler-free from day 12: the last bout, on day 13, is still hidden
+        app.set_spoiler_from(Some(12));
+        app.set_bout_days(app.bout_days_key(), [(1, vec![1, 3, 5, 7, 9, 11, 13])].into());
+        assert_eq!(record(&app), r#"[{"wins":4,"losses":2,"absent":0}]"#);
+    }
+}
@@ -0,0 +1,381 @@
+use crossterm::event::KeyCode;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+/// Everything a key can be bound to in normal mode. Names match the `[keys]` config section.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Up,
+    Down,
+    PrevPage,
+    NextPage,
+    PrevSection,
+    NextSection,
+    JumpToRank,
+    Goto,
+    Select,
+    ViewTorikumi,
+    ViewBanzuke,
+    ViewInfo,
+    ViewCompare,
+    ToggleCompare,
+    ViewStats,
+    ViewRecords,
+    ViewSchedule,
+    ViewJournal,
+    ViewDashboard,
+    ViewMatchups,
+    ViewYushoHistory,
+    ViewSettings,
+    FindBout,
+    DaySummary,
+    Close,
+    ChangeDay,
+    ChangeDivision,
+    ChangeBasho,
+    ToggleReplay,
+    ReplayNext,
+    ReplayPrev,
+    ToggleAutoAdvance,
+    SpoilerDay,
+    RevealBout,
+    RevealAll,
+    ToggleHelp,
+    ToggleLive,
+    CycleTheme,
+    CycleOrder,
+    CycleFilter,
+    JumpToUnfinished,
+    Screenshot,
+    RunCommand,
+    Inspect,
+    AddNote,
+    DeleteNote,
+    Quit,
+}
+
+/// Help sections in display order, each with its heading key and actions.
+pub const HELP_SECTIONS: &[(&str, &[Action])] = &[
+    ("help.navigation", &[
+        Action::Up,
+        Action::Down,
+        Action::PrevPage,
+        Action::NextPage,
+        Action::PrevSection,
+        Action::NextSection,
+        Action::JumpToRank,
+        Action::Goto,
+        Action::Select,
+        Action::ViewTorikumi,
+        Action::ViewBanzuke,
+        Action::ViewInfo,
+        Action::ViewCompare,
+        Action::ToggleCompare,
+        Action::ViewStats,
+        Action::ViewRecords,
+        Action::ViewSchedule,
+        Action::ViewJournal,
+        Action::ViewDashboard,
+        Action::ViewMatchups,
+        Action::ViewYushoHistory,
+        Action::ViewSettings,
+        Action::FindBout,
+        Action::DaySummary,
+        Action::Close,
+    ]),
+    ("help.switch_data", &[Action::ChangeDay, Action::ChangeDivision, Action::ChangeBasho]),
+    ("help.replay", &[Action::ToggleReplay, Action::ReplayNext, Action::ReplayPrev, Action::ToggleAutoAdvance]),
+    ("help.spoilers", &[Action::SpoilerDay, Action::RevealBout, Action::RevealAll]),
+    ("help.journal", &[Action::AddNote, Action::DeleteNote]),
+    ("help.other", &[Action::ToggleHelp, Action::ToggleLive, Action::CycleTheme, Action::CycleOrder, Action::CycleFilter, Action::JumpToUnfinished, Action::Screenshot, Action::RunCommand, Action::Inspect, Action::Quit]),
+];
+
+/// Bindings that can't be remapped, listed in help under their section: (section, keys, description).
+pub const FIXED_BINDINGS: &[(&str, &str, &str)] = &[
+    ("help.navigation", "Alt-1..9", "help.favorite"),
+    ("help.navigation", "Ctrl-P", "help.switcher"),
+    ("help.other", "Ctrl-C", "help.quit"),
+    ("help.other", "Ctrl-Z", "help.suspend"),
+];
+
+impl Action {
+    /// Name of the action in the `[keys]` config section, e.g. "toggle_help".
+    pub fn config_name(self) -> String {
+        match toml::Value::try_from(self) {
+            Ok(toml::Value::String(name)) => name,
+            _ => format!("{:?}", self),
+        }
+    }
+
+    /// i18n key of the action's help description.
+    pub fn description_key(self) -> &'static str {
+        match self {
+            Action::Up => "help.up",
+            Action::Down => "help.down",
+            Action::PrevPage => "help.prev_page",
+            Action::NextPage => "help.next_page",
+            Action::PrevSection => "help.prev_section",
+            Action::NextSection => "help.next_section",
+            Action::JumpToRank => "help.jump_to_rank",
+            Action::Goto => "help.goto",
+            Action::Select => "help.details",
+            Action::ViewTorikumi => "help.view_torikumi",
+            Action::ViewBanzuke => "help.view_banzuke",
+            Action::ViewInfo => "help.view_info",
+            Action::ViewCompare => "help.view_compare",
+            Action::ToggleCompare => "help.toggle_compare",
+            Action::ViewStats => "help.view_stats",
+            Action::ViewRecords => "help.view_records",
+            Action::ViewSchedule => "help.view_schedule",
+            Action::ViewJournal => "help.view_journal",
+            Action::ViewDashboard => "help.view_dashboard",
+            Action::ViewMatchups => "help.view_matchups",
+            Action::ViewYushoHistory => "help.view_yusho_history",
+            Action::ViewSettings => "help.view_settings",
+            Action::FindBout => "help.find_bout",
+            Action::DaySummary => "help.day_summary",
+            Action::Close => "help.close",
+            Action::ChangeDay => "help.change_day",
+            Action::ChangeDivision => "help.change_division",
+            Action::ChangeBasho => "help.change_basho",
+            Action::ToggleReplay => "help.replay_toggle",
+            Action::ReplayNext => "help.replay_next",
+            Action::ReplayPrev => "help.replay_prev",
+            Action::ToggleAutoAdvance => "help.replay_auto",
+            Action::SpoilerDay => "help.spoiler_day",
+            Action::RevealBout => "help.reveal_one",
+            Action::RevealAll => "help.reveal_all",
+            Action::ToggleHelp => "help.toggle_help",
+            Action::ToggleLive => "help.live",
+            Action::CycleTheme => "help.theme",
+            Action::CycleOrder => "help.order",
+            Action::CycleFilter => "help.cycle_filter",
+            Action::JumpToUnfinished => "help.jump_to_unfinished",
+            Action::Screenshot => "help.screenshot",
+            Action::RunCommand => "help.run_command",
+            Action::Inspect => "help.inspect",
+            Action::AddNote => "help.add_note",
+            Action::DeleteNote => "help.delete_note",
+            Action::Quit => "help.quit",
+        }
+    }
+}
+
+/// Keys bound to one action in the config: a single key or a list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyList(pub Vec<KeyCode>);
+
+impl<'de> Deserialize<'de> for KeyList {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum OneOrMany {
+            One(String),
+            Many(Vec<String>),
+        }
+        let names = match OneOrMany::deserialize(deserializer)? {
+            OneOrMany::One(name) => vec![name],
+            OneOrMany::Many(names) => names,
+        };
+        names
+            .iter()
+            .map(|name| parse_key(name).ok_or_else(|| serde::de::Error::custom(format!("unknown key \"{}\"", name))))
+            .collect::<Result<Vec<_>, _>>()
+            .map(KeyList)
+    }
+}
+
+/// Key bindings for normal mode, in a fixed order so help lists keys predictably.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<(KeyCode, Action)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        use Action as A;
+        use KeyCode as K;
+        let bindings = vec![
+            (K::Char('w'), A::Up),
+            (K::Up, A::Up),
+            (K::Char('s'), A::Down),
+            (K::Down, A::Down),
+            (K::Char('a'), A::PrevPage),
+            (K::Left, A::PrevPage),
+            (K::Char('d'), A::NextPage),
+            (K::Right, A::NextPage),
+            (K::Char('{'), A::PrevSection),
+            (K::Char('}'), A::NextSection),
+            (K::Char('g'), A::JumpToRank),
+            (K::Char('G'), A::Goto),
+            (K::Enter, A::Select),
+            (K::Char(' '), A::Select),
+            (K::Char('1'), A::ViewTorikumi),
+            (K::Char('2'), A::ViewBanzuke),
+            (K::Char('3'), A::ViewInfo),
+            (K::Char('4'), A::ViewCompare),
+            (K::Char('m'), A::ToggleCompare),
+            (K::Char('5'), A::ViewStats),
+            (K::Char('6'), A::ViewRecords),
+            (K::Char('o'), A::ViewSchedule),
+            (K::Char('7'), A::ViewJournal),
+            (K::Char('8'), A::ViewDashboard),
+            (K::Char('9'), A::ViewMatchups),
+            (K::Char('0'), A::ViewSettings),
+            (K::Char('Y'), A::ViewYushoHistory),
+            (K::Char('f'), A::FindBout),
+            (K::Char('y'), A::DaySummary),
+            (K::Esc, A::Close),
+            (K::Char('c'), A::ChangeDay),
+            (K::Char('v'), A::ChangeDivision),
+            (K::Char('b'), A::ChangeBasho),
+            (K::Char('r'), A::ToggleReplay),
+            (K::Char(']'), A::ReplayNext),
+            (K::Char('['), A::ReplayPrev),
+            (K::Char('t'), A::ToggleAutoAdvance),
+            (K::Char('x'), A::SpoilerDay),
+            (K::Char('e'), A::RevealBout),
+            (K::Char('E'), A::RevealAll),
+            (K::Char('h'), A::ToggleHelp),
+            (K::F(1), A::ToggleHelp),
+            (K::Char('l'), A::ToggleLive),
+            (K::Char('T'), A::CycleTheme),
+            (K::Char('O'), A::CycleOrder),
+            (K::Char('F'), A::CycleFilter),
+            (K::Char('u'), A::JumpToUnfinished),
+            (K::Char('p'), A::Screenshot),
+            (K::Char(':'), A::RunCommand),
+            (K::Char('J'), A::Inspect),
+            (K::Char('n'), A::AddNote),
+            (K::Delete, A::DeleteNote),
+            (K::Char('q'), A::Quit),
+        ];
+        Self { bindings }
+    }
+}
+
+impl Keymap {
+    /// The default bindings with `overrides` applied. An overridden action loses its
+    /// default keys, and keys it takes over are removed from other actions.
+    pub fn with_overrides(overrides: &HashMap<Action, KeyList>) -> Self {
+        let mut keymap = Self::default();
+        // Apply in help order so the result doesn't depend on HashMap iteration order
+        let actions = HELP_SECTIONS.iter().flat_map(|(_, actions)| actions.iter());
+        for action in actions {
+            if let Some(KeyList(keys)) = overrides.get(action) {
+                keymap.bindings.retain(|(k, a)| a != action && !keys.contains(k));
+                keymap.bindings.extend(keys.iter().map(|k| (*k, *action)));
+            }
+        }
+        keymap
+    }
+
+    pub fn action(&self, key: KeyCode) -> Option<Action> {
+        self.bindings.iter().find(|(k, _)| *k == key).map(|(_, a)| *a)
+    }
+
+    /// Bind `action` to `key` alone, taking the key from any action that had it.
+    pub fn rebind(&mut self, action: Action, key: KeyCode) {
+        self.bindings.retain(|(k, a)| *a != action && *k != key);
+        self.bindings.push((key, action));
+    }
+
+    pub fn keys_for(&self, action: Action) -> Vec<KeyCode> {
+        self.bindings.iter().filter(|(_, a)| *a == action).map(|(k, _)| *k).collect()
+    }
+
+    /// Keys for an action as shown in help and the footer, e.g. "h/F1".
+    pub fn label(&self, action: Action) -> String {
+        self.keys_for(action).into_iter().map(key_name).collect::<Vec<_>>().join("/")
+    }
+}
+
+/// Parse a key name from the config: a single character, or a name such as
+/// `Enter`, `Space`, `Esc`, `Tab`, `Backspace`, `Up`, `PageDown`, `Home` or `F1`.
+pub fn parse_key(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+    let key = match name.to_ascii_lowercase().as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "space" => KeyCode::Char(' '),
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "delete" | "del" => KeyCode::Delete,
+        f if f.starts_with('f') => KeyCode::F(f[1..].parse().ok().filter(|n| (1..=12).contains(n))?),
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// Name of a key as `parse_key` reads it back from the config, e.g. "Up" for `↑`.
+pub fn config_key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        other => key_name(other),
+    }
+}
+
+/// Display name of a key, matching the names `parse_key` accepts.
+pub fn key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        other => format!("{:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_key, Action, KeyList, Keymap};
+    use crossterm::event::KeyCode;
+    use std::collections::HashMap;
+
+    #[test]
+    fn parses_key_names() {
+        assert_eq!(parse_key("Q"), Some(KeyCode::Char('Q')));
+        assert_eq!(parse_key("PageDown"), Some(KeyCode::PageDown));
+        assert_eq!(parse_key("f5"), Some(KeyCode::F(5)));
+        assert_eq!(parse_key("f13"), None);
+        assert_eq!(parse_key("hyper"), None);
+    }
+
+    #[test]
+    fn overrides_replace_defaults_and_steal_keys() {
+        let overrides: HashMap<Action, KeyList> = toml::from_str("quit = \"Q\"\ntoggle_help = [\"?\", \"q\"]\n").unwrap();
+        let keymap = Keymap::with_overrides(&overrides);
+        assert_eq!(keymap.action(KeyCode::Char('Q')), Some(Action::Quit));
+        assert_eq!(keymap.action(KeyCode::Char('q')), Some(Action::ToggleHelp));
+        assert_eq!(keymap.action(KeyCode::Char('h')), None);
+        assert_eq!(keymap.label(Action::ToggleHelp), "?/q");
+        assert!(toml::from_str::<HashMap<Action, KeyList>>("quit = \"hyper\"\n").is_err());
+    }
+}
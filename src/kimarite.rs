@@ -0,0 +1,114 @@
+use ratatui::style::Color;
+
+/// Broad family of a winning technique, grouped for coloring and counting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    /// Pushing and thrusting out or down: oshidashi, tsukidashi
+    PushThrust,
+    /// Driving out or down from the belt: yorikiri, okuridashi
+    ForceOut,
+    /// Throws and twist-downs: uwatenage, tsukiotoshi
+    Throw,
+    /// Trips, sweeps and leg grabs: sotogake, ketaguri
+    LegTrip,
+    /// Pull-downs, slap-downs, body drops and the rare techniques, plus results decided
+    /// by a misstep rather than the winner's technique
+    Special,
+}
+
+pub const CATEGORIES: [Category; 5] =
+    [Category::PushThrust, Category::ForceOut, Category::Throw, Category::LegTrip, Category::Special];
+
+const PUSH_THRUST: &[&str] = &["oshidashi", "oshitaoshi", "tsukidashi", "tsukitaoshi"];
+const FORCE_OUT: &[&str] = &[
+    "yorikiri", "yoritaoshi", "abisetaoshi", "kimedashi", "kimetaoshi", "okuridashi", "okuritaoshi",
+    "okurinage", "okurigake", "okuritsuriotoshi", "okurihikiotoshi", "okuritsuridashi", "tsuridashi",
+    "tsuriotoshi", "utchari", "waridashi",
+];
+const THROW: &[&str] = &[
+    "uwatenage", "shitatenage", "kotenage", "sukuinage", "uwatedashinage", "shitatedashinage",
+    "koshinage", "kubinage", "ipponzeoi", "nichonage", "yaguranage", "kakenage", "tsukaminage",
+    "tsukiotoshi", "makiotoshi", "tottari", "sakatottari", "katasukashi", "sotomuso", "uchimuso",
+    "zubuneri", "uwatehineri", "shitatehineri", "amiuchi", "sabaori", "harimanage", "kotehineri",
+    "kubihineri", "gasshohineri", "tokkurinage", "osakate", "kainahineri",
+];
+const LEG_TRIP: &[&str] = &[
+    "ashitori", "chongake", "kawazugake", "kekaeshi", "ketaguri", "kirikaeshi", "komatasukui",
+    "kozumatori", "mitokorozeme", "nimaigeri", "omata", "sotogake", "sotokomata", "uchigake",
+    "tsumatori", "susoharai", "susotori", "watashikomi",
+];
+const SPECIAL: &[&str] = &[
+    "hatakikomi", "hikiotoshi", "hikkake", "sokubiotoshi", "tsutaezori", "izori", "kakezori",
+    "shumokuzori", "tasukizori", "ushiromotare", "yobimodoshi",
+    // Not techniques: the loser stepped out, fell or didn't show up
+    "isamiashi", "koshikudake", "tsukihiza", "tsukite", "fumidashi", "fusen", "fusensho", "fusenpai",
+];
+
+/// The category of a kimarite as the API names it ("yorikiri", "Oshidashi"), or `None`
+/// for a name it doesn't know.
+pub fn category(kimarite: &str) -> Option<Category> {
+    let name = kimarite.trim().to_ascii_lowercase();
+    [
+        (PUSH_THRUST, Category::PushThrust),
+        (FORCE_OUT, Category::ForceOut),
+        (THROW, Category::Throw),
+        (LEG_TRIP, Category::LegTrip),
+        (SPECIAL, Category::Special),
+    ]
+    .into_iter()
+    .find(|(names, _)| names.contains(&name.as_str()))
+    .map(|(_, category)| category)
+}
+
+impl Category {
+    /// i18n key of the category's name.
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Category::PushThrust => "kimarite.push_thrust",
+            Category::ForceOut => "kimarite.force_out",
+            Category::Throw => "kimarite.throw",
+            Category::LegTrip => "kimarite.leg_trip",
+            Category::Special => "kimarite.special",
+        }
+    }
+
+    /// Color the category's kimarite are drawn in, before adapting to the terminal.
+    pub fn color(self) -> Color {
+        match self {
+            Category::PushThrust => Color::LightRed,
+            Category::ForceOut => Color::LightBlue,
+            Category::Throw => Color::LightMagenta,
+            Category::LegTrip => Color::LightGreen,
+            Category::Special => Color::LightYellow,
+        }
+    }
+}
+
+/// Winning counts per category from per-kimarite counts, in [`CATEGORIES`] order and
+/// leaving out categories without any. Unknown kimarite aren't counted.
+pub fn count(kimarite: &[(String, usize)]) -> Vec<(Category, usize)> {
+    CATEGORIES
+        .into_iter()
+        .map(|c| (c, kimarite.iter().filter(|(name, _)| category(name) == Some(c)).map(|(_, n)| n).sum()))
+        .filter(|(_, n)| *n > 0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{category, count, Category};
+
+    #[test]
+    fn groups_kimarite_into_categories() {
+        assert_eq!(category("Oshidashi"), Some(Category::PushThrust));
+        assert_eq!(category("yorikiri"), Some(Category::ForceOut));
+        assert_eq!(category("tsukiotoshi"), Some(Category::Throw));
+        assert_eq!(category("sotogake"), Some(Category::LegTrip));
+        assert_eq!(category("hatakikomi"), Some(Category::Special));
+        assert_eq!(category("fusen"), Some(Category::Special));
+        assert_eq!(category("N/A"), None);
+
+        let counts = [("yorikiri".to_string(), 5), ("hatakikomi".to_string(), 2), ("okuridashi".to_string(), 1), ("mystery".to_string(), 4)];
+        assert_eq!(count(&counts), vec![(Category::ForceOut, 6), (Category::Special, 2)]);
+    }
+}
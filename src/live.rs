@@ -0,0 +1,206 @@
+use crate::api::{Basho, TorikumiEntry};
+use crate::config::RefreshConfig;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Utc};
+use std::time::Duration;
+
+type HourMinute = (u32, u32);
+
+// JST hours each division's bouts are held, with some slack either side: the lower
+// divisions fight in the morning and makuuchi finishes around 18:00
+const BOUT_HOURS: &[(&str, HourMinute, HourMinute)] = &[
+    ("Jonokuchi", (8, 30), (10, 30)),
+    ("Jonidan", (9, 0), (12, 0)),
+    ("Sandanme", (10, 30), (13, 30)),
+    ("Makushita", (12, 0), (15, 0)),
+    ("Juryo", (14, 0), (16, 30)),
+    ("Makuuchi", (15, 30), (18, 30)),
+];
+// When each division's last bout is usually fought (JST) and the typical seconds from one
+// bout to the next, tachiai preparation included
+const BOUT_PACING: &[(&str, HourMinute, u32)] = &[
+    ("Jonokuchi", (9, 5), 100),
+    ("Jonidan", (11, 0), 100),
+    ("Sandanme", (12, 40), 120),
+    ("Makushita", (14, 15), 150),
+    ("Juryo", (15, 40), 240),
+    ("Makuuchi", (17, 55), 300),
+];
+// Used for a division not listed above
+const ALL_BOUT_HOURS: (HourMinute, HourMinute) = ((8, 30), (18, 30));
+
+/// Start and end of the JST window in which `division` holds its bouts.
+fn bout_hours(division: &str) -> (NaiveTime, NaiveTime) {
+    let (start, end) = BOUT_HOURS
+        .iter()
+        .find(|(name, _, _)| name.eq_ignore_ascii_case(division))
+        .map_or(ALL_BOUT_HOURS, |(_, start, end)| (*start, *end));
+    let time = |(h, m): HourMinute| NaiveTime::from_hms_opt(h, m, 0).expect("valid time");
+    (time(start), time(end))
+}
+
+// Whether `day` (JST) falls between the basho start and end dates
+fn basho_day(basho: Option<&Basho>, day: NaiveDate) -> bool {
+    match basho {
+        Some(b) => {
+            let started = b.start_date_naive().is_none_or(|start| day >= start);
+            let finished = b.end_date_naive().is_some_and(|end| day > end);
+            started && !finished
+        }
+        // Without basho info we cannot tell, so stay responsive
+        None => true,
+    }
+}
+
+fn jst() -> FixedOffset {
+    FixedOffset::east_opt(9 * 3600).expect("valid JST offset")
+}
+
+/// Whether the event loop can drop to low power at `now`: nothing was pressed for
+/// `low_power_after_secs` and `division` isn't inside its bout window on a basho day.
+pub fn low_power(config: &RefreshConfig, idle_for: Duration, basho: Option<&Basho>, division: &str, now: DateTime<Utc>) -> bool {
+    if config.low_power_after_secs == 0 || idle_for < Duration::from_secs(config.low_power_after_secs) {
+        return false;
+    }
+    let now_jst = now.with_timezone(&jst());
+    let (start, end) = bout_hours(division);
+    let underway = basho_day(basho, now_jst.date_naive()) && now_jst.time() >= start && now_jst.time() < end;
+    !underway
+}
+
+/// How long live mode should wait at `now` before refreshing `division`, given the time
+/// `since_last` since the last refresh.
+///
+/// The short interval is only used while the division's bouts can be in progress: inside
+/// its JST bout window on a day between the basho start and end dates. Outside the
+/// window on a basho day, polling sleeps until the next window opens. Finished and
+/// upcoming basho are checked at the idle interval.
+pub fn until_next_refresh(config: &RefreshConfig, basho: Option<&Basho>, division: &str, now: DateTime<Utc>, since_last: Duration) -> Duration {
+    let active = Duration::from_secs(config.interval_secs.max(1));
+    let idle = Duration::from_secs(config.idle_interval_secs.max(config.interval_secs).max(1));
+
+    let jst = jst();
+    let now_jst = now.with_timezone(&jst);
+    let today = now_jst.date_naive();
+
+    if !basho_day(basho, today) {
+        return idle.saturating_sub(since_last);
+    }
+
+    let (start, end) = bout_hours(division);
+    let time = now_jst.time();
+    if time >= start && time < end {
+        return active.saturating_sub(since_last);
+    }
+
+    // Sleep until the window opens later today, or tomorrow if there are bouts then
+    let next_day = if time < start { Some(today) } else { today.succ_opt().filter(|d| basho_day(basho, *d)) };
+    match next_day {
+        Some(day) => {
+            let opens = day.and_time(start).and_local_timezone(jst).single().expect("fixed offset");
+            (opens.with_timezone(&Utc) - now).to_std().unwrap_or_default()
+        }
+        None => idle.saturating_sub(since_last),
+    }
+}
+
+/// Roughly when bout `match_no` of `division` starts on `date`, counting back from the
+/// division's usual finishing time by its typical pace. `last_match_no` is the day's
+/// final bout in the division.
+pub fn estimated_start(division: &str, date: NaiveDate, match_no: u8, last_match_no: u8) -> Option<DateTime<FixedOffset>> {
+    let (_, (hour, minute), pace) = BOUT_PACING.iter().find(|(name, _, _)| name.eq_ignore_ascii_case(division))?;
+    let jst = FixedOffset::east_opt(9 * 3600).expect("valid JST offset");
+    let last = date.and_hms_opt(*hour, *minute, 0)?.and_local_timezone(jst).single()?;
+    let earlier = u32::from(last_match_no.saturating_sub(match_no)) * pace;
+    Some(last - chrono::Duration::seconds(i64::from(earlier)))
+}
+
+/// Bouts in `current` that have a result now but had none in `previous`.
+pub fn new_results<'a>(previous: &[TorikumiEntry], current: &'a [TorikumiEntry]) -> Vec<&'a TorikumiEntry> {
+    current
+        .iter()
+        .filter(|bout| bout.winner_id.is_some())
+        .filter(|bout| !previous.iter().any(|p| p.id == bout.id && p.winner_id.is_some()))
+        .collect()
+}
+
+/// Whether every bout of a day has a result.
+pub fn day_complete(torikumi: &[TorikumiEntry]) -> bool {
+    !torikumi.is_empty() && torikumi.iter().all(|bout| bout.winner_id.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{estimated_start, low_power, until_next_refresh};
+    use crate::api::Basho;
+    use crate::config::RefreshConfig;
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use std::time::Duration;
+
+    fn basho() -> Basho {
+        Basho {
+            date: Some("202509".to_string()),
+            location: None,
+            start_date: Some("2025-09-14T00:00:00Z".to_string()),
+            end_date: Some("2025-09-28T00:00:00Z".to_string()),
+            yusho: None,
+            sansho: None,
+        }
+    }
+
+    #[test]
+    fn short_interval_during_bout_hours() {
+        let config = RefreshConfig::default();
+        // 07:00 UTC is 16:00 JST
+        let now = Utc.with_ymd_and_hms(2025, 9, 20, 7, 0, 0).unwrap();
+        let next = until_next_refresh(&config, Some(&basho()), "Makuuchi", now, Duration::from_secs(20));
+        assert_eq!(next, Duration::from_secs(40));
+    }
+
+    #[test]
+    fn sleeps_until_the_division_window_opens() {
+        let config = RefreshConfig::default();
+        // 07:00 UTC is 16:00 JST, after the jonokuchi bouts; they start again at 08:30
+        let now = Utc.with_ymd_and_hms(2025, 9, 20, 7, 0, 0).unwrap();
+        let next = until_next_refresh(&config, Some(&basho()), "Jonokuchi", now, Duration::ZERO);
+        assert_eq!(next, Duration::from_secs((16 * 60 + 30) * 60));
+        // 15:00 UTC is midnight JST, makuuchi starts at 15:30
+        let now = Utc.with_ymd_and_hms(2025, 9, 20, 15, 0, 0).unwrap();
+        let next = until_next_refresh(&config, Some(&basho()), "Makuuchi", now, Duration::ZERO);
+        assert_eq!(next, Duration::from_secs((15 * 60 + 30) * 60));
+    }
+
+    #[test]
+    fn low_power_only_when_idle_outside_bout_hours() {
+        let config = RefreshConfig { low_power_after_secs: 300, ..RefreshConfig::default() };
+        let idle = Duration::from_secs(600);
+        // 07:00 UTC is 16:00 JST, during the makuuchi bouts
+        let during = Utc.with_ymd_and_hms(2025, 9, 20, 7, 0, 0).unwrap();
+        assert!(!low_power(&config, idle, Some(&basho()), "Makuuchi", during));
+        assert!(low_power(&config, idle, Some(&basho()), "Jonokuchi", during));
+        assert!(!low_power(&config, Duration::from_secs(60), Some(&basho()), "Jonokuchi", during));
+        let after = Utc.with_ymd_and_hms(2025, 10, 1, 7, 0, 0).unwrap();
+        assert!(low_power(&config, idle, Some(&basho()), "Makuuchi", after));
+        let never = RefreshConfig { low_power_after_secs: 0, ..config };
+        assert!(!low_power(&never, idle, Some(&basho()), "Jonokuchi", during));
+    }
+
+    #[test]
+    fn estimates_bout_times_back_from_the_last_bout() {
+        let date = NaiveDate::from_ymd_opt(2025, 9, 20).unwrap();
+        let time = |division, match_no, last| estimated_start(division, date, match_no, last).map(|t| t.format("%H:%M %z").to_string());
+        assert_eq!(time("Makuuchi", 21, 21).as_deref(), Some("17:55 +0900"));
+        assert_eq!(time("Makuuchi", 1, 21).as_deref(), Some("16:15 +0900"));
+        assert_eq!(time("juryo", 1, 14).as_deref(), Some("14:48 +0900"));
+        assert_eq!(time("Mae-zumo", 1, 5), None);
+    }
+
+    #[test]
+    fn backs_off_after_basho() {
+        let config = RefreshConfig::default();
+        let now = Utc.with_ymd_and_hms(2025, 10, 1, 7, 0, 0).unwrap();
+        assert_eq!(until_next_refresh(&config, Some(&basho()), "Makuuchi", now, Duration::ZERO), Duration::from_secs(1800));
+        // The evening of senshuraku has no bouts to wait for
+        let now = Utc.with_ymd_and_hms(2025, 9, 28, 12, 0, 0).unwrap();
+        assert_eq!(until_next_refresh(&config, Some(&basho()), "Makuuchi", now, Duration::ZERO), Duration::from_secs(1800));
+    }
+}
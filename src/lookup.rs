@@ -0,0 +1,87 @@
+use crate::api::{SumoApi, TorikumiEntry};
+use futures::future::join_all;
+
+/// Where a rikishi's bout on the looked-up day was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoutLocation {
+    pub division: String,
+    pub rikishi_id: u32,
+    pub shikona: String,
+}
+
+/// The rikishi in `torikumi` whose shikona matches `query`, ignoring case. An exact name
+/// wins over one that only starts with the query.
+pub fn find_in(torikumi: &[TorikumiEntry], query: &str) -> Option<(u32, String)> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+    let sides = || {
+        torikumi
+            .iter()
+            .flat_map(|b| [(b.east_id, &b.east_shikona), (b.west_id, &b.west_shikona)])
+            .map(|(id, shikona)| (id, shikona.to_string()))
+    };
+    sides()
+        .find(|(_, shikona)| shikona.to_lowercase() == query)
+        .or_else(|| sides().find(|(_, shikona)| shikona.to_lowercase().starts_with(&query)))
+}
+
+/// Look for the rikishi on `day` of every division at once and return the highest
+/// division where they have a bout. Failed divisions only matter if nothing was found.
+pub async fn find_bout(api: &SumoApi, basho_id: &str, day: u8, divisions: &[&str], query: &str) -> anyhow::Result<Option<BoutLocation>> {
+    let results = join_all(divisions.iter().map(|division| api.get_torikumi(basho_id, division, day))).await;
+    let mut first_error = None;
+    for (division, result) in divisions.iter().zip(results) {
+        match result {
+            Ok(response) => {
+                if let Some((rikishi_id, shikona)) = find_in(response.torikumi.as_deref().unwrap_or_default(), query) {
+                    return Ok(Some(BoutLocation { division: division.to_string(), rikishi_id, shikona }));
+                }
+            }
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    match first_error {
+        Some(e) => Err(e),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_in;
+    use crate::api::TorikumiEntry;
+
+    fn bout(east: (u32, &str), west: (u32, &str)) -> TorikumiEntry {
+        TorikumiEntry {
+            id: String::new(),
+            basho_id: "202501".to_string(),
+            division: "Juryo".to_string(),
+            day: 3,
+            match_no: 1,
+            east_id: east.0,
+            east_shikona: east.1.into(),
+            east_rank: "Juryo 1 East".to_string(),
+            west_id: west.0,
+            west_shikona: west.1.into(),
+            west_rank: "Juryo 1 West".to_string(),
+            kimarite: None,
+            winner_id: None,
+            winner_en: None,
+            winner_jp: None,
+        }
+    }
+
+    #[test]
+    fn exact_names_beat_prefixes() {
+        let torikumi = vec![bout((1, "Tamawashi"), (2, "Tamashoho")), bout((3, "Tama"), (4, "Enho"))];
+        assert_eq!(find_in(&torikumi, "tama"), Some((3, "Tama".to_string())));
+        assert_eq!(find_in(&torikumi, " TAMASH "), Some((2, "Tamashoho".to_string())));
+        assert_eq!(find_in(&torikumi, "enh"), Some((4, "Enho".to_string())));
+        assert_eq!(find_in(&torikumi, "Hakuho"), None);
+        assert_eq!(find_in(&torikumi, ""), None);
+    }
+}
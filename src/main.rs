@@ -1,85 +1,632 @@
+mod achievements;
 mod api;
+mod bench;
+mod cache;
 mod cli;
+mod exit;
+mod goto;
+mod headless;
+mod compare;
+mod config;
+mod dashboard;
+mod dates;
+mod digest;
+mod doctor;
+mod form;
+mod highlight;
+mod hooks;
+mod html;
+mod i18n;
+mod json;
+mod keymap;
+mod ical;
+mod journal;
+mod kimarite;
+mod live;
+mod lookup;
+mod matchups;
+mod onboarding;
+mod paths;
+mod prefetch;
+mod queue;
+mod projection;
+mod rank;
+mod recent;
+mod requests;
+mod rivals;
+mod romaji;
+mod schedule;
+mod series;
+mod scripting;
+mod session;
+mod settings;
+mod sheet;
+mod signals;
+mod snapshot;
+mod stats;
+mod statusline;
+mod summary;
+mod switcher;
+mod text;
+mod theme;
 mod tui;
+mod update;
+mod yusho_history;
 
 use clap::Parser;
 use api::SumoApi;
-use cli::Args;
-use tui::{App, AppView, setup_terminal, restore_terminal};
-use crossterm::event::{self, Event};
+use cli::{Args, CacheCommand, Command, Division, OutputFormat, Verbosity};
+use config::{BanzukeColumn, Config, HooksConfig, RefreshConfig};
+use requests::{Kind, Reply, Requests};
+use scripting::Scripts;
+use i18n::{t, tf};
+use tui::{App, AppView, DayAdjustment, Pending, setup_terminal, restore_terminal};
+use crossterm::event::{Event, EventStream};
+use futures::StreamExt;
+use tokio::sync::mpsc;
+use std::collections::HashMap;
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::io;
+use std::io::{self, IsTerminal};
+use std::time::{Duration, Instant};
 use chrono::{Datelike, Utc};
 
 #[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
+async fn main() {
+    let args = match Args::try_parse() {
+        Ok(args) => args,
+        Err(e) if !e.use_stderr() || !json_errors_requested() => e.exit(),
+        Err(e) => fail(e.into(), exit::ErrorFormat::Json),
+    };
+    let errors = args.errors;
+    if let Err(e) = run(args).await {
+        fail(e, errors);
+    }
+}
+
+// Print a fatal error and exit with the code for its kind of failure
+fn fail(error: anyhow::Error, format: exit::ErrorFormat) -> ! {
+    let failure = exit::classify(&error);
+    eprintln!("{}", exit::render(&error, failure, format));
+    log_failure(&error);
+    std::process::exit(failure.code());
+}
+
+// Append a fatal error to `errors.log` in the log directory, best effort
+fn log_failure(error: &anyhow::Error) {
+    use std::io::Write;
+    let Some(dir) = paths::log_dir() else {
+        return;
+    };
+    let file = std::fs::create_dir_all(&dir)
+        .and_then(|()| std::fs::OpenOptions::new().create(true).append(true).open(dir.join("errors.log")));
+    if let Ok(mut file) = file {
+        let _ = writeln!(file, "{} {:#}", Utc::now().to_rfc3339(), error);
+    }
+}
+
+// Whether `--errors json` is on the command line, for reporting errors in the
+// arguments themselves
+fn json_errors_requested() -> bool {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter().any(|a| a == "--errors=json") || args.windows(2).any(|w| w[0] == "--errors" && w[1] == "json")
+}
+
+async fn run(mut args: Args) -> anyhow::Result<()> {
+    if let Some(basho) = &args.basho
+        && !valid_basho_id(basho)
+    {
+        return Err(exit::Exit::error(exit::Failure::BadArguments, format!("invalid basho ID \"{}\", expected YYYYMM", basho)));
+    }
+    if !args.fields.is_empty() && args.format != OutputFormat::Json {
+        return Err(exit::Exit::error(exit::Failure::BadArguments, "--fields only applies to --format json".to_string()));
+    }
+    let verbosity = args.verbosity();
+    // Piped or redirected output gets plain text instead of a TUI nobody can see. A
+    // headless run goes as far as the TUI would, then reads its script instead
+    let format = if args.headless.is_some() {
+        OutputFormat::Tui
+    } else if args.format == OutputFormat::Tui && !io::stdout().is_terminal() {
+        OutputFormat::Text
+    } else {
+        args.format
+    };
+    let loaded = Config::load(args.config.as_deref()).and_then(|mut config| config.apply_env().map(|()| config));
+    let (mut config, config_error) = match loaded {
+        Ok(config) => (config, None),
+        // The doctor reports a broken config instead of refusing to run
+        Err(e) if matches!(args.command, Some(Command::Doctor)) => (Config::default(), Some(e)),
+        Err(e) => return Err(e),
+    };
+    i18n::set_locale(i18n::Locale::detect(config.locale.as_deref()));
+    romaji::set_style(config.romanization);
+    dates::set_format(config.date_format);
+
+    if let Some(text) = &args.goto {
+        let goto = goto::parse(text, Utc::now().year())
+            .map_err(|e| exit::Exit::error(exit::Failure::BadArguments, format!("--goto: {}", e)))?;
+        args.basho = goto.basho_id.or(args.basho);
+        args.day = goto.day.or(args.day);
+        if let Some(division) = goto.division {
+            args.division = <Division as clap::ValueEnum>::from_str(division, true).ok();
+        }
+    }
+
+    // First launch in a terminal: ask a few questions and write the default config file
+    if args.config.is_none() && args.command.is_none() && format == OutputFormat::Tui && args.headless.is_none()
+        && let Some(path) = Config::default_path()
+        && onboarding::should_run(&path)
+    {
+        let answers = onboarding::ask(&mut io::stdin().lock(), &mut io::stderr())?;
+        match answers.save(&path) {
+            Ok(()) => {
+                eprintln!("{}", tf("onboarding.saved", &[&path.display()]));
+                config = Config::load(Some(&path))?;
+                config.apply_env()?;
+            }
+            Err(e) => eprintln!("{}", tf("onboarding.save_failed", &[&path.display(), &e])),
+        }
+    }
     
     // Initialize API client
-    let api = SumoApi::new();
+    let traffic = match (&args.record, &args.playback) {
+        (Some(dir), _) => api::Traffic::Record(dir.clone()),
+        (None, Some(dir)) => api::Traffic::Playback(dir.clone()),
+        (None, None) => api::Traffic::Network,
+    };
+    let playback = matches!(traffic, api::Traffic::Playback(_));
+    let mut api = SumoApi::with_config(&config.api)?.with_traffic(traffic);
+    if !playback && let Some(cache) = cache::DiskCache::default_location(&config.cache) {
+        api = api.with_disk_cache(cache);
+    }
+    api.set_trace(verbosity >= Verbosity::Debug);
+
+    match args.command {
+        Some(Command::Doctor) => {
+            let config_path = args.config.clone().or_else(Config::default_path);
+            let checks = doctor::run(&api, config_path.as_deref(), config_error.as_ref(), api.disk_cache()).await;
+            print!("{}", doctor::render(&checks));
+            let failed = checks.iter().filter(|c| c.status == doctor::Status::Fail).count();
+            if failed > 0 {
+                return Err(exit::Exit::error(exit::Failure::Other, format!("{} of {} checks failed", failed, checks.len())));
+            }
+            return Ok(());
+        }
+        Some(command) => return run_command(&api, &config, command, verbosity).await,
+        None => {}
+    }
     
+    // --resume starts where the last session left off; any flag given explicitly (--basho,
+    // --day, --division, --replay, --banzuke) wins over the resumed state
+    let session = if args.resume {
+        session::Session::default_path().and_then(|p| session::Session::load(&p))
+    } else {
+        None
+    };
+
     // Determine basho ID
-    let basho_id = if let Some(basho) = args.basho {
+    let basho_id = if let Some(basho) = args.basho.clone() {
         basho
+    } else if let Some(session) = &session {
+        session.basho_id.clone()
     } else {
         api.get_current_basho_id().await
     };
     
+    let replay = args.replay || args.replay_interval.is_some();
+
     // Determine day
     let day = if let Some(day) = args.day {
         day
+    } else if replay {
+        1
+    } else if let Some(session) = session.as_ref().filter(|s| args.basho.is_none() || args.basho.as_ref() == Some(&s.basho_id)) {
+        session.day
     } else {
         api.get_current_day(&basho_id).await.unwrap_or(1)
     };
     
-    let division = args.division.to_string();
+    let division = match (&args.division, &session, &config.division) {
+        (Some(division), _, _) => division.to_string(),
+        (None, Some(session), _) => session.division.clone(),
+        (None, None, Some(division)) => division.to_string(),
+        (None, None, None) => Division::Makuuchi.to_string(),
+    };
     
+    if verbosity >= Verbosity::Verbose {
+        let config_path = args.config.clone().or_else(Config::default_path).filter(|p| p.exists());
+        eprintln!("Config: {}", config_path.map_or_else(|| "defaults".to_string(), |p| p.display().to_string()));
+        eprintln!("Cache: {}", api.disk_cache().map_or_else(|| "off".to_string(), |c| c.dir().display().to_string()));
+        let basho_source = if args.basho.is_some() {
+            "--basho"
+        } else if session.is_some() {
+            "last session"
+        } else {
+            "current basho"
+        };
+        eprintln!("Basho {} from {}, day {}, {}", basho_id, basho_source, day, division);
+    }
+
     // Create app
     let mut app = App::new(basho_id.clone(), division.clone(), day);
     
     // Set initial view based on args
     if args.banzuke {
         app.current_view = AppView::Banzuke;
+    } else if let Some(session) = &session {
+        app.current_view = session.view();
+        app.dashboard_requested = app.current_view == AppView::Dashboard;
+    }
+
+    if args.spoiler_free.is_some() {
+        app.set_spoiler_from(args.spoiler_free);
+    }
+
+    app.live = args.live || config.refresh.enabled;
+    app.favorites = config.favorites.clone();
+    app.units = config.units;
+    app.config_path = args.config.clone().or_else(Config::default_path);
+    app.default_division = config.division.as_ref().map_or_else(|| app.default_division.clone(), |d| d.to_string());
+    app.refresh_interval_secs = config.refresh.interval_secs;
+    app.kanji_ranks = config.kanji_ranks;
+    app.reduced_motion = config.reduced_motion;
+    app.navigation = config.navigation;
+    app.torikumi_columns = config.torikumi.columns.clone();
+    app.banzuke_columns = config.banzuke.columns.clone();
+    // NO_COLOR asks for no colors at all, whatever the configured theme
+    app.theme_name = if theme::no_color_requested() { theme::ThemeName::Monochrome } else { config.theme };
+    app.keymap = keymap::Keymap::with_overrides(&config.keys);
+    app.theme_colors = config.colors.clone();
+    app.highlight_rules = config.highlight.clone();
+    app.color_support = theme::ColorSupport::detect();
+    app.theme = theme::Theme::build(app.theme_name, &app.theme_colors, app.color_support);
+
+    if replay {
+        let interval = args.replay_interval.unwrap_or(tui::DEFAULT_REPLAY_INTERVAL_SECS);
+        app.start_replay(Duration::from_secs(interval), args.replay_interval.is_some());
     }
     
     // Load initial data before setting up terminal
-    match load_data(&api, &basho_id, &division, day, &mut app, true).await {
-        Ok(_) => {
-            // Data loaded successfully, continue
-        },
-        Err(e) => {
-            eprintln!("Error loading data: {}", e);
-            eprintln!("Please check your internet connection and try again.");
-            eprintln!("You can also try specifying a different basho with --basho YYYYMM");
-            std::process::exit(1);
+    let started = Instant::now();
+    let mut data = load_data(&api, &LoadRequest::from_app(&app), verbosity)
+        .await
+        .map_err(|e| e.context("could not load data; check your internet connection or try a different basho with --basho YYYYMM"))?;
+    let load_errors = std::mem::take(&mut data.errors);
+    data.apply(&mut app);
+    if verbosity >= Verbosity::Verbose {
+        eprintln!("Loaded in {} ms", started.elapsed().as_millis());
+    }
+    if format != OutputFormat::Tui {
+        check_report_data(&app, args.banzuke, load_errors)?;
+        // Lower-division records list bouts, which need their days to stop at the loaded
+        // one in the HTML day report, or before the results replay and spoiler-free mode hide
+        let through_loaded_day = format == OutputFormat::Html && !args.banzuke && app.day < BASHO_DAYS;
+        if max_day_for_division(&app.division) < BASHO_DAYS && (through_loaded_day || app.last_visible_day().is_some()) {
+            let days = schedule::fetch_bout_days(&api, &app.basho_id, &app.division).await;
+            app.set_bout_days(app.bout_days_key(), days);
+        }
+    }
+
+    if format == OutputFormat::Html {
+        if args.banzuke {
+            print!("{}", html::render_banzuke(&app));
+        } else {
+            print!("{}", html::render_day_report(&app));
+        }
+        return Ok(());
+    }
+
+    if format == OutputFormat::Text {
+        if args.banzuke {
+            print!("{}", sheet::render_text(&app.basho_id, &app.division, app.banzuke.as_deref().unwrap_or_default()));
+        } else {
+            print!("{}", sheet::render_day_text(&app));
+        }
+        return Ok(());
+    }
+
+    if format == OutputFormat::Json {
+        let json = json::render(&app, args.banzuke, &args.fields).map_err(|e| exit::Exit::error(exit::Failure::BadArguments, e.to_string()))?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    // Reports keep match order; the configured order only applies to the TUI
+    app.set_torikumi_order(config.torikumi.order);
+    app.set_torikumi_filter(config.torikumi.filter);
+
+    let (mut scripts, errors) = Scripts::load_default();
+    if verbosity >= Verbosity::Normal {
+        for error in &errors {
+            eprintln!("⚠ Warning: Script failed to load: {}", error);
         }
     }
+    app.script_commands = scripts.command_names();
+    app.cached_basho = api.disk_cache().map(|cache| cache.basho_ids()).unwrap_or_default();
+
+    if let Some(path) = journal::Journal::default_path() {
+        match journal::Journal::load(&path) {
+            Ok(journal) => {
+                app.journal = journal;
+                app.journal_path = Some(path);
+            }
+            Err(e) if verbosity >= Verbosity::Normal => eprintln!("⚠ Warning: {}; notes won't be saved", e),
+            Err(_) => {}
+        }
+    }
+    let messages = scripts.on_day_loaded(&app);
+    show_script_messages(&mut app, messages);
+
+    if let Some(path) = &args.headless {
+        let script = if path.as_os_str() == "-" {
+            io::read_to_string(io::stdin())?
+        } else {
+            std::fs::read_to_string(path).map_err(|e| anyhow::anyhow!("could not read {}: {}", path.display(), e))?
+        };
+        let steps = headless::parse(&script)
+            .map_err(|e| exit::Exit::error(exit::Failure::BadArguments, format!("--headless: {}", e)))?;
+        api.set_trace(false);
+        return run_headless(app, &api, &config, scripts, &steps).await;
+    }
+
+    // Requests from here on would draw over the TUI
+    api.set_trace(false);
+    if verbosity >= Verbosity::Normal {
+        eprintln!("Data loading completed. Starting TUI...");
+        // Leave the messages above on screen long enough to be read
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    }
     
     // Setup terminal after data is loaded
     let mut terminal = setup_terminal()?;
     
     // Run the app with async support for reloading
-    let result = run_app_with_reload(&mut terminal, app, api).await;
+    let result = run_app_with_reload(&mut terminal, app, api, &config.refresh, &config.hooks, config.updates.check && args.playback.is_none(), scripts).await;
     
     // Restore terminal
     restore_terminal(&mut terminal)?;
     
-    if let Err(err) = result {
-        eprintln!("Error running app: {}", err);
-        std::process::exit(1);
+    let app = result.map_err(|e| anyhow::Error::from(e).context("error running app"))?;
+    if let Some(path) = session::Session::default_path()
+        && let Err(e) = session::Session::from_app(&app).save(&path)
+        && verbosity >= Verbosity::Normal
+    {
+        eprintln!("⚠ Warning: Could not save session: {}", e);
     }
     
     Ok(())
 }
 
-async fn load_data(
-    api: &SumoApi,
-    basho_id: &str,
-    division: &str,
+async fn run_command(api: &SumoApi, config: &Config, command: Command, verbosity: Verbosity) -> anyhow::Result<()> {
+    match command {
+        Command::Ical { year, output } => {
+            let year = year.unwrap_or_else(|| Utc::now().year());
+            let schedule = ical::basho_schedule(api, year).await;
+            let calendar = ical::render_calendar(&schedule, Utc::now());
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, calendar)?;
+                    if verbosity >= Verbosity::Normal {
+                        eprintln!("✓ Wrote {} basho to {}", schedule.len(), path.display());
+                    }
+                }
+                None => print!("{}", calendar),
+            }
+        }
+        Command::Prefetch { basho, divisions } => {
+            let Some(cache) = api.disk_cache() else {
+                anyhow::bail!("prefetch needs the network and a cache directory (is $HOME set, and --playback unused?)");
+            };
+            let basho_id = match basho {
+                Some(basho) => basho,
+                None => api.get_current_basho_id().await,
+            };
+            let divisions = prefetch_divisions(&divisions, config)?;
+            let report = if verbosity >= Verbosity::Normal {
+                prefetch::run(api, cache, &basho_id, &divisions, &mut io::stderr()).await?
+            } else {
+                prefetch::run(api, cache, &basho_id, &divisions, &mut io::sink()).await?
+            };
+            if verbosity >= Verbosity::Normal {
+                eprintln!(
+                    "✓ Fetched {} responses for basho {} ({} already cached) into {}",
+                    report.fetched,
+                    basho_id,
+                    report.skipped,
+                    cache.dir().display()
+                );
+            }
+            if !report.failed.is_empty() {
+                for (item, e) in &report.failed {
+                    eprintln!("✗ {}: {}", item, e);
+                }
+                anyhow::bail!("{} responses could not be fetched; run the command again to retry them", report.failed.len());
+            }
+        }
+        Command::Cache { action: CacheCommand::Stats } => {
+            let Some(cache) = cache::DiskCache::default_location(&config.cache) else {
+                anyhow::bail!("no cache directory: could not find the home directory");
+            };
+            print!("{}", cache::render_stats(&cache, &cache.stats()?));
+        }
+        Command::Status { one_line, max_age } => {
+            let division = config.division.clone().unwrap_or(Division::Makuuchi).to_string();
+            let parts = statusline::current(api, &division, &config.favorites, max_age).await?;
+            if one_line {
+                println!("{}", parts.join(" | "));
+            } else {
+                parts.iter().for_each(|part| println!("{}", part));
+            }
+        }
+        Command::Digest { basho, division, day, output, mail } => {
+            let basho_id = match basho {
+                Some(basho) => basho,
+                None => api.get_current_basho_id().await,
+            };
+            let day = match day {
+                Some(day) => day,
+                None => api.get_current_day(&basho_id).await.unwrap_or(1),
+            };
+            let division = division.or_else(|| config.division.clone()).unwrap_or(Division::Makuuchi).to_string();
+            let mut app = App::new(basho_id, division, day);
+            let mut data = load_data(api, &LoadRequest::from_app(&app), verbosity).await?;
+            let load_errors = std::mem::take(&mut data.errors);
+            data.apply(&mut app);
+            check_report_data(&app, false, load_errors)?;
+
+            let digest = digest::compose(&app);
+            if mail {
+                digest::send(&config.digest, &digest).await?;
+                if verbosity >= Verbosity::Normal {
+                    eprintln!("✓ Mailed \"{}\" to {}", digest.subject, config.digest.to.as_deref().unwrap_or_default());
+                }
+            }
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, &digest.body)?;
+                    if verbosity >= Verbosity::Normal {
+                        eprintln!("✓ Wrote the digest to {}", path.display());
+                    }
+                }
+                None if !mail => print!("{}", digest.body),
+                None => {}
+            }
+        }
+        Command::Doctor => unreachable!("the doctor runs before a valid config is required"),
+        Command::Bench { frames, width, height } => print!("{}", bench::run(frames, width, height)?),
+    }
+    Ok(())
+}
+
+/// Resolve `--divisions` to canonical division names: `all`, a list, or by default the
+/// configured division, then makuuchi.
+fn prefetch_divisions(requested: &[String], config: &Config) -> anyhow::Result<Vec<String>> {
+    if requested.is_empty() {
+        let division = config.division.clone().unwrap_or(Division::Makuuchi);
+        return Ok(vec![division.to_string()]);
+    }
+    if requested.iter().any(|d| d.trim().eq_ignore_ascii_case("all")) {
+        return Ok(tui::DIVISIONS.iter().map(|d| d.to_string()).collect());
+    }
+    requested
+        .iter()
+        .map(|name| {
+            tui::DIVISIONS
+                .iter()
+                .find(|d| d.eq_ignore_ascii_case(name.trim()))
+                .map(|d| d.to_string())
+                .ok_or_else(|| {
+                    let message = format!("unknown division '{}' (expected one of {} or all)", name, tui::DIVISIONS.join(", "));
+                    exit::Exit::error(exit::Failure::BadArguments, message)
+                })
+        })
+        .collect()
+}
+
+/// Fail a report that would come out empty, saying why: the API couldn't be reached,
+/// the basho doesn't exist, or it has no data for the request yet.
+fn check_report_data(app: &App, banzuke: bool, errors: Vec<anyhow::Error>) -> anyhow::Result<()> {
+    let empty = if banzuke {
+        app.banzuke.as_deref().is_none_or(<[_]>::is_empty)
+    } else {
+        app.torikumi.as_deref().is_none_or(<[_]>::is_empty)
+    };
+    if !empty {
+        return Ok(());
+    }
+    // A missing basho explains everything else, then a network failure
+    let telling = errors.into_iter().min_by_key(|e| match exit::classify(e) {
+        exit::Failure::NotFound => 0,
+        exit::Failure::Network => 1,
+        _ => 2,
+    });
+    if let Some(e) = telling {
+        return Err(e);
+    }
+    let what = if banzuke { "banzuke".to_string() } else { format!("torikumi for day {}", app.day) };
+    Err(exit::Exit::error(exit::Failure::NoData, format!("no {} {} in basho {} yet", app.division, what, app.basho_id)))
+}
+
+/// What to fetch for one load, captured from the app so the fetch can run as a
+/// background task.
+struct LoadRequest {
+    basho_id: String,
+    division: String,
     day: u8,
-    app: &mut App,
-    log_to_stderr: bool,
-) -> anyhow::Result<()> {
+    basho_changed: bool,
+    replaying: bool,
+    load_profiles: bool,
+    // Key of the previous-ranks data to fetch, when the rank-change column needs it
+    previous_ranks_key: Option<String>,
+    // Whether the division had a playoff, when already checked for this basho
+    playoff: Option<bool>,
+}
+
+impl LoadRequest {
+    fn from_app(app: &App) -> Self {
+        // The previous ranks cover every division, so only another basho needs new ones
+        let key = app.basho_id.clone();
+        let previous_ranks_key = (app.banzuke_columns.contains(&BanzukeColumn::RankChange)
+            && app.previous_ranks.as_ref().map(|(k, _)| k != &key).unwrap_or(true))
+        .then_some(key);
+        Self {
+            basho_id: app.basho_id.clone(),
+            division: app.division.clone(),
+            day: app.day,
+            basho_changed: app.basho_changed,
+            replaying: app.replay.is_some(),
+            load_profiles: app.rikishi_profiles.is_empty() && app.banzuke_columns.iter().any(|c| c.needs_profile())
+                || app.highlight_rules.iter().any(|r| r.needs_profile()),
+            previous_ranks_key,
+            playoff: app.playoff(),
+        }
+    }
+}
+
+/// Everything fetched for one load; applied to the app in one step.
+struct LoadedData {
+    basho: Option<api::Basho>,
+    day: u8,
+    torikumi: Vec<api::TorikumiEntry>,
+    banzuke: Option<Vec<api::BanzukeEntry>>,
+    profiles: Option<Vec<api::RikishiDetails>>,
+    previous_ranks: Option<(String, HashMap<u32, String>)>,
+    /// Whether the division had a playoff, if it was checked
+    playoff: Option<bool>,
+    /// Why `day` isn't the requested day, when it isn't
+    adjustment: Option<DayAdjustment>,
+    /// Why the basho, torikumi or banzuke are missing, if they are
+    errors: Vec<anyhow::Error>,
+}
+
+impl LoadedData {
+    fn apply(self, app: &mut App) {
+        if let Some(basho) = self.basho {
+            app.set_basho(basho);
+        }
+        app.day = self.day;
+        app.set_torikumi(self.torikumi);
+        if let Some(banzuke) = self.banzuke {
+            app.set_banzuke(banzuke);
+        }
+        if let Some(profiles) = self.profiles {
+            app.rikishi_profiles = profiles.into_iter().map(|r| (r.id, r)).collect();
+        }
+        if self.previous_ranks.is_some() {
+            app.previous_ranks = self.previous_ranks;
+        }
+        if let Some(playoff) = self.playoff {
+            app.set_playoff(playoff);
+        }
+        app.invalidate_rows();
+        app.basho_changed = false;
+    }
+}
+
+async fn load_data(api: &SumoApi, request: &LoadRequest, verbosity: Verbosity) -> anyhow::Result<LoadedData> {
+    let log_to_stderr = verbosity >= Verbosity::Normal;
+    let basho_id = request.basho_id.as_str();
+    let division = request.division.as_str();
+    let day = request.day;
     if log_to_stderr {
         eprintln!(
             "Loading data for basho {} division {} (requested day {})...",
@@ -91,13 +638,15 @@ async fn load_data(
 
     let max_day_allowed = max_day_for_division(division);
     let original_day = day;
-    let mut resolved_day = original_day.clamp(1, max_day_allowed);
+    let last_day = if request.playoff == Some(true) { PLAYOFF_DAY } else { max_day_allowed };
+    let mut resolved_day = original_day.clamp(1, last_day);
+    let mut adjustment = Some(DayAdjustment::DivisionDays);
+    let mut playoff = None;
     let today = Utc::now().date_naive();
 
-    // Clear existing torikumi data to avoid showing stale bouts while reloading
-    app.clear_torikumi();
-
     let mut skip_torikumi = false;
+    let mut errors = Vec::new();
+    let mut loaded_basho = None;
 
     // Load basho info
     match api.get_basho(basho_id).await {
@@ -107,32 +656,30 @@ async fn load_data(
             }
 
             let start_date = basho.start_date_naive();
-            let end_date = basho
-                .end_date
-                .as_deref()
-                .and_then(|s| s.split('T').next())
-                .and_then(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+            let end_date = basho.end_date_naive();
             let basho_ym = parse_basho_year_month(basho_id);
 
             let mut is_future = start_date.map(|s| today < s).unwrap_or(false);
             let mut is_finished = end_date.map(|e| today > e).unwrap_or(false);
 
-            if let Some((by, bm)) = basho_ym {
-                if !is_future && !is_finished {
-                    let now_tuple = (today.year(), today.month());
-                    let basho_tuple = (by, bm);
-                    if basho_tuple > now_tuple {
-                        is_future = true;
-                    } else if basho_tuple < now_tuple {
-                        is_finished = true;
-                    }
+            if let Some((by, bm)) = basho_ym
+                && !is_future
+                && !is_finished
+            {
+                let now_tuple = (today.year(), today.month());
+                let basho_tuple = (by, bm);
+                if basho_tuple > now_tuple {
+                    is_future = true;
+                } else if basho_tuple < now_tuple {
+                    is_finished = true;
                 }
             }
 
             if is_future {
                 skip_torikumi = true;
-                if app.basho_changed {
+                if request.basho_changed {
                     resolved_day = 1;
+                    adjustment = Some(DayAdjustment::Future);
                 }
                 if log_to_stderr {
                     eprintln!(
@@ -140,19 +687,32 @@ async fn load_data(
                         basho_id
                     );
                 }
-            } else if app.basho_changed && is_finished {
-                resolved_day = max_day_allowed;
+            } else if request.basho_changed && is_finished {
+                // Replays always restart from the first day of the new basho
+                resolved_day = if request.replaying { 1 } else { max_day_allowed };
+                adjustment = Some(DayAdjustment::Finished);
             }
 
-            app.set_basho(basho);
+            // Playoff bouts come as a day after the last, once the basho is over
+            if is_finished && request.playoff.is_none() {
+                playoff = check_playoff(api, basho_id, division).await;
+                if playoff == Some(true) && original_day >= PLAYOFF_DAY && !request.basho_changed {
+                    resolved_day = PLAYOFF_DAY;
+                }
+            }
+
+            loaded_basho = Some(basho);
         },
         Err(e) => {
             if log_to_stderr {
                 eprintln!("⚠ Warning: Could not load basho info: {}", e);
             }
+            errors.push(e);
         }
     }
 
+    // Whatever moved the day last, if it ended up moved at all
+    let adjustment = adjustment.filter(|_| resolved_day != original_day);
     if resolved_day != original_day && log_to_stderr {
         eprintln!(
             "ℹ️ Adjusted requested day {} to {} based on tournament status.",
@@ -161,41 +721,35 @@ async fn load_data(
         );
     }
 
-    if app.day != resolved_day {
-        app.day = resolved_day;
-    }
-
     // Load torikumi (daily matches)
+    let mut torikumi = Vec::new();
     if skip_torikumi {
-        app.set_torikumi(Vec::new());
         if log_to_stderr {
             eprintln!("ℹ️ Skipping torikumi fetch for upcoming basho {}.", basho_id);
         }
     } else {
         match api.get_torikumi(basho_id, division, resolved_day).await {
-            Ok(torikumi) => {
-                if let Some(matches) = torikumi.torikumi {
+            Ok(response) => {
+                if let Some(matches) = response.torikumi {
                     if log_to_stderr {
                         eprintln!("✓ Loaded {} matches for day {}", matches.len(), resolved_day);
                     }
-                    app.set_torikumi(matches);
-                } else {
-                    if log_to_stderr {
-                        eprintln!("⚠ No matches found for day {}", resolved_day);
-                    }
-                    app.set_torikumi(Vec::new());
+                    torikumi = matches;
+                } else if log_to_stderr {
+                    eprintln!("⚠ No matches found for day {}", resolved_day);
                 }
             },
             Err(e) => {
                 if log_to_stderr {
                     eprintln!("⚠ Warning: Could not load torikumi: {}", e);
                 }
-                app.set_torikumi(Vec::new());
+                errors.push(e);
             }
         }
     }
     
     // Load banzuke (rankings)
+    let mut banzuke = None;
     match api.get_banzuke(basho_id, division).await {
         Ok(banzuke_response) => {
             // Sort and interleave east and west wrestlers by rank
@@ -228,23 +782,86 @@ async fn load_data(
             if log_to_stderr {
                 eprintln!("✓ Loaded {} wrestlers in banzuke", all_entries.len());
             }
-            app.set_banzuke(all_entries);
+            banzuke = Some(all_entries);
         },
         Err(e) => {
             if log_to_stderr {
                 eprintln!("⚠ Warning: Could not load banzuke: {}", e);
             }
+            errors.push(e);
         }
     }
     
-    if log_to_stderr {
-        eprintln!("Data loading completed. Starting TUI...");
-        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    let (profiles, previous_ranks) = load_banzuke_extras(api, request, log_to_stderr).await;
+
+    Ok(LoadedData {
+        basho: loaded_basho,
+        day: resolved_day,
+        torikumi,
+        banzuke,
+        profiles,
+        previous_ranks,
+        playoff,
+        adjustment,
+        errors,
+    })
+}
+
+// Whether the division's finished basho had a playoff, or `None` when the API couldn't say
+async fn check_playoff(api: &SumoApi, basho_id: &str, division: &str) -> Option<bool> {
+    match api.get_torikumi(basho_id, division, PLAYOFF_DAY).await {
+        Ok(response) => Some(response.torikumi.is_some_and(|bouts| !bouts.is_empty())),
+        Err(e) if e.is::<api::NotFound>() => Some(false),
+        Err(_) => None,
     }
-    app.basho_changed = false;
-    Ok(())
 }
 
+// Fetch the extra data some banzuke columns need: rikishi profiles (once per session)
+// and the previous basho's ranks (once per basho and division)
+async fn load_banzuke_extras(
+    api: &SumoApi,
+    request: &LoadRequest,
+    log_to_stderr: bool,
+) -> (Option<Vec<api::RikishiDetails>>, Option<(String, HashMap<u32, String>)>) {
+    let mut profiles = None;
+    if request.load_profiles {
+        match api.get_rikishi_list().await {
+            Ok(list) => {
+                if log_to_stderr {
+                    eprintln!("✓ Loaded {} rikishi profiles", list.len());
+                }
+                profiles = Some(list);
+            }
+            Err(e) => {
+                if log_to_stderr {
+                    eprintln!("⚠ Warning: Could not load rikishi profiles: {}", e);
+                }
+            }
+        }
+    }
+
+    let mut previous_ranks = None;
+    if let Some(key) = &request.previous_ranks_key
+        && let Some(previous_id) = api::previous_basho_id(&request.basho_id)
+    {
+        match recent::fetch_ranks(api, &previous_id).await {
+            Ok(ranks) => previous_ranks = Some((key.clone(), ranks)),
+            Err(e) => {
+                if log_to_stderr {
+                    eprintln!("⚠ Warning: Could not load previous banzuke: {}", e);
+                }
+            }
+        }
+    }
+    (profiles, previous_ranks)
+}
+
+// Days of a basho, in every division
+const BASHO_DAYS: u8 = 15;
+
+// The day the API lists a division's playoff bouts under, when there were any
+const PLAYOFF_DAY: u8 = 16;
+
 fn max_day_for_division(division: &str) -> u8 {
     let normalized = division.to_ascii_lowercase();
     match normalized.as_str() {
@@ -253,6 +870,13 @@ fn max_day_for_division(division: &str) -> u8 {
     }
 }
 
+// Six digits naming one of the six basho months
+fn valid_basho_id(basho_id: &str) -> bool {
+    basho_id.len() == 6
+        && basho_id.chars().all(|c| c.is_ascii_digit())
+        && parse_basho_year_month(basho_id).is_some_and(|(_, month)| month % 2 == 1 && month <= 11)
+}
+
 fn parse_basho_year_month(basho_id: &str) -> Option<(i32, u32)> {
     if basho_id.len() < 6 {
         return None;
@@ -262,90 +886,599 @@ fn parse_basho_year_month(basho_id: &str) -> Option<(i32, u32)> {
     Some((year, month))
 }
 
+// Longest the event loop sleeps without input, so time-of-day changes to the
+// live refresh interval are picked up
+const MAX_IDLE_WAIT: Duration = Duration::from_secs(60);
+// The same in low-power idle, when only the clock reaching the bout window matters
+const LOW_POWER_WAIT: Duration = Duration::from_secs(10 * 60);
+
+/// Results of background API work, delivered to the event loop.
+enum ApiMessage {
+    // A full reload; `live` loads keep the current selection
+    Loaded { live: bool, requested_day: u8, result: anyhow::Result<LoadedData> },
+    RikishiDetails(u32, anyhow::Result<api::RikishiDetails>),
+    // Career bouts of the rikishi in the details popup, for their most-faced opponents and
+    // kinboshi, and their jun-yusho (finishes, basho checked)
+    RikishiMatches(u32, anyhow::Result<(Vec<api::HeadToHeadMatch>, (u32, u32))>),
+    HeadToHead(u32, u32, anyhow::Result<api::HeadToHeadResponse>),
+    Compare(compare::Fetched),
+    Profiles(anyhow::Result<Vec<api::RikishiDetails>>),
+    CareerStats(Vec<(u32, api::RikishiStats)>, Vec<String>),
+    CareerBouts(Vec<(u32, Vec<api::HeadToHeadMatch>)>, Vec<String>),
+    // Where the looked-up shikona has a bout on the looked-up day
+    BoutFound { query: String, day: u8, result: anyhow::Result<Option<lookup::BoutLocation>> },
+    // Whether the day picked in the day grid has torikumi
+    DayChecked(u8, anyhow::Result<bool>),
+    // Favorites found across every division for the dashboard
+    Dashboard(anyhow::Result<Vec<dashboard::Tracked>>),
+    // Career head-to-head of a projected contender against each remaining opponent
+    ProjectionHeadToHead(u32, Vec<(u32, Option<(u32, u32)>)>),
+    // Yusho winners of recent basho, newest first
+    YushoHistory(yusho_history::History),
+    // ("basho/division" key, records of its rikishi at the basho before)
+    RecentRecords(String, recent::Recent),
+    BoutSeries(String, series::Series),
+    // ("basho/division" key, days each of its rikishi fought on)
+    BoutDays(String, schedule::BoutDays),
+    // A newer release is published
+    UpdateAvailable(String),
+}
+
+// Whether `day` of the division has any torikumi yet
+async fn has_torikumi(api: &SumoApi, basho_id: &str, division: &str, day: u8) -> anyhow::Result<bool> {
+    let response = api.get_torikumi(basho_id, division, day).await?;
+    Ok(response.torikumi.is_some_and(|bouts| !bouts.is_empty()))
+}
+
+// Load the app's basho, division and day, unless the same load is already in flight
+fn start_load(requests: &mut Requests<ApiMessage>, api: &SumoApi, app: &App, live: bool) {
+    let api = api.clone();
+    let request = LoadRequest::from_app(app);
+    let key = format!("{}/{}/{}/{}", request.basho_id, request.division, request.day, live);
+    requests.start(Kind::Load, key, |reply: Reply<ApiMessage>| async move {
+        let result = load_data(&api, &request, Verbosity::Quiet).await;
+        reply.send(ApiMessage::Loaded { live, requested_day: request.day, result });
+    });
+}
+
 async fn run_app_with_reload(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     mut app: App,
     api: SumoApi,
-) -> io::Result<()> {
+    refresh: &RefreshConfig,
+    hooks: &HooksConfig,
+    check_updates: bool,
+    mut scripts: Scripts,
+) -> io::Result<App> {
+    let mut events = EventStream::new();
+    let mut signals = signals::Signals::new()?;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut requests = Requests::new(tx);
+    if check_updates {
+        requests.start(Kind::UpdateCheck, "", |reply| async move {
+            if let Some(version) = update::check().await {
+                reply.send(ApiMessage::UpdateAvailable(version));
+            }
+        });
+    }
+    // The settings view can change the interval while running
+    let mut refresh = refresh.clone();
+    let refresh = &mut refresh;
+    let mut last_refresh = Instant::now();
+    let mut last_input = Instant::now();
+    // Only redraw after something changed; idle wakeups are limited to scheduled work
+    let mut dirty = true;
+    // Stopped with Ctrl-Z and not yet given the terminal back: nothing is drawn until then
+    let mut suspended = false;
     loop {
-        terminal.draw(|f| tui::ui(f, &mut app))?;
+        if dirty && !suspended {
+            dirty = false;
+            let frame = terminal.draw(|f| tui::ui(f, &mut app))?;
 
-        if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                app.on_key(key.code);
+            if app.screenshot_requested {
+                app.screenshot_requested = false;
+                let stem = format!(
+                    "sumo-{}-{}-day{}-{}",
+                    app.basho_id,
+                    app.division.to_lowercase(),
+                    app.day,
+                    chrono::Local::now().format("%Y%m%d-%H%M%S")
+                );
+                app.status_message = Some(match snapshot::save(frame.buffer, std::path::Path::new("."), &stem) {
+                    Ok(path) => tf("status.screenshot_saved", &[&path.display()]),
+                    Err(e) => tf("status.screenshot_failed", &[&e]),
+                });
+                dirty = true;
             }
         }
 
+        refresh.interval_secs = app.refresh_interval_secs;
+
+        // Sleep until input or an API result arrives, or the next replay step / live refresh is due.
+        // Left alone outside bout hours, live polling stops until the next key
+        let low_power = live::low_power(refresh, last_input.elapsed(), app.basho.as_ref(), &app.division, Utc::now());
+        let until_refresh = live::until_next_refresh(refresh, app.basho.as_ref(), &app.division, Utc::now(), last_refresh.elapsed());
+        let mut timeout = if low_power { LOW_POWER_WAIT } else { MAX_IDLE_WAIT };
+        if let Some(until_step) = app.until_next_replay_step() {
+            timeout = timeout.min(until_step);
+        }
+        if let Some(until_expiry) = app.until_toast_expires() {
+            timeout = timeout.min(until_expiry);
+        }
+        if app.live && !low_power {
+            timeout = timeout.min(until_refresh);
+        }
+        // Count down the rate-limit pause once a second
+        let rate_limited = api.rate_limited_for();
+        if rate_limited.is_some() {
+            timeout = timeout.min(Duration::from_secs(1));
+        }
+
+        tokio::select! {
+            event = events.next() => match event {
+                Some(Ok(Event::Key(key))) => {
+                    last_input = Instant::now();
+                    app.on_key_event(key);
+                    dirty = true;
+                }
+                Some(Ok(Event::Resize(_, height))) => {
+                    terminal.autoresize()?;
+                    app.set_viewport_rows(tui::viewport_rows(height));
+                    dirty = true;
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => {
+                    requests.abort_all();
+                    return Err(e);
+                }
+                None => break,
+            },
+            // Results of a request superseded since are dropped
+            Some((ticket, message)) = rx.recv() => if requests.is_current(ticket) {
+                // New results change the head-to-head records of the rikishi in them
+                if let ApiMessage::Loaded { live: true, result: Ok(data), .. } = &message {
+                    for bout in live::new_results(app.torikumi.as_deref().unwrap_or_default(), &data.torikumi) {
+                        api.forget_head_to_head(bout.east_id, bout.west_id);
+                    }
+                }
+                handle_api_message(&mut app, message, refresh, hooks, &mut scripts);
+                dirty = true;
+            },
+            signal = signals.recv() => match signal {
+                signals::SignalEvent::Shutdown => app.should_quit = true,
+                signals::SignalEvent::Suspend => app.suspend_requested = true,
+                // The one way back from a stop, whether Ctrl-Z or a SIGSTOP from outside
+                signals::SignalEvent::Resume => {
+                    tui::resume_terminal(terminal).map_err(io::Error::other)?;
+                    suspended = false;
+                    dirty = true;
+                }
+            },
+            _ = tokio::time::sleep(timeout) => {}
+        }
+
         if app.should_quit {
             break;
         }
 
-        // Check if we need to reload data
-        if app.needs_reload {
+        if app.suspend_requested {
+            app.suspend_requested = false;
+            #[cfg(unix)]
+            {
+                tui::suspend_terminal(terminal).map_err(io::Error::other)?;
+                suspended = true;
+            }
+        }
+
+        let had_toast = app.toast.is_some();
+        app.on_tick();
+        if had_toast && app.toast.is_none() {
+            dirty = true;
+        }
+
+        // The API asked us to back off: hold polling and reloads until it allows requests again
+        match api.rate_limited_for() {
+            Some(left) => {
+                app.status_message = Some(tf("status.rate_limited", &[&left.as_secs().max(1)]));
+                dirty = true;
+            }
+            None if rate_limited.is_some() => {
+                app.status_message = Some(t("status.rate_limit_over").to_string());
+                dirty = true;
+            }
+            None => {}
+        }
+        let paused = api.rate_limited_for().is_some();
+
+        // Footer indicator; the header also shows a banner once the circuit breaker opens
+        let connection = api.health();
+        if app.connection != connection {
+            app.connection = connection;
+            dirty = true;
+        }
+
+        if let Some(command) = app.command_requested.take() {
+            let messages = scripts.run_command(&command, &app);
+            show_script_messages(&mut app, messages);
+            dirty = true;
+        }
+
+        // Live mode: refresh the current day in place, keeping the selection
+        let refresh_due = live::until_next_refresh(refresh, app.basho.as_ref(), &app.division, Utc::now(), last_refresh.elapsed()).is_zero()
+            && !live::low_power(refresh, last_input.elapsed(), app.basho.as_ref(), &app.division, Utc::now());
+        if app.live && !paused && !app.needs_reload && !requests.is_running(Kind::Load) && refresh_due {
+            last_refresh = Instant::now();
+            start_load(&mut requests, &api, &app, true);
+        }
+
+        // A new basho/division/day supersedes any load still in flight
+        if app.needs_reload && !paused {
             app.needs_reload = false;
-            
-            // Store values before borrowing mutably
-            let basho_id = app.basho_id.clone();
-            let division = app.division.clone();
-            let requested_day = app.day;
+            last_refresh = Instant::now();
+            dirty = true;
 
             app.status_message = None;
-            let overlay_message = format!("Reloading data for {} {}...", basho_id, division);
-            app.loading_overlay = Some(overlay_message);
+            app.loading_overlay = Some(tf(
+                "loading.reloading",
+                &[&app.basho_id, &i18n::division_name(&app.division)],
+            ));
+            // Clear existing torikumi data to avoid showing stale bouts while reloading
+            app.clear_torikumi();
+            start_load(&mut requests, &api, &app, false);
+        }
+
+        if let Some(rikishi_id) = app.requested_rikishi_id.take() {
+            let details_api = api.clone();
+            requests.start(Kind::Rikishi, rikishi_id.to_string(), |reply| async move {
+                reply.send(ApiMessage::RikishiDetails(rikishi_id, details_api.get_rikishi(rikishi_id).await));
+            });
+            if !app.career_stats.contains_key(&rikishi_id) {
+                let stats_api = api.clone();
+                requests.start(Kind::RikishiStats, rikishi_id.to_string(), |reply| async move {
+                    let (stats, errors) = stats::fetch_career_stats(&stats_api, vec![rikishi_id]).await;
+                    reply.send(ApiMessage::CareerStats(stats, errors));
+                });
+            }
+            // The most-faced opponents and achievements follow once the whole bout history is in
+            app.rivals = None;
+            app.achievements = None;
+            let hidden_basho = (app.replay.is_some() || app.spoiler_from.is_some()).then(|| app.basho_id.clone());
+            let api = api.clone();
+            requests.start(Kind::Rivals, rikishi_id.to_string(), |reply| async move {
+                let result = api.get_rikishi_matches(rikishi_id).await.map(|matches| {
+                    let jun_yusho = achievements::jun_yusho(&api, rikishi_id, &matches, hidden_basho.as_deref());
+                    (matches, jun_yusho)
+                });
+                reply.send(ApiMessage::RikishiMatches(rikishi_id, result));
+            });
+        }
+
+        if let Some((rikishi_id, opponent_id)) = app.requested_head_to_head.take() {
+            let api = api.clone();
+            requests.start(Kind::HeadToHead, format!("{}-{}", rikishi_id, opponent_id), |reply| async move {
+                let result = api.get_head_to_head(rikishi_id, opponent_id).await;
+                reply.send(ApiMessage::HeadToHead(rikishi_id, opponent_id, result));
+            });
+        }
+
+        if std::mem::take(&mut app.profiles_requested) {
+            let api = api.clone();
+            requests.start(Kind::Profiles, "", |reply| async move {
+                reply.send(ApiMessage::Profiles(api.get_rikishi_list().await));
+            });
+        }
+
+        if std::mem::take(&mut app.career_stats_requested) {
+            let missing: Vec<u32> = app
+                .banzuke
+                .iter()
+                .flatten()
+                .map(|e| e.rikishi_id)
+                .filter(|id| !app.career_stats.contains_key(id))
+                .collect();
+            if !missing.is_empty() {
+                let api = api.clone();
+                requests.start(Kind::CareerStats, format!("{:?}", missing), |reply| async move {
+                    let (stats, errors) = stats::fetch_career_stats(&api, missing).await;
+                    reply.send(ApiMessage::CareerStats(stats, errors));
+                });
+            }
+            let missing: Vec<u32> = app
+                .banzuke
+                .iter()
+                .flatten()
+                .map(|e| e.rikishi_id)
+                .filter(|id| !app.career_bouts.contains_key(id))
+                .collect();
+            if !missing.is_empty() {
+                let api = api.clone();
+                requests.start(Kind::CareerBouts, format!("{:?}", missing), |reply| async move {
+                    let (bouts, errors) = stats::fetch_career_bouts(&api, missing).await;
+                    reply.send(ApiMessage::CareerBouts(bouts, errors));
+                });
+            }
+        }
+
+        if let Some(day) = app.requested_day_check.take() {
+            let api = api.clone();
+            let (basho_id, division) = (app.basho_id.clone(), app.division.clone());
+            requests.start(Kind::DayCheck, format!("{}/{}/{}", basho_id, division, day), |reply| async move {
+                let result = has_torikumi(&api, &basho_id, &division, day).await;
+                reply.send(ApiMessage::DayChecked(day, result));
+            });
+        }
+
+        if let Some(key) = app.request_recent_records() {
+            let api = api.clone();
+            let (basho_id, division) = (app.basho_id.clone(), app.division.clone());
+            requests.start(Kind::RecentRecords, key.clone(), |reply| async move {
+                let records = recent::fetch(&api, &basho_id, &division).await;
+                reply.send(ApiMessage::RecentRecords(key, records));
+            });
+        }
+
+        if let Some(key) = app.request_bout_days() {
+            let api = api.clone();
+            let (basho_id, division) = (app.basho_id.clone(), app.division.clone());
+            requests.start(Kind::BoutDays, key.clone(), |reply| async move {
+                let days = schedule::fetch_bout_days(&api, &basho_id, &division).await;
+                reply.send(ApiMessage::BoutDays(key, days));
+            });
+        }
+
+        if let Some(key) = app.request_bout_series() {
+            let api = api.clone();
+            let torikumi = app.torikumi.clone().unwrap_or_default();
+            requests.start(Kind::BoutSeries, key.clone(), |reply| async move {
+                let series = series::fetch(&api, torikumi).await;
+                reply.send(ApiMessage::BoutSeries(key, series));
+            });
+        }
 
-            terminal.draw(|f| tui::ui(f, &mut app))?;
+        if let Some(query) = app.find_bout_requested.take() {
+            let api = api.clone();
+            let (basho_id, day) = (app.basho_id.clone(), app.day);
+            requests.start(Kind::FindBout, format!("{}/{}/{}", basho_id, day, query), |reply| async move {
+                let result = lookup::find_bout(&api, &basho_id, day, tui::DIVISIONS, &query).await;
+                reply.send(ApiMessage::BoutFound { query, day, result });
+            });
+        }
+
+        if std::mem::take(&mut app.dashboard_requested) && !app.favorites.is_empty() {
+            let api = api.clone();
+            let (basho_id, day, favorites) = (app.basho_id.clone(), app.day, app.favorites.clone());
+            let key = format!("{}/{}/{:?}", basho_id, day, favorites);
+            requests.start(Kind::Dashboard, key, |reply| async move {
+                let result = dashboard::fetch(&api, &basho_id, day, tui::DIVISIONS, &favorites).await;
+                reply.send(ApiMessage::Dashboard(result));
+            });
+        }
+
+        if std::mem::take(&mut app.yusho_history_requested) {
+            let api = api.clone();
+            let latest = app.basho_id.clone();
+            requests.start(Kind::YushoHistory, latest.clone(), |reply| async move {
+                let history = yusho_history::fetch(&api, &latest, yusho_history::HISTORY_BASHO).await;
+                reply.send(ApiMessage::YushoHistory(history));
+            });
+        }
 
-            match load_data(&api, &basho_id, &division, requested_day, &mut app, false).await {
-                Ok(_) => {
+        if let Some((rikishi_id, opponents)) = app.projection_requested.take() {
+            let api = api.clone();
+            requests.start(Kind::Projection, format!("{}/{:?}", rikishi_id, opponents), |reply| async move {
+                let records = projection::fetch_head_to_head(&api, rikishi_id, opponents).await;
+                reply.send(ApiMessage::ProjectionHeadToHead(rikishi_id, records));
+            });
+        }
+
+        if std::mem::take(&mut app.compare_requested) {
+            let missing = app.comparison.missing(&app.compare_ids, &app.career_stats);
+            if !missing.is_empty() {
+                let api = api.clone();
+                requests.start(Kind::Compare, format!("{:?}", missing), |reply| async move {
+                    reply.send(ApiMessage::Compare(compare::fetch(&api, missing).await));
+                });
+            }
+        }
+    }
+
+    requests.abort_all();
+    Ok(app)
+}
+
+// Run a headless script, printing the app's state after each step. Reloads and day-grid
+// checks finish before the next step; other background lookups aren't made
+async fn run_headless(mut app: App, api: &SumoApi, config: &Config, mut scripts: Scripts, steps: &[headless::Step]) -> anyhow::Result<()> {
+    for step in steps {
+        headless::apply(&mut app, step);
+        if let Some(command) = app.command_requested.take() {
+            let messages = scripts.run_command(&command, &app);
+            show_script_messages(&mut app, messages);
+        }
+        if let Some(day) = app.requested_day_check.take() {
+            let result = has_torikumi(api, &app.basho_id, &app.division, day).await;
+            handle_api_message(&mut app, ApiMessage::DayChecked(day, result), &config.refresh, &config.hooks, &mut scripts);
+        }
+        if std::mem::take(&mut app.needs_reload) {
+            app.clear_torikumi();
+            let request = LoadRequest::from_app(&app);
+            let result = load_data(api, &request, Verbosity::Quiet).await;
+            let message = ApiMessage::Loaded { live: false, requested_day: request.day, result };
+            handle_api_message(&mut app, message, &config.refresh, &config.hooks, &mut scripts);
+        }
+        println!("{}", serde_json::to_string(&headless::State::from_app(&app))?);
+        if app.should_quit {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn handle_api_message(app: &mut App, message: ApiMessage, refresh: &RefreshConfig, hooks: &HooksConfig, scripts: &mut Scripts) {
+    match message {
+        ApiMessage::Loaded { live: true, result, .. } => match result {
+            Ok(data) => {
+                let (selection, followed) = (app.selection(), app.followed_rikishi());
+                let previous = app.torikumi.take().unwrap_or_default();
+                data.apply(app);
+                // Bouts can move as the card is updated: stay with the same rikishi
+                app.restore_selection(selection);
+                app.follow(&followed);
+                app.dashboard_requested = app.current_view == AppView::Dashboard;
+                let next = live::until_next_refresh(refresh, app.basho.as_ref(), &app.division, Utc::now(), Duration::ZERO);
+                let next_at = chrono::Local::now() + chrono::Duration::from_std(next).unwrap_or_default();
+                app.status_message = Some(tf(
+                    "status.live_updated",
+                    &[&chrono::Local::now().format("%H:%M:%S"), &next_at.format("%H:%M")],
+                ));
+                // Toast the end of the day's card and offer its summary as the last result comes in
+                if previous.first().is_some_and(|b| b.day == app.day)
+                    && !live::day_complete(&previous)
+                    && app.bouts_remaining() == Some(0)
+                {
+                    let key = app.keymap.label(keymap::Action::DaySummary);
+                    app.show_toast(tf("toast.day_complete", &[&app.day, &key]));
+                }
+                let messages = scripts.on_results(app, &previous);
+                show_script_messages(app, messages);
+                let errors = hooks::run_live_hooks(hooks, app, &previous);
+                if !errors.is_empty() {
+                    app.status_message = Some(tf("status.hook_failed", &[&errors.join(" | ")]));
+                }
+            }
+            Err(e) => {
+                app.status_message = Some(tf("status.live_failed", &[&e]));
+            }
+        },
+        ApiMessage::Loaded { live: false, requested_day, result } => {
+            match result {
+                Ok(data) => {
+                    app.day_adjustment = data.adjustment.map(|reason| (requested_day, reason));
+                    data.apply(app);
                     let active_day = app.day;
-                    if active_day != requested_day {
-                        app.status_message = Some(format!(
-                            "Reloaded {} {} Day {} (auto-selected)",
-                            basho_id, division, active_day
-                        ));
-                    } else {
-                        app.status_message = Some(format!(
-                            "Reloaded {} {} Day {}",
-                            basho_id, division, active_day
-                        ));
+                    let division = i18n::division_name(&app.division);
+                    let key = if active_day != requested_day { "status.reloaded_auto" } else { "status.reloaded" };
+                    app.status_message = Some(tf(key, &[&app.basho_id, &division, &active_day]));
+                    // A new banzuke brings rikishi whose career stats aren't loaded yet
+                    app.career_stats_requested = app.current_view == AppView::Records;
+                    // Another day or basho needs the favorites found again
+                    app.dashboard = None;
+                    app.dashboard_requested = app.current_view == AppView::Dashboard;
+                    let followed = std::mem::take(&mut app.followed);
+                    app.follow(&followed);
+                    if let Some(rikishi_id) = app.pending_bout_focus.take() {
+                        app.focus_bout(rikishi_id);
                     }
+                    let messages = scripts.on_day_loaded(app);
+                    show_script_messages(app, messages);
                 }
                 Err(e) => {
-                    let msg = format!("Failed to reload data: {}", e);
-                    app.status_message = Some(msg.clone());
-                    eprintln!("{}", msg);
+                    app.status_message = Some(tf("status.reload_failed", &[&e]));
                 }
             }
-
             app.loading_overlay = None;
         }
-
-        // Check if we need to load rikishi details
-        if let Some(rikishi_id) = app.requested_rikishi_id.take() {
-            match api.get_rikishi(rikishi_id).await {
-                Ok(details) => {
+        // Only shown while its loading popup is still open
+        ApiMessage::RikishiDetails(rikishi_id, result) => match result {
+            Ok(details) => {
+                if app.settle_pending(&Pending::Rikishi(rikishi_id), None) {
                     app.rikishi_details = Some(details);
                     app.show_rikishi_details = true;
-                },
-                Err(e) => {
-                    eprintln!("Error loading rikishi details: {}", e);
                 }
             }
-        }
-
-        // Check if we need to load head-to-head data
-        if let Some((rikishi_id, opponent_id)) = app.requested_head_to_head.take() {
-            match api.get_head_to_head(rikishi_id, opponent_id).await {
-                Ok(h2h) => {
-                    app.head_to_head_data = Some(h2h);
-                    app.show_head_to_head = true;
-                },
-                Err(e) => {
-                    eprintln!("Error loading head-to-head data: {}", e);
+            Err(e) => {
+                if !app.settle_pending(&Pending::Rikishi(rikishi_id), Some(e.to_string())) {
+                    app.status_message = Some(tf("status.rikishi_failed", &[&e]));
+                }
+            }
+        },
+        ApiMessage::RikishiMatches(rikishi_id, result) => match result {
+            Ok((matches, jun_yusho)) => app.set_bout_history(rikishi_id, matches, jun_yusho),
+            Err(e) => {
+                app.rivals = Some((rikishi_id, Vec::new()));
+                app.status_message = Some(tf("status.rivals_failed", &[&e]));
+            }
+        },
+        ApiMessage::HeadToHead(rikishi_id, opponent_id, result) => match result {
+            Ok(h2h) => {
+                if app.settle_pending(&Pending::HeadToHead(rikishi_id, opponent_id), None) {
+                    app.set_head_to_head(rikishi_id, h2h);
                 }
             }
+            Err(e) => {
+                if !app.settle_pending(&Pending::HeadToHead(rikishi_id, opponent_id), Some(e.to_string())) {
+                    app.status_message = Some(tf("status.h2h_failed", &[&e]));
+                }
+            }
+        },
+        ApiMessage::Compare(fetched) => {
+            if let Some(error) = fetched.errors.first() {
+                app.status_message = Some(tf("status.compare_failed", &[error]));
+            }
+            app.comparison.merge(fetched, &mut app.career_stats);
+        }
+        ApiMessage::Profiles(result) => match result {
+            Ok(profiles) => {
+                app.rikishi_profiles = profiles.into_iter().map(|r| (r.id, r)).collect();
+                app.invalidate_rows();
+            }
+            Err(e) => {
+                app.status_message = Some(tf("status.profiles_failed", &[&e]));
+            }
+        },
+        ApiMessage::CareerStats(stats, errors) => {
+            app.career_stats.extend(stats);
+            if let Some(error) = errors.first() {
+                app.status_message = Some(tf("status.career_stats_failed", &[&errors.len(), error]));
+            }
+        }
+        ApiMessage::CareerBouts(bouts, errors) => {
+            app.career_bouts.extend(bouts);
+            if let Some(error) = errors.first() {
+                app.status_message = Some(tf("status.career_stats_failed", &[&errors.len(), error]));
+            }
+        }
+        ApiMessage::DayChecked(day, result) => app.settle_day_check(day, result.ok()),
+        ApiMessage::BoutFound { query, day, result } => match result {
+            Ok(Some(found)) if found.division == app.division => {
+                app.focus_bout(found.rikishi_id);
+                app.status_message = Some(tf("status.bout_found", &[&found.shikona, &i18n::division_name(&found.division)]));
+            }
+            Ok(Some(found)) => {
+                // Switch division; the bout is selected once the new torikumi arrives
+                app.division = found.division;
+                app.pending_bout_focus = Some(found.rikishi_id);
+                app.needs_reload = true;
+            }
+            Ok(None) => app.status_message = Some(tf("status.no_bout", &[&query, &day])),
+            Err(e) => app.status_message = Some(tf("status.find_bout_failed", &[&e])),
+        },
+        ApiMessage::Dashboard(result) => match result {
+            Ok(tracked) => app.dashboard = Some(tracked),
+            Err(e) => app.status_message = Some(tf("status.dashboard_failed", &[&e])),
+        },
+        ApiMessage::ProjectionHeadToHead(rikishi_id, records) => {
+            if let Some(projection) = app.projection.as_mut().filter(|p| p.rikishi_id == rikishi_id) {
+                projection.head_to_head = records;
+            }
+        }
+        ApiMessage::YushoHistory(history) => app.yusho_history = Some(history),
+        ApiMessage::RecentRecords(key, records) => {
+            app.recent_records = Some((key, records));
+            app.invalidate_rows();
         }
+        ApiMessage::BoutSeries(key, series) => {
+            app.bout_series = Some((key, series));
+            app.invalidate_rows();
+        }
+        ApiMessage::BoutDays(key, days) => app.set_bout_days(key, days),
+        ApiMessage::UpdateAvailable(version) => app.update_available = Some(version),
     }
+}
 
-    Ok(())
+// Messages from user scripts replace the status line
+fn show_script_messages(app: &mut App, messages: Vec<String>) {
+    if !messages.is_empty() {
+        app.status_message = Some(tf("status.script", &[&messages.join(" | ")]));
+    }
 }
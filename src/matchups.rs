@@ -0,0 +1,136 @@
+use crate::api::{BanzukeEntry, TorikumiEntry};
+
+/// How the bout between a row's rikishi and a column's rikishi went, from the row's side.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Meeting {
+    Win,
+    Loss,
+    FusenWin,
+    FusenLoss,
+    /// Fought, but the result is held back by spoiler-free or replay mode
+    Hidden,
+    /// Paired on the loaded day without a result yet
+    Scheduled,
+}
+
+impl Meeting {
+    fn flipped(self) -> Self {
+        match self {
+            Meeting::Win => Meeting::Loss,
+            Meeting::Loss => Meeting::Win,
+            Meeting::FusenWin => Meeting::FusenLoss,
+            Meeting::FusenLoss => Meeting::FusenWin,
+            other => other,
+        }
+    }
+}
+
+/// Who has met whom in a division this basho, in banzuke order along both axes.
+#[derive(Debug, Default)]
+pub struct Matrix {
+    pub rikishi: Vec<(u32, String)>,
+    /// `cells[row][column]`; the diagonal is always `None`
+    pub cells: Vec<Vec<Option<Meeting>>>,
+}
+
+impl Matrix {
+    /// Fill the grid from each rikishi's banzuke record, showing results for the first
+    /// `revealed(entry)` entries only, and add the loaded day's undecided pairings.
+    pub fn compute(banzuke: &[BanzukeEntry], torikumi: &[TorikumiEntry], revealed: impl Fn(&BanzukeEntry) -> u8) -> Self {
+        let rikishi: Vec<(u32, String)> = banzuke.iter().map(|e| (e.rikishi_id, e.shikona_en.to_string())).collect();
+        let index_of_name = |shikona: &str| banzuke.iter().position(|e| &*e.shikona_en == shikona);
+        let index_of_id = |id: u32| banzuke.iter().position(|e| e.rikishi_id == id);
+        let mut cells = vec![vec![None; rikishi.len()]; rikishi.len()];
+
+        for (row, entry) in banzuke.iter().enumerate() {
+            let revealed = revealed(entry);
+            for (i, record) in entry.record.iter().flatten().enumerate() {
+                let Some(column) = index_of_name(&record.opponent_shikona_en).filter(|c| *c != row) else {
+                    continue;
+                };
+                let meeting = match record.result.as_str() {
+                    "" => continue,
+                    _ if i >= revealed as usize => Meeting::Hidden,
+                    "win" => Meeting::Win,
+                    "loss" => Meeting::Loss,
+                    "fusen-win" => Meeting::FusenWin,
+                    "fusen-loss" => Meeting::FusenLoss,
+                    _ => continue,
+                };
+                cells[row][column] = Some(meeting);
+                cells[column][row].get_or_insert(meeting.flipped());
+            }
+        }
+        for bout in torikumi.iter().filter(|b| b.winner_id.is_none()) {
+            if let (Some(east), Some(west)) = (index_of_id(bout.east_id), index_of_id(bout.west_id)) {
+                cells[east][west].get_or_insert(Meeting::Scheduled);
+                cells[west][east].get_or_insert(Meeting::Scheduled);
+            }
+        }
+        Self { rikishi, cells }
+    }
+
+    /// Opponents the rikishi in `row` has met or is paired with, and those still left.
+    pub fn met_and_unmet(&self, row: usize) -> (usize, usize) {
+        let Some(cells) = self.cells.get(row) else {
+            return (0, 0);
+        };
+        let met = cells.iter().filter(|c| c.is_some()).count();
+        (met, cells.len().saturating_sub(met + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Matrix, Meeting};
+    use crate::api::{BanzukeEntry, MatchRecord};
+
+    fn entry(id: u32, shikona: &str, results: &[(&str, &str)]) -> BanzukeEntry {
+        BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: id,
+            shikona_en: shikona.into(),
+            shikona_jp: None,
+            rank_value: id,
+            rank: String::new(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: Some(
+                results
+                    .iter()
+                    .map(|(result, opponent)| MatchRecord {
+                        result: result.to_string(),
+                        opponent_shikona_en: opponent.to_string(),
+                        opponent_shikona_jp: String::new(),
+                        kimarite: None,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn fills_both_sides_of_each_meeting() {
+        let banzuke = vec![
+            entry(1, "Hoshoryu", &[("win", "Onosato"), ("fusen-win", "Takayasu"), ("", "")]),
+            entry(2, "Onosato", &[("loss", "Hoshoryu"), ("win", "Kirishima")]),
+            entry(3, "Takayasu", &[]),
+            entry(4, "Kirishima", &[]),
+        ];
+        let matrix = Matrix::compute(&banzuke, &[], |_| 1);
+        assert_eq!(matrix.cells[0][1], Some(Meeting::Win));
+        assert_eq!(matrix.cells[1][0], Some(Meeting::Loss));
+        // Day 2 is past the revealed days
+        assert_eq!(matrix.cells[0][2], Some(Meeting::Hidden));
+        assert_eq!(matrix.cells[2][0], Some(Meeting::Hidden));
+        assert_eq!(matrix.cells[3][1], Some(Meeting::Hidden));
+        assert_eq!(matrix.cells[0][0], None);
+        assert_eq!(matrix.met_and_unmet(0), (2, 1));
+        assert_eq!(matrix.met_and_unmet(2), (1, 2));
+
+        let matrix = Matrix::compute(&banzuke, &[], |_| 15);
+        assert_eq!(matrix.cells[2][0], Some(Meeting::FusenLoss));
+        assert_eq!(matrix.cells[3][1], Some(Meeting::Loss));
+    }
+}
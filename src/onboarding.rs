@@ -0,0 +1,120 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+use crate::i18n::t;
+
+/// Answers collected by the first-run setup.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetupAnswers {
+    pub division: String,
+    pub units: String,
+    pub theme: String,
+    pub favorites: Vec<String>,
+}
+
+/// Whether to offer the setup: only when no config file exists yet and a person is at
+/// the terminal to answer.
+pub fn should_run(config_path: &Path) -> bool {
+    !config_path.exists() && io::stdin().is_terminal() && io::stderr().is_terminal()
+}
+
+/// Ask the setup questions on `output`, reading answers from `input`. An empty or
+/// unrecognized answer keeps the default shown in brackets.
+pub fn ask<R: BufRead, W: Write>(input: &mut R, output: &mut W) -> io::Result<SetupAnswers> {
+    writeln!(output, "{}", t("onboarding.welcome"))?;
+
+    let division = choose(
+        input,
+        output,
+        t("onboarding.division"),
+        &["makuuchi", "juryo", "makushita", "sandanme", "jonidan", "jonokuchi"],
+    )?;
+    let units = choose(input, output, t("onboarding.units"), &["metric", "imperial"])?;
+    let theme = choose(input, output, t("onboarding.theme"), &["default", "high_contrast", "monochrome"])?;
+
+    write!(output, "{}: ", t("onboarding.favorites"))?;
+    output.flush()?;
+    let favorites = read_line(input)?
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    Ok(SetupAnswers { division, units, theme, favorites })
+}
+
+// Ask for one of `options`; the first is the default
+fn choose<R: BufRead, W: Write>(input: &mut R, output: &mut W, question: &str, options: &[&str]) -> io::Result<String> {
+    write!(output, "{} ({}) [{}]: ", question, options.join("/"), options[0])?;
+    output.flush()?;
+    let answer = read_line(input)?.to_lowercase().replace('-', "_");
+    let choice = options.iter().find(|o| **o == answer).unwrap_or(&options[0]);
+    Ok(choice.to_string())
+}
+
+fn read_line<R: BufRead>(input: &mut R) -> io::Result<String> {
+    let mut line = String::new();
+    input.read_line(&mut line)?;
+    Ok(line.trim().to_string())
+}
+
+impl SetupAnswers {
+    /// The config file for these answers, with the other sections left commented out.
+    pub fn to_toml(&self) -> String {
+        let favorites = self
+            .favorites
+            .iter()
+            .map(|f| format!("\"{}\"", f.replace('\\', "\\\\").replace('"', "\\\"")))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "# sumo configuration, written by the first-run setup\n\
+             division = \"{}\"\n\
+             units = \"{}\"\n\
+             theme = \"{}\"\n\
+             favorites = [{}]\n\
+             \n\
+             # [refresh]\n\
+             # enabled = false\n\
+             # interval_secs = 60\n",
+            self.division, self.units, self.theme, favorites
+        )
+    }
+
+    /// Write the config file, creating its directory.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_toml())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ask;
+    use crate::config::{Config, Units};
+    use std::io::Cursor;
+
+    #[test]
+    fn answers_produce_a_valid_config() {
+        let mut input = Cursor::new("juryo\n\nmonochrome\nOnosato, Hoshoryu\n");
+        let mut output = Vec::new();
+        let answers = ask(&mut input, &mut output).unwrap();
+        assert_eq!(answers.division, "juryo");
+        assert_eq!(answers.units, "metric");
+
+        let config: Config = toml::from_str(&answers.to_toml()).unwrap();
+        assert_eq!(config.favorites, vec!["Onosato", "Hoshoryu"]);
+        assert_eq!(config.units, Units::Metric);
+        assert!(config.division.is_some());
+    }
+
+    #[test]
+    fn unknown_answers_keep_defaults() {
+        let mut input = Cursor::new("sekitori\nfurlongs\nneon\n\n");
+        let answers = ask(&mut input, &mut Vec::new()).unwrap();
+        assert_eq!((answers.division.as_str(), answers.units.as_str(), answers.theme.as_str()), ("makuuchi", "metric", "default"));
+        assert!(answers.favorites.is_empty());
+    }
+}
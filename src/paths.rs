@@ -0,0 +1,238 @@
+use std::path::PathBuf;
+
+// Directory name under each platform base directory
+const APP_DIR: &str = "sumo";
+// Files kept in the state directory, moved out of the macOS config directory they used
+// to share
+const STATE_FILES: [&str; 3] = ["session.toml", "update.toml", "journal.toml"];
+
+/// What a directory holds, which decides where the platform wants it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    /// `config.toml` and `scripts/`
+    Config,
+    /// Cached API responses, safe to delete
+    Cache,
+    /// Session and update-check state kept between runs
+    State,
+    /// Error log
+    Log,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Platform {
+    /// Linux and the BSDs, following the XDG base directory spec
+    Unix,
+    MacOs,
+    Windows,
+}
+
+impl Platform {
+    fn current() -> Self {
+        if cfg!(windows) {
+            Platform::Windows
+        } else if cfg!(target_os = "macos") {
+            Platform::MacOs
+        } else {
+            Platform::Unix
+        }
+    }
+}
+
+// Base directories of the platform, from the `directories` crate: the XDG directories
+// on Linux, the Library folders on macOS and the known folders on Windows
+struct Bases {
+    home: PathBuf,
+    config: PathBuf,
+    cache: PathBuf,
+    data_local: PathBuf,
+    state: Option<PathBuf>,
+}
+
+impl Bases {
+    fn current() -> Option<Self> {
+        let dirs = directories::BaseDirs::new()?;
+        Some(Bases {
+            home: dirs.home_dir().to_path_buf(),
+            config: dirs.config_dir().to_path_buf(),
+            cache: dirs.cache_dir().to_path_buf(),
+            data_local: dirs.data_local_dir().to_path_buf(),
+            state: dirs.state_dir().map(PathBuf::from),
+        })
+    }
+}
+
+// The sumo directory of `kind` on `platform` under `bases`, reading environment variables
+// through `env`. The XDG variables are honoured on macOS too when set, as other terminal
+// tools do. On Unix logs are state, as the XDG spec has it, so they get a directory inside it.
+fn dir_for(kind: Kind, platform: Platform, bases: &Bases, env: &dyn Fn(&str) -> Option<PathBuf>) -> Option<PathBuf> {
+    let app = |base: &PathBuf| base.join(APP_DIR);
+    match platform {
+        Platform::Windows => {
+            let (base, sub) = match kind {
+                Kind::Config => (&bases.config, "config"),
+                Kind::Cache => (&bases.cache, "cache"),
+                Kind::State => (&bases.data_local, "data"),
+                Kind::Log => (&bases.data_local, "logs"),
+            };
+            Some(app(base).join(sub))
+        }
+        Platform::Unix => Some(match kind {
+            Kind::Config => app(&bases.config),
+            Kind::Cache => app(&bases.cache),
+            Kind::State => app(bases.state.as_ref()?),
+            Kind::Log => app(bases.state.as_ref()?).join("log"),
+        }),
+        Platform::MacOs => {
+            let xdg = match kind {
+                Kind::Config => "XDG_CONFIG_HOME",
+                Kind::Cache => "XDG_CACHE_HOME",
+                Kind::State | Kind::Log => "XDG_STATE_HOME",
+            };
+            if let Some(base) = env(xdg).filter(|v| v.is_absolute()) {
+                return Some(if kind == Kind::Log { app(&base).join("log") } else { app(&base) });
+            }
+            // macOS keeps config and state both under Application Support, so state
+            // takes a directory of its own there
+            Some(match kind {
+                Kind::Config => app(&bases.config),
+                Kind::Cache => app(&bases.cache),
+                Kind::State => app(&bases.data_local).join("state"),
+                Kind::Log => bases.home.join("Library/Logs").join(APP_DIR),
+            })
+        }
+    }
+}
+
+fn dir(kind: Kind) -> Option<PathBuf> {
+    dir_for(kind, Platform::current(), &Bases::current()?, &|name| std::env::var_os(name).map(PathBuf::from))
+}
+
+/// Directory of `config.toml` and `scripts/`: `$XDG_CONFIG_HOME/sumo` (`~/.config/sumo`),
+/// `~/Library/Application Support/sumo` on macOS or `%APPDATA%\sumo\config` on Windows.
+/// A macOS config from before this layout, in `~/.config/sumo`, is still used.
+pub fn config_dir() -> Option<PathBuf> {
+    if Platform::current() == Platform::MacOs
+        && let Some(bases) = Bases::current()
+        && let legacy = bases.home.join(".config").join(APP_DIR)
+        && legacy.join("config.toml").exists()
+    {
+        return Some(legacy);
+    }
+    dir(Kind::Config)
+}
+
+/// Directory of cached API responses: `$XDG_CACHE_HOME/sumo` (`~/.cache/sumo`),
+/// `~/Library/Caches/sumo` on macOS or `%LOCALAPPDATA%\sumo\cache` on Windows.
+pub fn cache_dir() -> Option<PathBuf> {
+    dir(Kind::Cache)
+}
+
+/// Directory of state kept between runs: `$XDG_STATE_HOME/sumo` (`~/.local/state/sumo`),
+/// `~/Library/Application Support/sumo/state` on macOS or `%LOCALAPPDATA%\sumo\data` on
+/// Windows.
+/// State files from before macOS had its own state directory are moved into it.
+pub fn state_dir() -> Option<PathBuf> {
+    let dir = dir(Kind::State)?;
+    if Platform::current() == Platform::MacOs
+        && let Some(legacy) = dir.parent()
+    {
+        for name in STATE_FILES {
+            let (old, new) = (legacy.join(name), dir.join(name));
+            if old.exists() && !new.exists() {
+                let _ = std::fs::create_dir_all(&dir).and_then(|()| std::fs::rename(&old, &new));
+            }
+        }
+    }
+    Some(dir)
+}
+
+/// Directory of the error log: `$XDG_STATE_HOME/sumo/log` (`~/.local/state/sumo/log`),
+/// `~/Library/Logs/sumo` on macOS or `%LOCALAPPDATA%\sumo\logs` on Windows.
+pub fn log_dir() -> Option<PathBuf> {
+    dir(Kind::Log)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dir_for, Bases, Kind, Platform};
+    use std::path::PathBuf;
+
+    fn unix_bases() -> Bases {
+        Bases {
+            home: PathBuf::from("/home/rikishi"),
+            config: PathBuf::from("/home/rikishi/.config"),
+            cache: PathBuf::from("/tmp/cache"),
+            data_local: PathBuf::from("/home/rikishi/.local/share"),
+            state: Some(PathBuf::from("/home/rikishi/.local/state")),
+        }
+    }
+
+    fn mac_bases() -> Bases {
+        Bases {
+            home: PathBuf::from("/Users/rikishi"),
+            config: PathBuf::from("/Users/rikishi/Library/Application Support"),
+            cache: PathBuf::from("/Users/rikishi/Library/Caches"),
+            data_local: PathBuf::from("/Users/rikishi/Library/Application Support"),
+            state: None,
+        }
+    }
+
+    fn windows_bases() -> Bases {
+        Bases {
+            home: PathBuf::from(r"C:\Users\r"),
+            config: PathBuf::from(r"C:\Users\r\AppData\Roaming"),
+            cache: PathBuf::from(r"C:\Users\r\AppData\Local"),
+            data_local: PathBuf::from(r"C:\Users\r\AppData\Local"),
+            state: None,
+        }
+    }
+
+    #[test]
+    fn picks_each_platforms_directories() {
+        let no_env = |_: &str| None;
+        let unix = |kind| dir_for(kind, Platform::Unix, &unix_bases(), &no_env);
+        assert_eq!(unix(Kind::Config), Some(PathBuf::from("/home/rikishi/.config/sumo")));
+        assert_eq!(unix(Kind::Cache), Some(PathBuf::from("/tmp/cache/sumo")));
+        assert_eq!(unix(Kind::State), Some(PathBuf::from("/home/rikishi/.local/state/sumo")));
+        assert_eq!(unix(Kind::Log), Some(PathBuf::from("/home/rikishi/.local/state/sumo/log")));
+
+        let mac = |kind| dir_for(kind, Platform::MacOs, &mac_bases(), &no_env);
+        assert_eq!(mac(Kind::Config), Some(PathBuf::from("/Users/rikishi/Library/Application Support/sumo")));
+        assert_eq!(mac(Kind::Cache), Some(PathBuf::from("/Users/rikishi/Library/Caches/sumo")));
+        assert_eq!(mac(Kind::State), Some(PathBuf::from("/Users/rikishi/Library/Application Support/sumo/state")));
+        assert_eq!(mac(Kind::Log), Some(PathBuf::from("/Users/rikishi/Library/Logs/sumo")));
+
+        // XDG variables still win on macOS; an empty one counts as unset
+        let xdg_env = |name: &str| match name {
+            "XDG_CACHE_HOME" => Some(PathBuf::from("/tmp/cache")),
+            "XDG_STATE_HOME" => Some(PathBuf::new()),
+            _ => None,
+        };
+        let mac_xdg = |kind| dir_for(kind, Platform::MacOs, &mac_bases(), &xdg_env);
+        assert_eq!(mac_xdg(Kind::Cache), Some(PathBuf::from("/tmp/cache/sumo")));
+        assert_eq!(mac_xdg(Kind::State), Some(PathBuf::from("/Users/rikishi/Library/Application Support/sumo/state")));
+
+        let windows = |kind| dir_for(kind, Platform::Windows, &windows_bases(), &no_env);
+        assert_eq!(windows(Kind::Config), Some(PathBuf::from(r"C:\Users\r\AppData\Roaming").join("sumo").join("config")));
+        assert_eq!(windows(Kind::State), Some(PathBuf::from(r"C:\Users\r\AppData\Local").join("sumo").join("data")));
+        assert_eq!(windows(Kind::Log), Some(PathBuf::from(r"C:\Users\r\AppData\Local").join("sumo").join("logs")));
+        assert_eq!(dir_for(Kind::State, Platform::Unix, &Bases { state: None, ..unix_bases() }, &no_env), None);
+    }
+
+    #[test]
+    fn keeps_each_kind_apart() {
+        for (platform, bases) in [(Platform::Unix, unix_bases()), (Platform::MacOs, mac_bases()), (Platform::Windows, windows_bases())] {
+            let dirs: Vec<PathBuf> = [Kind::Config, Kind::Cache, Kind::State, Kind::Log]
+                .into_iter()
+                .filter_map(|kind| dir_for(kind, platform, &bases, &|_| None))
+                .collect();
+            assert_eq!(dirs.len(), 4, "{:?}", platform);
+            for (i, a) in dirs.iter().enumerate() {
+                for b in &dirs[i + 1..] {
+                    assert_ne!(a, b, "{:?}", platform);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::api::{self, BanzukeResponse, RateLimited, SumoApi, TorikumiResponse};
+use crate::cache::DiskCache;
+use crate::live::day_complete;
+use crate::queue::{self, Priority};
+use chrono::Utc;
+use std::io::Write;
+
+const BASHO_DAYS: u8 = 15;
+const BAR_WIDTH: usize = 30;
+// Rate-limit pauses waited out for one request before giving up on it
+const RATE_LIMIT_RETRIES: u32 = 3;
+
+/// One response downloaded by `sumo prefetch`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    Banzuke(String),
+    Torikumi(String, u8),
+}
+
+impl Item {
+    fn path(&self, basho_id: &str) -> String {
+        match self {
+            Item::Banzuke(division) => api::banzuke_path(basho_id, division),
+            Item::Torikumi(division, day) => api::torikumi_path(basho_id, division, *day),
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Item::Banzuke(division) => format!("{} banzuke", division),
+            Item::Torikumi(division, day) => format!("{} day {}", division, day),
+        }
+    }
+}
+
+/// Everything to download for `divisions`: each banzuke, then every day's torikumi.
+pub fn plan(divisions: &[String]) -> Vec<Item> {
+    divisions
+        .iter()
+        .flat_map(|division| {
+            std::iter::once(Item::Banzuke(division.clone()))
+                .chain((1..=BASHO_DAYS).map(|day| Item::Torikumi(division.clone(), day)))
+        })
+        .collect()
+}
+
+/// Whether the cached copy of `item` can't change any more, so a resumed prefetch skips
+/// it: a day with every result in, or anything at all once the basho has finished.
+pub fn is_final(cache: &DiskCache, basho_id: &str, item: &Item, finished: bool) -> bool {
+    let Some(body) = cache.get(&item.path(basho_id)) else {
+        return false;
+    };
+    match item {
+        Item::Banzuke(_) => finished && serde_json::from_str::<BanzukeResponse>(&body).is_ok(),
+        Item::Torikumi(..) => serde_json::from_str::<TorikumiResponse>(&body)
+            .is_ok_and(|r| finished || day_complete(r.torikumi.as_deref().unwrap_or_default())),
+    }
+}
+
+/// `[######------]  12/96`
+pub fn progress_bar(done: usize, total: usize, width: usize) -> String {
+    let filled = (done * width).checked_div(total).unwrap_or(width).min(width);
+    let digits = total.to_string().len();
+    format!("[{}{}] {:>digits$}/{}", "#".repeat(filled), "-".repeat(width - filled), done, total)
+}
+
+/// What a prefetch run did.
+#[derive(Debug, Default)]
+pub struct Report {
+    pub fetched: usize,
+    pub skipped: usize,
+    pub failed: Vec<(String, anyhow::Error)>,
+}
+
+/// Download the basho info, banzuke and all fifteen days of torikumi for `divisions`
+/// into the disk cache, drawing a progress bar on `progress`. Items already final in
+/// the cache are skipped, so an interrupted run picks up where it stopped.
+pub async fn run(api: &SumoApi, cache: &DiskCache, basho_id: &str, divisions: &[String], progress: &mut impl Write) -> anyhow::Result<Report> {
+    let basho = api
+        .get_basho(basho_id)
+        .await
+        .map_err(|e| anyhow::anyhow!("could not load basho {}: {}", basho_id, e))?;
+    let today = Utc::now().date_naive();
+    let finished = basho.end_date_naive().is_some_and(|end| today > end);
+
+    let items = plan(divisions);
+    let mut report = Report::default();
+    for (done, item) in items.iter().enumerate() {
+        write!(progress, "\r{} {:<20}", progress_bar(done, items.len(), BAR_WIDTH), item.label())?;
+        progress.flush()?;
+        if is_final(cache, basho_id, item, finished) {
+            report.skipped += 1;
+            continue;
+        }
+        match queue::with_priority(Priority::Prefetch, fetch(api, basho_id, item)).await {
+            Ok(()) => report.fetched += 1,
+            Err(e) => report.failed.push((item.label(), e)),
+        }
+    }
+    writeln!(progress, "\r{} {:<20}", progress_bar(items.len(), items.len(), BAR_WIDTH), "")?;
+    Ok(report)
+}
+
+// Fetch one item, which the API writes through to the disk cache, waiting out any
+// rate limiting rather than failing the rest of the run
+async fn fetch(api: &SumoApi, basho_id: &str, item: &Item) -> anyhow::Result<()> {
+    let mut attempts = 0;
+    loop {
+        let result = match item {
+            Item::Banzuke(division) => api.get_banzuke(basho_id, division).await.map(drop),
+            Item::Torikumi(division, day) => api.get_torikumi(basho_id, division, *day).await.map(drop),
+        };
+        let limited = result.as_ref().err().and_then(|e| e.downcast_ref::<RateLimited>()).map(|l| l.retry_after);
+        match limited {
+            Some(retry_after) if attempts < RATE_LIMIT_RETRIES => {
+                attempts += 1;
+                tokio::time::sleep(retry_after).await;
+            }
+            _ => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_final, plan, progress_bar, Item};
+    use crate::api::torikumi_path;
+    use crate::cache::DiskCache;
+    use crate::config::CacheConfig;
+
+    #[test]
+    fn plans_every_day_and_draws_progress() {
+        let items = plan(&["Makuuchi".to_string(), "Juryo".to_string()]);
+        assert_eq!(items.len(), 32);
+        assert_eq!(items[0], Item::Banzuke("Makuuchi".to_string()));
+        assert_eq!(items[15], Item::Torikumi("Makuuchi".to_string(), 15));
+        assert_eq!(items[16], Item::Banzuke("Juryo".to_string()));
+
+        assert_eq!(progress_bar(0, 32, 8), "[--------]  0/32");
+        assert_eq!(progress_bar(16, 32, 8), "[####----] 16/32");
+        assert_eq!(progress_bar(32, 32, 8), "[########] 32/32");
+        assert_eq!(progress_bar(0, 0, 4), "[####] 0/0");
+    }
+
+    #[test]
+    fn only_complete_days_count_as_final_while_the_basho_runs() {
+        let dir = std::env::temp_dir().join(format!("sumo-prefetch-test-{}", std::process::id()));
+        let cache = DiskCache::new(&dir, &CacheConfig::default());
+        let bout = |winner: &str| {
+            format!(
+                r#"{{"id":"1","bashoId":"202501","division":"Makuuchi","day":1,"matchNo":1,"eastId":1,"eastShikona":"Oho","eastRank":"Maegashira 1 East","westId":2,"westShikona":"Endo","westRank":"Maegashira 1 West","kimarite":"","winnerId":{},"winnerEn":"","winnerJp":""}}"#,
+                winner
+            )
+        };
+        let response = |bout: String| {
+            format!(r#"{{"date":"202501","location":null,"startDate":"","endDate":"","torikumi":[{}]}}"#, bout)
+        };
+        cache.put(&torikumi_path("202501", "Makuuchi", 1), &response(bout("1"))).unwrap();
+        cache.put(&torikumi_path("202501", "Makuuchi", 2), &response(bout("null"))).unwrap();
+
+        let day = |day| Item::Torikumi("Makuuchi".to_string(), day);
+        assert!(is_final(&cache, "202501", &day(1), false));
+        assert!(!is_final(&cache, "202501", &day(2), false));
+        assert!(is_final(&cache, "202501", &day(2), true));
+        assert!(!is_final(&cache, "202501", &day(3), true));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,148 @@
+use crate::api::{BanzukeEntry, SumoApi};
+use crate::matchups::Matrix;
+use crate::rank::Rank;
+use futures::future::join_all;
+
+// Rank slots either side of a rikishi that the schedulers usually pair them within
+const RANK_WINDOW: u32 = 8;
+// Wins behind a contender that still count as being in the race with them; late in a
+// basho the leaders are matched against each other whatever their rank
+const RACE_WINDOW: u8 = 2;
+
+/// Someone a contender hasn't fought yet and could plausibly still meet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub rikishi_id: u32,
+    pub shikona: String,
+    pub rank: String,
+    pub wins: u8,
+    pub losses: u8,
+    /// Within the usual scheduling range by rank
+    pub near_rank: bool,
+    /// Close enough on wins to be drawn in for the yusho race
+    pub in_race: bool,
+}
+
+/// Remaining plausible opponents of a contender, with career head-to-head records once
+/// they are loaded.
+#[derive(Debug, Clone, Default)]
+pub struct Projection {
+    pub rikishi_id: u32,
+    pub shikona: String,
+    pub candidates: Vec<Candidate>,
+    /// Opponent id -> (contender's wins, opponent's wins)
+    pub head_to_head: Vec<(u32, Option<(u32, u32)>)>,
+}
+
+impl Projection {
+    pub fn head_to_head(&self, opponent_id: u32) -> Option<Option<(u32, u32)>> {
+        self.head_to_head.iter().find(|(id, _)| *id == opponent_id).map(|(_, record)| *record)
+    }
+}
+
+// Whether the rikishi has withdrawn: their latest result in the revealed days is an absence
+fn withdrawn(entry: &BanzukeEntry, revealed: u8) -> bool {
+    entry
+        .record
+        .iter()
+        .flatten()
+        .take(revealed as usize)
+        .filter(|r| !r.result.is_empty())
+        .last()
+        .is_some_and(|r| r.result == "absent")
+}
+
+/// The rikishi in `banzuke[row]` hasn't met yet (according to `matrix`, which must be
+/// computed from the same banzuke) and could still face: those close in rank or close
+/// behind, level with or ahead of them on wins. Withdrawn rikishi are left out.
+pub fn remaining_opponents(banzuke: &[BanzukeEntry], matrix: &Matrix, row: usize, revealed: impl Fn(&BanzukeEntry) -> u8) -> Vec<Candidate> {
+    let Some(contender) = banzuke.get(row) else {
+        return Vec::new();
+    };
+    let ordinal = |entry: &BanzukeEntry| Rank::parse(&entry.rank).map(|r| r.ordinal());
+    let (contender_wins, _, _) = contender.win_loss_absent(revealed(contender));
+    banzuke
+        .iter()
+        .enumerate()
+        .filter(|(column, entry)| {
+            *column != row && matrix.cells[row][*column].is_none() && !withdrawn(entry, revealed(entry))
+        })
+        .filter_map(|(_, entry)| {
+            let (wins, losses, _) = entry.win_loss_absent(revealed(entry));
+            let near_rank = ordinal(contender)
+                .zip(ordinal(entry))
+                .is_some_and(|(a, b)| a.abs_diff(b) <= RANK_WINDOW);
+            let in_race = wins + RACE_WINDOW >= contender_wins && contender_wins > 0;
+            (near_rank || in_race).then(|| Candidate {
+                rikishi_id: entry.rikishi_id,
+                shikona: entry.shikona_en.to_string(),
+                rank: entry.rank.clone(),
+                wins,
+                losses,
+                near_rank,
+                in_race,
+            })
+        })
+        .collect()
+}
+
+/// Career head-to-head of the contender against each candidate, `None` where the lookup
+/// failed.
+pub async fn fetch_head_to_head(api: &SumoApi, rikishi_id: u32, opponents: Vec<u32>) -> Vec<(u32, Option<(u32, u32)>)> {
+    let results = join_all(opponents.iter().map(|opponent| api.get_head_to_head(rikishi_id, *opponent))).await;
+    opponents
+        .into_iter()
+        .zip(results)
+        .map(|(opponent, result)| (opponent, result.ok().map(|h2h| (h2h.rikishi_wins, h2h.opponent_wins))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remaining_opponents;
+    use crate::api::{BanzukeEntry, MatchRecord};
+    use crate::matchups::Matrix;
+
+    fn entry(id: u32, shikona: &str, rank: &str, results: &[(&str, &str)]) -> BanzukeEntry {
+        BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: id,
+            shikona_en: shikona.into(),
+            shikona_jp: None,
+            rank_value: id,
+            rank: rank.to_string(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: Some(
+                results
+                    .iter()
+                    .map(|(result, opponent)| MatchRecord {
+                        result: result.to_string(),
+                        opponent_shikona_en: opponent.to_string(),
+                        opponent_shikona_jp: String::new(),
+                        kimarite: None,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn unmet_rikishi_near_in_rank_or_in_the_race() {
+        let banzuke = vec![
+            entry(1, "Hoshoryu", "Yokozuna 1 East", &[("win", "Onosato"), ("win", "Oho"), ("win", "Daieisho")]),
+            entry(2, "Onosato", "Ozeki 1 East", &[("loss", "Hoshoryu"), ("win", "Ura")]),
+            entry(3, "Kirishima", "Sekiwake 1 East", &[("absent", ""), ("absent", "")]),
+            entry(4, "Ura", "Maegashira 5 East", &[("win", "Endo"), ("loss", "Onosato")]),
+            entry(5, "Oho", "Maegashira 14 West", &[("win", "Tobizaru"), ("loss", "Hoshoryu")]),
+            entry(6, "Tobizaru", "Maegashira 15 East", &[("loss", "Oho"), ("win", "Endo")]),
+            entry(7, "Endo", "Maegashira 16 West", &[("loss", "Ura"), ("loss", "Tobizaru")]),
+        ];
+        let matrix = Matrix::compute(&banzuke, &[], |_| 15);
+        let candidates = remaining_opponents(&banzuke, &matrix, 0, |_| 15);
+        let names: Vec<(&str, bool, bool)> = candidates.iter().map(|c| (c.shikona.as_str(), c.near_rank, c.in_race)).collect();
+        // Kirishima withdrew; Endo is too far down the banzuke and too far behind
+        assert_eq!(names, [("Ura", true, true), ("Tobizaru", false, true)]);
+    }
+}
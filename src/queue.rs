@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+
+/// How soon a request is needed. Waiting requests are sent highest priority first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    /// Aggregates over many requests: matchup head-to-heads, career records, comparisons
+    Analytics,
+    /// Data fetched ahead of being looked at
+    Prefetch,
+    /// What the user is waiting on: the loaded day, detail popups, lookups
+    User,
+}
+
+tokio::task_local! {
+    static PRIORITY: Priority;
+}
+
+/// Run `future` with the API requests it makes queued at `priority`.
+pub async fn with_priority<F: Future>(priority: Priority, future: F) -> F::Output {
+    PRIORITY.scope(priority, future).await
+}
+
+/// The priority of requests made here: the enclosing [`with_priority`], or
+/// [`Priority::User`] outside of one.
+pub fn current() -> Priority {
+    PRIORITY.try_with(|p| *p).unwrap_or(Priority::User)
+}
+
+struct State {
+    limit: usize,
+    running: usize,
+    // Keyed so the first entry is the highest priority, then the longest waiting
+    waiting: BTreeMap<(std::cmp::Reverse<Priority>, u64), oneshot::Sender<()>>,
+    arrivals: u64,
+}
+
+/// Lets at most `limit` requests run at once, starting waiting ones by priority.
+#[derive(Clone)]
+pub struct Queue {
+    state: Arc<Mutex<State>>,
+}
+
+/// A running request's place in the [`Queue`], handed to the next one when dropped.
+pub struct Slot {
+    queue: Queue,
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.queue.release();
+    }
+}
+
+// Gives the slot back if the request stops waiting after being let in
+struct Waiting {
+    queue: Queue,
+    admitted: Option<oneshot::Receiver<()>>,
+}
+
+impl Drop for Waiting {
+    fn drop(&mut self) {
+        if let Some(mut admitted) = self.admitted.take() {
+            admitted.close();
+            if admitted.try_recv().is_ok() {
+                self.queue.release();
+            }
+        }
+    }
+}
+
+impl Queue {
+    pub fn new(limit: usize) -> Self {
+        let state = State { limit: limit.max(1), running: 0, waiting: BTreeMap::new(), arrivals: 0 };
+        Self { state: Arc::new(Mutex::new(state)) }
+    }
+
+    /// Wait for a slot to send a request at `priority`.
+    pub async fn acquire(&self, priority: Priority) -> Slot {
+        let admitted = {
+            let mut state = self.state.lock().unwrap();
+            if state.running < state.limit {
+                state.running += 1;
+                return Slot { queue: self.clone() };
+            }
+            let (tx, rx) = oneshot::channel();
+            state.arrivals += 1;
+            let key = (std::cmp::Reverse(priority), state.arrivals);
+            state.waiting.insert(key, tx);
+            rx
+        };
+        let mut waiting = Waiting { queue: self.clone(), admitted: Some(admitted) };
+        if let Some(admitted) = waiting.admitted.as_mut() {
+            // The sender only goes away once the request was let in
+            let _ = admitted.await;
+        }
+        waiting.admitted = None;
+        Slot { queue: self.clone() }
+    }
+
+    // Pass a finished request's slot to the next waiting one still there
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        while let Some((_, next)) = state.waiting.pop_first() {
+            if next.send(()).is_ok() {
+                return;
+            }
+        }
+        state.running -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{current, with_priority, Priority, Queue};
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test]
+    async fn waiting_requests_go_by_priority() {
+        assert_eq!(current(), Priority::User);
+        assert_eq!(with_priority(Priority::Analytics, async { current() }).await, Priority::Analytics);
+
+        let queue = Queue::new(1);
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let running = queue.acquire(Priority::Analytics).await;
+        let mut waiting = Vec::new();
+        for (name, priority) in [("matrix", Priority::Analytics), ("prefetch", Priority::Prefetch), ("day", Priority::User)] {
+            let (queue, order) = (queue.clone(), order.clone());
+            waiting.push(tokio::spawn(async move {
+                let _slot = queue.acquire(priority).await;
+                order.lock().unwrap().push(name);
+            }));
+            tokio::task::yield_now().await;
+        }
+        // A request given up on while waiting doesn't hold up the rest
+        let abandoned = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.acquire(Priority::User).await }
+        });
+        tokio::task::yield_now().await;
+        abandoned.abort();
+
+        drop(running);
+        for task in waiting {
+            task.await.unwrap();
+        }
+        assert_eq!(*order.lock().unwrap(), ["day", "prefetch", "matrix"]);
+    }
+}
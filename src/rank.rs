@@ -0,0 +1,295 @@
+/// Banzuke tiers from highest to lowest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Tier {
+    Yokozuna,
+    Ozeki,
+    Sekiwake,
+    Komusubi,
+    Maegashira,
+    Juryo,
+    Makushita,
+    Sandanme,
+    Jonidan,
+    Jonokuchi,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    East,
+    West,
+}
+
+/// A parsed rank such as "Maegashira 7 East".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rank {
+    pub tier: Tier,
+    pub number: Option<u32>,
+    pub side: Option<Side>,
+}
+
+impl Tier {
+    // Approximate number of rank slots per tier, used to place ranks on one linear scale
+    fn size(self) -> u32 {
+        match self {
+            Tier::Yokozuna | Tier::Ozeki | Tier::Sekiwake | Tier::Komusubi => 1,
+            Tier::Maegashira => 17,
+            Tier::Juryo => 14,
+            Tier::Makushita => 60,
+            Tier::Sandanme => 90,
+            Tier::Jonidan => 100,
+            Tier::Jonokuchi => 30,
+        }
+    }
+
+    /// i18n key of the tier's name; the lower tiers are named like their divisions.
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Tier::Yokozuna => "tier.yokozuna",
+            Tier::Ozeki => "tier.ozeki",
+            Tier::Sekiwake => "tier.sekiwake",
+            Tier::Komusubi => "tier.komusubi",
+            Tier::Maegashira => "tier.maegashira",
+            Tier::Juryo => "division.juryo",
+            Tier::Makushita => "division.makushita",
+            Tier::Sandanme => "division.sandanme",
+            Tier::Jonidan => "division.jonidan",
+            Tier::Jonokuchi => "division.jonokuchi",
+        }
+    }
+
+    /// The tier's name in kanji, as written on the banzuke.
+    pub fn kanji(self) -> &'static str {
+        match self {
+            Tier::Yokozuna => "横綱",
+            Tier::Ozeki => "大関",
+            Tier::Sekiwake => "関脇",
+            Tier::Komusubi => "小結",
+            Tier::Maegashira => "前頭",
+            Tier::Juryo => "十両",
+            Tier::Makushita => "幕下",
+            Tier::Sandanme => "三段目",
+            Tier::Jonidan => "序二段",
+            Tier::Jonokuchi => "序ノ口",
+        }
+    }
+
+    const ALL: [Tier; 10] = [
+        Tier::Yokozuna,
+        Tier::Ozeki,
+        Tier::Sekiwake,
+        Tier::Komusubi,
+        Tier::Maegashira,
+        Tier::Juryo,
+        Tier::Makushita,
+        Tier::Sandanme,
+        Tier::Jonidan,
+        Tier::Jonokuchi,
+    ];
+}
+
+impl Rank {
+    /// Parse the API's long form ("Maegashira 7 East"), the short form ("M7e", "Ms12w")
+    /// or kanji ("東前頭七枚目", "西大関").
+    pub fn parse(rank: &str) -> Option<Self> {
+        let r = rank.trim();
+        if !r.is_ascii() {
+            return Self::parse_kanji(r);
+        }
+        let l = r.to_lowercase();
+        let number: Option<u32> = {
+            let digits: String = r.chars().filter(|c| c.is_ascii_digit()).collect();
+            digits.parse().ok()
+        };
+        let side = if l.contains("east") || l.ends_with('e') && number.is_some() {
+            Some(Side::East)
+        } else if l.contains("west") || l.ends_with('w') && number.is_some() {
+            Some(Side::West)
+        } else {
+            None
+        };
+
+        let long_forms = [
+            ("yokozuna", Tier::Yokozuna),
+            ("ozeki", Tier::Ozeki),
+            ("sekiwake", Tier::Sekiwake),
+            ("komusubi", Tier::Komusubi),
+            ("maegashira", Tier::Maegashira),
+            ("juryo", Tier::Juryo),
+            ("makushita", Tier::Makushita),
+            ("sandanme", Tier::Sandanme),
+            ("jonidan", Tier::Jonidan),
+            ("jonokuchi", Tier::Jonokuchi),
+        ];
+        if let Some((_, tier)) = long_forms.iter().find(|(name, _)| l.starts_with(name)) {
+            return Some(Rank { tier: *tier, number, side });
+        }
+
+        // Short forms; check two-letter prefixes before single letters
+        let prefix: String = l.chars().take_while(|c| c.is_ascii_alphabetic()).collect();
+        let prefix = prefix.trim_end_matches(['e', 'w']);
+        let tier = match prefix {
+            "ms" => Tier::Makushita,
+            "sd" => Tier::Sandanme,
+            "jd" => Tier::Jonidan,
+            "jk" => Tier::Jonokuchi,
+            "y" => Tier::Yokozuna,
+            "o" => Tier::Ozeki,
+            "s" => Tier::Sekiwake,
+            "k" => Tier::Komusubi,
+            "m" => Tier::Maegashira,
+            "j" => Tier::Juryo,
+            _ => return None,
+        };
+        Some(Rank { tier, number, side })
+    }
+
+    fn parse_kanji(rank: &str) -> Option<Self> {
+        let (side, rest) = match rank.chars().next()? {
+            '東' => (Some(Side::East), &rank['東'.len_utf8()..]),
+            '西' => (Some(Side::West), &rank['西'.len_utf8()..]),
+            _ => (None, rank),
+        };
+        let tier = *Tier::ALL.iter().find(|t| rest.starts_with(t.kanji()))?;
+        let rest = rest[tier.kanji().len()..].trim_end_matches("枚目");
+        let number = match rest {
+            "" => None,
+            "筆頭" => Some(1),
+            _ => Some(rest.parse().ok().or_else(|| kanji_number(rest))?),
+        };
+        Some(Rank { tier, number, side })
+    }
+
+    /// The rank as written on the banzuke: "東前頭筆頭", "西十両十二枚目", "東大関". Sanyaku
+    /// ranks leave out their number.
+    pub fn kanji(&self) -> String {
+        let side = match self.side {
+            Some(Side::East) => "東",
+            Some(Side::West) => "西",
+            None => "",
+        };
+        let number = match (self.tier, self.number) {
+            (Tier::Yokozuna | Tier::Ozeki | Tier::Sekiwake | Tier::Komusubi, _) | (_, None) => String::new(),
+            (_, Some(1)) => "筆頭".to_string(),
+            (_, Some(n)) => format!("{}枚目", kanji_numeral(n)),
+        };
+        format!("{}{}{}", side, self.tier.kanji(), number)
+    }
+
+    /// Whether this rank falls under `pattern`: the same tier, and the same number and
+    /// side where the pattern gives them.
+    pub fn matches(&self, pattern: &Rank) -> bool {
+        self.tier == pattern.tier
+            && pattern.number.is_none_or(|n| self.number == Some(n))
+            && pattern.side.is_none_or(|s| self.side == Some(s))
+    }
+
+    /// Position on a single descending scale (0 is the top Yokozuna). Sanyaku ranks share
+    /// one slot per tier; numbered tiers use their number within the tier.
+    pub fn ordinal(&self) -> u32 {
+        let base: u32 = Tier::ALL
+            .iter()
+            .take_while(|t| **t != self.tier)
+            .map(|t| t.size())
+            .sum();
+        match self.tier {
+            Tier::Yokozuna | Tier::Ozeki | Tier::Sekiwake | Tier::Komusubi => base,
+            _ => base + self.number.unwrap_or(1).saturating_sub(1).min(self.tier.size() - 1),
+        }
+    }
+}
+
+const DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+// 12 -> "十二", 100 -> "百", 203 -> "二百三"
+fn kanji_numeral(n: u32) -> String {
+    let mut out = String::new();
+    for (value, unit) in [(100, '百'), (10, '十')] {
+        match n / value % 10 {
+            0 => {}
+            1 => out.push(unit),
+            d => out.extend([DIGITS[d as usize], unit]),
+        }
+    }
+    if !n.is_multiple_of(10) || n == 0 {
+        out.push(DIGITS[(n % 10) as usize]);
+    }
+    out
+}
+
+// The inverse of `kanji_numeral`
+fn kanji_number(text: &str) -> Option<u32> {
+    let (mut total, mut digit) = (0, None);
+    for c in text.chars() {
+        match c {
+            '百' | '十' => {
+                total += digit.take().unwrap_or(1) * if c == '百' { 100 } else { 10 };
+            }
+            _ => digit = Some(DIGITS.iter().position(|d| *d == c)? as u32),
+        }
+    }
+    Some(total + digit.unwrap_or(0)).filter(|n| *n > 0)
+}
+
+/// Number of rank slots between two ranks, or `None` when either cannot be parsed.
+pub fn rank_difference(a: &str, b: &str) -> Option<u32> {
+    let a = Rank::parse(a)?.ordinal();
+    let b = Rank::parse(b)?.ordinal();
+    Some(a.abs_diff(b))
+}
+
+/// Estimated probability that the wrestler ranked `a` beats the wrestler ranked `b`,
+/// from the rank gap alone (logistic curve; ten slots higher is roughly 70%).
+pub fn win_probability(a: &str, b: &str) -> Option<f64> {
+    let a = Rank::parse(a)?.ordinal() as f64;
+    let b = Rank::parse(b)?.ordinal() as f64;
+    Some(1.0 / (1.0 + (-(b - a) * 0.085).exp()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rank_difference, win_probability, Rank, Side, Tier};
+
+    #[test]
+    fn parses_long_and_short_forms() {
+        assert_eq!(
+            Rank::parse("Maegashira 7 East"),
+            Some(Rank { tier: Tier::Maegashira, number: Some(7), side: Some(Side::East) })
+        );
+        assert_eq!(Rank::parse("Ms12w").map(|r| (r.tier, r.number)), Some((Tier::Makushita, Some(12))));
+        assert_eq!(Rank::parse("Ozeki 1 West").map(|r| r.tier), Some(Tier::Ozeki));
+        assert_eq!(Rank::parse("???"), None);
+    }
+
+    #[test]
+    fn reads_and_writes_kanji() {
+        let kanji = |rank: &str| Rank::parse(rank).map(|r| r.kanji());
+        assert_eq!(kanji("Yokozuna 1 East").as_deref(), Some("東横綱"));
+        assert_eq!(kanji("Maegashira 1 West").as_deref(), Some("西前頭筆頭"));
+        assert_eq!(kanji("Maegashira 10 East").as_deref(), Some("東前頭十枚目"));
+        assert_eq!(kanji("J12w").as_deref(), Some("西十両十二枚目"));
+        assert_eq!(kanji("Jonidan 103 East").as_deref(), Some("東序二段百三枚目"));
+        for rank in ["Ozeki 1 West", "Maegashira 1 East", "Maegashira 17 West", "Sandanme 45 East", "Jonidan 100 West"] {
+            let parsed = Rank::parse(rank).unwrap();
+            assert_eq!(Rank::parse(&parsed.kanji()).map(|r| r.ordinal()), Some(parsed.ordinal()), "{}", rank);
+        }
+        assert_eq!(Rank::parse("前頭5枚目").map(|r| (r.tier, r.number, r.side)), Some((Tier::Maegashira, Some(5), None)));
+        assert_eq!(Rank::parse("東前頭"), Some(Rank { tier: Tier::Maegashira, number: None, side: Some(Side::East) }));
+    }
+
+    #[test]
+    fn ordinal_orders_tiers() {
+        let k = Rank::parse("Komusubi 1 East").unwrap().ordinal();
+        let m1 = Rank::parse("Maegashira 1 East").unwrap().ordinal();
+        let j1 = Rank::parse("Juryo 1 West").unwrap().ordinal();
+        assert!(k < m1 && m1 < j1);
+        assert_eq!(rank_difference("Maegashira 3 East", "Maegashira 10 West"), Some(7));
+    }
+
+    #[test]
+    fn higher_rank_is_favored() {
+        let p = win_probability("Yokozuna 1 East", "Maegashira 5 West").unwrap();
+        assert!(p > 0.5);
+        let even = win_probability("Maegashira 5 East", "Maegashira 5 West").unwrap();
+        assert!((even - 0.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::api::{self, SumoApi};
+use futures::stream::{self, StreamExt};
+use std::collections::HashMap;
+
+/// Earlier basho shown in the banzuke's recent column.
+pub const RECENT_BASHO: usize = 3;
+// Banzuke requested at once when they aren't cached
+const CONCURRENT_REQUESTS: usize = 4;
+
+/// A rikishi's final record at an earlier basho.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Record {
+    pub wins: u8,
+    pub losses: u8,
+    pub absences: u8,
+}
+
+impl std::fmt::Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.absences {
+            0 => write!(f, "{}-{}", self.wins, self.losses),
+            absences => write!(f, "{}-{}-{}", self.wins, self.losses, absences),
+        }
+    }
+}
+
+/// Records at each of the last [`RECENT_BASHO`] basho by rikishi, newest first; `None`
+/// where they weren't on the banzuke fetched for it.
+pub type Recent = HashMap<u32, Vec<Option<Record>>>;
+
+/// A rikishi's recent records as a strip such as `9-6, 8-7, 11-4`.
+pub fn strip(records: &[Option<Record>]) -> String {
+    records.iter().map(|r| r.map_or_else(|| "-".to_string(), |r| r.to_string())).collect::<Vec<_>>().join(", ")
+}
+
+// The division and those either side of it, where promoted or demoted rikishi were
+fn nearby_divisions(division: &str) -> &'static [&'static str] {
+    let divisions = crate::tui::DIVISIONS;
+    let i = divisions.iter().position(|d| d.eq_ignore_ascii_case(division)).unwrap_or(0);
+    &divisions[i.saturating_sub(1)..(i + 2).min(divisions.len())]
+}
+
+/// Records of `division`'s rikishi at the basho before `basho_id`, from the banzuke of
+/// that division and the ones either side of it. Banzuke that can't be loaded are left out.
+pub async fn fetch(api: &SumoApi, basho_id: &str, division: &str) -> Recent {
+    let earlier = std::iter::successors(api::previous_basho_id(basho_id), |id| api::previous_basho_id(id)).take(RECENT_BASHO);
+    let mut lookups = Vec::new();
+    for (i, id) in earlier.enumerate() {
+        lookups.extend(nearby_divisions(division).iter().map(|d| (i, id.clone(), d.to_string())));
+    }
+    let banzuke: Vec<_> = stream::iter(lookups)
+        .map(|(i, id, division)| async move {
+            let response = api.get_banzuke(&id, &division).await;
+            (i, division, response)
+        })
+        .buffered(CONCURRENT_REQUESTS)
+        .collect()
+        .await;
+
+    let mut recent = Recent::new();
+    for (i, division, response) in banzuke {
+        let Ok(response) = response else { continue };
+        let days = crate::max_day_for_division(&division);
+        for entry in response.east.iter().chain(&response.west) {
+            let (wins, losses, absences) = entry.win_loss_absent(days);
+            recent.entry(entry.rikishi_id).or_insert_with(|| vec![None; RECENT_BASHO])[i] = Some(Record { wins, losses, absences });
+        }
+    }
+    recent
+}
+
+/// Rank of every rikishi on the `basho_id` banzuke of any division, so those promoted or
+/// demoted across divisions since are found too. Divisions that can't be loaded are left
+/// out, unless none can.
+pub async fn fetch_ranks(api: &SumoApi, basho_id: &str) -> anyhow::Result<HashMap<u32, String>> {
+    let divisions = crate::tui::DIVISIONS;
+    let responses = futures::future::join_all(divisions.iter().map(|division| api.get_banzuke(basho_id, division))).await;
+    let mut ranks = HashMap::new();
+    let mut error = None;
+    for response in responses {
+        match response {
+            Ok(banzuke) => ranks.extend(banzuke.east.into_iter().chain(banzuke.west).map(|e| (e.rikishi_id, e.rank))),
+            Err(e) => error = Some(e),
+        }
+    }
+    match error {
+        Some(e) if ranks.is_empty() => Err(e),
+        _ => Ok(ranks),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fetch_ranks, nearby_divisions, strip, Record};
+
+    #[test]
+    fn strips_read_newest_first() {
+        let record = |wins, losses, absences| Some(Record { wins, losses, absences });
+        assert_eq!(strip(&[record(9, 6, 0), None, record(3, 4, 8)]), "9-6, -, 3-4-8");
+        assert_eq!(nearby_divisions("Makuuchi"), ["Makuuchi", "Juryo"]);
+        assert_eq!(nearby_divisions("sandanme"), ["Makushita", "Sandanme", "Jonidan"]);
+        assert_eq!(nearby_divisions("Jonokuchi"), ["Jonidan", "Jonokuchi"]);
+    }
+
+    #[tokio::test]
+    async fn ranks_span_every_division() {
+        use crate::api::{banzuke_path, recording_name, SumoApi, Traffic};
+        use crate::config::ApiConfig;
+        let dir = std::env::temp_dir().join(format!("sumo-ranks-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let banzuke = |division: &str, id: u32, rank: &str| {
+            let body = format!(
+                r#"{{"bashoId":"202507","division":"{}","east":[{{"side":"East","rikishiID":{},"shikonaEn":"A","rankValue":1,"rank":"{}"}}],"west":[]}}"#,
+                division, id, rank
+            );
+            std::fs::write(dir.join(recording_name(&banzuke_path("202507", division))), body).unwrap();
+        };
+        banzuke("Juryo", 1, "Juryo 2 East");
+        banzuke("Makushita", 2, "Makushita 1 East");
+
+        let api = SumoApi::with_config(&ApiConfig::default()).unwrap().with_traffic(Traffic::Playback(dir.clone()));
+        let ranks = fetch_ranks(&api, "202507").await.unwrap();
+        let missing = fetch_ranks(&api, "202505").await;
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!((ranks[&1].as_str(), ranks[&2].as_str()), ("Juryo 2 East", "Makushita 1 East"));
+        assert!(missing.is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::queue::{self, Priority};
+use std::collections::HashMap;
+use std::future::Future;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::task::JoinHandle;
+
+/// Background fetches the TUI makes; at most one of each kind runs at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    Load,
+    Rikishi,
+    RikishiStats,
+    Rivals,
+    HeadToHead,
+    Compare,
+    Profiles,
+    CareerStats,
+    CareerBouts,
+    FindBout,
+    DayCheck,
+    Dashboard,
+    Projection,
+    YushoHistory,
+    RecentRecords,
+    BoutSeries,
+    BoutDays,
+    UpdateCheck,
+}
+
+impl Kind {
+    /// Priority of the API requests made for this kind of fetch.
+    pub fn priority(self) -> Priority {
+        match self {
+            Kind::Rivals | Kind::Compare | Kind::Profiles | Kind::CareerStats | Kind::CareerBouts | Kind::Projection | Kind::YushoHistory | Kind::RecentRecords => {
+                Priority::Analytics
+            }
+            Kind::Load
+            | Kind::Rikishi
+            | Kind::RikishiStats
+            | Kind::HeadToHead
+            | Kind::FindBout
+            | Kind::DayCheck
+            | Kind::Dashboard
+            | Kind::BoutDays
+            | Kind::UpdateCheck => Priority::User,
+            Kind::BoutSeries => Priority::Prefetch,
+        }
+    }
+}
+
+/// Which request a message came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ticket {
+    kind: Kind,
+    generation: u64,
+}
+
+/// Sends a request's results back, tagged with its ticket.
+pub struct Reply<M> {
+    ticket: Ticket,
+    tx: UnboundedSender<(Ticket, M)>,
+}
+
+impl<M> Reply<M> {
+    pub fn send(&self, message: M) {
+        let _ = self.tx.send((self.ticket, message));
+    }
+}
+
+struct Running {
+    key: String,
+    ticket: Ticket,
+    task: JoinHandle<()>,
+}
+
+/// Starts background fetches, coalescing a request with the same one still in flight and
+/// cancelling whatever else of its kind is running, so that only the newest request's
+/// results reach the app.
+pub struct Requests<M> {
+    running: HashMap<Kind, Running>,
+    generation: u64,
+    tx: UnboundedSender<(Ticket, M)>,
+}
+
+impl<M: Send + 'static> Requests<M> {
+    pub fn new(tx: UnboundedSender<(Ticket, M)>) -> Self {
+        Self { running: HashMap::new(), generation: 0, tx }
+    }
+
+    /// Run `fetch` unless a request of `kind` with the same `key` is still in flight.
+    /// Another request of that kind is aborted and its results, even ones already sent,
+    /// are no longer current. Returns whether `fetch` was started.
+    pub fn start<F>(&mut self, kind: Kind, key: impl Into<String>, fetch: impl FnOnce(Reply<M>) -> F) -> bool
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let key = key.into();
+        if let Some(running) = self.running.get(&kind)
+            && running.key == key
+            && !running.task.is_finished()
+        {
+            return false;
+        }
+        self.generation += 1;
+        let ticket = Ticket { kind, generation: self.generation };
+        let task = tokio::spawn(queue::with_priority(kind.priority(), fetch(Reply { ticket, tx: self.tx.clone() })));
+        if let Some(old) = self.running.insert(kind, Running { key, ticket, task }) {
+            old.task.abort();
+        }
+        true
+    }
+
+    pub fn is_running(&self, kind: Kind) -> bool {
+        self.running.get(&kind).is_some_and(|r| !r.task.is_finished())
+    }
+
+    /// Whether a message comes from the latest request of its kind.
+    pub fn is_current(&self, ticket: Ticket) -> bool {
+        self.running.get(&ticket.kind).is_some_and(|r| r.ticket == ticket)
+    }
+
+    pub fn abort_all(&mut self) {
+        for (_, running) in self.running.drain() {
+            running.task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Kind, Requests};
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn coalesces_repeats_and_drops_superseded_results() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut requests = Requests::new(tx);
+        let (_release, wait) = tokio::sync::oneshot::channel::<()>();
+        assert!(requests.start(Kind::Load, "202501/Makuuchi/5", |reply| async move {
+            reply.send(5);
+            let _ = wait.await;
+        }));
+        // The same day again while it loads
+        assert!(!requests.start(Kind::Load, "202501/Makuuchi/5", |reply| async move { reply.send(-1) }));
+        let (stale, five) = rx.recv().await.unwrap();
+        assert_eq!(five, 5);
+        assert!(requests.is_current(stale));
+
+        // Another day supersedes it, along with the result it already sent
+        assert!(requests.start(Kind::Load, "202501/Makuuchi/6", |reply| async move { reply.send(6) }));
+        assert!(!requests.is_current(stale));
+        let (ticket, six) = rx.recv().await.unwrap();
+        assert_eq!(six, 6);
+        assert!(requests.is_current(ticket));
+        assert!(!requests.is_current(stale));
+    }
+}
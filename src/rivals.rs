@@ -0,0 +1,98 @@
+use crate::api::HeadToHeadMatch;
+use std::collections::HashMap;
+
+/// Opponents listed in the rikishi details popup.
+pub const TOP_RIVALS: usize = 5;
+
+/// An opponent and the rikishi's record against them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rival {
+    pub opponent_id: u32,
+    /// Shikona in their latest bout together
+    pub shikona: String,
+    pub wins: u32,
+    pub losses: u32,
+    /// Every bout between them, including any not decided yet
+    pub bouts: u32,
+}
+
+/// The `limit` opponents `rikishi_id` has met most often in `matches` (their bout
+/// history), most bouts first; ties go to the better record for the rikishi.
+pub fn most_faced(rikishi_id: u32, matches: &[HeadToHeadMatch], limit: usize) -> Vec<Rival> {
+    let mut rivals: HashMap<u32, (Rival, &str)> = HashMap::new();
+    for m in matches {
+        let (opponent_id, shikona) = if m.east_id == rikishi_id {
+            (m.west_id, &m.west_shikona)
+        } else if m.west_id == rikishi_id {
+            (m.east_id, &m.east_shikona)
+        } else {
+            continue;
+        };
+        let (rival, latest) = rivals.entry(opponent_id).or_insert_with(|| {
+            let rival = Rival { opponent_id, shikona: shikona.clone(), wins: 0, losses: 0, bouts: 0 };
+            (rival, m.basho_id.as_str())
+        });
+        rival.bouts += 1;
+        match m.winner_id {
+            Some(winner) if winner == rikishi_id => rival.wins += 1,
+            Some(_) => rival.losses += 1,
+            None => {}
+        }
+        if m.basho_id.as_str() > *latest {
+            rival.shikona = shikona.clone();
+            *latest = m.basho_id.as_str();
+        }
+    }
+    let mut rivals: Vec<Rival> = rivals.into_values().map(|(rival, _)| rival).collect();
+    rivals.sort_by(|a, b| {
+        b.bouts
+            .cmp(&a.bouts)
+            .then((b.wins as i64 - b.losses as i64).cmp(&(a.wins as i64 - a.losses as i64)))
+            .then_with(|| a.shikona.cmp(&b.shikona))
+    });
+    rivals.truncate(limit);
+    rivals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::most_faced;
+    use crate::api::HeadToHeadMatch;
+
+    fn bout(basho_id: &str, east: (u32, &str), west: (u32, &str), winner: Option<u32>) -> HeadToHeadMatch {
+        HeadToHeadMatch {
+            basho_id: basho_id.to_string(),
+            division: "Makuuchi".to_string(),
+            day: 1,
+            match_no: 1,
+            east_id: east.0,
+            east_shikona: east.1.to_string(),
+            east_rank: String::new(),
+            west_id: west.0,
+            west_shikona: west.1.to_string(),
+            west_rank: String::new(),
+            kimarite: None,
+            winner_id: winner,
+            winner_en: None,
+            winner_jp: None,
+        }
+    }
+
+    #[test]
+    fn most_bouts_first_with_the_latest_shikona() {
+        let matches = [
+            bout("202301", (1, "Hoshoryu"), (2, "Kotoshoho"), Some(1)),
+            bout("202303", (2, "Kotoshoho"), (1, "Hoshoryu"), Some(2)),
+            bout("202411", (1, "Hoshoryu"), (2, "Kotozakura"), Some(1)),
+            bout("202409", (3, "Onosato"), (1, "Hoshoryu"), Some(3)),
+            bout("202501", (1, "Hoshoryu"), (3, "Onosato"), None),
+            bout("202501", (4, "Kirishima"), (1, "Hoshoryu"), Some(1)),
+            bout("202501", (4, "Kirishima"), (5, "Abi"), Some(5)),
+        ];
+        let rivals: Vec<(String, u32, u32, u32)> = most_faced(1, &matches, 2)
+            .into_iter()
+            .map(|r| (r.shikona, r.wins, r.losses, r.bouts))
+            .collect();
+        assert_eq!(rivals, [("Kotozakura".to_string(), 2, 1, 3), ("Onosato".to_string(), 0, 1, 2)]);
+    }
+}
@@ -0,0 +1,138 @@
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+/// How long vowels are written in romanized names and terms, as set by `romanization`
+/// in the config. The API spells everything the plain way.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Romanization {
+    /// Long vowels written as short ones: Ozeki, Hoshoryu
+    #[default]
+    Plain,
+    /// Hepburn with macrons: Ōzeki, Hōshōryū
+    Macrons,
+    /// Circumflexes, for fonts without macrons: Ôzeki, Hôshôryû
+    Circumflex,
+    /// Kana spelled out as typed: Oozeki, Houshouryuu
+    Wapuro,
+}
+
+static STYLE: OnceLock<Romanization> = OnceLock::new();
+
+/// Set the romanization for the rest of the process. Only the first call has an effect.
+pub fn set_style(style: Romanization) {
+    let _ = STYLE.set(style);
+}
+
+pub fn style() -> Romanization {
+    STYLE.get().copied().unwrap_or_default()
+}
+
+// Words with long vowels, spelled as typed in kana (wāpuro), which says both where the
+// long vowels are and whether an "o" is lengthened by "o" or "u". Words missing here
+// are shown as the API spells them.
+const LEXICON: &[&str] = &[
+    // Ranks, divisions and terms
+    "oozeki", "juuryou", "yuushou", "sanshou", "shukunshou", "kantoushou", "ginoushou", "dohyou",
+    // Basho and venues
+    "kyuushuu", "oosaka", "toukyou", "ryougoku",
+    // Shikona
+    "houshouryuu", "oonosato", "ouhou", "kotoshouhou", "daieishou", "endou", "shoudai", "takanoshou",
+    "hakuhou", "kakuryuu", "kotoshougiku", "goueidou", "shouhouzan", "hokutoufuji", "takakeishou",
+    "oonokatsu", "hakuouhou", "kinbouzan", "gounoyama", "chuuranoumi", "shounannoumi", "rouga",
+    "ooshouma", "ryuuden", "chiyoshouma", "tsurugishou", "mitoryuu", "houmashou",
+    "tokushouryuu", "kotoyuushou", "asashouryuu", "oonokuni",
+];
+
+// The long vowel after `c` in a wāpuro spelling, if `next` lengthens it
+fn long_vowel(c: char, next: Option<char>) -> Option<char> {
+    match (c, next) {
+        ('o', Some('o' | 'u')) => Some('ō'),
+        ('u', Some('u')) => Some('ū'),
+        ('a', Some('a')) => Some('ā'),
+        _ => None,
+    }
+}
+
+// Spell a lexicon word in `style`, lowercase
+fn spell(wapuro: &str, style: Romanization) -> String {
+    if style == Romanization::Wapuro {
+        return wapuro.to_string();
+    }
+    let mut out = String::with_capacity(wapuro.len() + 4);
+    let mut chars = wapuro.chars().peekable();
+    while let Some(c) = chars.next() {
+        match long_vowel(c, chars.peek().copied()) {
+            Some(long) => {
+                chars.next();
+                out.push(match (style, long) {
+                    (Romanization::Macrons, long) => long,
+                    (Romanization::Circumflex, 'ō') => 'ô',
+                    (Romanization::Circumflex, 'ū') => 'û',
+                    (Romanization::Circumflex, _) => 'â',
+                    _ => c,
+                });
+            }
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+// The lexicon word that `word` is the plain spelling of
+fn lookup(word: &str) -> Option<&'static str> {
+    LEXICON.iter().copied().find(|entry| spell(entry, Romanization::Plain).eq_ignore_ascii_case(word))
+}
+
+/// `text` with each known word respelled in `style`, keeping the capitalization of its
+/// first letter.
+pub fn convert(text: &str, style: Romanization) -> Cow<'_, str> {
+    if style == Romanization::Plain {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len() + 8);
+    let mut changed = false;
+    for word in text.split_inclusive(|c: char| !c.is_ascii_alphabetic()) {
+        let (letters, rest) = word.split_at(word.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(word.len()));
+        match lookup(letters) {
+            Some(entry) => {
+                let spelled = spell(entry, style);
+                let mut chars = spelled.chars();
+                if letters.starts_with(|c: char| c.is_ascii_uppercase())
+                    && let Some(first) = chars.next()
+                {
+                    out.extend(first.to_uppercase());
+                }
+                out.push_str(chars.as_str());
+                changed = true;
+            }
+            None => out.push_str(letters),
+        }
+        out.push_str(rest);
+    }
+    if changed { Cow::Owned(out) } else { Cow::Borrowed(text) }
+}
+
+/// `text` in the configured romanization; see [`convert`].
+pub fn romanize(text: &str) -> Cow<'_, str> {
+    convert(text, style())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert, Romanization};
+
+    #[test]
+    fn respells_known_words_only() {
+        let text = "Hoshoryu (Yokozuna 1 East) def. Onosato, Ozeki";
+        assert_eq!(convert(text, Romanization::Plain), text);
+        assert_eq!(convert(text, Romanization::Macrons), "Hōshōryū (Yokozuna 1 East) def. Ōnosato, Ōzeki");
+        assert_eq!(convert(text, Romanization::Circumflex), "Hôshôryû (Yokozuna 1 East) def. Ônosato, Ôzeki");
+        assert_eq!(convert(text, Romanization::Wapuro), "Houshouryuu (Yokozuna 1 East) def. Oonosato, Oozeki");
+        assert_eq!(convert("Juryo 3 West", Romanization::Macrons), "Jūryō 3 West");
+        assert_eq!(convert("Kyushu Basho - November 2025", Romanization::Macrons), "Kyūshū Basho - November 2025");
+        // Names the lexicon doesn't know are left alone
+        assert_eq!(convert("Hiradoumi", Romanization::Macrons), "Hiradoumi");
+    }
+}
@@ -0,0 +1,227 @@
+use crate::api::{BanzukeEntry, SumoApi, TorikumiEntry};
+use futures::stream::{self, StreamExt};
+use std::collections::HashMap;
+
+// Days of torikumi requested at once when they aren't cached
+const CONCURRENT_REQUESTS: usize = 4;
+
+/// The days each rikishi fought on at a basho, in order. Below Juryo the record lists
+/// bouts rather than days, and this maps each entry to its day.
+pub type BoutDays = HashMap<u32, Vec<u8>>;
+
+/// Bout days of `division`'s rikishi from its torikumi for every day of the basho. Days
+/// that can't be loaded are left out.
+pub async fn fetch_bout_days(api: &SumoApi, basho_id: &str, division: &str) -> BoutDays {
+    let torikumi: Vec<_> = stream::iter(1..=15u8)
+        .map(|day| api.get_torikumi(basho_id, division, day))
+        .buffered(CONCURRENT_REQUESTS)
+        .collect()
+        .await;
+    bout_days(torikumi.into_iter().flatten().filter_map(|response| response.torikumi).flatten())
+}
+
+// Group the days of `bouts` by rikishi
+fn bout_days(bouts: impl IntoIterator<Item = TorikumiEntry>) -> BoutDays {
+    let mut days = BoutDays::new();
+    for bout in bouts {
+        for id in [bout.east_id, bout.west_id] {
+            days.entry(id).or_default().push(bout.day);
+        }
+    }
+    for list in days.values_mut() {
+        list.sort_unstable();
+    }
+    days
+}
+
+/// How many of the bouts fought on `days` came by the end of `day`.
+pub fn bouts_through(days: &[u8], day: u8) -> u8 {
+    days.iter().filter(|d| **d <= day).count() as u8
+}
+
+/// One bout of a rikishi's basho, played or scheduled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleRow {
+    /// Day of the bout; below Juryo the record only gives the bout order, so this is
+    /// `None` for played bouts there
+    pub day: Option<u8>,
+    pub bout: usize,
+    pub opponent: String,
+    pub opponent_rank: Option<String>,
+    pub outcome: Outcome,
+    pub kimarite: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Win,
+    Loss,
+    FusenWin,
+    FusenLoss,
+    /// Result held back by spoiler-free or replay mode
+    Hidden,
+    /// On the loaded day's torikumi without a result yet
+    Scheduled,
+    Other(String),
+}
+
+/// The basho of `rikishi_id` so far: each result in their banzuke record, then their bout
+/// on the loaded day if it has no result yet. `days` is the basho length (15 for
+/// sekitori, whose record has one entry per day); `revealed` is how many entries may
+/// show results.
+pub fn rows(banzuke: &[BanzukeEntry], torikumi: &[TorikumiEntry], rikishi_id: u32, days: u8, revealed: u8) -> Vec<ScheduleRow> {
+    let Some(entry) = banzuke.iter().find(|e| e.rikishi_id == rikishi_id) else {
+        return Vec::new();
+    };
+    let rank_of = |shikona: &str| banzuke.iter().find(|e| &*e.shikona_en == shikona).map(|e| e.rank.clone());
+    let per_day = days == 15;
+
+    let mut rows: Vec<ScheduleRow> = entry
+        .record
+        .iter()
+        .flatten()
+        .take(days as usize)
+        .enumerate()
+        .filter(|(_, r)| !r.result.is_empty() && !r.opponent_shikona_en.is_empty())
+        .map(|(i, r)| {
+            let hidden = i >= revealed as usize;
+            let outcome = match r.result.as_str() {
+                _ if hidden => Outcome::Hidden,
+                "win" => Outcome::Win,
+                "loss" => Outcome::Loss,
+                "fusen-win" => Outcome::FusenWin,
+                "fusen-loss" => Outcome::FusenLoss,
+                other => Outcome::Other(other.to_string()),
+            };
+            ScheduleRow {
+                day: per_day.then_some(i as u8 + 1),
+                bout: 0,
+                opponent: r.opponent_shikona_en.clone(),
+                opponent_rank: rank_of(&r.opponent_shikona_en),
+                outcome,
+                kimarite: r.kimarite.clone().filter(|k| !hidden && !k.is_empty()),
+            }
+        })
+        .collect();
+
+    let upcoming = torikumi
+        .iter()
+        .filter(|b| b.winner_id.is_none())
+        .find(|b| b.east_id == rikishi_id || b.west_id == rikishi_id);
+    if let Some(bout) = upcoming {
+        let (opponent, rank) = if bout.east_id == rikishi_id {
+            (&bout.west_shikona, &bout.west_rank)
+        } else {
+            (&bout.east_shikona, &bout.east_rank)
+        };
+        rows.push(ScheduleRow {
+            day: Some(bout.day),
+            bout: 0,
+            opponent: opponent.to_string(),
+            opponent_rank: Some(rank.clone()),
+            outcome: Outcome::Scheduled,
+            kimarite: None,
+        });
+    }
+
+    for (i, row) in rows.iter_mut().enumerate() {
+        row.bout = i + 1;
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bout_days, bouts_through, rows, Outcome};
+    use crate::api::{BanzukeEntry, MatchRecord, TorikumiEntry};
+
+    fn result(result: &str, opponent: &str) -> MatchRecord {
+        MatchRecord {
+            result: result.to_string(),
+            opponent_shikona_en: opponent.to_string(),
+            opponent_shikona_jp: String::new(),
+            kimarite: Some("oshidashi".to_string()),
+        }
+    }
+
+    fn entry(id: u32, shikona: &str, rank: &str, record: Vec<MatchRecord>) -> BanzukeEntry {
+        BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: id,
+            shikona_en: shikona.into(),
+            shikona_jp: None,
+            rank_value: id,
+            rank: rank.to_string(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: Some(record),
+        }
+    }
+
+    fn bout(day: u8, east: (u32, &str, &str), west: (u32, &str, &str)) -> TorikumiEntry {
+        TorikumiEntry {
+            id: format!("202501-{}-{}", day, east.0),
+            basho_id: "202501".to_string(),
+            division: "Makushita".to_string(),
+            day,
+            match_no: 1,
+            east_id: east.0,
+            east_shikona: east.1.into(),
+            east_rank: east.2.to_string(),
+            west_id: west.0,
+            west_shikona: west.1.into(),
+            west_rank: west.2.to_string(),
+            kimarite: None,
+            winner_id: None,
+            winner_en: None,
+            winner_jp: None,
+        }
+    }
+
+    #[test]
+    fn played_hidden_and_scheduled_bouts() {
+        let mut record = vec![result("win", "Onosato"), result("loss", "Kotozakura"), result("win", "Wakatakakage")];
+        record.resize(15, result("", ""));
+        let banzuke = vec![
+            entry(1, "Hoshoryu", "Yokozuna 1 East", record),
+            entry(2, "Onosato", "Ozeki 1 West", Vec::new()),
+        ];
+        let bout = TorikumiEntry {
+            id: "202501-4-1".to_string(),
+            basho_id: "202501".to_string(),
+            division: "Makuuchi".to_string(),
+            day: 4,
+            match_no: 1,
+            east_id: 3,
+            east_shikona: "Takayasu".into(),
+            east_rank: "Maegashira 1 East".to_string(),
+            west_id: 1,
+            west_shikona: "Hoshoryu".into(),
+            west_rank: "Yokozuna 1 East".to_string(),
+            kimarite: None,
+            winner_id: None,
+            winner_en: None,
+            winner_jp: None,
+        };
+
+        let schedule = rows(&banzuke, &[bout], 1, 15, 2);
+        assert_eq!(schedule.len(), 4);
+        assert_eq!((schedule[0].day, schedule[0].opponent_rank.as_deref()), (Some(1), Some("Ozeki 1 West")));
+        assert_eq!(schedule[1].outcome, Outcome::Loss);
+        assert_eq!((schedule[2].outcome.clone(), schedule[2].kimarite.clone()), (Outcome::Hidden, None));
+        assert_eq!((schedule[3].day, schedule[3].outcome.clone()), (Some(4), Outcome::Scheduled));
+        assert_eq!(schedule[3].opponent, "Takayasu");
+    }
+
+    #[test]
+    fn maps_lower_division_bouts_to_days() {
+        let (a, b, c) = ((1, "Asa", "Makushita 1 East"), (2, "Hana", "Makushita 1 West"), (3, "Kaze", "Makushita 2 East"));
+        let days = bout_days([bout(3, a, b), bout(1, a, c), bout(6, c, b)]);
+        assert_eq!(days[&1], [1, 3]);
+        assert_eq!(days[&2], [3, 6]);
+        // Two of Asa's bouts are in by day 5, but only one of Hana's
+        assert_eq!((bouts_through(&days[&1], 5), bouts_through(&days[&2], 5)), (2, 1));
+        assert_eq!(bouts_through(&days[&3], 0), 0);
+    }
+}
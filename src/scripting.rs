@@ -0,0 +1,263 @@
+use crate::tui::App;
+use std::path::{Path, PathBuf};
+
+/// Directory scripts are loaded from, next to the config file.
+pub fn default_dir() -> Option<PathBuf> {
+    Some(
+        crate::config::Config::default_path()?
+            .parent()?
+            .join("scripts"),
+    )
+}
+
+#[cfg(feature = "scripting")]
+pub use engine::Scripts;
+
+#[cfg(feature = "scripting")]
+mod engine {
+    use super::{App, day_value};
+    use crate::api::TorikumiEntry;
+    use rhai::{AST, Dynamic, Engine, FnPtr, Scope};
+    use std::cell::RefCell;
+    use std::path::Path;
+    use std::rc::Rc;
+
+    // Keeps a runaway script from freezing the TUI
+    const MAX_OPERATIONS: u64 = 1_000_000;
+
+    struct Script {
+        name: String,
+        ast: AST,
+    }
+
+    /// Loaded Rhai scripts and the commands they registered. A script may define
+    /// `fn on_day_loaded(day)` and `fn on_result(bout)`, call `notify(message)` to show
+    /// a message in the footer, and add commands to the `:` prompt with
+    /// `register_command(name, |day| ...)`.
+    pub struct Scripts {
+        engine: Engine,
+        scripts: Vec<Script>,
+        // (command name, script index, function)
+        commands: Vec<(String, usize, FnPtr)>,
+        messages: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl Scripts {
+        /// Compile and run every `.rhai` file in `dir`, in name order. Scripts that fail
+        /// to load are skipped and reported in the returned messages.
+        pub fn load(dir: &Path) -> (Self, Vec<String>) {
+            let messages = Rc::new(RefCell::new(Vec::new()));
+            let registered = Rc::new(RefCell::new(Vec::new()));
+
+            let mut engine = Engine::new();
+            engine.set_max_operations(MAX_OPERATIONS);
+            let sink = messages.clone();
+            engine.register_fn("notify", move |message: &str| {
+                sink.borrow_mut().push(message.to_string())
+            });
+            // print() would write over the TUI, so it goes to the footer as well
+            let sink = messages.clone();
+            engine.on_print(move |message| sink.borrow_mut().push(message.to_string()));
+            engine.on_debug(|_, _, _| {});
+            let sink = registered.clone();
+            engine.register_fn("register_command", move |name: &str, function: FnPtr| {
+                sink.borrow_mut().push((name.to_string(), function));
+            });
+
+            let mut scripts = Self {
+                engine,
+                scripts: Vec::new(),
+                commands: Vec::new(),
+                messages,
+            };
+            let mut errors = Vec::new();
+            let mut paths: Vec<_> = std::fs::read_dir(dir)
+                .map(|entries| entries.filter_map(|e| Some(e.ok()?.path())).collect())
+                .unwrap_or_default();
+            paths.retain(|p| p.extension().is_some_and(|ext| ext == "rhai"));
+            paths.sort();
+
+            for path in paths {
+                let name = path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned();
+                let loaded = scripts
+                    .engine
+                    .compile_file(path.clone())
+                    .and_then(|ast| scripts.engine.run_ast(&ast).map(|_| ast));
+                match loaded {
+                    Ok(ast) => {
+                        let index = scripts.scripts.len();
+                        let commands = std::mem::take(&mut *registered.borrow_mut());
+                        scripts
+                            .commands
+                            .extend(commands.into_iter().map(|(command, f)| (command, index, f)));
+                        scripts.scripts.push(Script { name, ast });
+                    }
+                    Err(e) => {
+                        registered.borrow_mut().clear();
+                        errors.push(format!("{}: {}", name, e));
+                    }
+                }
+            }
+            errors.extend(scripts.take_messages());
+            (scripts, errors)
+        }
+
+        /// Names of the commands scripts registered, in load order.
+        pub fn command_names(&self) -> Vec<String> {
+            self.commands
+                .iter()
+                .map(|(name, _, _)| name.clone())
+                .collect()
+        }
+
+        /// Run `on_day_loaded(day)` in every script that defines it.
+        pub fn on_day_loaded(&mut self, app: &App) -> Vec<String> {
+            let day = day_value(app);
+            self.call_hook("on_day_loaded", &day)
+        }
+
+        /// Run `on_result(bout)` for each bout of the loaded day that has a winner now
+        /// but had none in `previous`.
+        pub fn on_results(&mut self, app: &App, previous: &[TorikumiEntry]) -> Vec<String> {
+            let current = app.torikumi.as_deref().unwrap_or_default();
+            let mut messages = Vec::new();
+            for bout in crate::live::new_results(previous, current) {
+                if let Ok(bout) = rhai::serde::to_dynamic(bout) {
+                    messages.extend(self.call_hook("on_result", &bout));
+                }
+            }
+            messages
+        }
+
+        /// Run a registered command with the loaded day.
+        pub fn run_command(&mut self, name: &str, app: &App) -> Vec<String> {
+            let day = day_value(app);
+            let Some((_, index, function)) = self.commands.iter().find(|(n, _, _)| n == name)
+            else {
+                return Vec::new();
+            };
+            let script = &self.scripts[*index];
+            let result = function.call::<Dynamic>(&self.engine, &script.ast, (day,));
+            let mut messages = self.take_messages();
+            if let Err(e) = result {
+                messages.push(format!("{}: {}", script.name, e));
+            }
+            messages
+        }
+
+        fn call_hook(&mut self, hook: &str, argument: &Dynamic) -> Vec<String> {
+            let mut errors = Vec::new();
+            for script in &self.scripts {
+                let defined = script
+                    .ast
+                    .iter_functions()
+                    .any(|f| f.name == hook && f.params.len() == 1);
+                if !defined {
+                    continue;
+                }
+                let result = self.engine.call_fn::<Dynamic>(
+                    &mut Scope::new(),
+                    &script.ast,
+                    hook,
+                    (argument.clone(),),
+                );
+                if let Err(e) = result {
+                    errors.push(format!("{}: {}", script.name, e));
+                }
+            }
+            let mut messages = self.take_messages();
+            messages.extend(errors);
+            messages
+        }
+
+        fn take_messages(&self) -> Vec<String> {
+            std::mem::take(&mut *self.messages.borrow_mut())
+        }
+    }
+}
+
+/// Stand-in used when the `scripting` feature is disabled: nothing is loaded.
+#[cfg(not(feature = "scripting"))]
+pub struct Scripts;
+
+#[cfg(not(feature = "scripting"))]
+impl Scripts {
+    pub fn load(_dir: &Path) -> (Self, Vec<String>) {
+        (Self, Vec::new())
+    }
+
+    pub fn command_names(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    pub fn on_day_loaded(&mut self, _app: &App) -> Vec<String> {
+        Vec::new()
+    }
+
+    pub fn on_results(
+        &mut self,
+        _app: &App,
+        _previous: &[crate::api::TorikumiEntry],
+    ) -> Vec<String> {
+        Vec::new()
+    }
+
+    pub fn run_command(&mut self, _name: &str, _app: &App) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl Scripts {
+    /// Scripts from the default directory; a missing directory loads nothing.
+    pub fn load_default() -> (Self, Vec<String>) {
+        match default_dir() {
+            Some(dir) => Self::load(&dir),
+            None => Self::load(Path::new("")),
+        }
+    }
+}
+
+// The day passed to hooks and commands: basho, division, day number and its bouts
+#[cfg(feature = "scripting")]
+fn day_value(app: &App) -> rhai::Dynamic {
+    let day = serde_json::json!({
+        "basho_id": app.basho_id,
+        "division": app.division,
+        "day": app.day,
+        "torikumi": app.torikumi.clone().unwrap_or_default(),
+    });
+    rhai::serde::to_dynamic(day).unwrap_or_default()
+}
+
+#[cfg(all(test, feature = "scripting"))]
+mod tests {
+    use super::Scripts;
+    use crate::tui::App;
+
+    #[test]
+    fn hooks_and_commands_report_messages() {
+        let dir = std::env::temp_dir().join(format!("sumo-scripts-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("greet.rhai"),
+            "fn on_day_loaded(day) { notify(`day ${day.day} of ${day.basho_id}`); }\n\
+             register_command(\"count\", |day| print(`${day.torikumi.len()} bouts`));\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("broken.rhai"), "fn (").unwrap();
+
+        let (mut scripts, errors) = Scripts::load(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("broken.rhai"));
+        assert_eq!(scripts.command_names(), vec!["count"]);
+
+        let app = App::new("202509".to_string(), "Makuuchi".to_string(), 3);
+        assert_eq!(scripts.on_day_loaded(&app), vec!["day 3 of 202509"]);
+        assert_eq!(scripts.run_command("count", &app), vec!["0 bouts"]);
+    }
+}
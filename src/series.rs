@@ -0,0 +1,73 @@
+use crate::api::{HeadToHeadResponse, SumoApi, TorikumiEntry};
+use futures::stream::{self, StreamExt};
+use std::collections::HashMap;
+
+// Head-to-head lookups made at once when they aren't cached
+const CONCURRENT_REQUESTS: usize = 4;
+
+/// Career series of each bout on a day going into it, as (east wins, west wins), by bout ID.
+pub type Series = HashMap<String, (u32, u32)>;
+
+/// The career series between `bout`'s rikishi before it: their head-to-head totals less
+/// the bouts from its day of the basho onwards, so a result already in never shows.
+pub fn before(bout: &TorikumiEntry, h2h: &HeadToHeadResponse) -> (u32, u32) {
+    let (mut east, mut west) = (h2h.rikishi_wins, h2h.opponent_wins);
+    for m in &h2h.matches {
+        if (m.basho_id.as_str(), m.day) < (bout.basho_id.as_str(), bout.day) {
+            continue;
+        }
+        match m.won_by(bout.east_id) {
+            Some(true) => east = east.saturating_sub(1),
+            Some(false) => west = west.saturating_sub(1),
+            None => {}
+        }
+    }
+    (east, west)
+}
+
+/// Look up the series of every bout in `torikumi`. Bouts whose head-to-head can't be
+/// loaded are left out.
+pub async fn fetch(api: &SumoApi, torikumi: Vec<TorikumiEntry>) -> Series {
+    stream::iter(torikumi)
+        .map(|bout| async move {
+            let h2h = api.get_head_to_head(bout.east_id, bout.west_id).await;
+            h2h.ok().map(|h2h| (bout.id.clone(), before(&bout, &h2h)))
+        })
+        .buffered(CONCURRENT_REQUESTS)
+        .filter_map(|series| async move { series })
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::before;
+    use crate::api::{HeadToHeadResponse, TorikumiEntry};
+    use serde_json::json;
+
+    #[test]
+    fn series_leaves_out_the_day_and_later() {
+        let bout: TorikumiEntry = serde_json::from_value(json!({
+            "id": "202509-5-1", "bashoId": "202509", "division": "Makuuchi", "day": 5, "matchNo": 1,
+            "eastId": 1, "eastShikona": "Hoshoryu", "eastRank": "Yokozuna 1 East",
+            "westId": 2, "westShikona": "Onosato", "westRank": "Ozeki 1 West",
+            "kimarite": "yorikiri", "winnerId": 2, "winnerEn": "Onosato", "winnerJp": null,
+        }))
+        .unwrap();
+        let meeting = |basho: &str, day: u8, winner: u32| {
+            json!({
+                "bashoId": basho, "division": "Makuuchi", "day": day, "matchNo": 1,
+                "eastId": 1, "eastShikona": "Hoshoryu", "eastRank": "Yokozuna 1 East",
+                "westId": 2, "westShikona": "Onosato", "westRank": "Ozeki 1 West",
+                "kimarite": "yorikiri", "winnerId": winner, "winnerEn": null, "winnerJp": null,
+            })
+        };
+        let h2h: HeadToHeadResponse = serde_json::from_value(json!({
+            "kimariteLosses": null, "kimariteWins": null,
+            "matches": [meeting("202511", 3, 1), meeting("202509", 5, 2), meeting("202507", 14, 1), meeting("202505", 13, 2)],
+            "opponentWins": 2, "rikishiWins": 2, "total": 4,
+        }))
+        .unwrap();
+        assert_eq!(before(&bout, &h2h), (1, 1));
+    }
+}
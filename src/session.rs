@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use crate::tui::{App, AppView};
+
+/// What was on screen when the TUI last exited, restored with `--resume`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Session {
+    pub basho_id: String,
+    pub division: String,
+    pub day: u8,
+    pub view: String,
+}
+
+impl Session {
+    pub fn from_app(app: &App) -> Self {
+        let view = match app.current_view {
+            AppView::Torikumi => "torikumi",
+            AppView::Banzuke => "banzuke",
+            AppView::BashoInfo => "info",
+            // The marked rikishi aren't saved, so there is nothing to compare on resume
+            AppView::Compare => "torikumi",
+            AppView::Stats => "stats",
+            AppView::Records => "records",
+            AppView::Journal => "journal",
+            AppView::Dashboard => "dashboard",
+            AppView::Matchups => "matchups",
+            AppView::YushoHistory => "yusho_history",
+            AppView::Settings => "settings",
+            AppView::Schedule => "banzuke",
+        };
+        Self {
+            basho_id: app.basho_id.clone(),
+            division: app.division.clone(),
+            day: app.day,
+            view: view.to_string(),
+        }
+    }
+
+    pub fn view(&self) -> AppView {
+        match self.view.as_str() {
+            "banzuke" => AppView::Banzuke,
+            "info" => AppView::BashoInfo,
+            "stats" => AppView::Stats,
+            "records" => AppView::Records,
+            "journal" => AppView::Journal,
+            "dashboard" => AppView::Dashboard,
+            "matchups" => AppView::Matchups,
+            "yusho_history" => AppView::YushoHistory,
+            "settings" => AppView::Settings,
+            _ => AppView::Torikumi,
+        }
+    }
+
+    /// `session.toml` in the platform state directory (see [`crate::paths::state_dir`]).
+    pub fn default_path() -> Option<PathBuf> {
+        Some(crate::paths::state_dir()?.join("session.toml"))
+    }
+
+    pub fn load(path: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        toml::from_str(&contents).ok()
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Session;
+    use crate::tui::AppView;
+
+    #[test]
+    fn round_trips_through_toml() {
+        let session = Session {
+            basho_id: "202509".to_string(),
+            division: "Juryo".to_string(),
+            day: 12,
+            view: "banzuke".to_string(),
+        };
+        let parsed: Session = toml::from_str(&toml::to_string(&session).unwrap()).unwrap();
+        assert_eq!(parsed, session);
+        assert_eq!(parsed.view(), AppView::Banzuke);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::config::{Config, Units};
+use crate::keymap::{Action, HELP_SECTIONS};
+use crate::theme::ThemeName;
+use std::path::Path;
+
+/// Live refresh intervals the settings view steps through, in seconds.
+pub const REFRESH_INTERVALS: &[u64] = &[15, 30, 60, 120, 300, 600];
+
+/// One line of the settings view.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Setting {
+    Theme,
+    Units,
+    RefreshInterval,
+    Division,
+    Key(Action),
+}
+
+impl Setting {
+    /// Every setting in display order: the general ones, then a key binding per action
+    /// in help order.
+    pub fn all() -> Vec<Setting> {
+        let general = [Setting::Theme, Setting::Units, Setting::RefreshInterval, Setting::Division];
+        let keys = HELP_SECTIONS.iter().flat_map(|(_, actions)| actions.iter()).map(|action| Setting::Key(*action));
+        general.into_iter().chain(keys).collect()
+    }
+
+    /// Section of the config file the setting lives in (`None` for the top level) and
+    /// its key there.
+    pub fn location(self) -> (Option<&'static str>, String) {
+        match self {
+            Setting::Theme => (None, "theme".to_string()),
+            Setting::Units => (None, "units".to_string()),
+            Setting::RefreshInterval => (Some("refresh"), "interval_secs".to_string()),
+            Setting::Division => (None, "division".to_string()),
+            Setting::Key(action) => (Some("keys"), action.config_name()),
+        }
+    }
+}
+
+pub fn theme_value(theme: ThemeName) -> &'static str {
+    match theme {
+        ThemeName::Default => "default",
+        ThemeName::HighContrast => "high_contrast",
+        ThemeName::Monochrome => "monochrome",
+    }
+}
+
+pub fn units_value(units: Units) -> &'static str {
+    match units {
+        Units::Metric => "metric",
+        Units::Imperial => "imperial",
+    }
+}
+
+/// The interval after `secs` in [`REFRESH_INTERVALS`], wrapping round.
+pub fn next_interval(secs: u64) -> u64 {
+    REFRESH_INTERVALS.iter().copied().find(|s| *s > secs).unwrap_or(REFRESH_INTERVALS[0])
+}
+
+// A section header line such as "[refresh]"
+fn is_header(line: &str) -> bool {
+    line.trim_start().starts_with('[')
+}
+
+// Whether `line` assigns `key`
+fn assigns(line: &str, key: &str) -> bool {
+    !line.trim_start().starts_with('#') && line.split_once('=').is_some_and(|(k, _)| k.trim() == key)
+}
+
+/// `contents` of a config file with `key` set to `value` (already written as TOML) in
+/// `section`, or at the top level before any section. Every other line, comments
+/// included, is kept.
+pub fn set_value(contents: &str, section: Option<&str>, key: &str, value: &str) -> String {
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    let assignment = format!("{} = {}", key, value);
+    let (start, end) = match section {
+        None => (0, lines.iter().position(|l| is_header(l)).unwrap_or(lines.len())),
+        Some(name) => {
+            let header = format!("[{}]", name);
+            match lines.iter().position(|l| l.split('#').next().is_some_and(|l| l.trim() == header)) {
+                Some(i) => (i + 1, lines[i + 1..].iter().position(|l| is_header(l)).map_or(lines.len(), |p| i + 1 + p)),
+                None => {
+                    if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                        lines.push(String::new());
+                    }
+                    lines.extend([header, assignment]);
+                    return lines.join("\n") + "\n";
+                }
+            }
+        }
+    };
+
+    match lines[start..end].iter().position(|l| assigns(l, key)) {
+        Some(i) => {
+            let at = start + i;
+            // An array spread over several lines goes with its key
+            let old = lines[at].split_once('=').map_or("", |(_, v)| v.trim());
+            if old.starts_with('[') && !old.contains(']')
+                && let Some(close) = lines[at + 1..end].iter().position(|l| l.contains(']'))
+            {
+                lines.drain(at + 1..=at + 1 + close);
+            }
+            lines[at] = assignment;
+        }
+        None => {
+            // After the last setting, before any blank lines leading to the next section
+            let mut at = end;
+            while at > start && lines[at - 1].trim().is_empty() {
+                at -= 1;
+            }
+            lines.insert(at, assignment);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Write one setting into the config file at `path`, creating the file if needed. The
+/// file is left alone if the change would stop it from loading.
+pub fn save(path: &Path, section: Option<&str>, key: &str, value: &str) -> anyhow::Result<()> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => anyhow::bail!("could not read {}: {}", path.display(), e),
+    };
+    let updated = set_value(&contents, section, key, value);
+    toml::from_str::<Config>(&updated).map_err(|e| anyhow::anyhow!("{} would no longer load: {}", path.display(), e))?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("part");
+    std::fs::write(&partial, updated)?;
+    std::fs::rename(&partial, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::set_value;
+
+    #[test]
+    fn sets_values_keeping_the_rest_of_the_file() {
+        let file = "# my settings\ntheme = \"monochrome\"  \nfavorites = [\"Onosato\"]\n\n[refresh]\nenabled = true\n\n[keys]\nquit = [\n  \"q\",\n  \"Q\",\n]\n";
+        let file = set_value(file, None, "theme", "\"default\"");
+        let file = set_value(&file, None, "units", "\"imperial\"");
+        let file = set_value(&file, Some("refresh"), "interval_secs", "30");
+        let file = set_value(&file, Some("keys"), "quit", "[\"x\"]");
+        let file = set_value(&file, Some("hooks"), "on_result", "\"true\"");
+        assert_eq!(
+            file,
+            "# my settings\ntheme = \"default\"\nfavorites = [\"Onosato\"]\nunits = \"imperial\"\n\n[refresh]\nenabled = true\ninterval_secs = 30\n\n[keys]\nquit = [\"x\"]\n\n[hooks]\non_result = \"true\"\n"
+        );
+        assert_eq!(set_value("", None, "division", "\"juryo\""), "division = \"juryo\"\n");
+    }
+}
@@ -0,0 +1,238 @@
+use crate::api::{BanzukeEntry, SumoApi};
+use crate::tui::App;
+use crate::rank::{Rank, Tier};
+use crate::romaji::romanize;
+use crate::text::{display_width, pad_to_width};
+
+/// One line of a traditional banzuke: the rank in the middle, its East holder on the
+/// left and its West holder on the right.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SheetRow {
+    pub rank: String,
+    pub sanyaku: bool,
+    pub east: Option<String>,
+    pub west: Option<String>,
+}
+
+/// Pair the banzuke East and West by rank, highest first. Each side of a rank holds one
+/// rikishi; an extra East or West at the same rank (e.g. a third Ozeki) gets its own row.
+pub fn rows(banzuke: &[BanzukeEntry]) -> Vec<SheetRow> {
+    let mut entries: Vec<&BanzukeEntry> = banzuke.iter().collect();
+    entries.sort_by_key(|e| e.rank_value);
+
+    let mut rows: Vec<SheetRow> = Vec::new();
+    for entry in entries {
+        let rank = rank_without_side(&entry.rank);
+        let east = !entry.side.eq_ignore_ascii_case("west");
+        let free = rows
+            .iter_mut()
+            .find(|r| r.rank == romanize(rank) && if east { r.east.is_none() } else { r.west.is_none() });
+        let row = match free {
+            Some(row) => row,
+            None => {
+                let sanyaku = Rank::parse(&entry.rank).is_some_and(|r| r.tier < Tier::Maegashira);
+                rows.push(SheetRow { rank: romanize(rank).into_owned(), sanyaku, east: None, west: None });
+                rows.last_mut().unwrap()
+            }
+        };
+        let name = Some(romanize(&entry.shikona_en).into_owned());
+        if east {
+            row.east = name;
+        } else {
+            row.west = name;
+        }
+    }
+    rows
+}
+
+/// "Maegashira 7 East" -> "Maegashira 7"
+fn rank_without_side(rank: &str) -> &str {
+    rank.trim().trim_end_matches(" East").trim_end_matches(" West")
+}
+
+/// Render the banzuke as aligned monospace text, East | rank | West, with a rule under
+/// the sanyaku.
+pub fn render_text(basho_id: &str, division: &str, banzuke: &[BanzukeEntry]) -> String {
+    let rows = rows(banzuke);
+    let side_width = rows
+        .iter()
+        .flat_map(|r| [r.east.as_deref(), r.west.as_deref()])
+        .map(|name| display_width(name.unwrap_or("")))
+        .max()
+        .unwrap_or(0)
+        .max("East".len());
+    let rank_width = rows.iter().map(|r| display_width(&r.rank)).max().unwrap_or(0).max("Rank".len());
+
+    let line = |east: &str, rank: &str, west: &str| {
+        let east_pad = side_width.saturating_sub(display_width(east));
+        let rank_pad = rank_width.saturating_sub(display_width(rank));
+        let centred = format!("{}{}", " ".repeat(rank_pad / 2), rank);
+        format!("{}{} | {} | {}", " ".repeat(east_pad), east, pad_to_width(&centred, rank_width), west)
+            .trim_end()
+            .to_string()
+    };
+    let rule = format!("{}-+-{}-+-{}", "-".repeat(side_width), "-".repeat(rank_width), "-".repeat(side_width));
+
+    let month: u32 = basho_id.get(4..6).and_then(|m| m.parse().ok()).unwrap_or(0);
+    let mut out = format!(
+        "{} - {} {} Banzuke\n\n",
+        romanize(SumoApi::get_basho_name(month)),
+        SumoApi::format_basho_date(basho_id),
+        romanize(division)
+    );
+    out.push_str(&line("East", "Rank", "West"));
+    out.push('\n');
+    out.push_str(&rule);
+    out.push('\n');
+    let mut in_sanyaku = rows.first().is_some_and(|r| r.sanyaku);
+    for row in &rows {
+        if in_sanyaku && !row.sanyaku {
+            out.push_str(&rule);
+            out.push('\n');
+            in_sanyaku = false;
+        }
+        out.push_str(&line(row.east.as_deref().unwrap_or(""), &row.rank, row.west.as_deref().unwrap_or("")));
+        out.push('\n');
+    }
+    out
+}
+
+/// Render the loaded day's torikumi as aligned plain text, one bout per line with the
+/// East side right-aligned against the West and ○/● marking the result. Each line
+/// carries both names, so the output can be filtered with `grep`.
+pub fn render_day_text(app: &App) -> String {
+    let torikumi = app.torikumi.as_deref().unwrap_or_default();
+    let banzuke = app.banzuke.as_deref().unwrap_or_default();
+    let side = |id: u32, shikona: &str, rank: &str| {
+        let entry = banzuke.iter().find(|e| e.rikishi_id == id);
+        let (wins, losses) = entry.map_or((0, 0), |e| e.wins_losses(app.revealed_for(e)));
+        format!("{} ({}, {}-{})", romanize(shikona), romanize(rank), wins, losses)
+    };
+    // Replay and spoiler-free mode leave hidden results unmarked
+    let mark = |bout: &crate::api::TorikumiEntry, id: u32| match bout.winner_id {
+        _ if app.bout_hidden(bout) => " ",
+        Some(winner) if winner == id => "○",
+        Some(_) => "●",
+        None => " ",
+    };
+    let lines: Vec<(String, String, String)> = torikumi
+        .iter()
+        .map(|bout| {
+            (
+                format!("{} {}", side(bout.east_id, &bout.east_shikona, &bout.east_rank), mark(bout, bout.east_id)),
+                format!("{} {}", mark(bout, bout.west_id), side(bout.west_id, &bout.west_shikona, &bout.west_rank)),
+                if app.bout_hidden(bout) { String::new() } else { bout.kimarite.clone().unwrap_or_default() },
+            )
+        })
+        .collect();
+    let east_width = lines.iter().map(|(east, _, _)| display_width(east)).max().unwrap_or(0);
+    let west_width = lines.iter().map(|(_, west, _)| display_width(west)).max().unwrap_or(0);
+
+    let month: u32 = app.basho_id.get(4..6).and_then(|m| m.parse().ok()).unwrap_or(0);
+    let mut out = format!(
+        "{} - {} {} Day {}\n\n",
+        romanize(SumoApi::get_basho_name(month)),
+        SumoApi::format_basho_date(&app.basho_id),
+        romanize(&app.division),
+        app.day
+    );
+    if lines.is_empty() {
+        out.push_str("No matches available for the selected day.\n");
+    }
+    for (east, west, kimarite) in &lines {
+        let line = format!(
+            "{}{} | {} | {}",
+            " ".repeat(east_width.saturating_sub(display_width(east))),
+            east,
+            pad_to_width(west, west_width),
+            kimarite
+        );
+        out.push_str(line.trim_end().trim_end_matches('|').trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_day_text, render_text, rows, SheetRow};
+    use crate::api::BanzukeEntry;
+    use crate::tui::App;
+
+    fn entry(rank_value: u32, shikona: &str, rank: &str, side: &str) -> BanzukeEntry {
+        BanzukeEntry {
+            side: side.to_string(),
+            rikishi_id: rank_value,
+            shikona_en: shikona.into(),
+            shikona_jp: None,
+            rank_value,
+            rank: rank.to_string(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: None,
+        }
+    }
+
+    #[test]
+    fn pairs_sides_by_rank_with_sanyaku_on_top() {
+        let banzuke = vec![
+            entry(201, "Takayasu", "Maegashira 1 East", "East"),
+            entry(101, "Hoshoryu", "Yokozuna 1 East", "East"),
+            entry(111, "Onosato", "Ozeki 1 East", "East"),
+            entry(112, "Kotozakura", "Ozeki 1 West", "West"),
+            entry(113, "Kirishima", "Ozeki 2 East", "East"),
+            entry(202, "Oho", "Maegashira 1 West", "West"),
+        ];
+        let sheet = rows(&banzuke);
+        let row = |rank: &str, sanyaku, east: Option<&str>, west: Option<&str>| SheetRow {
+            rank: rank.to_string(),
+            sanyaku,
+            east: east.map(str::to_string),
+            west: west.map(str::to_string),
+        };
+        assert_eq!(
+            sheet,
+            vec![
+                row("Yokozuna 1", true, Some("Hoshoryu"), None),
+                row("Ozeki 1", true, Some("Onosato"), Some("Kotozakura")),
+                row("Ozeki 2", true, Some("Kirishima"), None),
+                row("Maegashira 1", false, Some("Takayasu"), Some("Oho")),
+            ]
+        );
+
+        let text = render_text("202501", "Makuuchi", &banzuke);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "Hatsu Basho - January 2025 Makuuchi Banzuke");
+        assert_eq!(lines[2], "      East |     Rank     | West");
+        assert_eq!(lines[5], "   Onosato |   Ozeki 1    | Kotozakura");
+        assert_eq!(lines[7], "-----------+--------------+-----------");
+        assert_eq!(lines[8], "  Takayasu | Maegashira 1 | Oho");
+    }
+
+    #[test]
+    fn day_text_has_one_line_per_bout() {
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        let bout = |no: u8, east: &str, west: &str, winner: Option<u32>, kimarite: Option<&str>| {
+            serde_json::from_value(serde_json::json!({
+                "id": format!("202501-3-{}", no), "bashoId": "202501", "division": "Makuuchi", "day": 3, "matchNo": no,
+                "eastId": no * 2, "eastShikona": east, "eastRank": "Maegashira 1 East",
+                "westId": no * 2 + 1, "westShikona": west, "westRank": "Maegashira 1 West",
+                "kimarite": kimarite, "winnerId": winner, "winnerEn": null, "winnerJp": null,
+            }))
+            .unwrap()
+        };
+        app.set_torikumi(vec![bout(1, "Oho", "Endo", Some(3), Some("yorikiri")), bout(2, "Tamawashi", "Ura", None, None)]);
+
+        let text = render_day_text(&app);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "Hatsu Basho - January 2025 Makuuchi Day 3");
+        assert_eq!(lines[2], "      Oho (Maegashira 1 East, 0-0) ● | ○ Endo (Maegashira 1 West, 0-0) | yorikiri");
+        assert_eq!(lines[3], "Tamawashi (Maegashira 1 East, 0-0)   |   Ura (Maegashira 1 West, 0-0)");
+
+        // Spoiler-free mode leaves the result out
+        app.set_spoiler_from(Some(3));
+        let text = render_day_text(&app);
+        assert_eq!(text.lines().nth(2), Some("      Oho (Maegashira 1 East, 0-0)   |   Endo (Maegashira 1 West, 0-0)"));
+    }
+}
@@ -0,0 +1,64 @@
+use std::io;
+
+/// Process signals the event loop reacts to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalEvent {
+    /// SIGINT, SIGTERM or SIGHUP: shut down cleanly
+    Shutdown,
+    /// SIGTSTP: restore the terminal and stop the process
+    Suspend,
+    /// SIGCONT: the process was continued after being stopped from outside
+    Resume,
+}
+
+/// Signal listeners registered for the lifetime of the TUI.
+pub struct Signals {
+    #[cfg(unix)]
+    interrupt: tokio::signal::unix::Signal,
+    #[cfg(unix)]
+    terminate: tokio::signal::unix::Signal,
+    #[cfg(unix)]
+    hangup: tokio::signal::unix::Signal,
+    #[cfg(unix)]
+    suspend: tokio::signal::unix::Signal,
+    #[cfg(unix)]
+    resume: tokio::signal::unix::Signal,
+}
+
+impl Signals {
+    #[cfg(unix)]
+    pub fn new() -> io::Result<Self> {
+        use tokio::signal::unix::{signal, SignalKind};
+        Ok(Self {
+            interrupt: signal(SignalKind::interrupt())?,
+            terminate: signal(SignalKind::terminate())?,
+            hangup: signal(SignalKind::hangup())?,
+            // SIGTSTP's default action would stop us with the terminal still in raw mode
+            suspend: signal(SignalKind::from_raw(libc::SIGTSTP))?,
+            resume: signal(SignalKind::from_raw(libc::SIGCONT))?,
+        })
+    }
+
+    #[cfg(not(unix))]
+    pub fn new() -> io::Result<Self> {
+        Ok(Self {})
+    }
+
+    /// Wait for the next signal.
+    #[cfg(unix)]
+    pub async fn recv(&mut self) -> SignalEvent {
+        tokio::select! {
+            _ = self.interrupt.recv() => SignalEvent::Shutdown,
+            _ = self.terminate.recv() => SignalEvent::Shutdown,
+            _ = self.hangup.recv() => SignalEvent::Shutdown,
+            _ = self.suspend.recv() => SignalEvent::Suspend,
+            _ = self.resume.recv() => SignalEvent::Resume,
+        }
+    }
+
+    #[cfg(not(unix))]
+    pub async fn recv(&mut self) -> SignalEvent {
+        let _ = tokio::signal::ctrl_c().await;
+        SignalEvent::Shutdown
+    }
+}
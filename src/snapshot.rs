@@ -0,0 +1,145 @@
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use crate::text::display_width;
+use std::path::{Path, PathBuf};
+
+/// Write the frame buffer as both an ANSI-colored `.ans` file and a plain `.txt` file.
+/// Returns the path of the `.ans` file.
+pub fn save(buffer: &Buffer, dir: &Path, stem: &str) -> std::io::Result<PathBuf> {
+    let ans_path = dir.join(format!("{}.ans", stem));
+    let txt_path = dir.join(format!("{}.txt", stem));
+    std::fs::write(&ans_path, buffer_to_ansi(buffer))?;
+    std::fs::write(&txt_path, buffer_to_text(buffer))?;
+    Ok(ans_path)
+}
+
+/// Render the buffer as text with SGR escape sequences preserving colors and modifiers.
+pub fn buffer_to_ansi(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    let area = buffer.area;
+    for y in area.top()..area.bottom() {
+        let mut current: Option<(Color, Color, Modifier)> = None;
+        let mut skip = 0;
+        for x in area.left()..area.right() {
+            let cell = &buffer[(x, y)];
+            // Wide characters occupy more than one cell; the trailing cells are placeholders
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let style = (cell.fg, cell.bg, cell.modifier);
+            if current != Some(style) {
+                out.push_str(&sgr(cell.fg, cell.bg, cell.modifier));
+                current = Some(style);
+            }
+            out.push_str(cell.symbol());
+            skip = display_width(cell.symbol()).saturating_sub(1);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+/// Render the buffer as plain text with trailing whitespace trimmed from each line.
+pub fn buffer_to_text(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    let area = buffer.area;
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        let mut skip = 0;
+        for x in area.left()..area.right() {
+            let cell = &buffer[(x, y)];
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            line.push_str(cell.symbol());
+            skip = display_width(cell.symbol()).saturating_sub(1);
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+// Build a full SGR sequence (reset first) for the given colors and modifiers
+fn sgr(fg: Color, bg: Color, modifier: Modifier) -> String {
+    let mut codes = vec!["0".to_string()];
+    let modifier_codes = [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ];
+    for (m, code) in modifier_codes {
+        if modifier.contains(m) {
+            codes.push(code.to_string());
+        }
+    }
+    if let Some(code) = color_code(fg, false) {
+        codes.push(code);
+    }
+    if let Some(code) = color_code(bg, true) {
+        codes.push(code);
+    }
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let base = if background { 40 } else { 30 };
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => base,
+        Color::Red => base + 1,
+        Color::Green => base + 2,
+        Color::Yellow => base + 3,
+        Color::Blue => base + 4,
+        Color::Magenta => base + 5,
+        Color::Cyan => base + 6,
+        Color::Gray => base + 7,
+        Color::DarkGray => base + 60,
+        Color::LightRed => base + 61,
+        Color::LightGreen => base + 62,
+        Color::LightYellow => base + 63,
+        Color::LightBlue => base + 64,
+        Color::LightMagenta => base + 65,
+        Color::LightCyan => base + 66,
+        Color::White => base + 67,
+        Color::Indexed(i) => return Some(format!("{};5;{}", base + 8, i)),
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{};{};{}", base + 8, r, g, b)),
+    };
+    Some(code.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{buffer_to_ansi, buffer_to_text};
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::style::{Color, Style};
+
+    #[test]
+    fn text_trims_trailing_space() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        buf.set_string(0, 0, "Hakuho", Style::default());
+        assert_eq!(buffer_to_text(&buf), "Hakuho\n\n");
+    }
+
+    #[test]
+    fn ansi_preserves_colors() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buf.set_string(0, 0, "W", Style::default().fg(Color::Black).bg(Color::Green));
+        let ansi = buffer_to_ansi(&buf);
+        assert!(ansi.starts_with("\x1b[0;30;42mW\x1b[0m   "));
+        assert!(ansi.ends_with("\x1b[0m\n"));
+    }
+
+    #[test]
+    fn wide_characters_are_not_doubled() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        buf.set_string(0, 0, "横綱", Style::default());
+        assert_eq!(buffer_to_text(&buf), "横綱\n");
+    }
+}
@@ -0,0 +1,431 @@
+use crate::achievements;
+use crate::api::{BanzukeEntry, HeadToHeadMatch, RikishiDetails, RikishiStats, SumoApi};
+use crate::kimarite::{self, Category};
+use chrono::Datelike;
+use futures::StreamExt;
+use std::collections::HashMap;
+
+/// Entries in each list of the records view.
+pub const RECORDS_SHOWN: usize = 10;
+// Career bouts needed to appear in the win-rate list
+const MIN_BOUTS_FOR_RATE: u32 = 30;
+// Career stats requests in flight at once when filling the records view
+const CONCURRENT_REQUESTS: usize = 8;
+
+/// Summary of the loaded division for the stats view.
+#[derive(Debug, Default, PartialEq)]
+pub struct DivisionStats {
+    pub rikishi: usize,
+    pub average_age: Option<f64>,
+    pub average_height: Option<f64>,
+    pub average_weight: Option<f64>,
+    /// Rikishi per heya, most first
+    pub heya: Vec<(String, usize)>,
+    /// Winning kimarite so far this basho, most used first
+    pub kimarite: Vec<(String, usize)>,
+    /// Winning kimarite per category, in category order
+    pub kimarite_categories: Vec<(Category, usize)>,
+    /// Rikishi per (wins, losses) record, best record first
+    pub records: Vec<((u8, u8), usize)>,
+    pub extremes: Extremes,
+}
+
+/// Who stands out in the division, from profile data.
+#[derive(Debug, Default, PartialEq)]
+pub struct Extremes {
+    /// Weight in kg
+    pub heaviest: Option<Superlative>,
+    pub lightest: Option<Superlative>,
+    /// Height in cm
+    pub tallest: Option<Superlative>,
+    pub shortest: Option<Superlative>,
+    /// Age in years
+    pub oldest: Option<Superlative>,
+    pub youngest: Option<Superlative>,
+    /// Debut as YYYYMM
+    pub longest_serving: Option<Superlative>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Superlative {
+    pub shikona: String,
+    pub value: u32,
+}
+
+impl DivisionStats {
+    /// Compute the stats from the banzuke, counting the first `revealed(entry)` results of
+    /// each record. Age, size and heya come from `profiles` and skip rikishi without one.
+    pub fn compute(
+        banzuke: &[BanzukeEntry],
+        profiles: &HashMap<u32, RikishiDetails>,
+        revealed: impl Fn(&BanzukeEntry) -> u8,
+        today: chrono::NaiveDate,
+    ) -> Self {
+        let members: Vec<&RikishiDetails> = banzuke.iter().filter_map(|e| profiles.get(&e.rikishi_id)).collect();
+        let ages = members.iter().filter_map(|p| Some(today.years_since(birth_date(p)?)? as f64));
+
+        let mut heya = HashMap::new();
+        for name in members.iter().filter_map(|p| p.heya.as_deref()) {
+            *heya.entry(name.to_string()).or_insert(0) += 1;
+        }
+
+        // Each bout shows up in both rikishi's records; count it once, from the winner's
+        let mut kimarite = HashMap::new();
+        let results = banzuke.iter().flat_map(|e| e.record.iter().flatten().take(revealed(e) as usize));
+        for name in results.filter(|r| r.result == "win").filter_map(|r| r.kimarite.as_deref()) {
+            *kimarite.entry(name.to_string()).or_insert(0) += 1;
+        }
+
+        let mut records = HashMap::new();
+        for entry in banzuke {
+            let (wins, losses, _) = entry.win_loss_absent(revealed(entry));
+            *records.entry((wins, losses)).or_insert(0) += 1;
+        }
+        let mut records: Vec<_> = records.into_iter().collect();
+        records.sort_by(|((w1, l1), _), ((w2, l2), _)| w2.cmp(w1).then(l1.cmp(l2)));
+
+        let kimarite = by_count(kimarite);
+        Self {
+            rikishi: banzuke.len(),
+            average_age: average(ages),
+            average_height: average(members.iter().filter_map(|p| Some(p.height? as f64))),
+            average_weight: average(members.iter().filter_map(|p| Some(p.weight? as f64))),
+            heya: by_count(heya),
+            kimarite_categories: kimarite::count(&kimarite),
+            kimarite,
+            records,
+            extremes: Extremes::compute(banzuke, profiles, today),
+        }
+    }
+}
+
+impl Extremes {
+    fn compute(banzuke: &[BanzukeEntry], profiles: &HashMap<u32, RikishiDetails>, today: chrono::NaiveDate) -> Self {
+        let members: Vec<(&BanzukeEntry, &RikishiDetails)> =
+            banzuke.iter().filter_map(|e| Some((e, profiles.get(&e.rikishi_id)?))).collect();
+        // The member with the smallest or largest `key`, reported with `value`
+        let pick = |largest: bool, key: &dyn Fn(&RikishiDetails) -> Option<u32>, value: &dyn Fn(&RikishiDetails) -> Option<u32>| {
+            let candidates = members.iter().filter_map(|(e, p)| Some((key(p)?, e, p)));
+            let best = if largest {
+                candidates.max_by_key(|(k, _, _)| *k)
+            } else {
+                candidates.min_by_key(|(k, _, _)| *k)
+            }?;
+            Some(Superlative { shikona: best.1.shikona_en.to_string(), value: value(best.2)? })
+        };
+        let age = |p: &RikishiDetails| today.years_since(birth_date(p)?);
+        let born = |p: &RikishiDetails| Some(birth_date(p)?.num_days_from_ce() as u32);
+        let debut = |p: &RikishiDetails| p.debut.as_deref()?.get(..6)?.parse().ok();
+
+        Self {
+            heaviest: pick(true, &|p| p.weight, &|p| p.weight),
+            lightest: pick(false, &|p| p.weight, &|p| p.weight),
+            tallest: pick(true, &|p| p.height, &|p| p.height),
+            shortest: pick(false, &|p| p.height, &|p| p.height),
+            oldest: pick(false, &born, &age),
+            youngest: pick(true, &born, &age),
+            longest_serving: pick(false, &debut, &debut),
+        }
+    }
+}
+
+fn birth_date(profile: &RikishiDetails) -> Option<chrono::NaiveDate> {
+    let birth = profile.birth_date.as_deref()?;
+    chrono::NaiveDate::parse_from_str(birth.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// All-time records among the loaded division's rikishi, each list best first.
+#[derive(Debug, Default, PartialEq)]
+pub struct CareerRecords {
+    pub most_yusho: Vec<(String, u32)>,
+    pub most_wins: Vec<(String, u32)>,
+    /// Gold stars over a whole career
+    pub most_kinboshi: Vec<(String, u32)>,
+    /// Share of career bouts won, for rikishi with at least MIN_BOUTS_FOR_RATE
+    pub best_win_rate: Vec<(String, f64)>,
+    /// Longest run of consecutive wins over a whole career, across basho
+    pub longest_streaks: Vec<(String, u32)>,
+}
+
+impl CareerRecords {
+    /// Rank the rikishi of `banzuke` by their career stats and stored bout history;
+    /// rikishi whose stats or bouts are not loaded are left out of the lists that need
+    /// them. Bouts of basho `hidden.0` after day `hidden.1` are not counted, for replay
+    /// and spoiler-free mode.
+    pub fn compute(
+        banzuke: &[BanzukeEntry],
+        career_stats: &HashMap<u32, RikishiStats>,
+        career_bouts: &HashMap<u32, Vec<HeadToHeadMatch>>,
+        hidden: Option<(&str, u8)>,
+    ) -> Self {
+        let careers: Vec<(String, &RikishiStats)> = banzuke
+            .iter()
+            .filter_map(|e| Some((e.shikona_en.to_string(), career_stats.get(&e.rikishi_id)?)))
+            .collect();
+        let ranked = |value: &dyn Fn(&RikishiStats) -> u32| {
+            top(careers.iter().map(|(name, stats)| (name.clone(), value(stats))).filter(|(_, v)| *v > 0).collect())
+        };
+        let rates = careers
+            .iter()
+            .filter(|(_, s)| s.total_wins + s.total_losses >= MIN_BOUTS_FOR_RATE)
+            .map(|(name, s)| (name.clone(), s.total_wins as f64 / (s.total_wins + s.total_losses) as f64))
+            .collect();
+
+        let histories: Vec<(String, u32, Vec<&HeadToHeadMatch>)> = banzuke
+            .iter()
+            .filter_map(|e| {
+                let bouts = career_bouts.get(&e.rikishi_id)?;
+                let shown = bouts.iter().filter(|m| hidden.is_none_or(|(basho_id, day)| m.basho_id != basho_id || m.day <= day)).collect();
+                Some((e.shikona_en.to_string(), e.rikishi_id, shown))
+            })
+            .collect();
+        let per_history = |value: &dyn Fn(u32, &[&HeadToHeadMatch]) -> u32| {
+            top(histories.iter().map(|(name, id, bouts)| (name.clone(), value(*id, bouts))).filter(|(_, v)| *v > 0).collect())
+        };
+
+        Self {
+            most_yusho: ranked(&|s| s.yusho),
+            most_wins: ranked(&|s| s.total_wins),
+            most_kinboshi: per_history(&|id, bouts| achievements::kinboshi(id, bouts.iter().copied())),
+            best_win_rate: top(rates),
+            longest_streaks: per_history(&longest_winning_run),
+        }
+    }
+}
+
+// Longest run of wins in `bouts`, taken in the order they were fought; fusen wins
+// count, bouts without a result are skipped
+fn longest_winning_run(rikishi_id: u32, bouts: &[&HeadToHeadMatch]) -> u32 {
+    let mut bouts = bouts.to_vec();
+    bouts.sort_by(|a, b| (&a.basho_id, a.day, a.match_no).cmp(&(&b.basho_id, b.day, b.match_no)));
+    let mut longest = 0;
+    let mut run = 0;
+    for winner in bouts.iter().filter_map(|m| m.winner_id) {
+        run = if winner == rikishi_id { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    longest
+}
+
+// The RECORDS_SHOWN highest values, ties in banzuke order
+fn top<T: PartialOrd>(mut entries: Vec<(String, T)>) -> Vec<(String, T)> {
+    entries.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    entries.truncate(RECORDS_SHOWN);
+    entries
+}
+
+/// Fetch career stats for `ids`, a few requests at a time. Failed requests are
+/// returned as error messages.
+pub async fn fetch_career_stats(api: &SumoApi, ids: Vec<u32>) -> (Vec<(u32, RikishiStats)>, Vec<String>) {
+    fetch_each(ids, |id| api.get_rikishi_stats(id)).await
+}
+
+/// Fetch the whole bout history of each of `ids`, a few rikishi at a time. Failed
+/// requests are returned as error messages.
+pub async fn fetch_career_bouts(api: &SumoApi, ids: Vec<u32>) -> (Vec<(u32, Vec<HeadToHeadMatch>)>, Vec<String>) {
+    fetch_each(ids, |id| api.get_rikishi_matches(id)).await
+}
+
+async fn fetch_each<T, F, Fut>(ids: Vec<u32>, fetch: F) -> (Vec<(u32, T)>, Vec<String>)
+where
+    F: Fn(u32) -> Fut,
+    Fut: std::future::Future<Output = anyhow::Result<T>>,
+{
+    let fetch = &fetch;
+    let mut results = futures::stream::iter(ids)
+        .map(|id| async move { (id, fetch(id).await) })
+        .buffer_unordered(CONCURRENT_REQUESTS);
+    let (mut fetched, mut errors) = (Vec::new(), Vec::new());
+    while let Some((id, result)) = results.next().await {
+        match result {
+            Ok(value) => fetched.push((id, value)),
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+    (fetched, errors)
+}
+
+fn average(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+// Most frequent first, ties by name
+fn by_count(counts: HashMap<String, usize>) -> Vec<(String, usize)> {
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|(a, n), (b, m)| m.cmp(n).then(a.cmp(b)));
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CareerRecords, DivisionStats, Superlative};
+    use crate::kimarite::Category;
+    use crate::api::RikishiStats;
+    use crate::api::{BanzukeEntry, HeadToHeadMatch, MatchRecord, RikishiDetails};
+    use std::collections::HashMap;
+
+    fn result(result: &str, kimarite: &str) -> MatchRecord {
+        MatchRecord {
+            result: result.to_string(),
+            opponent_shikona_en: String::new(),
+            opponent_shikona_jp: String::new(),
+            kimarite: Some(kimarite.to_string()),
+        }
+    }
+
+    fn entry(id: u32, record: Vec<MatchRecord>) -> BanzukeEntry {
+        BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: id,
+            shikona_en: format!("Rikishi{}", id).into(),
+            shikona_jp: None,
+            rank_value: id,
+            rank: format!("Maegashira {} East", id),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: Some(record),
+        }
+    }
+
+    fn profile(id: u32, heya: &str, birth_date: &str, height: u32, weight: u32) -> RikishiDetails {
+        RikishiDetails {
+            id,
+            sumodb_id: None,
+            nsk_id: None,
+            shikona_en: format!("Rikishi{}", id),
+            shikona_jp: String::new(),
+            current_rank: None,
+            heya: Some(heya.to_string()),
+            birth_date: Some(birth_date.to_string()),
+            shusshin: None,
+            height: Some(height),
+            weight: Some(weight),
+            debut: None,
+        }
+    }
+
+    #[test]
+    fn summarizes_division() {
+        let banzuke = vec![
+            entry(1, vec![result("win", "yorikiri"), result("win", "oshidashi"), result("win", "yorikiri")]),
+            entry(2, vec![result("loss", "yorikiri"), result("win", "yorikiri"), result("loss", "hatakikomi")]),
+            entry(3, vec![result("loss", "oshidashi"), result("loss", "yorikiri"), result("win", "hatakikomi")]),
+        ];
+        let profiles: HashMap<_, _> = [
+            profile(1, "Isegahama", "2000-01-01T00:00:00Z", 190, 160),
+            profile(2, "Isegahama", "1990-01-01T00:00:00Z", 180, 140),
+        ]
+        .into_iter()
+        .map(|p| (p.id, p))
+        .collect();
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+
+        let stats = DivisionStats::compute(&banzuke, &profiles, |_| 2, today);
+        assert_eq!(stats.rikishi, 3);
+        assert_eq!(stats.average_age, Some(30.0));
+        assert_eq!(stats.average_height, Some(185.0));
+        assert_eq!(stats.heya, vec![("Isegahama".to_string(), 2)]);
+        assert_eq!(stats.kimarite, vec![("yorikiri".to_string(), 2), ("oshidashi".to_string(), 1)]);
+        assert_eq!(stats.kimarite_categories, vec![(Category::PushThrust, 1), (Category::ForceOut, 2)]);
+        assert_eq!(stats.records, vec![((2, 0), 1), ((1, 1), 1), ((0, 2), 1)]);
+    }
+
+    #[test]
+    fn finds_extremes() {
+        let banzuke = vec![entry(1, Vec::new()), entry(2, Vec::new()), entry(3, Vec::new())];
+        let mut veteran = profile(2, "Oitekaze", "1990-03-01", 180, 185);
+        veteran.debut = Some("200803".to_string());
+        let profiles: HashMap<_, _> = [profile(1, "Isegahama", "2000-01-01", 192, 150), veteran]
+            .into_iter()
+            .map(|p| (p.id, p))
+            .collect();
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+
+        let extremes = DivisionStats::compute(&banzuke, &profiles, |_| 0, today).extremes;
+        let name = |s: &Option<Superlative>| s.as_ref().map(|s| (s.shikona.clone(), s.value));
+        assert_eq!(name(&extremes.heaviest), Some(("Rikishi2".to_string(), 185)));
+        assert_eq!(name(&extremes.tallest), Some(("Rikishi1".to_string(), 192)));
+        assert_eq!(name(&extremes.oldest), Some(("Rikishi2".to_string(), 35)));
+        assert_eq!(name(&extremes.youngest), Some(("Rikishi1".to_string(), 25)));
+        assert_eq!(name(&extremes.longest_serving), Some(("Rikishi2".to_string(), 200803)));
+    }
+
+    fn bout(basho_id: &str, day: u8, east: (u32, &str), west: (u32, &str), winner: u32) -> HeadToHeadMatch {
+        HeadToHeadMatch {
+            basho_id: basho_id.to_string(),
+            division: "Makuuchi".to_string(),
+            day,
+            match_no: 1,
+            east_id: east.0,
+            east_shikona: String::new(),
+            east_rank: east.1.to_string(),
+            west_id: west.0,
+            west_shikona: String::new(),
+            west_rank: west.1.to_string(),
+            kimarite: Some("yorikiri".to_string()),
+            winner_id: Some(winner),
+            winner_en: None,
+            winner_jp: None,
+        }
+    }
+
+    #[test]
+    fn ranks_career_records() {
+        let banzuke = vec![entry(1, Vec::new()), entry(2, Vec::new()), entry(3, Vec::new())];
+        let career = |yusho, total_wins, total_losses| RikishiStats { yusho, total_wins, total_losses, ..RikishiStats::default() };
+        let stats: HashMap<_, _> = [(1, career(3, 400, 200)), (2, career(0, 20, 5)), (3, career(1, 500, 400))].into_iter().collect();
+        let (m1, y) = ("Maegashira 1 East", "Yokozuna 1 East");
+        // Newest first, as the API returns them. Rikishi1 wins the last two days of one
+        // basho and the first two of the next, two of them as kinboshi; Rikishi2's run
+        // of three stays within one basho.
+        let bouts: HashMap<_, _> = [
+            (1, vec![
+                bout("202503", 3, (1, m1), (9, m1), 9),
+                bout("202503", 2, (1, m1), (9, y), 1),
+                bout("202503", 1, (1, m1), (9, m1), 1),
+                bout("202501", 15, (1, m1), (9, y), 1),
+                bout("202501", 14, (1, m1), (9, m1), 1),
+                bout("202501", 13, (1, m1), (9, m1), 9),
+            ]),
+            (2, vec![
+                bout("202501", 4, (2, m1), (9, y), 2),
+                bout("202501", 3, (2, m1), (9, m1), 2),
+                bout("202501", 2, (2, m1), (9, m1), 2),
+                bout("202501", 1, (2, m1), (9, m1), 9),
+            ]),
+        ]
+        .into_iter()
+        .collect();
+
+        let records = CareerRecords::compute(&banzuke, &stats, &bouts, None);
+        assert_eq!(records.most_yusho, vec![("Rikishi1".to_string(), 3), ("Rikishi3".to_string(), 1)]);
+        assert_eq!(records.most_wins[0], ("Rikishi3".to_string(), 500));
+        assert_eq!(records.most_kinboshi, vec![("Rikishi1".to_string(), 2), ("Rikishi2".to_string(), 1)]);
+        // Rikishi2 has too few bouts for the win rate
+        assert_eq!(records.best_win_rate.iter().map(|(n, _)| n.as_str()).collect::<Vec<_>>(), vec!["Rikishi1", "Rikishi3"]);
+        assert_eq!(records.longest_streaks, vec![("Rikishi1".to_string(), 4), ("Rikishi2".to_string(), 3)]);
+
+        // With the basho's results hidden from day 2, the run and a kinboshi are cut short
+        let records = CareerRecords::compute(&banzuke, &stats, &bouts, Some(("202503", 1)));
+        assert_eq!(records.longest_streaks, vec![("Rikishi1".to_string(), 3), ("Rikishi2".to_string(), 3)]);
+        assert_eq!(records.most_kinboshi, vec![("Rikishi1".to_string(), 1), ("Rikishi2".to_string(), 1)]);
+    }
+
+    #[test]
+    fn lower_division_bouts_count_past_day_seven() {
+        let banzuke = vec![entry(1, Vec::new())];
+        let ms = "Makushita 5 East";
+        // Seven bouts spread over the fifteen days, newest first
+        let history = [13, 11, 9, 7, 5, 3, 1]
+            .map(|day| HeadToHeadMatch { division: "Makushita".to_string(), ..bout("202503", day, (1, ms), (9, ms), 1) });
+        let bouts: HashMap<_, _> = [(1, history.to_vec())].into_iter().collect();
+
+        let records = CareerRecords::compute(&banzuke, &HashMap::new(), &bouts, None);
+        assert_eq!(records.longest_streaks, vec![("Rikishi1".to_string(), 7)]);
+        // Spoiler-free from day 12 hides only the day 13 bout
+        let records = CareerRecords::compute(&banzuke, &HashMap::new(), &bouts, Some(("202503", 11)));
+        assert_eq!(records.longest_streaks, vec![("Rikishi1".to_string(), 6)]);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::api::{BanzukeEntry, SumoApi};
+use crate::i18n::tf;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// How long a status is printed again from the cache before the API is asked again.
+pub const DEFAULT_MAX_AGE_SECS: u64 = 60;
+// Rikishi named as leading the division
+const LEADERS: usize = 2;
+
+/// Where the basho stands, as `sumo status` reports it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Progress {
+    /// Days until day 1
+    Upcoming(i64),
+    Day(u8),
+    Final,
+}
+
+/// What `sumo status` prints: the basho, its leaders and the favorites' bouts today.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Status {
+    /// Short basho name, e.g. `Aki`
+    pub basho: String,
+    pub progress: Progress,
+    /// Shikona, wins and losses of the division's leaders
+    pub leaders: Vec<(String, u8, u8)>,
+    /// Favorites with a bout today and whether they won it, once decided
+    pub favorites: Vec<(String, Option<bool>)>,
+}
+
+impl Status {
+    /// The status as separate parts: the basho with its leaders, then the favorites.
+    pub fn parts(&self) -> Vec<String> {
+        let heading = match self.progress {
+            Progress::Upcoming(days) => tf("statusline.upcoming", &[&self.basho, &days]),
+            Progress::Day(day) => tf("statusline.day", &[&self.basho, &day]),
+            Progress::Final => tf("statusline.final", &[&self.basho]),
+        };
+        let mut leaders = self
+            .leaders
+            .iter()
+            .map(|(name, w, l)| format!("{} {}-{}", name, w, l));
+        let mut parts = vec![match leaders.next() {
+            Some(first) => format!("{}: {}", heading, first),
+            None => heading,
+        }];
+        parts.extend(leaders);
+        if !self.favorites.is_empty() {
+            let favorites = self.favorites.iter().map(|(name, won)| {
+                let result = match won {
+                    Some(true) => "W",
+                    Some(false) => "L",
+                    None => "-",
+                };
+                format!("{} {}", name, result)
+            });
+            parts.push(tf(
+                "statusline.favorites",
+                &[&favorites.collect::<Vec<_>>().join(", ")],
+            ));
+        }
+        parts
+    }
+}
+
+/// The `count` rikishi with the most wins over the first `days` days, fewest losses first
+/// among equals.
+pub fn leaders(banzuke: &[BanzukeEntry], days: u8, count: usize) -> Vec<(String, u8, u8)> {
+    let mut records: Vec<(String, u8, u8)> = banzuke
+        .iter()
+        .map(|e| {
+            let (wins, losses) = e.wins_losses(days);
+            (
+                crate::romaji::romanize(&e.shikona_en).into_owned(),
+                wins,
+                losses,
+            )
+        })
+        .filter(|(_, wins, losses)| wins + losses > 0)
+        .collect();
+    records.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.cmp(&b.2)));
+    records.truncate(count);
+    records
+}
+
+/// Look up the status of `basho_id` for `division` and the configured favorites.
+pub async fn fetch(
+    api: &SumoApi,
+    basho_id: &str,
+    division: &str,
+    favorites: &[String],
+) -> anyhow::Result<Status> {
+    let basho = api.get_basho(basho_id).await?;
+    let month = basho_id.get(4..6).and_then(|m| m.parse().ok()).unwrap_or(0);
+    let name = crate::i18n::basho_name(month);
+    let today = chrono::Utc::now().date_naive();
+    let last_day = crate::max_day_for_division(division);
+    let progress = match (basho.start_date_naive(), basho.end_date_naive()) {
+        (Some(start), _) if today < start => Progress::Upcoming((start - today).num_days()),
+        (_, Some(end)) if today > end => Progress::Final,
+        (Some(start), _) => {
+            Progress::Day(((today - start).num_days() + 1).clamp(1, last_day as i64) as u8)
+        }
+        _ => Progress::Final,
+    };
+    let days = match progress {
+        Progress::Upcoming(_) => 0,
+        Progress::Day(day) => day,
+        Progress::Final => last_day,
+    };
+
+    let mut leading = Vec::new();
+    if days > 0 {
+        let banzuke = api.get_banzuke(basho_id, division).await?;
+        leading = leaders(
+            &banzuke
+                .east
+                .into_iter()
+                .chain(banzuke.west)
+                .collect::<Vec<_>>(),
+            days,
+            LEADERS,
+        );
+    }
+    let mut tracked = Vec::new();
+    if let Progress::Day(day) = progress
+        && !favorites.is_empty()
+    {
+        // Favorites are extra: the rest is still worth showing without them
+        let found = crate::dashboard::fetch(api, basho_id, day, crate::tui::DIVISIONS, favorites)
+            .await
+            .unwrap_or_default();
+        tracked = found
+            .iter()
+            .filter(|t| t.bout.is_some())
+            .map(|t| (t.name.clone(), t.won()))
+            .collect();
+    }
+    Ok(Status {
+        basho: name
+            .trim_end_matches(" Basho")
+            .trim_end_matches("場所")
+            .to_string(),
+        progress,
+        leaders: leading,
+        favorites: tracked,
+    })
+}
+
+/// The last status printed, kept in the cache directory so a status bar polling every
+/// few seconds doesn't send a request each time.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct StatusCache {
+    /// Unix time it was looked up
+    made_at: i64,
+    /// Basho, division and favorites it was made for
+    key: String,
+    parts: Vec<String>,
+}
+
+fn cache_path() -> Option<PathBuf> {
+    Some(crate::paths::cache_dir()?.join("status.toml"))
+}
+
+fn load_cache(path: &Path) -> Option<StatusCache> {
+    toml::from_str(&std::fs::read_to_string(path).ok()?).ok()
+}
+
+fn save_cache(path: &Path, cache: &StatusCache) {
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if let Ok(contents) = toml::to_string(cache) {
+        let _ = std::fs::write(path, contents);
+    }
+}
+
+/// The status parts for `division` at the current basho: from the cache when it is less
+/// than `max_age_secs` old, otherwise looked up again. A failed lookup falls back to the
+/// cached status however old it is.
+pub async fn current(
+    api: &SumoApi,
+    division: &str,
+    favorites: &[String],
+    max_age_secs: u64,
+) -> anyhow::Result<Vec<String>> {
+    let basho_id = api.get_current_basho_id().await;
+    let key = format!("{}/{}/{}", basho_id, division, favorites.join(","));
+    let path = cache_path();
+    let now = chrono::Utc::now().timestamp();
+    let cached = path
+        .as_deref()
+        .and_then(load_cache)
+        .filter(|c| c.key == key);
+    if let Some(cache) = &cached
+        && now - cache.made_at < max_age_secs as i64
+    {
+        return Ok(cache.parts.clone());
+    }
+    match fetch(api, &basho_id, division, favorites).await {
+        Ok(status) => {
+            let parts = status.parts();
+            if let Some(path) = &path {
+                save_cache(
+                    path,
+                    &StatusCache {
+                        made_at: now,
+                        key,
+                        parts: parts.clone(),
+                    },
+                );
+            }
+            Ok(parts)
+        }
+        Err(e) => cached.map(|c| c.parts).ok_or(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Progress, Status};
+
+    #[test]
+    fn parts_fit_a_status_bar() {
+        let mut status = Status {
+            basho: "Aki".to_string(),
+            progress: Progress::Day(12),
+            leaders: vec![
+                ("Onosato".to_string(), 11, 1),
+                ("Hoshoryu".to_string(), 10, 2),
+            ],
+            favorites: vec![
+                ("Ura".to_string(), Some(true)),
+                ("Tobizaru".to_string(), None),
+            ],
+        };
+        assert_eq!(
+            status.parts().join(" | "),
+            "Aki D12: Onosato 11-1 | Hoshoryu 10-2 | fav: Ura W, Tobizaru -"
+        );
+
+        status.progress = Progress::Upcoming(3);
+        (status.leaders, status.favorites) = (Vec::new(), Vec::new());
+        assert_eq!(status.parts(), ["Aki starts in 3 days"]);
+    }
+}
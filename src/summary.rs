@@ -0,0 +1,185 @@
+use crate::api::{BanzukeEntry, TorikumiEntry};
+use crate::rank::{Rank, Tier};
+use std::collections::HashMap;
+
+// Upsets and kimarite listed in the summary
+const UPSETS_SHOWN: usize = 3;
+const KIMARITE_SHOWN: usize = 3;
+// Most rikishi listed in the yusho race
+const RACE_SHOWN: usize = 6;
+
+/// A win by the lower-ranked rikishi; `gap` is the rank slots between them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Upset {
+    pub winner: String,
+    pub winner_rank: String,
+    pub loser: String,
+    pub loser_rank: String,
+    pub gap: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contender {
+    pub shikona: String,
+    pub wins: u8,
+    pub losses: u8,
+}
+
+/// What happened on one day of the basho, built from the loaded torikumi and records.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaySummary {
+    pub day: u8,
+    pub bouts: usize,
+    pub upsets: Vec<Upset>,
+    /// (maegashira, yokozuna) for each kinboshi won
+    pub kinboshi: Vec<(String, String)>,
+    pub fusen: usize,
+    pub kimarite: Vec<(String, usize)>,
+    /// Leaders and everyone one loss behind, best record first
+    pub race: Vec<Contender>,
+}
+
+fn is_fusen(bout: &TorikumiEntry) -> bool {
+    bout.kimarite.as_deref().is_some_and(|k| k.eq_ignore_ascii_case("fusen"))
+}
+
+impl DaySummary {
+    /// Summarise `torikumi`; `records` gives the (wins, losses) the yusho race is built from.
+    pub fn compute(day: u8, torikumi: &[TorikumiEntry], banzuke: &[BanzukeEntry], records: &HashMap<u32, (u8, u8)>) -> Self {
+        let mut upsets = Vec::new();
+        let mut kinboshi = Vec::new();
+        let mut kimarite: HashMap<&str, usize> = HashMap::new();
+        for bout in torikumi {
+            let Some(winner_id) = bout.winner_id else { continue };
+            if is_fusen(bout) {
+                continue;
+            }
+            if let Some(k) = bout.kimarite.as_deref().filter(|k| !k.is_empty()) {
+                *kimarite.entry(k).or_default() += 1;
+            }
+            let east_won = winner_id == bout.east_id;
+            let (winner, winner_rank, loser, loser_rank) = if east_won {
+                (&bout.east_shikona, &bout.east_rank, &bout.west_shikona, &bout.west_rank)
+            } else {
+                (&bout.west_shikona, &bout.west_rank, &bout.east_shikona, &bout.east_rank)
+            };
+            let (Some(w), Some(l)) = (Rank::parse(winner_rank), Rank::parse(loser_rank)) else { continue };
+            if w.tier == Tier::Maegashira && l.tier == Tier::Yokozuna {
+                kinboshi.push((winner.to_string(), loser.to_string()));
+            }
+            if w.ordinal() > l.ordinal() {
+                upsets.push(Upset {
+                    winner: winner.to_string(),
+                    winner_rank: winner_rank.clone(),
+                    loser: loser.to_string(),
+                    loser_rank: loser_rank.clone(),
+                    gap: w.ordinal() - l.ordinal(),
+                });
+            }
+        }
+        // Stable sort keeps torikumi order among equal gaps
+        upsets.sort_by_key(|u| std::cmp::Reverse(u.gap));
+        upsets.truncate(UPSETS_SHOWN);
+
+        let mut kimarite: Vec<(String, usize)> = kimarite.into_iter().map(|(k, n)| (k.to_string(), n)).collect();
+        kimarite.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        kimarite.truncate(KIMARITE_SHOWN);
+
+        let mut race: Vec<Contender> = banzuke
+            .iter()
+            .filter_map(|e| {
+                let (wins, losses) = records.get(&e.rikishi_id).copied()?;
+                Some(Contender { shikona: e.shikona_en.to_string(), wins, losses })
+            })
+            .collect();
+        race.sort_by(|a, b| a.losses.cmp(&b.losses).then(b.wins.cmp(&a.wins)));
+        if let Some(fewest) = race.first().map(|c| c.losses) {
+            race.retain(|c| c.losses <= fewest + 1);
+        }
+        race.truncate(RACE_SHOWN);
+
+        DaySummary {
+            day,
+            bouts: torikumi.len(),
+            upsets,
+            kinboshi,
+            fusen: torikumi.iter().filter(|b| is_fusen(b)).count(),
+            kimarite,
+            race,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DaySummary;
+    use crate::api::{BanzukeEntry, TorikumiEntry};
+    use std::collections::HashMap;
+
+    fn bout(east: (u32, &str, &str), west: (u32, &str, &str), winner: Option<u32>, kimarite: &str) -> TorikumiEntry {
+        TorikumiEntry {
+            id: format!("{}-{}", east.0, west.0),
+            basho_id: "202501".to_string(),
+            division: "Makuuchi".to_string(),
+            day: 5,
+            match_no: 1,
+            east_id: east.0,
+            east_shikona: east.1.into(),
+            east_rank: east.2.to_string(),
+            west_id: west.0,
+            west_shikona: west.1.into(),
+            west_rank: west.2.to_string(),
+            kimarite: Some(kimarite.to_string()),
+            winner_id: winner,
+            winner_en: None,
+            winner_jp: None,
+        }
+    }
+
+    fn entry(id: u32, shikona: &str) -> BanzukeEntry {
+        BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: id,
+            shikona_en: shikona.into(),
+            shikona_jp: None,
+            rank_value: id,
+            rank: String::new(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: None,
+        }
+    }
+
+    #[test]
+    fn summarises_upsets_kinboshi_fusen_and_the_race() {
+        let yokozuna = (1, "Hoshoryu", "Yokozuna 1 East");
+        let ozeki = (2, "Onosato", "Ozeki 1 East");
+        let m1 = (3, "Oho", "Maegashira 1 West");
+        let m12 = (4, "Tamawashi", "Maegashira 12 East");
+        let m13 = (5, "Endo", "Maegashira 13 West");
+        let m14 = (6, "Enho", "Maegashira 14 East");
+        let torikumi = vec![
+            bout(m1, yokozuna, Some(3), "oshidashi"),
+            bout(ozeki, m12, Some(4), "oshidashi"),
+            bout(m13, m14, Some(5), "fusen"),
+        ];
+
+        let banzuke: Vec<_> = [(1, "Hoshoryu"), (2, "Onosato"), (3, "Oho"), (4, "Tamawashi"), (5, "Endo")]
+            .into_iter()
+            .map(|(id, name)| entry(id, name))
+            .collect();
+        let records = HashMap::from([(1, (3, 2)), (2, (3, 2)), (3, (5, 0)), (4, (4, 1)), (5, (3, 2))]);
+        let summary = DaySummary::compute(5, &torikumi, &banzuke, &records);
+
+        assert_eq!(summary.bouts, 3);
+        assert_eq!(summary.fusen, 1);
+        assert_eq!(summary.kinboshi, vec![("Oho".to_string(), "Hoshoryu".to_string())]);
+        // The fusen win is no upset; Tamawashi over an Ozeki beats Oho over a Yokozuna
+        let upsets: Vec<_> = summary.upsets.iter().map(|u| u.winner.as_str()).collect();
+        assert_eq!(upsets, vec!["Tamawashi", "Oho"]);
+        assert_eq!(summary.kimarite, vec![("oshidashi".to_string(), 2)]);
+        let race: Vec<_> = summary.race.iter().map(|c| (c.shikona.as_str(), c.wins, c.losses)).collect();
+        assert_eq!(race, vec![("Oho", 5, 0), ("Tamawashi", 4, 1)]);
+    }
+}
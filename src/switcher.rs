@@ -0,0 +1,110 @@
+use crate::keymap::Action;
+
+/// Matches listed in the switcher at once.
+pub const SHOWN: usize = 10;
+
+/// Where a switcher entry takes you.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    Rikishi(u32),
+    Basho(String),
+    Division(String),
+    Action(Action),
+    /// A command registered by a user script
+    Command(String),
+}
+
+/// One thing the switcher can jump to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// i18n key of the kind of entry, shown next to it
+    pub kind: &'static str,
+    /// Text searched and shown
+    pub label: String,
+    pub target: Target,
+}
+
+impl Entry {
+    pub fn new(kind: &'static str, label: impl Into<String>, target: Target) -> Self {
+        Self { kind, label: label.into(), target }
+    }
+}
+
+// Lowercase, with long vowel marks dropped so "kyushu" finds "Kyūshū"
+fn fold(text: &str) -> Vec<char> {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'ō' | 'ô' => 'o',
+            'ū' | 'û' => 'u',
+            'ā' | 'â' => 'a',
+            c => c,
+        })
+        .collect()
+}
+
+// How well `term` matches `text` as a subsequence, case-insensitively, or `None` when it
+// doesn't. Runs of consecutive letters and letters starting a word count extra.
+fn term_score(term: &str, text: &str) -> Option<u32> {
+    let text = fold(text);
+    let mut score = 0;
+    let mut from = 0;
+    let mut previous: Option<usize> = None;
+    for c in fold(term) {
+        let at = from + text[from..].iter().position(|t| *t == c)?;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == at) {
+            score += 4;
+        }
+        if at == 0 || !text[at - 1].is_alphanumeric() {
+            score += 2;
+        }
+        previous = Some(at);
+        from = at + 1;
+    }
+    Some(score)
+}
+
+/// Score of `text` for a query of space-separated terms, each of which has to match
+/// somewhere, or `None` when one doesn't.
+pub fn score(query: &str, text: &str) -> Option<u32> {
+    query.split_whitespace().map(|term| term_score(term, text)).sum()
+}
+
+/// The entries matching `query`, best first, ties in their original order. An empty
+/// query matches everything.
+pub fn search<'a>(entries: &'a [Entry], query: &str) -> Vec<&'a Entry> {
+    let mut matches: Vec<(u32, usize, &Entry)> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(i, entry)| Some((score(query, &entry.label)?, i, entry)))
+        .collect();
+    matches.sort_by(|(a, i, _), (b, j, _)| b.cmp(a).then(i.cmp(j)));
+    matches.into_iter().map(|(_, _, entry)| entry).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{score, search, Entry, Target};
+
+    #[test]
+    fn ranks_fuzzy_matches() {
+        assert!(score("hsr", "Hoshoryu").is_some());
+        assert_eq!(score("hsx", "Hoshoryu"), None);
+        assert_eq!(score("hoshoryu", "Hōshōryū"), score("hoshoryu", "Hoshoryu"));
+        // Consecutive letters beat scattered ones
+        assert!(score("hosho", "Hoshoryu") > score("hosho", "Hokutoshoho"));
+
+        let entries = vec![
+            Entry::new("switcher.rikishi", "Onosato", Target::Rikishi(1)),
+            Entry::new("switcher.basho", "202411 November 2024 (Kyushu)", Target::Basho("202411".to_string())),
+            Entry::new("switcher.basho", "202501 January 2025 (Hatsu)", Target::Basho("202501".to_string())),
+            Entry::new("switcher.division", "Juryo", Target::Division("Juryo".to_string())),
+        ];
+        let found = |query| search(&entries, query).iter().map(|e| e.target.clone()).collect::<Vec<_>>();
+        assert_eq!(found("kyushu 2024"), vec![Target::Basho("202411".to_string())]);
+        // "January" has the letters too, further apart
+        assert_eq!(found("jur"), vec![Target::Division("Juryo".to_string()), Target::Basho("202501".to_string())]);
+        assert_eq!(found("").len(), 4);
+    }
+}
@@ -0,0 +1,64 @@
+use std::borrow::Cow;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Number of terminal columns the string occupies. CJK characters take two columns.
+pub fn display_width(s: &str) -> usize {
+    UnicodeWidthStr::width(s)
+}
+
+/// Cut `s` to at most `width` columns, ending with an ellipsis when anything was removed.
+/// Wide characters are never split.
+pub fn truncate_to_width(s: &str, width: usize) -> Cow<'_, str> {
+    if display_width(s) <= width {
+        return Cow::Borrowed(s);
+    }
+    if width == 0 {
+        return Cow::Borrowed("");
+    }
+    // Leave one column for the ellipsis
+    let budget = width - 1;
+    let mut out = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > budget {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.push('…');
+    Cow::Owned(out)
+}
+
+/// Truncate to `width` columns and pad with spaces on the right to exactly `width` columns.
+pub fn pad_to_width(s: &str, width: usize) -> String {
+    let mut out = truncate_to_width(s, width).into_owned();
+    let used = display_width(&out);
+    out.extend(std::iter::repeat_n(' ', width.saturating_sub(used)));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{display_width, pad_to_width, truncate_to_width};
+
+    #[test]
+    fn cjk_counts_two_columns() {
+        assert_eq!(display_width("豊昇龍"), 6);
+        assert_eq!(display_width("Hoshoryu"), 8);
+    }
+
+    #[test]
+    fn truncation_never_splits_wide_chars() {
+        assert_eq!(truncate_to_width("豊昇龍", 4), "豊…");
+        assert_eq!(truncate_to_width("豊昇龍", 6), "豊昇龍");
+        assert_eq!(truncate_to_width("Hoshoryu", 5), "Hosh…");
+    }
+
+    #[test]
+    fn padding_fills_display_columns() {
+        assert_eq!(pad_to_width("大の里", 8), "大の里  ");
+        assert_eq!(display_width(&pad_to_width("豊昇龍", 5)), 5);
+    }
+}
@@ -0,0 +1,337 @@
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Deserializer};
+use std::str::FromStr;
+
+/// Built-in color schemes, selectable with `theme = "..."` in the config or `T` at runtime.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeName {
+    #[default]
+    Default,
+    HighContrast,
+    Monochrome,
+}
+
+impl ThemeName {
+    pub fn next(self) -> Self {
+        match self {
+            ThemeName::Default => ThemeName::HighContrast,
+            ThemeName::HighContrast => ThemeName::Monochrome,
+            ThemeName::Monochrome => ThemeName::Default,
+        }
+    }
+
+    /// i18n key of the theme's display name.
+    pub fn label_key(self) -> &'static str {
+        match self {
+            ThemeName::Default => "theme.default",
+            ThemeName::HighContrast => "theme.high_contrast",
+            ThemeName::Monochrome => "theme.monochrome",
+        }
+    }
+}
+
+/// Styles for each role in the interface. Renderers use these instead of raw colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Header bar, table headers, popup headings
+    pub heading: Style,
+    /// Field labels
+    pub label: Style,
+    /// Footer, secondary labels and hints
+    pub accent: Style,
+    /// Less prominent labels (birth details)
+    pub detail: Style,
+    /// Wins and favorable results
+    pub positive: Style,
+    /// Losses and errors
+    pub negative: Style,
+    /// De-emphasized text
+    pub muted: Style,
+    /// The selected table row
+    pub selected: Style,
+    /// The winner's cell in the torikumi
+    pub winner: Style,
+}
+
+impl Theme {
+    pub fn new(name: ThemeName) -> Self {
+        let fg = |c: Color| Style::default().fg(c);
+        let bold = Modifier::BOLD;
+        match name {
+            ThemeName::Default => Theme {
+                heading: fg(Color::Yellow).add_modifier(bold),
+                label: fg(Color::Yellow),
+                accent: fg(Color::Cyan),
+                detail: fg(Color::Magenta),
+                positive: fg(Color::Green),
+                negative: fg(Color::Red),
+                muted: fg(Color::DarkGray),
+                selected: Style::default().bg(Color::Yellow).fg(Color::Black),
+                winner: Style::default().fg(Color::Black).bg(Color::Green).add_modifier(bold),
+            },
+            // Bright colors only, nothing dim, and bold where color alone carries meaning
+            ThemeName::HighContrast => Theme {
+                heading: fg(Color::White).add_modifier(bold | Modifier::UNDERLINED),
+                label: fg(Color::LightYellow).add_modifier(bold),
+                accent: fg(Color::LightCyan),
+                detail: fg(Color::LightMagenta).add_modifier(bold),
+                positive: fg(Color::LightGreen).add_modifier(bold),
+                negative: fg(Color::LightRed).add_modifier(bold),
+                muted: fg(Color::White),
+                selected: Style::default().bg(Color::White).fg(Color::Black).add_modifier(bold),
+                winner: Style::default().fg(Color::Black).bg(Color::LightGreen).add_modifier(bold),
+            },
+            // No colors at all: emphasis comes from text attributes only
+            ThemeName::Monochrome => Theme {
+                heading: Style::default().add_modifier(bold | Modifier::UNDERLINED),
+                label: Style::default().add_modifier(bold),
+                accent: Style::default(),
+                detail: Style::default().add_modifier(bold),
+                positive: Style::default().add_modifier(bold),
+                negative: Style::default().add_modifier(Modifier::UNDERLINED),
+                muted: Style::default().add_modifier(Modifier::DIM),
+                selected: Style::default().add_modifier(Modifier::REVERSED),
+                winner: Style::default().add_modifier(bold | Modifier::UNDERLINED),
+            },
+        }
+    }
+}
+
+/// Per-role foreground color overrides from the `[colors]` config section. Colors may be
+/// names (`"yellow"`), 256-color indices (`"208"`) or RGB hex (`"#ffaf00"`).
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ThemeColors {
+    #[serde(deserialize_with = "deserialize_color")]
+    pub heading: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub label: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub accent: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub detail: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub positive: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub negative: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub muted: Option<Color>,
+}
+
+/// Read an optional color written as for `[colors]`.
+pub fn deserialize_color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Color>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Color::from_str(&value)
+        .map(Some)
+        .map_err(|_| serde::de::Error::custom(format!("invalid color \"{}\"", value)))
+}
+
+/// How many colors the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+impl ColorSupport {
+    /// Detect support from `COLORTERM` and `TERM`.
+    pub fn detect() -> Self {
+        Self::from_env(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if matches!(colorterm, Some("truecolor") | Some("24bit")) {
+            return ColorSupport::TrueColor;
+        }
+        match term {
+            Some(term) if term.contains("direct") => ColorSupport::TrueColor,
+            Some(term) if term.contains("256color") => ColorSupport::Ansi256,
+            _ => ColorSupport::Ansi16,
+        }
+    }
+
+    /// Map a color to the closest one the terminal can show. Named colors pass through.
+    pub fn adapt(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorSupport::TrueColor, c) => c,
+            (ColorSupport::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_256(r, g, b)),
+            (ColorSupport::Ansi16, Color::Rgb(r, g, b)) => nearest_ansi16((r, g, b)),
+            (ColorSupport::Ansi16, Color::Indexed(i)) => nearest_ansi16(indexed_to_rgb(i)),
+            (_, c) => c,
+        }
+    }
+}
+
+/// Whether the user asked for no colors through the `NO_COLOR` convention.
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+// The 16 ANSI colors with xterm's default RGB values
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+// Levels of the 6x6x6 color cube in the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn nearest_ansi16((r, g, b): (u8, u8, u8)) -> Color {
+    let distance = |(cr, cg, cb): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, cr) + d(g, cg) + d(b, cb)
+    };
+    ANSI16
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map(|(c, _)| *c)
+        .unwrap_or(Color::Reset)
+}
+
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, l)| (**l as i32 - v as i32).abs())
+            .map(|(i, _)| i as u8)
+            .unwrap_or(0)
+    };
+    // Grays get the finer 24-step ramp
+    if r == g && g == b {
+        return match r {
+            0..=3 => 16,
+            247..=255 => 231,
+            v => 232 + ((v as u16).saturating_sub(8) / 10).min(23) as u8,
+        };
+    }
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+fn indexed_to_rgb(i: u8) -> (u8, u8, u8) {
+    match i {
+        0..=15 => ANSI16[i as usize].1,
+        16..=231 => {
+            let i = i - 16;
+            (CUBE_LEVELS[(i / 36) as usize], CUBE_LEVELS[(i / 6 % 6) as usize], CUBE_LEVELS[(i % 6) as usize])
+        }
+        _ => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+impl Theme {
+    /// Build a theme with user color overrides, mapped to what the terminal supports.
+    pub fn build(name: ThemeName, colors: &ThemeColors, support: ColorSupport) -> Self {
+        let mut theme = Theme::new(name);
+        // Monochrome ignores color overrides by design
+        if name != ThemeName::Monochrome {
+            let overrides = [
+                (&mut theme.heading, colors.heading),
+                (&mut theme.label, colors.label),
+                (&mut theme.accent, colors.accent),
+                (&mut theme.detail, colors.detail),
+                (&mut theme.positive, colors.positive),
+                (&mut theme.negative, colors.negative),
+                (&mut theme.muted, colors.muted),
+            ];
+            for (style, color) in overrides {
+                if let Some(color) = color {
+                    *style = style.fg(color);
+                }
+            }
+        }
+        let adapt = |style: Style| Style {
+            fg: style.fg.map(|c| support.adapt(c)),
+            bg: style.bg.map(|c| support.adapt(c)),
+            ..style
+        };
+        Theme {
+            heading: adapt(theme.heading),
+            label: adapt(theme.label),
+            accent: adapt(theme.accent),
+            detail: adapt(theme.detail),
+            positive: adapt(theme.positive),
+            negative: adapt(theme.negative),
+            muted: adapt(theme.muted),
+            selected: adapt(theme.selected),
+            winner: adapt(theme.winner),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::new(ThemeName::Default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ColorSupport, Theme, ThemeColors, ThemeName};
+    use ratatui::style::Color;
+
+    #[test]
+    fn monochrome_uses_no_colors() {
+        let theme = Theme::new(ThemeName::Monochrome);
+        let styles = [
+            theme.heading, theme.label, theme.accent, theme.detail, theme.positive,
+            theme.negative, theme.muted, theme.selected, theme.winner,
+        ];
+        for style in styles {
+            assert!(style.fg.is_none() || style.fg == Some(Color::Reset));
+            assert!(style.bg.is_none() || style.bg == Some(Color::Reset));
+        }
+    }
+
+    #[test]
+    fn themes_cycle_back_to_default() {
+        let name = ThemeName::Default.next().next().next();
+        assert_eq!(name, ThemeName::Default);
+    }
+
+    #[test]
+    fn detects_color_support() {
+        assert_eq!(ColorSupport::from_env(Some("truecolor"), Some("xterm")), ColorSupport::TrueColor);
+        assert_eq!(ColorSupport::from_env(None, Some("screen-256color")), ColorSupport::Ansi256);
+        assert_eq!(ColorSupport::from_env(None, Some("linux")), ColorSupport::Ansi16);
+        assert_eq!(ColorSupport::from_env(None, None), ColorSupport::Ansi16);
+    }
+
+    #[test]
+    fn rgb_degrades_to_palette() {
+        assert_eq!(ColorSupport::Ansi256.adapt(Color::Rgb(255, 135, 0)), Color::Indexed(208));
+        assert_eq!(ColorSupport::Ansi16.adapt(Color::Rgb(250, 10, 10)), Color::LightRed);
+        assert_eq!(ColorSupport::Ansi16.adapt(Color::Indexed(46)), Color::LightGreen);
+        assert_eq!(ColorSupport::Ansi16.adapt(Color::Yellow), Color::Yellow);
+    }
+
+    #[test]
+    fn overrides_are_adapted() {
+        let colors: ThemeColors = toml::from_str("heading = \"#ff8700\"\n").unwrap();
+        let theme = Theme::build(ThemeName::Default, &colors, ColorSupport::Ansi256);
+        assert_eq!(theme.heading.fg, Some(Color::Indexed(208)));
+        assert!(toml::from_str::<ThemeColors>("heading = \"not-a-color\"\n").is_err());
+    }
+}
@@ -1,9 +1,9 @@
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture, KeyCode},
+    event::{DisableMouseCapture, EnableMouseCapture, KeyCode, KeyEvent, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use chrono::Utc;
+use chrono::{Datelike, Utc};
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout},
@@ -13,10 +13,49 @@ use ratatui::{
     Frame, Terminal,
 };
 use std::io;
-use crate::api::{Basho, BanzukeEntry, TorikumiEntry, RikishiDetails, HeadToHeadResponse};
-use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use crate::i18n::{t, tf};
+use crate::romaji::romanize;
+use crate::text::{pad_to_width, truncate_to_width};
+use crate::config::{BanzukeColumn, BanzukeConfig, NavigationConfig, RefreshConfig, TorikumiColumn, TorikumiConfig, TorikumiFilter, TorikumiOrder, Units};
+use crate::keymap::{Action, Keymap, FIXED_BINDINGS, HELP_SECTIONS, key_name};
+use crate::highlight::{self, HighlightRule};
+use crate::kimarite;
+use crate::switcher::{self, Target};
+use crate::achievements::{self, Achievements};
+use crate::form;
+use crate::yusho_history;
+use crate::rank;
+use crate::rivals::{self, Rival};
+use crate::theme::{ColorSupport, Theme, ThemeColors, ThemeName};
+use crate::compare::{Comparison, MAX_COMPARED};
+use crate::schedule::{self, Outcome};
+use crate::summary::DaySummary;
+use crate::stats::{CareerRecords, DivisionStats, Extremes, Superlative};
+use crate::journal::Journal;
+use crate::dashboard::Tracked;
+use crate::matchups::{Matrix, Meeting};
+use crate::projection::{self, Projection};
+use crate::settings::{self, Setting};
+use crate::api::{Basho, BanzukeEntry, Health, RikishiStats, TorikumiEntry, RikishiDetails, HeadToHeadMatch, HeadToHeadResponse};
+use std::collections::{HashMap, HashSet};
 
-const DIVISIONS: &[&str] = &["Makuuchi", "Juryo", "Makushita", "Sandanme", "Jonidan", "Jonokuchi"];
+mod widgets;
+
+pub use widgets::{Popup, PopupStack, Selection};
+use widgets::{Acceleration, BanzukePanel, TorikumiTable};
+
+/// Seconds between days when replay auto-advance is toggled on without `--replay-interval`.
+pub const DEFAULT_REPLAY_INTERVAL_SECS: u64 = 10;
+// How long a toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(6);
+
+// Days in a basho, and days per row of the day grid
+const BASHO_DAYS: u8 = 15;
+const DAY_GRID_COLUMNS: u8 = 5;
+
+pub const DIVISIONS: &[&str] = &["Makuuchi", "Juryo", "Makushita", "Sandanme", "Jonidan", "Jonokuchi"];
 
 #[derive(Clone, PartialEq)]
 pub enum InputMode {
@@ -24,6 +63,25 @@ pub enum InputMode {
     EditingDay,
     SelectingDivision,
     EditingBasho,
+    EditingSpoilerDay,
+    EnteringCommand,
+    FindingBout,
+    JumpingToRank,
+    WritingNote,
+    // Typing into the quick switcher
+    Switching,
+    // Typing a basho, division and day to go to at once
+    EnteringGoto,
+    // Waiting for the key to bind to the selected action in the settings view
+    BindingKey,
+}
+
+/// What the day grid shows for a day of the basho.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayStatus {
+    Results,
+    Pending,
+    Future,
 }
 
 pub struct App {
@@ -32,35 +90,256 @@ pub struct App {
     pub banzuke: Option<Vec<BanzukeEntry>>,
     pub torikumi: Option<Vec<TorikumiEntry>>,
     pub current_view: AppView,
-    pub selected_index: usize,
     pub division: String,
     pub day: u8,
     pub basho_id: String,
     pub show_help: bool,
-    pub scroll_offset: usize,
+    pub help_scroll: usize,
+    pub help_filter: String,
+    // Typing into the help search box
+    pub help_filtering: bool,
+    pub keymap: Keymap,
+    /// Units shown first in rikishi details
+    pub units: Units,
+    /// Show ranks in kanji in the banzuke and rikishi details
+    pub kanji_ranks: bool,
+    /// Nothing appears or disappears on a timer: notices go to the status line instead
+    pub reduced_motion: bool,
+    /// Wrapping, acceleration and scrolling of list movement
+    pub navigation: NavigationConfig,
+    acceleration: Acceleration,
+    /// Newer release found by the startup version check
+    pub update_available: Option<String>,
+    /// Connection health from recent API requests, shown in the footer
+    pub connection: Option<Health>,
+    // Table rows that fit in the current terminal
+    pub viewport_rows: usize,
     // Map rikishi id -> (wins, losses)
     pub record_map: HashMap<u32, (u8, u8)>,
     pub input_mode: InputMode,
     pub input_buffer: String,
     pub needs_reload: bool,
     pub division_selector_index: usize,
+    // Day highlighted in the day grid
+    pub day_cursor: u8,
     pub show_rikishi_details: bool,
     pub rikishi_details: Option<RikishiDetails>,
     pub requested_rikishi_id: Option<u32>,
+    // Most-faced opponents of the rikishi in the details popup: (rikishi id, rivals), once loaded
+    pub rivals: Option<(u32, Vec<Rival>)>,
+    // Rival highlighted in the details popup
+    pub rival_index: usize,
+    // Kinboshi and jun-yusho of the rikishi in the details popup, once their bouts are in
+    pub achievements: Option<Achievements>,
+    // Career bouts of the rikishi in the details popup, without any whose results are hidden
+    pub bout_history: Option<(u32, Vec<HeadToHeadMatch>)>,
+    // The details popup compares this basho with the last one instead of listing rivals
+    pub show_previous_basho: bool,
     pub show_head_to_head: bool,
-    pub head_to_head_data: Option<HeadToHeadResponse>,
+    // With the rikishi whose wins the response counts as `rikishi_wins`
+    pub head_to_head_data: Option<(u32, HeadToHeadResponse)>,
     pub requested_head_to_head: Option<(u32, u32)>, // (rikishi_id, opponent_id)
+    // Day picked in the day grid whose torikumi to look for before loading it
+    pub requested_day_check: Option<u8>,
+    pub checking_day: Option<u8>,
+    // Detail popup shown as loading until its fetch is in, with the error once it failed
+    pub pending: Option<(Pending, Option<String>)>,
+    // Rikishi marked for the comparison view, in marking order
+    pub compare_ids: Vec<u32>,
+    pub comparison: Comparison,
+    // Career totals by rikishi id, for the comparison and records views
+    pub career_stats: HashMap<u32, RikishiStats>,
+    // Whole bout history by rikishi id, for the records view
+    pub career_bouts: HashMap<u32, Vec<HeadToHeadMatch>>,
+    // Fetch whatever the comparison view is missing
+    pub compare_requested: bool,
+    // Fetch rikishi profiles for the stats view
+    pub profiles_requested: bool,
+    // The stats view shows the heaviest, tallest, ... instead of the breakdowns
+    pub show_extremes: bool,
+    // Fetch career stats and bout histories for the records view
+    pub career_stats_requested: bool,
+    // Rikishi whose basho the schedule view shows
+    pub schedule_rikishi: Option<u32>,
+    // Favorites across every division for the dashboard, once loaded
+    pub dashboard: Option<Vec<Tracked>>,
+    // Load the dashboard for the current basho and day
+    pub dashboard_requested: bool,
+    // Remaining opponents of the rikishi picked in the matchup grid, shown while set
+    pub projection: Option<Projection>,
+    // Load head-to-head records for the projection: (rikishi id, opponent ids)
+    pub projection_requested: Option<(u32, Vec<u32>)>,
+    // Yusho winners of recent basho, newest first, once loaded
+    pub yusho_history: Option<yusho_history::History>,
+    // Load the yusho history up to the current basho
+    pub yusho_history_requested: bool,
+    // Division whose winners the yusho history lists
+    pub yusho_division: String,
+    // API data behind the selection or popup, shown over everything while set
+    pub inspector: Option<Inspector>,
+    // Shikona to look for on the current day across every division
+    pub find_bout_requested: Option<String>,
+    // Rikishi whose bout to select once the division being loaded arrives
+    pub pending_bout_focus: Option<u32>,
+    // Rikishi selected before the reload in flight, selected again once it is in
+    pub followed: Followed,
+    // Summary popup of the loaded day, shown while set
+    pub day_summary: Option<DaySummary>,
+    // Short notice in the top corner and when it was raised
+    pub toast: Option<(String, Instant)>,
+    /// How the torikumi is sorted; `torikumi` is kept in this order
+    pub torikumi_order: TorikumiOrder,
+    /// Which bouts the torikumi table shows
+    pub torikumi_filter: TorikumiFilter,
+    torikumi_table: TorikumiTable,
+    banzuke_panel: BanzukePanel,
+    // Selection in each of the other views, kept while another view is shown
+    list_selections: HashMap<AppView, Selection>,
     pub loading_overlay: Option<String>,
     pub status_message: Option<String>,
     pub basho_changed: bool,
     pub input_error: Option<String>,
+    pub screenshot_requested: bool,
+    /// Commands registered by user scripts, offered at the `:` prompt
+    pub script_commands: Vec<String>,
+    /// Script command to run, set from the `:` prompt
+    pub command_requested: Option<String>,
+    /// Basho with responses in the disk cache, offered by the quick switcher
+    pub cached_basho: Vec<String>,
+    // Everything the quick switcher can jump to, gathered as it opens, and the selected
+    // match
+    switcher: Vec<switcher::Entry>,
+    switcher_index: usize,
+    pub suspend_requested: bool,
+    pub replay: Option<Replay>,
+    // Results on this day and later are hidden (spoiler-free mode)
+    pub spoiler_from: Option<u8>,
+    // Torikumi ids of hidden bouts the user has revealed one at a time
+    pub revealed_bouts: HashSet<String>,
+    // Live mode: periodically refresh the current day in place
+    pub live: bool,
+    // Favorite shikona; index 0 is bound to Alt-1
+    pub favorites: Vec<String>,
+    pub torikumi_columns: Vec<TorikumiColumn>,
+    pub banzuke_columns: Vec<BanzukeColumn>,
+    // Profiles of active rikishi, loaded only when a banzuke column or highlight rule needs them
+    pub rikishi_profiles: HashMap<u32, RikishiDetails>,
+    // ("basho" key, rikishi id -> rank in any division at the previous basho) for the
+    // rank-change column
+    pub previous_ranks: Option<(String, HashMap<u32, String>)>,
+    // ("basho/division" key, records at the last few basho) for the recent column
+    pub recent_records: Option<(String, crate::recent::Recent)>,
+    recent_records_requested: Option<String>,
+    // ("basho/division/day" key, career series of the day's bouts) for the head-to-head column
+    pub bout_series: Option<(String, crate::series::Series)>,
+    bout_series_requested: Option<String>,
+    // ("basho/division" key, days each rikishi fought on) to reveal lower-division
+    // records day by day
+    bout_days: Option<(String, schedule::BoutDays)>,
+    bout_days_requested: Option<String>,
+    // ("basho/division" key, whether it had a playoff) once checked after the basho
+    playoff_checked: Option<(String, bool)>,
+    /// (requested day, reason) when the loaded day isn't the one asked for, until the next load
+    pub day_adjustment: Option<(u8, DayAdjustment)>,
+    /// Styles for rikishi matching the `[[highlight]]` rules in the torikumi and banzuke
+    pub highlight_rules: Vec<HighlightRule>,
+    pub theme_name: ThemeName,
+    pub theme_colors: ThemeColors,
+    pub color_support: ColorSupport,
+    pub theme: Theme,
+    /// Notes written with `n`, shown in the journal view
+    pub journal: Journal,
+    // Where the journal is saved; None when it couldn't be loaded, so a broken file
+    // isn't overwritten
+    pub journal_path: Option<PathBuf>,
+    /// Config file the settings view writes changes to
+    pub config_path: Option<PathBuf>,
+    /// Division opened at startup, as set in the config
+    pub default_division: String,
+    /// Seconds between live refreshes during bout hours
+    pub refresh_interval_secs: u64,
+    // The torikumi and banzuke rows need formatting again before the next frame
+    rows_stale: bool,
 }
 
-#[derive(Clone, PartialEq)]
+/// A pre-formatted table cell, so drawing a frame only has to truncate the visible ones.
+struct CachedCell {
+    text: String,
+    // Highlighted as the bout's winner
+    winner: bool,
+    // From the user's highlight rules
+    style: Style,
+}
+
+impl CachedCell {
+    fn plain(text: String) -> Self {
+        Self { text, winner: false, style: Style::default() }
+    }
+}
+
+/// Day-by-day replay of a finished basho. Results are only revealed up to `App::day`.
+pub struct Replay {
+    pub auto_advance: bool,
+    pub interval: Duration,
+    pub last_step: Instant,
+}
+
+/// Pretty-printed API data for the JSON inspector.
+pub struct Inspector {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub scroll: usize,
+}
+
+/// The rikishi selected in the torikumi and banzuke, to find again in reloaded data.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Followed {
+    /// Both rikishi of the selected bout, east first
+    pub bout: Vec<u32>,
+    pub banzuke: Option<u32>,
+}
+
+/// A detail popup whose data is still being fetched.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pending {
+    Rikishi(u32),
+    /// (rikishi id, opponent id)
+    HeadToHead(u32, u32),
+}
+
+/// Why a load showed another day than the one asked for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayAdjustment {
+    /// The basho hasn't started, so it opens on day 1
+    Future,
+    /// A finished basho opens on its last day, or day 1 for a replay
+    Finished,
+    /// The day was past the last of the division, playoff included
+    DivisionDays,
+}
+
+/// One line of the help overlay.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HelpLine {
+    Section(&'static str),
+    // Key labels and the i18n key of the description
+    Key(String, &'static str),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum AppView {
     Torikumi,
     Banzuke,
     BashoInfo,
+    Compare,
+    Stats,
+    Records,
+    Schedule,
+    Journal,
+    Dashboard,
+    Matchups,
+    YushoHistory,
+    Settings,
 }
 
 impl App {
@@ -71,528 +350,2309 @@ impl App {
             banzuke: None,
             torikumi: None,
             current_view: AppView::Torikumi,
-            selected_index: 0,
             division,
             day,
             basho_id,
             show_help: false,
-            scroll_offset: 0,
+            help_scroll: 0,
+            help_filter: String::new(),
+            help_filtering: false,
+            keymap: Keymap::default(),
+            units: Units::default(),
+            kanji_ranks: false,
+            reduced_motion: false,
+            navigation: NavigationConfig::default(),
+            acceleration: Acceleration::default(),
+            update_available: None,
+            connection: None,
+            viewport_rows: 10,
             record_map: HashMap::new(),
             input_mode: InputMode::Normal,
             input_buffer: String::new(),
             needs_reload: false,
             division_selector_index: 0,
+            day_cursor: 1,
             show_rikishi_details: false,
             rikishi_details: None,
             requested_rikishi_id: None,
+            rivals: None,
+            rival_index: 0,
+            achievements: None,
+            bout_history: None,
+            show_previous_basho: false,
             show_head_to_head: false,
             head_to_head_data: None,
             requested_head_to_head: None,
+            requested_day_check: None,
+            checking_day: None,
+            pending: None,
+            compare_ids: Vec::new(),
+            comparison: Comparison::default(),
+            career_stats: HashMap::new(),
+            career_bouts: HashMap::new(),
+            compare_requested: false,
+            profiles_requested: false,
+            show_extremes: false,
+            career_stats_requested: false,
+            schedule_rikishi: None,
+            dashboard: None,
+            dashboard_requested: false,
+            projection: None,
+            yusho_history: None,
+            yusho_history_requested: false,
+            yusho_division: DIVISIONS[0].to_string(),
+            inspector: None,
+            projection_requested: None,
+            find_bout_requested: None,
+            pending_bout_focus: None,
+            followed: Followed::default(),
+            day_summary: None,
+            toast: None,
+            torikumi_order: TorikumiOrder::Match,
+            torikumi_filter: TorikumiFilter::All,
+            torikumi_table: TorikumiTable::default(),
+            banzuke_panel: BanzukePanel::default(),
+            list_selections: HashMap::new(),
             loading_overlay: None,
             status_message: None,
             basho_changed: false,
             input_error: None,
+            screenshot_requested: false,
+            script_commands: Vec::new(),
+            command_requested: None,
+            cached_basho: Vec::new(),
+            switcher: Vec::new(),
+            switcher_index: 0,
+            suspend_requested: false,
+            replay: None,
+            spoiler_from: None,
+            revealed_bouts: HashSet::new(),
+            live: false,
+            favorites: Vec::new(),
+            torikumi_columns: TorikumiConfig::default().columns,
+            banzuke_columns: BanzukeConfig::default().columns,
+            rikishi_profiles: HashMap::new(),
+            previous_ranks: None,
+            recent_records: None,
+            recent_records_requested: None,
+            bout_series: None,
+            bout_series_requested: None,
+            bout_days: None,
+            bout_days_requested: None,
+            playoff_checked: None,
+            day_adjustment: None,
+            highlight_rules: Vec::new(),
+            theme_name: ThemeName::Default,
+            theme_colors: ThemeColors::default(),
+            color_support: ColorSupport::TrueColor,
+            theme: Theme::default(),
+            journal: Journal::default(),
+            journal_path: None,
+            config_path: None,
+            default_division: DIVISIONS[0].to_string(),
+            refresh_interval_secs: RefreshConfig::default().interval_secs,
+            rows_stale: true,
         }
     }
 
-    pub fn set_basho(&mut self, basho: Basho) {
-        self.basho = Some(basho);
+    /// Mark the cached table rows as out of date, e.g. after loading profiles or
+    /// changing columns; they are rebuilt before the next frame.
+    pub fn invalidate_rows(&mut self) {
+        self.rows_stale = true;
     }
 
-    pub fn set_banzuke(&mut self, banzuke: Vec<BanzukeEntry>) {
-        // Store banzuke
-        self.banzuke = Some(banzuke);
-        // Recompute records map
-        self.recompute_records();
+    // Rebuild the cached table rows if anything behind them changed
+    fn refresh_rows(&mut self) {
+        if !std::mem::take(&mut self.rows_stale) {
+            return;
+        }
+        let torikumi_columns = self.torikumi_columns();
+        let torikumi = self.shown_bouts().map(|bout| torikumi_cells(self, &torikumi_columns, bout)).collect();
+        self.torikumi_table.set_rows(torikumi);
+        let banzuke_columns = self.banzuke_columns();
+        let today = Utc::now().date_naive();
+        let banzuke = self
+            .banzuke
+            .iter()
+            .flatten()
+            .map(|entry| banzuke_cells(self, &banzuke_columns, entry, self.revealed_for(entry), today))
+            .collect();
+        let banzuke_styles = self
+            .banzuke
+            .iter()
+            .flatten()
+            .map(|entry| self.highlight(entry.rikishi_id, &entry.shikona_en, &entry.rank))
+            .collect();
+        let sections = banzuke_sections(self.banzuke.as_deref().unwrap_or_default());
+        self.banzuke_panel.set_rows(banzuke, banzuke_styles, sections);
     }
 
-    pub fn set_torikumi(&mut self, torikumi: Vec<TorikumiEntry>) {
-        let len = torikumi.len();
-        self.torikumi = Some(torikumi);
+    // Style of a rikishi's name from the highlight rules, in colors the terminal and theme
+    // allow
+    fn highlight(&self, rikishi_id: u32, shikona: &str, rank: &str) -> Style {
+        let Some(style) = highlight::style(&self.highlight_rules, shikona, rank, self.rikishi_profiles.get(&rikishi_id)) else {
+            return Style::default();
+        };
+        let color = |c: Option<Color>| c.filter(|_| self.theme_name != ThemeName::Monochrome).map(|c| self.color_support.adapt(c));
+        Style { fg: color(style.fg), bg: color(style.bg), ..style }
+    }
 
-        if self.current_view == AppView::Torikumi {
-            if len == 0 {
-                self.selected_index = 0;
-                self.scroll_offset = 0;
-            } else if self.selected_index >= len {
-                self.selected_index = len - 1;
-                if self.scroll_offset > self.selected_index {
-                    self.scroll_offset = self.selected_index;
-                }
-            }
+    // Color of a kimarite by its category, left plain in monochrome and for kimarite the
+    // categories don't know
+    fn kimarite_style(&self, category: Option<kimarite::Category>) -> Style {
+        match category {
+            Some(category) if self.theme_name != ThemeName::Monochrome => Style::default().fg(self.color_support.adapt(category.color())),
+            _ => Style::default(),
         }
     }
 
-    pub fn clear_torikumi(&mut self) {
-        self.torikumi = None;
-        if self.current_view == AppView::Torikumi {
-            self.selected_index = 0;
-            self.scroll_offset = 0;
-        }
+    fn torikumi_columns(&self) -> Vec<TorikumiColumn> {
+        if self.torikumi_columns.is_empty() { TorikumiConfig::default().columns } else { self.torikumi_columns.clone() }
     }
 
-    fn recompute_records(&mut self) {
-        self.record_map.clear();
-        if let Some(list) = &self.banzuke {
-            for entry in list {
-                let mut wins: u8 = 0;
-                let mut losses: u8 = 0;
-                if let Some(records) = &entry.record {
-                    for r in records {
-                        let s = r.result.trim();
-                        let sl = s.to_lowercase();
-                        // Heuristics: support common encodings of results
-                        if sl == "w" || sl == "win" || sl.contains("win") || s == "○" {
-                            wins = wins.saturating_add(1);
-                        } else if sl == "l" || sl == "loss" || sl.contains("loss") || s == "●" {
-                            losses = losses.saturating_add(1);
-                        }
-                    }
-                }
-                self.record_map.insert(entry.rikishi_id, (wins, losses));
-            }
-        }
+    fn banzuke_columns(&self) -> Vec<BanzukeColumn> {
+        if self.banzuke_columns.is_empty() { BanzukeConfig::default().columns } else { self.banzuke_columns.clone() }
     }
 
-    pub fn on_key(&mut self, key: KeyCode) {
-        // Handle input mode first
-        match self.input_mode {
-            InputMode::Normal => {
-                match key {
-                    KeyCode::Char('q') => self.should_quit = true,
-                    KeyCode::Char('h') | KeyCode::F(1) => self.show_help = !self.show_help,
-                    KeyCode::Char('c') => {
-                        self.input_mode = InputMode::EditingDay;
-                        self.input_buffer.clear();
-                        self.input_error = None;
-                    },
-                    KeyCode::Char('v') => {
-                        self.input_mode = InputMode::SelectingDivision;
-                        // Find current division index
-                        self.division_selector_index = DIVISIONS.iter()
-                            .position(|&d| d == self.division)
-                            .unwrap_or(0);
-                        self.input_error = None;
-                    },
-                    KeyCode::Char('b') => {
-                        self.input_mode = InputMode::EditingBasho;
-                        self.input_buffer.clear();
-                        self.input_error = None;
-                    },
-                    KeyCode::Char('1') => {
-                        self.current_view = AppView::Torikumi;
-                        self.selected_index = 0;
-                        self.scroll_offset = 0;
-                    },
-                    KeyCode::Char('2') => {
-                        self.current_view = AppView::Banzuke;
-                        self.selected_index = 0;
-                        self.scroll_offset = 0;
-                    },
-                    KeyCode::Char('3') => {
-                        self.current_view = AppView::BashoInfo;
-                        self.selected_index = 0;
-                        self.scroll_offset = 0;
-                    },
-                    // Page navigation with a/d and left/right arrows
-                    KeyCode::Char('a') | KeyCode::Left => {
-                        match self.current_view {
-                            AppView::Torikumi => {
-                                // Already at first page, do nothing
-                            },
-                            AppView::Banzuke => {
-                                self.current_view = AppView::Torikumi;
-                                self.selected_index = 0;
-                                self.scroll_offset = 0;
-                            },
-                            AppView::BashoInfo => {
-                                self.current_view = AppView::Banzuke;
-                                self.selected_index = 0;
-                                self.scroll_offset = 0;
-                            },
-                        }
-                    },
-                    KeyCode::Char('d') | KeyCode::Right => {
-                        match self.current_view {
-                            AppView::Torikumi => {
-                                self.current_view = AppView::Banzuke;
-                                self.selected_index = 0;
-                                self.scroll_offset = 0;
-                            },
-                            AppView::Banzuke => {
-                                self.current_view = AppView::BashoInfo;
-                                self.selected_index = 0;
-                                self.scroll_offset = 0;
-                            },
-                            AppView::BashoInfo => {
-                                // Already at last page, do nothing
-                            },
-                        }
-                    },
-                    // WASD navigation
-                    KeyCode::Char('w') | KeyCode::Up => {
-                        if self.selected_index > 0 {
-                            self.selected_index -= 1;
-                            if self.selected_index < self.scroll_offset {
-                                self.scroll_offset = self.selected_index;
-                            }
-                        }
-                    }
-                    KeyCode::Char('s') | KeyCode::Down => {
-                        let max_index = match self.current_view {
-                            AppView::Torikumi => self.torikumi.as_ref().map(|t| t.len()).unwrap_or(0),
-                            AppView::Banzuke => self.banzuke.as_ref().map(|b| b.len()).unwrap_or(0),
-                            AppView::BashoInfo => 0,
-                        };
-                        if self.selected_index + 1 < max_index {
-                            self.selected_index += 1;
-                            // Adjust scroll if selection goes beyond visible area (assume 10 visible items)
-                            let visible_items = 10;
-                            if self.selected_index >= self.scroll_offset + visible_items {
-                                self.scroll_offset = self.selected_index - visible_items + 1;
-                            }
-                        }
-                    }
-                    KeyCode::Enter | KeyCode::Char(' ') => {
-                        // If in banzuke view, show rikishi details
-                        if self.current_view == AppView::Banzuke {
-                            if let Some(banzuke) = &self.banzuke {
-                                if self.selected_index < banzuke.len() {
-                                    let rikishi_id = banzuke[self.selected_index].rikishi_id;
-                                    self.requested_rikishi_id = Some(rikishi_id);
-                                }
-                            }
-                        }
-                        // If in torikumi view, show head-to-head
-                        else if self.current_view == AppView::Torikumi {
-                            if let Some(torikumi) = &self.torikumi {
-                                if self.selected_index < torikumi.len() {
-                                    let match_entry = &torikumi[self.selected_index];
-                                    let east_id = match_entry.east_id;
-                                    let west_id = match_entry.west_id;
-                                    self.requested_head_to_head = Some((east_id, west_id));
-                                }
-                            }
-                        }
-                    }
-                    KeyCode::Esc => {
-                        if self.show_rikishi_details {
-                            self.show_rikishi_details = false;
-                            self.rikishi_details = None;
-                        } else if self.show_head_to_head {
-                            self.show_head_to_head = false;
-                            self.head_to_head_data = None;
-                        } else {
-                            self.show_help = false;
-                        }
-                    }
-                    _ => {}
-                }
-            },
-            InputMode::EditingDay => {
-                match key {
-                    KeyCode::Char(c) if c.is_ascii_digit() => {
-                        if self.input_buffer.len() < 2 {
-                            self.input_buffer.push(c);
-                            self.input_error = None;
-                        }
-                    },
-                    KeyCode::Backspace => {
-                        self.input_buffer.pop();
-                        self.input_error = None;
-                    },
-                    KeyCode::Enter => {
-                        if let Ok(day) = self.input_buffer.parse::<u8>() {
-                            if (1..=15).contains(&day) {
-                                self.day = day;
-                                self.needs_reload = true;
-                                self.input_mode = InputMode::Normal;
-                                self.input_buffer.clear();
-                                self.input_error = None;
-                            } else {
-                                self.input_error = Some("Invalid day/basho".to_string());
-                            }
-                        } else {
-                            self.input_error = Some("Invalid day/basho".to_string());
-                        }
-                    },
-                    KeyCode::Esc => {
-                        self.input_mode = InputMode::Normal;
-                        self.input_buffer.clear();
-                        self.input_error = None;
-                    },
-                    _ => {}
-                }
-            },
-            InputMode::SelectingDivision => {
-                match key {
-                    KeyCode::Up => {
-                        if self.division_selector_index > 0 {
-                            self.division_selector_index -= 1;
-                        }
-                    },
-                    KeyCode::Down => {
-                        if self.division_selector_index + 1 < DIVISIONS.len() {
-                            self.division_selector_index += 1;
-                        }
-                    },
-                    KeyCode::Enter => {
-                        self.division = DIVISIONS[self.division_selector_index].to_string();
-                        self.needs_reload = true;
-                        self.input_mode = InputMode::Normal;
-                        self.input_error = None;
-                    },
-                    KeyCode::Esc => {
-                        self.input_mode = InputMode::Normal;
-                        self.input_error = None;
-                    },
-                    _ => {}
-                }
-            },
-            InputMode::EditingBasho => {
-                match key {
-                    KeyCode::Char(c) if c.is_ascii_digit() => {
-                        if self.input_buffer.len() < 6 {
-                            self.input_buffer.push(c);
-                            self.input_error = None;
-                        }
-                    },
-                    KeyCode::Backspace => {
-                        self.input_buffer.pop();
-                        self.input_error = None;
-                    },
-                    KeyCode::Enter => {
-                        let mut valid = false;
-                        if self.input_buffer.len() == 6 {
-                            if let Ok(year) = self.input_buffer[0..4].parse::<i32>() {
-                                if let Ok(month) = self.input_buffer[4..6].parse::<u32>() {
-                                    if year >= 1958 && month >= 1 && month <= 12 && month % 2 == 1 {
-                                        self.basho_id = self.input_buffer.clone();
-                                        self.basho_changed = true;
-                                        self.needs_reload = true;
-                                        self.input_mode = InputMode::Normal;
-                                        self.input_buffer.clear();
-                                        self.input_error = None;
-                                        valid = true;
-                                    }
-                                }
-                            }
-                        }
-                        if !valid {
-                            self.input_error = Some("Invalid day/basho".to_string());
-                        }
-                    },
-                    KeyCode::Esc => {
-                        self.input_mode = InputMode::Normal;
-                        self.input_buffer.clear();
-                        self.input_error = None;
-                    },
-                    _ => {}
-                }
-            },
+    /// Key of the recent records to fetch, once per loaded banzuke while the recent column
+    /// is shown.
+    pub fn request_recent_records(&mut self) -> Option<String> {
+        let key = format!("{}/{}", self.basho_id, self.division);
+        if self.banzuke.is_none() || !self.banzuke_columns().contains(&BanzukeColumn::Recent) {
+            return None;
+        }
+        if self.recent_records_requested.as_ref() == Some(&key) {
+            return None;
         }
+        self.recent_records_requested = Some(key.clone());
+        Some(key)
     }
-}
 
-pub fn ui(f: &mut Frame, app: &mut App) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .margin(1)
-        .constraints([
-            Constraint::Length(3), // Header
-            Constraint::Min(0),    // Main content
-            Constraint::Length(3), // Footer
-        ])
-        .split(f.area());
+    // Whether records count bouts rather than days and are only revealed up to a day,
+    // so each bout has to be mapped to the day it was fought on
+    fn needs_bout_days(&self) -> bool {
+        (self.replay.is_some() || self.spoiler_from.is_some()) && crate::max_day_for_division(&self.division) < BASHO_DAYS
+    }
 
-    // Header
-    let basho_date = crate::api::SumoApi::format_basho_date(&app.basho_id);
-    let basho_month: u32 = app.basho_id[4..6].parse().unwrap_or(9);
-    let basho_name = crate::api::SumoApi::get_basho_name(basho_month);
-    
-    let header_text = if basho_has_started(app) {
-        format!(
-            "{} - {} {} - Day {}",
-            basho_name, basho_date, app.division, app.day
-        )
-    } else {
-        format!(
-            "{} - {} {} (Upcoming)",
-            basho_name, basho_date, app.division
-        )
-    };
+    /// Key the loaded basho and division's bout days are stored under.
+    pub fn bout_days_key(&self) -> String {
+        format!("{}/{}", self.basho_id, self.division)
+    }
 
-    let header = Paragraph::new(header_text)
-    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
-    .alignment(Alignment::Center)
-    .block(Block::default().borders(Borders::ALL).title("Sumo TUI"));
+    /// Key of the bout days to fetch, once per loaded banzuke of a lower division while
+    /// replay or spoiler-free mode holds results back.
+    pub fn request_bout_days(&mut self) -> Option<String> {
+        let key = self.bout_days_key();
+        if self.banzuke.is_none() || !self.needs_bout_days() || self.bout_days_requested.as_ref() == Some(&key) {
+            return None;
+        }
+        self.bout_days_requested = Some(key.clone());
+        Some(key)
+    }
 
-    f.render_widget(header, chunks[0]);
+    // Key of the loaded day's bouts
+    fn bout_series_key(&self) -> String {
+        format!("{}/{}/{}", self.basho_id, self.division, self.day)
+    }
 
-    // Main content
-    match app.current_view {
-        AppView::Torikumi => render_torikumi(f, chunks[1], app),
-        AppView::Banzuke => render_banzuke(f, chunks[1], app),
-        AppView::BashoInfo => render_basho_info(f, chunks[1], app),
+    /// Key of the career series to fetch in the background, once per loaded day while the
+    /// head-to-head or edge column is shown.
+    pub fn request_bout_series(&mut self) -> Option<String> {
+        let key = self.bout_series_key();
+        let columns = self.torikumi_columns();
+        if self.torikumi.as_ref().is_none_or(|t| t.is_empty())
+            || !columns.iter().any(|c| matches!(c, TorikumiColumn::HeadToHead | TorikumiColumn::Edge))
+        {
+            return None;
+        }
+        if self.bout_series_requested.as_ref() == Some(&key) {
+            return None;
+        }
+        self.bout_series_requested = Some(key.clone());
+        Some(key)
     }
 
-    // Footer
-    let footer_text = "q: Quit | 1: Torikumi | 2: Banzuke | 3: Info | c: Day | v: Division | b: Basho | h: Help";
-    let mut footer_lines = vec![Line::from(footer_text)];
-    if let Some(status) = &app.status_message {
-        footer_lines.push(Line::from(status.clone()));
+    /// Enter replay mode at the current day, optionally advancing automatically every `interval`.
+    pub fn start_replay(&mut self, interval: Duration, auto_advance: bool) {
+        self.replay = Some(Replay {
+            auto_advance,
+            interval,
+            last_step: Instant::now(),
+        });
+        self.recompute_records();
     }
 
-    let footer = Paragraph::new(footer_lines)
-        .style(Style::default().fg(Color::Cyan))
-        .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL));
+    /// A rank as the banzuke and details show it: in kanji when configured (and the rank
+    /// parses), otherwise romanized.
+    pub fn rank_text(&self, rank: &str) -> String {
+        match rank::Rank::parse(rank).filter(|_| self.kanji_ranks) {
+            Some(parsed) => parsed.kanji(),
+            None => romanize(rank).into_owned(),
+        }
+    }
 
-    f.render_widget(footer, chunks[2]);
+    /// Number of days whose results count towards records: the days before the replay day
+    /// in replay mode (including the replay day once all its bouts are revealed),
+    /// otherwise the whole basho.
+    pub fn revealed_days(&self) -> u8 {
+        let days = if self.replay.is_some() {
+            if self.day_fully_revealed() { self.day } else { self.day.saturating_sub(1) }
+        } else {
+            BASHO_DAYS
+        };
+        match self.spoiler_from {
+            Some(from) => days.min(from.saturating_sub(1)),
+            None => days,
+        }
+    }
 
-    // Help popup
-    if app.show_help {
-        render_help_popup(f);
+    /// Last day whose results are shown while replay or spoiler-free mode holds later ones
+    /// back; `None` when nothing is hidden.
+    pub fn last_visible_day(&self) -> Option<u8> {
+        (self.replay.is_some() || self.spoiler_from.is_some()).then(|| self.revealed_days())
     }
-    
-    // Input popups
-    match app.input_mode {
-        InputMode::EditingDay => render_input_popup(f, "Day (1-15)", &app.input_buffer, app.input_error.as_deref()),
-        InputMode::SelectingDivision => render_division_selector(f, app.division_selector_index),
-        InputMode::EditingBasho => render_input_popup(f, "Basho (YYYYMM, e.g., 202501)", &app.input_buffer, app.input_error.as_deref()),
-        InputMode::Normal => {},
+
+    /// Number of entries of `entry`'s record that count towards records: those fought in
+    /// the first `revealed_days()` days.
+    pub fn revealed_for(&self, entry: &BanzukeEntry) -> u8 {
+        self.bouts_through(entry, self.revealed_days())
     }
-    
-    // Rikishi details popup
-    if app.show_rikishi_details {
-        if let Some(details) = &app.rikishi_details {
-            render_rikishi_details(f, details);
+
+    /// Number of entries of `entry`'s record fought by the end of `day`. Sekitori have one
+    /// entry a day; below Juryo the record lists bouts, which are mapped to their days and
+    /// left out until those days are known.
+    pub fn bouts_through(&self, entry: &BanzukeEntry, day: u8) -> u8 {
+        let bouts = crate::max_day_for_division(&self.division);
+        if bouts == BASHO_DAYS {
+            return day;
         }
-    }
-    
-    // Head-to-head popup
-    if app.show_head_to_head {
-        if let Some(h2h) = &app.head_to_head_data {
-            render_head_to_head(f, h2h);
+        if day >= BASHO_DAYS {
+            return bouts;
+        }
+        match &self.bout_days {
+            Some((key, bout_days)) if *key == self.bout_days_key() => {
+                bout_days.get(&entry.rikishi_id).map_or(0, |d| schedule::bouts_through(d, day))
+            }
+            _ => 0,
         }
     }
 
-    if let Some(message) = &app.loading_overlay {
-        let area = centered_rect(50, 20, f.area());
-        f.render_widget(Clear, area);
-
-        let paragraph = Paragraph::new(message.clone())
-            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
-            .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).title("Please wait"));
-
-        f.render_widget(paragraph, area);
+    /// Whether winners and kimarite for bouts on `day` are hidden, either by spoiler-free
+    /// mode or because replay has not moved past that day yet.
+    pub fn results_hidden(&self, day: u8) -> bool {
+        self.spoiler_from.is_some_and(|from| day >= from)
+            || (self.replay.is_some() && day >= self.day)
     }
-}
 
-fn render_torikumi(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
-    if let Some(torikumi) = &app.torikumi {
-        if torikumi.is_empty() {
-            let message = if basho_has_started(app) {
-                "No matches available for the selected day."
-            } else {
-                "This basho has not started yet."
-            };
+    /// Whether the result of a single bout is hidden, taking per-bout reveals into account.
+    pub fn bout_hidden(&self, bout: &TorikumiEntry) -> bool {
+        self.results_hidden(bout.day) && !self.revealed_bouts.contains(&bout.id)
+    }
 
-            let paragraph = Paragraph::new(message)
-                .block(Block::default().borders(Borders::ALL).title("Daily Matches"))
-                .alignment(Alignment::Center);
+    fn day_fully_revealed(&self) -> bool {
+        match &self.torikumi {
+            Some(torikumi) if !torikumi.is_empty() => torikumi.iter().all(|b| !self.bout_hidden(b)),
+            _ => false,
+        }
+    }
 
-            f.render_widget(paragraph, area);
+    // Reveal the selected bout, or every bout of the loaded day when `all` is set
+    fn reveal_bouts(&mut self, all: bool) {
+        let Some(torikumi) = &self.torikumi else {
             return;
+        };
+        if all {
+            self.revealed_bouts.extend(torikumi.iter().map(|b| b.id.clone()));
+        } else if let Some(bout) = self.selected_bout() {
+            self.revealed_bouts.insert(bout.id.clone());
         }
+        self.recompute_records();
+    }
 
-        let visible_height = area.height.saturating_sub(3) as usize; // Account for borders and header
-        let start_index = app.scroll_offset;
-        let end_index = (start_index + visible_height).min(torikumi.len());
-        
-        let rows: Vec<Row> = torikumi
-            .iter()
-            .enumerate()
-            .skip(start_index)
-            .take(end_index - start_index)
-            .map(|(i, match_entry)| {
-                let style = if i == app.selected_index {
-                    Style::default().bg(Color::Yellow).fg(Color::Black)
-                } else {
-                    Style::default()
-                };
-
-                let east_name = match_entry.east_shikona.clone();
-                let west_name = match_entry.west_shikona.clone();
-                let winner_opt = match_entry.winner_en.as_ref();
-                let kimarite = match_entry.kimarite.as_ref().unwrap_or(&"N/A".to_string()).to_string();
-                // Capitalize first letter of kimarite
-                let kimarite = if !kimarite.is_empty() {
-                    let mut chars: Vec<char> = kimarite.chars().collect();
-                    chars[0] = chars[0].to_uppercase().next().unwrap_or(chars[0]);
-                    chars.into_iter().collect()
-                } else {
-                    kimarite
-                };
-
-                // Compose "Name (Rank) (W-L)"
-                let (ew, el) = app.record_map.get(&match_entry.east_id).copied().unwrap_or((0, 0));
-                let (ww, wl) = app.record_map.get(&match_entry.west_id).copied().unwrap_or((0, 0));
-                let east_text = format!("{} ({}) ({}-{})", east_name, abbr_rank(&match_entry.east_rank), ew, el);
-                let west_text = format!("{} ({}) ({}-{})", west_name, abbr_rank(&match_entry.west_rank), ww, wl);
-
-                // Bold the winner if present
-                let (east_span, west_span) = if let Some(winner) = winner_opt {
-                    let east_is_winner = winner == &east_name;
-                    let west_is_winner = winner == &west_name;
-
-                    let win_style = Style::default().fg(Color::Black).bg(Color::Green).add_modifier(Modifier::BOLD);
-                    let east_span = if east_is_winner {
-                        Span::styled(east_text, win_style)
-                    } else {
-                        Span::raw(east_text)
-                    };
-                    let west_span = if west_is_winner {
-                        Span::styled(west_text, win_style)
-                    } else {
-                        Span::raw(west_text)
-                    };
-                    (east_span, west_span)
-                } else {
-                    (Span::raw(east_text), Span::raw(west_text))
-                };
+    /// Enable spoiler-free mode hiding results from `from_day` onwards, or disable it with `None`.
+    pub fn set_spoiler_from(&mut self, from_day: Option<u8>) {
+        self.spoiler_from = from_day;
+        self.recompute_records();
+    }
 
-                Row::new(vec![
-                    Cell::from(Line::from(vec![east_span])),
-                    Cell::from(Line::from(vec![west_span])),
-                    Cell::from(kimarite),
-                ]).style(style)
-            })
-            .collect();
+    /// Store head-to-head data for `rikishi_id`, dropping bouts from the current basho whose
+    /// results are hidden and removing them from the totals.
+    pub fn set_head_to_head(&mut self, rikishi_id: u32, mut h2h: HeadToHeadResponse) {
+        let (hidden, shown): (Vec<_>, Vec<_>) = h2h
+            .matches
+            .into_iter()
+            .partition(|m| m.basho_id == self.basho_id && self.results_hidden(m.day));
+        h2h.matches = shown;
+        for m in hidden {
+            h2h.total = h2h.total.saturating_sub(1);
+            let (counter, by_kimarite) = match m.won_by(rikishi_id) {
+                Some(true) => (&mut h2h.rikishi_wins, &mut h2h.kimarite_wins),
+                Some(false) => (&mut h2h.opponent_wins, &mut h2h.kimarite_losses),
+                None => continue,
+            };
+            *counter = counter.saturating_sub(1);
+            if let (Some(map), Some(kimarite)) = (by_kimarite.as_mut(), m.kimarite.as_ref())
+                && let Some(count) = map.get_mut(kimarite)
+            {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    map.remove(kimarite);
+                }
+            }
+        }
+        self.head_to_head_data = Some((rikishi_id, h2h));
+        self.show_head_to_head = true;
+    }
 
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Percentage(40), // East
-                Constraint::Percentage(40), // West
-                Constraint::Percentage(20), // Kimarite
-            ],
-        )
-        .header(
-            Row::new(vec!["East", "West", "Kimarite"])
-                .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
-        )
-        .block(Block::default().borders(Borders::ALL).title("Daily Matches"));
+    /// Fetch the details of `rikishi_id`, showing their popup as loading until they are in.
+    pub fn open_rikishi(&mut self, rikishi_id: u32) {
+        self.requested_rikishi_id = Some(rikishi_id);
+        self.pending = Some((Pending::Rikishi(rikishi_id), None));
+    }
 
-        f.render_widget(table, area);
-    } else {
-        let paragraph = Paragraph::new("Loading torikumi data...")
-            .block(Block::default().borders(Borders::ALL).title("Daily Matches"))
-            .alignment(Alignment::Center);
-        f.render_widget(paragraph, area);
+    /// Fetch the head-to-head of two rikishi, showing its popup as loading until it is in.
+    pub fn open_head_to_head(&mut self, rikishi_id: u32, opponent_id: u32) {
+        self.requested_head_to_head = Some((rikishi_id, opponent_id));
+        self.pending = Some((Pending::HeadToHead(rikishi_id, opponent_id), None));
     }
-}
+
+    /// Settle the loading popup waiting on `request`: closed once its data is in, or left
+    /// open with `error` to retry. Returns whether the popup was still waiting on it.
+    pub fn settle_pending(&mut self, request: &Pending, error: Option<String>) -> bool {
+        match &mut self.pending {
+            Some((pending, failed)) if pending == request => {
+                match error {
+                    Some(error) => *failed = Some(error),
+                    None => self.pending = None,
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // While a detail popup loads, Close gives up on it and Select retries a failed fetch.
+    // Returns whether the action was used.
+    fn on_pending_action(&mut self, action: Action) -> bool {
+        let Some((request, error)) = &self.pending else {
+            return false;
+        };
+        match (action, request.clone()) {
+            (Action::Close, _) => self.pending = None,
+            (Action::Select, _) if error.is_none() => {}
+            (Action::Select, Pending::Rikishi(id)) => self.open_rikishi(id),
+            (Action::Select, Pending::HeadToHead(a, b)) => self.open_head_to_head(a, b),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Store the most-faced opponents and the kinboshi of `rikishi_id` from their bout
+    /// history, leaving out bouts from the current basho whose results are hidden, along
+    /// with their jun-yusho count.
+    pub fn set_bout_history(&mut self, rikishi_id: u32, mut matches: Vec<HeadToHeadMatch>, jun_yusho: (u32, u32)) {
+        matches.retain(|m| !(m.basho_id == self.basho_id && self.results_hidden(m.day)));
+        self.rivals = Some((rikishi_id, rivals::most_faced(rikishi_id, &matches, rivals::TOP_RIVALS)));
+        self.rival_index = 0;
+        self.achievements = Some(Achievements { rikishi_id, kinboshi: achievements::kinboshi(rikishi_id, &matches), jun_yusho });
+        self.bout_history = Some((rikishi_id, matches));
+    }
+
+    // In the details popup, the compare key switches between the rivals and the comparison
+    // with the last basho; Up, Down and Select pick a rival and open the full head-to-head
+    // with them. Returns whether the action was used.
+    fn on_details_action(&mut self, action: Action) -> bool {
+        if !self.show_rikishi_details || self.show_head_to_head {
+            return false;
+        }
+        if action == Action::ToggleCompare {
+            self.show_previous_basho = !self.show_previous_basho;
+            return true;
+        }
+        let Some((rikishi_id, rivals)) = &self.rivals else {
+            return false;
+        };
+        if self.show_previous_basho || rivals.is_empty() {
+            return false;
+        }
+        let len = rivals.len();
+        match action {
+            Action::Up | Action::Down => self.rival_index = self.step_index(self.rival_index, len, action == Action::Down),
+            Action::Select => {
+                let opponent_id = rivals[self.rival_index.min(len - 1)].opponent_id;
+                let rikishi_id = *rikishi_id;
+                self.open_head_to_head(rikishi_id, opponent_id);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Whether the loaded basho and division had a playoff, once that was checked.
+    pub fn playoff(&self) -> Option<bool> {
+        let key = format!("{}/{}", self.basho_id, self.division);
+        self.playoff_checked.as_ref().filter(|(checked, _)| *checked == key).map(|(_, playoff)| *playoff)
+    }
+
+    pub fn set_playoff(&mut self, playoff: bool) {
+        self.playoff_checked = Some((format!("{}/{}", self.basho_id, self.division), playoff));
+    }
+
+    /// The last day of the loaded division: the playoff, after the final day, when it had one.
+    pub fn last_day(&self) -> u8 {
+        match self.playoff() {
+            Some(true) => crate::PLAYOFF_DAY,
+            _ => crate::max_day_for_division(&self.division),
+        }
+    }
+
+    /// Settle the day grid's check of `day`: loaded when its torikumi exists or couldn't be
+    /// checked (`None`), otherwise left open saying there is none.
+    pub fn settle_day_check(&mut self, day: u8, exists: Option<bool>) {
+        if self.input_mode != InputMode::EditingDay || self.checking_day != Some(day) {
+            return;
+        }
+        self.checking_day = None;
+        if exists == Some(false) {
+            self.input_error = Some(tf("input.no_torikumi", &[&crate::i18n::division_name(&self.division), &day]));
+            return;
+        }
+        self.pick_day(day);
+    }
+
+    // Close the day grid and load `day`
+    fn pick_day(&mut self, day: u8) {
+        self.day = day;
+        self.needs_reload = true;
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.input_error = None;
+    }
+
+    fn step_replay(&mut self, forward: bool) {
+        let max_day = self.last_day();
+        let Some(replay) = self.replay.as_mut() else {
+            return;
+        };
+        replay.last_step = Instant::now();
+        let next = if forward {
+            (self.day < max_day).then(|| self.day + 1)
+        } else {
+            (self.day > 1).then(|| self.day - 1)
+        };
+        match next {
+            Some(day) => {
+                self.day = day;
+                self.needs_reload = true;
+            }
+            None if forward => {
+                replay.auto_advance = false;
+                self.status_message = Some(t("status.replay_final").to_string());
+            }
+            None => {}
+        }
+    }
+
+    /// Time left until auto-advance moves the replay to the next day, if it is running.
+    pub fn until_next_replay_step(&self) -> Option<Duration> {
+        let replay = self.replay.as_ref().filter(|r| r.auto_advance)?;
+        Some(replay.interval.saturating_sub(replay.last_step.elapsed()))
+    }
+
+    /// Whether `day` of the loaded basho has results, is still to come, or neither yet.
+    /// Sekitori records show which days have results; for the lower divisions, which
+    /// don't fight every day, the calendar decides.
+    pub fn day_status(&self, day: u8, today: chrono::NaiveDate) -> DayStatus {
+        let per_day = crate::max_day_for_division(&self.division) == BASHO_DAYS;
+        let recorded = per_day
+            && self.banzuke.iter().flatten().any(|e| {
+                e.record.as_ref().and_then(|r| r.get(day as usize - 1)).is_some_and(|r| !r.result.is_empty())
+            });
+        let decided_today = day == self.day && self.torikumi.iter().flatten().any(|b| b.winner_id.is_some());
+        if recorded || decided_today {
+            return DayStatus::Results;
+        }
+        match self.basho.as_ref().and_then(|b| b.start_date_naive()) {
+            Some(start) => {
+                let date = start + chrono::Days::new(day as u64 - 1);
+                if date > today {
+                    DayStatus::Future
+                } else if date < today && !per_day {
+                    DayStatus::Results
+                } else {
+                    DayStatus::Pending
+                }
+            }
+            None => DayStatus::Pending,
+        }
+    }
+
+    /// Time left until the toast disappears, if one is showing.
+    pub fn until_toast_expires(&self) -> Option<Duration> {
+        let (_, raised) = self.toast.as_ref()?;
+        Some(TOAST_DURATION.saturating_sub(raised.elapsed()))
+    }
+
+    /// Pop up a notice that clears itself, or with reduced motion put it in the status
+    /// line instead.
+    pub fn show_toast(&mut self, message: String) {
+        if self.reduced_motion {
+            self.status_message = Some(message);
+        } else {
+            self.toast = Some((message, Instant::now()));
+        }
+    }
+
+    /// Bouts of the loaded day still without a winner, or `None` when there is no
+    /// torikumi or its results are hidden.
+    pub fn bouts_remaining(&self) -> Option<usize> {
+        let torikumi = self.torikumi.as_ref().filter(|t| !t.is_empty())?;
+        if self.results_hidden(self.day) {
+            return None;
+        }
+        Some(torikumi.iter().filter(|b| b.winner_id.is_none()).count())
+    }
+
+    /// Advance time-based state; called once per event loop iteration.
+    pub fn on_tick(&mut self) {
+        if self.until_toast_expires().is_some_and(|left| left.is_zero()) {
+            self.toast = None;
+        }
+        if let Some(replay) = &self.replay
+            && replay.auto_advance
+            && !self.needs_reload
+            && replay.last_step.elapsed() >= replay.interval
+        {
+            self.step_replay(true);
+        }
+    }
+
+    pub fn set_basho(&mut self, basho: Basho) {
+        self.basho = Some(basho);
+    }
+
+    pub fn set_banzuke(&mut self, banzuke: Vec<BanzukeEntry>) {
+        // Store banzuke
+        self.banzuke = Some(banzuke);
+        // Recompute records map
+        self.recompute_records();
+    }
+
+    /// Store the days the division's rikishi fought on and recount records with them.
+    pub fn set_bout_days(&mut self, key: String, days: schedule::BoutDays) {
+        self.bout_days = Some((key, days));
+        self.recompute_records();
+        self.invalidate_rows();
+    }
+
+    pub fn set_torikumi(&mut self, mut torikumi: Vec<TorikumiEntry>) {
+        sort_torikumi(&mut torikumi, self.torikumi_order);
+        self.torikumi = Some(torikumi);
+        self.filter_torikumi();
+    }
+
+    /// Number of selectable rows in the current view.
+    pub fn current_list_len(&self) -> usize {
+        match self.current_view {
+            AppView::Torikumi => self.torikumi_table.shown().len(),
+            AppView::Banzuke | AppView::Matchups => self.banzuke.as_ref().map(|b| b.len()).unwrap_or(0),
+            AppView::Journal => self.journal.for_basho(&self.basho_id).len(),
+            AppView::Settings => Setting::all().len(),
+            AppView::Dashboard => self.dashboard.as_ref().map(|d| d.len()).unwrap_or(0),
+            AppView::YushoHistory => self.yusho_history.as_ref().map(|h| h.len()).unwrap_or(0),
+            AppView::BashoInfo | AppView::Compare | AppView::Stats | AppView::Records | AppView::Schedule => 0,
+        }
+    }
+
+    /// Selection in the current view's list.
+    pub fn selection(&self) -> Selection {
+        match self.current_view {
+            AppView::Torikumi => self.torikumi_table.selection,
+            AppView::Banzuke => self.banzuke_panel.selection,
+            _ => self.list_selections.get(&self.current_view).copied().unwrap_or_default(),
+        }
+    }
+
+    pub(crate) fn selection_mut(&mut self) -> &mut Selection {
+        match self.current_view {
+            AppView::Torikumi => &mut self.torikumi_table.selection,
+            AppView::Banzuke => &mut self.banzuke_panel.selection,
+            _ => self.list_selections.entry(self.current_view.clone()).or_default(),
+        }
+    }
+
+    /// Restore a previous selection, clamped to the current list.
+    pub fn restore_selection(&mut self, mut selection: Selection) {
+        selection.clamp(self.current_list_len());
+        *self.selection_mut() = selection;
+    }
+
+    // Open the summary of the loaded day once all its results are in and shown
+    fn toggle_day_summary(&mut self) {
+        if self.day_summary.take().is_some() {
+            return;
+        }
+        let torikumi = self.torikumi.as_deref().unwrap_or_default();
+        if !crate::live::day_complete(torikumi) {
+            self.status_message = Some(tf("status.summary_incomplete", &[&self.day]));
+        } else if torikumi.iter().any(|b| self.bout_hidden(b)) {
+            self.status_message = Some(t("status.summary_hidden").to_string());
+        } else {
+            let banzuke = self.banzuke.as_deref().unwrap_or_default();
+            self.day_summary = Some(DaySummary::compute(self.day, torikumi, banzuke, &self.record_map));
+        }
+    }
+
+    /// Show the torikumi with the bout of `rikishi_id` selected, if they have one.
+    pub fn focus_bout(&mut self, rikishi_id: u32) -> bool {
+        let Some(index) = self
+            .torikumi
+            .iter()
+            .flatten()
+            .position(|b| b.east_id == rikishi_id || b.west_id == rikishi_id)
+        else {
+            return false;
+        };
+        self.current_view = AppView::Torikumi;
+        self.select_bout(index);
+        true
+    }
+
+    pub fn clear_torikumi(&mut self) {
+        self.followed = self.followed_rikishi();
+        self.torikumi = None;
+        self.day_summary = None;
+        self.filter_torikumi();
+        self.torikumi_table.selection = Selection::default();
+    }
+
+    /// The rikishi of the selected bout and banzuke row.
+    pub fn followed_rikishi(&self) -> Followed {
+        let banzuke = self.banzuke.as_ref().and_then(|b| b.get(self.banzuke_panel.selection.index));
+        Followed {
+            bout: self.selected_bout().map(|b| vec![b.east_id, b.west_id]).unwrap_or_default(),
+            banzuke: banzuke.map(|e| e.rikishi_id),
+        }
+    }
+
+    /// Select the bout and banzuke row of the `followed` rikishi where the loaded data
+    /// still has them; the bout of the first one found, east before west. Elsewhere the
+    /// selection stays on its row.
+    pub fn follow(&mut self, followed: &Followed) {
+        let (rows, mode) = (self.viewport_rows, self.navigation.scroll);
+        let bout = followed.bout.iter().find_map(|id| {
+            let index = self.torikumi.iter().flatten().position(|b| b.east_id == *id || b.west_id == *id)?;
+            self.torikumi_table.row_of(index)
+        });
+        if let Some(row) = bout {
+            let len = self.torikumi_table.shown().len();
+            self.torikumi_table.selection.scroll_to(row, len, rows, mode);
+        }
+        let banzuke = self.banzuke.as_deref().unwrap_or_default();
+        match banzuke.iter().position(|e| Some(e.rikishi_id) == followed.banzuke) {
+            Some(index) => self.banzuke_panel.selection.scroll_to(index, banzuke.len(), rows, mode),
+            None => self.banzuke_panel.selection.clamp(banzuke.len()),
+        }
+    }
+
+    fn recompute_records(&mut self) {
+        self.record_map.clear();
+        // Revealing results can take bouts out of the unfinished filter
+        self.filter_torikumi();
+        let records: Vec<_> = self.banzuke.iter().flatten().map(|e| (e.rikishi_id, e.wins_losses(self.revealed_for(e)))).collect();
+        self.record_map.extend(records);
+    }
+
+    /// Handle a key event, including modifier combinations, before falling back to `on_key`.
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        // Raw mode turns Ctrl-C into a key press instead of SIGINT
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.should_quit = true;
+            return;
+        }
+        // Likewise Ctrl-Z, which the main loop turns into a suspend
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('z') {
+            self.suspend_requested = true;
+            return;
+        }
+        if self.input_mode == InputMode::Normal
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+            && key.code == KeyCode::Char('p')
+        {
+            self.open_switcher();
+            return;
+        }
+        if self.input_mode == InputMode::Normal
+            && key.modifiers.contains(KeyModifiers::ALT)
+            && let KeyCode::Char(c) = key.code
+            && let Some(slot) = c.to_digit(10).filter(|d| *d >= 1)
+        {
+            self.jump_to_favorite(slot as usize);
+            return;
+        }
+        self.on_key(key.code);
+    }
+
+    /// Open the quick switcher over everything loaded: rikishi of the banzuke and the
+    /// loaded profiles, the loaded and cached basho, divisions, actions and script commands.
+    pub fn open_switcher(&mut self) {
+        use switcher::Entry;
+        // Keys that only make sense pressed in place
+        const SKIPPED: &[Action] = &[
+            Action::Up,
+            Action::Down,
+            Action::PrevPage,
+            Action::NextPage,
+            Action::PrevSection,
+            Action::NextSection,
+            Action::Select,
+            Action::Close,
+        ];
+        let mut entries = Vec::new();
+        let mut seen = HashSet::new();
+        let banzuke = self.banzuke.iter().flatten().map(|e| (e.rikishi_id, e.shikona_en.to_string(), e.shikona_jp.clone()));
+        let profiles = self.rikishi_profiles.values().map(|p| (p.id, p.shikona_en.clone(), Some(p.shikona_jp.clone())));
+        for (id, shikona, shikona_jp) in banzuke.chain(profiles) {
+            if !shikona.is_empty() && seen.insert(id) {
+                let label = match shikona_jp.filter(|jp| !jp.is_empty()) {
+                    Some(jp) => format!("{} {}", romanize(&shikona), jp),
+                    None => romanize(&shikona).into_owned(),
+                };
+                entries.push(Entry::new("switcher.rikishi", label, Target::Rikishi(id)));
+            }
+        }
+        let mut basho: Vec<String> = std::iter::once(self.basho_id.clone())
+            .chain(crate::api::previous_basho_id(&self.basho_id))
+            .chain(self.cached_basho.iter().cloned())
+            .chain(self.yusho_history.iter().flatten().map(|(id, _)| id.clone()))
+            .collect();
+        basho.sort_by(|a, b| b.cmp(a));
+        basho.dedup();
+        for id in basho {
+            let month = id.get(4..6).and_then(|m| m.parse().ok()).unwrap_or(0);
+            let label = format!("{} {} · {}", id, crate::i18n::basho_name(month), crate::dates::basho_date(&id));
+            entries.push(Entry::new("switcher.basho", label, Target::Basho(id)));
+        }
+        for division in DIVISIONS {
+            entries.push(Entry::new("switcher.division", crate::i18n::division_name(division), Target::Division(division.to_string())));
+        }
+        for action in HELP_SECTIONS.iter().flat_map(|(_, actions)| actions.iter()).filter(|a| !SKIPPED.contains(a)) {
+            entries.push(Entry::new("switcher.action", t(action.description_key()), Target::Action(*action)));
+        }
+        for command in &self.script_commands {
+            entries.push(Entry::new("switcher.command", command.clone(), Target::Command(command.clone())));
+        }
+        self.switcher = entries;
+        self.switcher_index = 0;
+        self.input_mode = InputMode::Switching;
+        self.input_buffer.clear();
+        self.input_error = None;
+    }
+
+    // Load the basho, division and day a goto string named, keeping the rest; stays in
+    // the prompt if the day is past the end of the division's basho
+    fn go_to(&mut self, goto: crate::goto::Goto) {
+        let division = goto.division.map_or_else(|| self.division.clone(), str::to_string);
+        let day = goto.day.unwrap_or(self.day);
+        // Only the loaded basho and division are known to have a playoff
+        let same = division == self.division && goto.basho_id.as_ref().is_none_or(|id| *id == self.basho_id);
+        let last_day = if same { self.last_day() } else { crate::max_day_for_division(&division) };
+        if day > last_day {
+            self.input_error = Some(t("input.invalid_day").to_string());
+            return;
+        }
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        if let Some(basho_id) = goto.basho_id.filter(|id| *id != self.basho_id) {
+            self.basho_id = basho_id;
+            self.basho_changed = true;
+            self.needs_reload = true;
+        }
+        if division != self.division || day != self.day {
+            self.division = division;
+            self.day = day;
+            self.needs_reload = true;
+        }
+    }
+
+    /// Switcher entries matching what has been typed, best first.
+    pub fn switcher_matches(&self) -> Vec<&switcher::Entry> {
+        switcher::search(&self.switcher, &self.input_buffer)
+    }
+
+    // Close the switcher and go where the selected match points
+    fn switch_to_selected(&mut self) {
+        let Some(target) = self.switcher_matches().get(self.switcher_index).map(|e| e.target.clone()) else {
+            self.input_error = Some(t("switcher.no_match").to_string());
+            return;
+        };
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.switcher.clear();
+        match target {
+            Target::Rikishi(id) => {
+                self.show_help = false;
+                self.show_rikishi_details = false;
+                self.show_head_to_head = false;
+                self.pending = None;
+                if self.focus_bout(id) {
+                    return;
+                }
+                match self.banzuke.iter().flatten().position(|e| e.rikishi_id == id) {
+                    Some(index) => {
+                        self.current_view = AppView::Banzuke;
+                        self.select(index);
+                    }
+                    // Someone from another division: their details at least
+                    None => self.open_rikishi(id),
+                }
+            }
+            Target::Basho(id) if id != self.basho_id => {
+                self.basho_id = id;
+                self.basho_changed = true;
+                self.needs_reload = true;
+            }
+            Target::Division(division) if division != self.division => {
+                self.division = division;
+                self.needs_reload = true;
+            }
+            Target::Basho(_) | Target::Division(_) => {}
+            Target::Action(action) => self.perform(action),
+            Target::Command(name) => self.command_requested = Some(name),
+        }
+    }
+
+    /// Jump to the bout of the favorite in `slot` (1-based) on the loaded day, or to their
+    /// banzuke row when they are not fighting or the banzuke is already shown.
+    pub fn jump_to_favorite(&mut self, slot: usize) {
+        let Some(name) = self.favorites.get(slot - 1).cloned() else {
+            self.status_message = Some(tf("status.favorite_empty", &[&slot]));
+            return;
+        };
+        let matches = |shikona: &str| shikona.eq_ignore_ascii_case(&name);
+
+        self.show_help = false;
+        self.show_rikishi_details = false;
+        self.show_head_to_head = false;
+        self.pending = None;
+
+        let bout_index = self.torikumi.as_ref().and_then(|t| {
+            t.iter().position(|b| matches(&b.east_shikona) || matches(&b.west_shikona))
+        });
+        let banzuke_index = self
+            .banzuke
+            .as_ref()
+            .and_then(|b| b.iter().position(|e| matches(&e.shikona_en)));
+
+        match (bout_index, banzuke_index) {
+            (Some(i), _) if self.current_view != AppView::Banzuke => {
+                self.current_view = AppView::Torikumi;
+                self.select_bout(i);
+            }
+            (_, Some(i)) => {
+                self.current_view = AppView::Banzuke;
+                self.select(i);
+            }
+            (Some(i), None) => {
+                self.current_view = AppView::Torikumi;
+                self.select(i);
+            }
+            (None, None) => {
+                self.status_message = Some(tf("status.favorite_missing", &[&name, &crate::i18n::division_name(&self.division)]));
+            }
+        }
+    }
+
+    /// Update the number of table rows that fit on screen and keep the selection visible.
+    pub fn set_viewport_rows(&mut self, rows: usize) {
+        self.viewport_rows = rows.max(1);
+        let (len, mode) = (self.current_list_len(), self.navigation.scroll);
+        self.selection_mut().fit(len, rows.max(1), mode);
+    }
+
+    // Switch to `view`, back on the row selected there last
+    fn show_view(&mut self, view: AppView) {
+        self.current_view = view;
+        let (len, rows, mode) = (self.current_list_len(), self.viewport_rows, self.navigation.scroll);
+        // The window may have been resized meanwhile; a list not loaded yet keeps its place
+        if len > 0 {
+            self.selection_mut().fit(len, rows, mode);
+        }
+    }
+
+    // Select a row and scroll so it is visible
+    fn select(&mut self, index: usize) {
+        let (len, rows, mode) = (self.current_list_len(), self.viewport_rows, self.navigation.scroll);
+        self.selection_mut().scroll_to(index, len, rows, mode);
+    }
+
+    // Rows a press moving down (or up) moves: one, or more while the key is held with
+    // acceleration on. Negative going up.
+    fn step_rows(&mut self, down: bool) -> isize {
+        let by = if self.navigation.accelerate { self.acceleration.rows(down, Instant::now()) } else { 1 } as isize;
+        if down { by } else { -by }
+    }
+
+    // Move the selection up or down a row, or further while the key is held with
+    // acceleration on
+    fn step_selection(&mut self, down: bool) {
+        let by = self.step_rows(down);
+        let (len, rows, navigation) = (self.current_list_len(), self.viewport_rows, self.navigation);
+        self.selection_mut().step(by, len, rows, &navigation);
+    }
+
+    // Move `index` up or down a short list of `len` drawn whole: the rivals in the details
+    // popup, the switcher's matches or the division selector. Wrapping and acceleration apply as in the
+    // views; with every row on screen there is nothing for the scroll mode to do.
+    fn step_index(&mut self, index: usize, len: usize, down: bool) -> usize {
+        let by = self.step_rows(down);
+        let mut selection = Selection { index, scroll: 0 };
+        selection.step(by, len, len, &self.navigation);
+        selection.index
+    }
+
+    // Mark or unmark the selected banzuke rikishi for comparison; in the comparison
+    // view itself, start over
+    fn toggle_compare(&mut self) {
+        match self.current_view {
+            AppView::Compare => {
+                self.compare_ids.clear();
+                self.status_message = Some(t("status.compare_cleared").to_string());
+            }
+            AppView::Banzuke => {
+                let Some(entry) = self.banzuke.as_ref().and_then(|b| b.get(self.banzuke_panel.selection.index)) else {
+                    return;
+                };
+                let (id, name) = (entry.rikishi_id, entry.shikona_en.clone());
+                if let Some(i) = self.compare_ids.iter().position(|c| *c == id) {
+                    self.compare_ids.remove(i);
+                    self.status_message = Some(tf("status.compare_removed", &[&name, &self.compare_ids.len(), &MAX_COMPARED]));
+                } else if self.compare_ids.len() >= MAX_COMPARED {
+                    self.status_message = Some(tf("status.compare_full", &[&MAX_COMPARED]));
+                } else {
+                    self.compare_ids.push(id);
+                    self.status_message = Some(tf("status.compare_added", &[&name, &self.compare_ids.len(), &MAX_COMPARED]));
+                }
+            }
+            _ => self.status_message = Some(t("status.compare_from_banzuke").to_string()),
+        }
+    }
+
+    fn show_stats(&mut self) {
+        self.show_view(AppView::Stats);
+        // Age, size and heya need the profiles, which are otherwise only loaded for columns
+        self.profiles_requested = self.rikishi_profiles.is_empty();
+    }
+
+    // Open the schedule of the selected banzuke rikishi, or go back to the banzuke
+    fn toggle_schedule(&mut self) {
+        match self.current_view {
+            AppView::Schedule => {
+                self.current_view = AppView::Banzuke;
+                let index = self
+                    .banzuke
+                    .iter()
+                    .flatten()
+                    .position(|e| Some(e.rikishi_id) == self.schedule_rikishi)
+                    .unwrap_or(0);
+                self.select(index);
+            }
+            AppView::Banzuke => {
+                let Some(entry) = self.banzuke.as_ref().and_then(|b| b.get(self.banzuke_panel.selection.index)) else {
+                    return;
+                };
+                self.schedule_rikishi = Some(entry.rikishi_id);
+                self.current_view = AppView::Schedule;
+            }
+            _ => self.status_message = Some(t("status.schedule_from_banzuke").to_string()),
+        }
+    }
+
+    fn show_records(&mut self) {
+        self.show_view(AppView::Records);
+        self.career_stats_requested = true;
+    }
+
+    // Open the yusho history on the current division, loading it unless it already
+    // reaches the current basho
+    fn show_yusho_history(&mut self) {
+        self.show_view(AppView::YushoHistory);
+        self.yusho_division = self.division.clone();
+        if self.yusho_history.as_ref().and_then(|h| h.first()).is_none_or(|(id, _)| *id != self.basho_id) {
+            self.yusho_history = None;
+            self.yusho_history_requested = true;
+        }
+    }
+
+    fn show_settings(&mut self) {
+        self.show_view(AppView::Settings);
+    }
+
+    /// Change the selected setting and save it: general settings step to their next
+    /// value, a key binding waits for the key to bind.
+    fn change_setting(&mut self) {
+        let Some(setting) = Setting::all().get(self.selection().index).copied() else {
+            return;
+        };
+        let value = match setting {
+            Setting::Theme => {
+                self.set_theme(self.theme_name.next());
+                settings::theme_value(self.theme_name)
+            }
+            Setting::Units => {
+                self.units = match self.units {
+                    Units::Metric => Units::Imperial,
+                    Units::Imperial => Units::Metric,
+                };
+                settings::units_value(self.units)
+            }
+            Setting::RefreshInterval => {
+                self.refresh_interval_secs = settings::next_interval(self.refresh_interval_secs);
+                return self.save_setting(setting, &self.refresh_interval_secs.to_string());
+            }
+            Setting::Division => {
+                let current = DIVISIONS.iter().position(|d| d.eq_ignore_ascii_case(&self.default_division)).unwrap_or(0);
+                self.default_division = DIVISIONS[(current + 1) % DIVISIONS.len()].to_string();
+                &self.default_division.to_lowercase()
+            }
+            Setting::Key(_) => {
+                self.input_mode = InputMode::BindingKey;
+                return;
+            }
+        };
+        self.save_setting(setting, &toml::Value::from(value).to_string());
+    }
+
+    // Bind the key pressed in the settings view to the selected action
+    fn bind_key(&mut self, key: KeyCode) {
+        self.input_mode = InputMode::Normal;
+        let Some(Setting::Key(action)) = Setting::all().get(self.selection().index).copied() else {
+            return;
+        };
+        self.keymap.rebind(action, key);
+        let keys = self.keymap.keys_for(action).into_iter().map(|k| toml::Value::from(crate::keymap::config_key_name(k))).collect();
+        self.save_setting(Setting::Key(action), &toml::Value::Array(keys).to_string());
+    }
+
+    fn save_setting(&mut self, setting: Setting, value: &str) {
+        self.invalidate_rows();
+        let (section, key) = setting.location();
+        self.status_message = Some(match &self.config_path {
+            Some(path) => match settings::save(path, section, &key, value) {
+                Ok(()) => tf("status.setting_saved", &[&path.display()]),
+                Err(e) => tf("status.setting_save_failed", &[&e]),
+            },
+            None => t("status.settings_unavailable").to_string(),
+        });
+    }
+
+    fn show_journal(&mut self) {
+        self.show_view(AppView::Journal);
+    }
+
+    fn show_dashboard(&mut self) {
+        self.show_view(AppView::Dashboard);
+        self.dashboard_requested = true;
+    }
+
+    // Open the dashboard row's bout, switching division if needed, or their banzuke
+    // row when they aren't fighting on the loaded day
+    fn open_tracked(&mut self) {
+        let Some(tracked) = self.dashboard.as_ref().and_then(|d| d.get(self.selection().index)) else {
+            return;
+        };
+        let Some((division, entry)) = &tracked.placing else {
+            self.status_message = Some(tf("status.favorite_unranked", &[&tracked.name]));
+            return;
+        };
+        let rikishi_id = entry.rikishi_id;
+        if *division != self.division {
+            self.division = division.clone();
+            self.current_view = AppView::Torikumi;
+            self.pending_bout_focus = Some(rikishi_id);
+            self.needs_reload = true;
+        } else if !self.focus_bout(rikishi_id)
+            && let Some(index) = self.banzuke.iter().flatten().position(|e| e.rikishi_id == rikishi_id)
+        {
+            self.current_view = AppView::Banzuke;
+            self.select(index);
+        }
+    }
+
+    // Project the remaining opponents of the rikishi selected in the matchup grid
+    fn open_projection(&mut self) {
+        let banzuke = self.banzuke.as_deref().unwrap_or_default();
+        let Some(entry) = banzuke.get(self.selection().index) else {
+            return;
+        };
+        let revealed = |entry: &BanzukeEntry| self.revealed_for(entry);
+        let matrix = Matrix::compute(banzuke, self.torikumi.as_deref().unwrap_or_default(), revealed);
+        let candidates = projection::remaining_opponents(banzuke, &matrix, self.selection().index, revealed);
+        self.projection_requested = Some((entry.rikishi_id, candidates.iter().map(|c| c.rikishi_id).collect()));
+        self.projection = Some(Projection {
+            rikishi_id: entry.rikishi_id,
+            shikona: entry.shikona_en.to_string(),
+            candidates,
+            head_to_head: Vec::new(),
+        });
+    }
+
+    // Add a note for the loaded day and save the journal
+    fn add_note(&mut self, text: &str) {
+        self.journal.add(&self.basho_id, self.day, text, chrono::Local::now());
+        self.save_journal(tf("status.note_added", &[&self.day]));
+    }
+
+    // Delete the note selected in the journal view
+    fn delete_note(&mut self) {
+        let Some(entry) = self.journal.for_basho(&self.basho_id).get(self.selection().index).map(|e| (*e).clone()) else {
+            return;
+        };
+        self.journal.remove(&entry);
+        self.restore_selection(self.selection());
+        self.save_journal(t("status.note_deleted").to_string());
+    }
+
+    fn save_journal(&mut self, done: String) {
+        self.status_message = Some(match &self.journal_path {
+            Some(path) => match self.journal.save(path) {
+                Ok(()) => done,
+                Err(e) => tf("status.journal_save_failed", &[&e]),
+            },
+            None => t("status.journal_unavailable").to_string(),
+        });
+    }
+
+    pub fn set_theme(&mut self, name: ThemeName) {
+        self.theme_name = name;
+        self.theme = Theme::build(name, &self.theme_colors, self.color_support);
+        // Highlight rules drop their colors in monochrome
+        self.invalidate_rows();
+        self.status_message = Some(tf("status.theme", &[&t(name.label_key())]));
+    }
+
+    /// Re-sort the torikumi, keeping the selected bout selected.
+    pub fn set_torikumi_order(&mut self, order: TorikumiOrder) {
+        self.torikumi_order = order;
+        let selected = self.selected_bout().map(|b| b.id.clone());
+        let Some(torikumi) = self.torikumi.as_mut() else { return };
+        sort_torikumi(torikumi, order);
+        self.filter_torikumi();
+        self.reselect_bout(selected);
+    }
+
+    /// Show only the bouts `filter` lets through, keeping the selected bout selected if
+    /// it is still shown.
+    pub fn set_torikumi_filter(&mut self, filter: TorikumiFilter) {
+        let selected = self.selected_bout().map(|b| b.id.clone());
+        self.torikumi_filter = filter;
+        self.filter_torikumi();
+        self.reselect_bout(selected);
+    }
+
+    // Work out which bouts the filter shows, after the torikumi, its order or the
+    // results shown change
+    fn filter_torikumi(&mut self) {
+        let torikumi = self.torikumi.as_deref().unwrap_or_default();
+        let shown = (0..torikumi.len())
+            .filter(|i| {
+                let bout = &torikumi[*i];
+                let decided = bout.winner_id.is_some() && !self.bout_hidden(bout);
+                bout_shown(bout, decided, self.torikumi_filter, &self.favorites)
+            })
+            .collect();
+        self.torikumi_table.set_shown(shown);
+        self.invalidate_rows();
+    }
+
+    // Select the first bout still without a shown result, preferring those the filter
+    // shows
+    fn jump_to_unfinished(&mut self) {
+        let torikumi = self.torikumi.as_deref().unwrap_or_default();
+        let unfinished = |i: &usize| torikumi.get(*i).is_some_and(|b| b.winner_id.is_none() || self.bout_hidden(b));
+        let index = self.torikumi_table.shown().iter().copied().find(unfinished).or_else(|| (0..torikumi.len()).find(unfinished));
+        match index {
+            Some(index) => {
+                self.current_view = AppView::Torikumi;
+                self.select_bout(index);
+            }
+            None if torikumi.is_empty() => self.status_message = Some(t("torikumi.loading").to_string()),
+            None => self.status_message = Some(t("torikumi.complete").to_string()),
+        }
+    }
+
+    /// Bouts the torikumi table shows, in display order.
+    pub fn shown_bouts(&self) -> impl Iterator<Item = &TorikumiEntry> {
+        let torikumi = self.torikumi.as_deref().unwrap_or_default();
+        self.torikumi_table.shown().iter().filter_map(|i| torikumi.get(*i))
+    }
+
+    /// The bout selected in the torikumi table.
+    pub fn selected_bout(&self) -> Option<&TorikumiEntry> {
+        self.torikumi.as_ref()?.get(self.torikumi_table.selected()?)
+    }
+
+    // Select the bout with `id` in the torikumi view again, if it is still shown
+    fn reselect_bout(&mut self, id: Option<String>) {
+        let index = id.and_then(|id| self.shown_bouts().position(|b| b.id == id));
+        if self.current_view == AppView::Torikumi
+            && let Some(index) = index
+        {
+            self.select(index);
+        }
+    }
+
+    // Select `torikumi[index]`, showing every bout again if the filter hides it
+    fn select_bout(&mut self, index: usize) {
+        if self.torikumi_table.row_of(index).is_none() {
+            self.torikumi_filter = TorikumiFilter::All;
+            self.filter_torikumi();
+        }
+        if let Some(row) = self.torikumi_table.row_of(index) {
+            self.select(row);
+        }
+    }
+
+    /// Run a normal-mode action bound in the keymap.
+    pub fn perform(&mut self, action: Action) {
+        if self.on_pending_action(action) || self.on_details_action(action) {
+            return;
+        }
+        match action {
+            Action::Quit => self.should_quit = true,
+            Action::ToggleHelp => self.show_help = !self.show_help,
+            Action::Screenshot => self.screenshot_requested = true,
+            Action::ToggleReplay => {
+                if self.replay.is_some() {
+                    self.replay = None;
+                    self.recompute_records();
+                    self.status_message = Some(t("status.replay_off").to_string());
+                } else if basho_has_finished(self) {
+                    if self.day != 1 {
+                        self.day = 1;
+                        self.needs_reload = true;
+                    }
+                    self.start_replay(Duration::from_secs(DEFAULT_REPLAY_INTERVAL_SECS), false);
+                    self.status_message = Some(t("status.replay_on").to_string());
+                } else {
+                    self.status_message = Some(t("status.replay_unavailable").to_string());
+                }
+            },
+            Action::ReplayNext => self.step_replay(true),
+            Action::ReplayPrev => self.step_replay(false),
+            Action::RevealBout if self.current_view == AppView::Torikumi => self.reveal_bouts(false),
+            Action::RevealAll if self.current_view == AppView::Torikumi => self.reveal_bouts(true),
+            Action::ToggleLive => {
+                self.live = !self.live;
+                self.status_message = Some(if self.live {
+                    t("status.live_on").to_string()
+                } else {
+                    t("status.live_off").to_string()
+                });
+            },
+            Action::CycleTheme => self.set_theme(self.theme_name.next()),
+            Action::JumpToUnfinished => self.jump_to_unfinished(),
+            Action::CycleFilter => {
+                self.set_torikumi_filter(self.torikumi_filter.next());
+                self.status_message = Some(tf("status.filter", &[&t(self.torikumi_filter.label_key())]));
+            }
+            Action::CycleOrder => {
+                self.set_torikumi_order(self.torikumi_order.next());
+                self.status_message = Some(tf("status.order", &[&t(self.torikumi_order.label_key())]));
+            },
+            Action::SpoilerDay => {
+                self.input_mode = InputMode::EditingSpoilerDay;
+                self.input_buffer.clear();
+                self.input_error = None;
+            },
+            Action::ToggleAutoAdvance => {
+                if let Some(replay) = self.replay.as_mut() {
+                    replay.auto_advance = !replay.auto_advance;
+                    replay.last_step = Instant::now();
+                }
+            },
+            Action::ChangeDay => {
+                self.input_mode = InputMode::EditingDay;
+                self.day_cursor = self.day.clamp(1, self.last_day());
+                self.checking_day = None;
+                self.input_buffer.clear();
+                self.input_error = None;
+            },
+            Action::ChangeDivision => {
+                self.input_mode = InputMode::SelectingDivision;
+                // Find current division index
+                self.division_selector_index = DIVISIONS.iter()
+                    .position(|&d| d == self.division)
+                    .unwrap_or(0);
+                self.input_error = None;
+            },
+            Action::ChangeBasho => {
+                self.input_mode = InputMode::EditingBasho;
+                self.input_buffer.clear();
+                self.input_error = None;
+            },
+            Action::RunCommand => {
+                if self.script_commands.is_empty() {
+                    self.status_message = Some(t("status.no_commands").to_string());
+                } else {
+                    self.input_mode = InputMode::EnteringCommand;
+                    self.input_buffer.clear();
+                    self.input_error = None;
+                }
+            },
+            Action::DaySummary => self.toggle_day_summary(),
+            Action::Inspect => self.inspect(),
+            Action::JumpToRank => {
+                if self.current_view != AppView::Banzuke {
+                    self.show_view(AppView::Banzuke);
+                }
+                self.input_mode = InputMode::JumpingToRank;
+                self.input_buffer.clear();
+                self.input_error = None;
+            },
+            Action::Goto => {
+                self.input_mode = InputMode::EnteringGoto;
+                self.input_buffer.clear();
+                self.input_error = None;
+            },
+            Action::FindBout => {
+                self.input_mode = InputMode::FindingBout;
+                self.input_buffer.clear();
+                self.input_error = None;
+            },
+            Action::ViewTorikumi => {
+                self.show_view(AppView::Torikumi);
+            },
+            Action::ViewBanzuke => {
+                self.show_view(AppView::Banzuke);
+            },
+            Action::ViewInfo => {
+                self.show_view(AppView::BashoInfo);
+            },
+            Action::ViewCompare => {
+                self.show_view(AppView::Compare);
+                self.compare_requested = true;
+            },
+            Action::ToggleCompare => self.toggle_compare(),
+            Action::ViewStats => self.show_stats(),
+            Action::ViewRecords => self.show_records(),
+            Action::ViewSchedule => self.toggle_schedule(),
+            Action::ViewJournal => self.show_journal(),
+            Action::ViewDashboard => self.show_dashboard(),
+            Action::ViewMatchups => {
+                self.show_view(AppView::Matchups);
+            },
+            Action::ViewYushoHistory => self.show_yusho_history(),
+            Action::ViewSettings => self.show_settings(),
+            Action::AddNote => {
+                self.input_mode = InputMode::WritingNote;
+                self.input_buffer.clear();
+                self.input_error = None;
+            },
+            Action::DeleteNote if self.current_view == AppView::Journal => self.delete_note(),
+            // Page navigation
+            Action::PrevPage => {
+                match self.current_view {
+                    AppView::Torikumi => {
+                        // Already at first page, do nothing
+                    },
+                    AppView::Banzuke => {
+                        self.show_view(AppView::Torikumi);
+                    },
+                    AppView::BashoInfo => {
+                        self.show_view(AppView::Banzuke);
+                    },
+                    AppView::Compare => {
+                        self.current_view = AppView::BashoInfo;
+                    },
+                    AppView::Stats => {
+                        self.current_view = AppView::Compare;
+                        self.compare_requested = true;
+                    },
+                    AppView::Records => self.show_stats(),
+                    AppView::Journal => self.show_records(),
+                    AppView::Dashboard => self.show_journal(),
+                    AppView::Matchups => self.show_dashboard(),
+                    AppView::YushoHistory => {
+                        self.show_view(AppView::Matchups);
+                    },
+                    AppView::Settings => self.show_yusho_history(),
+                    AppView::Schedule => self.toggle_schedule(),
+                }
+            },
+            Action::NextPage => {
+                match self.current_view {
+                    AppView::Torikumi => {
+                        self.show_view(AppView::Banzuke);
+                    },
+                    AppView::Banzuke => {
+                        self.show_view(AppView::BashoInfo);
+                    },
+                    AppView::BashoInfo => {
+                        self.current_view = AppView::Compare;
+                        self.compare_requested = true;
+                    },
+                    AppView::Compare => self.show_stats(),
+                    AppView::Stats => self.show_records(),
+                    AppView::Records => self.show_journal(),
+                    AppView::Journal => self.show_dashboard(),
+                    AppView::Dashboard => {
+                        self.show_view(AppView::Matchups);
+                    },
+                    AppView::Matchups => self.show_yusho_history(),
+                    AppView::YushoHistory => self.show_settings(),
+                    AppView::Settings | AppView::Schedule => {
+                        // Already at last page, do nothing
+                    },
+                }
+            },
+            // List navigation
+            Action::Up => self.step_selection(false),
+            Action::Down => self.step_selection(true),
+            Action::NextSection | Action::PrevSection if self.current_view == AppView::Banzuke => {
+                let sections = banzuke_sections(self.banzuke.as_deref().unwrap_or_default());
+                let mut starts = sections.iter().map(|(start, _)| *start);
+                let target = if action == Action::NextSection {
+                    starts.find(|start| *start > self.banzuke_panel.selection.index)
+                } else {
+                    starts.rev().find(|start| *start < self.banzuke_panel.selection.index)
+                };
+                if let Some(index) = target {
+                    self.select(index);
+                }
+            }
+            Action::Select => {
+                // If in banzuke view, show rikishi details
+                if self.current_view == AppView::Banzuke {
+                    if let Some(banzuke) = &self.banzuke
+                        && self.banzuke_panel.selection.index < banzuke.len()
+                    {
+                        let rikishi_id = banzuke[self.banzuke_panel.selection.index].rikishi_id;
+                        self.open_rikishi(rikishi_id);
+                    }
+                }
+                // If in torikumi view, show head-to-head
+                else if self.current_view == AppView::Torikumi
+                    && let Some(match_entry) = self.selected_bout()
+                {
+                    let (east_id, west_id) = (match_entry.east_id, match_entry.west_id);
+                    self.open_head_to_head(east_id, west_id);
+                }
+                // In the stats view, switch between the breakdowns and the extremes
+                else if self.current_view == AppView::Stats {
+                    self.show_extremes = !self.show_extremes;
+                }
+                else if self.current_view == AppView::Dashboard {
+                    self.open_tracked();
+                }
+                else if self.current_view == AppView::Matchups {
+                    self.open_projection();
+                }
+                else if self.current_view == AppView::Settings {
+                    self.change_setting();
+                }
+                // In the yusho history, move on to the next division
+                else if self.current_view == AppView::YushoHistory {
+                    let current = DIVISIONS.iter().position(|d| d.eq_ignore_ascii_case(&self.yusho_division)).unwrap_or(0);
+                    self.yusho_division = DIVISIONS[(current + 1) % DIVISIONS.len()].to_string();
+                }
+                // In the journal, open the day the note is about
+                else if self.current_view == AppView::Journal
+                    && let Some(day) = self.journal.for_basho(&self.basho_id).get(self.selection().index).map(|e| e.day)
+                {
+                    self.show_view(AppView::Torikumi);
+                    if day != self.day {
+                        self.day = day;
+                        self.needs_reload = true;
+                    }
+                }
+            }
+            Action::Close => {
+                // The head-to-head is drawn over the details when opened from them
+                if self.show_head_to_head {
+                    self.show_head_to_head = false;
+                    self.head_to_head_data = None;
+                } else if self.show_rikishi_details {
+                    self.show_rikishi_details = false;
+                    self.rikishi_details = None;
+                    self.rivals = None;
+                    self.achievements = None;
+                    self.bout_history = None;
+                } else if self.day_summary.is_some() {
+                    self.day_summary = None;
+                } else if self.projection.is_some() {
+                    self.projection = None;
+                } else if self.current_view == AppView::Schedule {
+                    self.toggle_schedule();
+                } else {
+                    self.show_help = false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Open the JSON inspector on the open popup's data, or else on the selected bout,
+    /// banzuke entry or the basho in the info view. Fields are shown as this app reads
+    /// them from the API.
+    pub fn inspect(&mut self) {
+        // The selection's own data would give away results the screen holds back
+        let limited = self.replay.is_some() || self.spoiler_from.is_some();
+        let selected = self.selection().index;
+        let found = if self.show_rikishi_details
+            && let Some(details) = &self.rikishi_details
+        {
+            Some((details.shikona_en.clone(), serde_json::to_string_pretty(details)))
+        } else if self.show_head_to_head
+            && let Some((rikishi_id, h2h)) = &self.head_to_head_data
+        {
+            let names = h2h.matches.first().map(|m| head_to_head_names(m, *rikishi_id)).map(|(a, b)| format!("{} - {}", a, b));
+            Some((names.unwrap_or_default(), serde_json::to_string_pretty(h2h)))
+        } else {
+            match self.current_view {
+                AppView::Torikumi => match self.selected_bout() {
+                    Some(bout) if self.bout_hidden(bout) => {
+                        self.status_message = Some(t("status.inspect_hidden").to_string());
+                        return;
+                    }
+                    bout => bout.map(|bout| (bout.id.clone(), serde_json::to_string_pretty(bout))),
+                },
+                AppView::Banzuke | AppView::BashoInfo if limited => {
+                    self.status_message = Some(t("status.inspect_hidden").to_string());
+                    return;
+                }
+                AppView::Banzuke => self
+                    .banzuke
+                    .as_ref()
+                    .and_then(|b| b.get(selected))
+                    .map(|entry| (entry.shikona_en.to_string(), serde_json::to_string_pretty(entry))),
+                AppView::BashoInfo => self.basho.as_ref().map(|basho| (self.basho_id.clone(), serde_json::to_string_pretty(basho))),
+                _ => None,
+            }
+        };
+        match found {
+            Some((name, Ok(json))) => {
+                self.inspector = Some(Inspector {
+                    title: tf("inspect.title", &[&name]),
+                    lines: json.lines().map(str::to_string).collect(),
+                    scroll: 0,
+                });
+            }
+            Some((_, Err(e))) => self.status_message = Some(tf("status.inspect_failed", &[&e])),
+            None => self.status_message = Some(t("status.inspect_nothing").to_string()),
+        }
+    }
+
+    // Keys while the JSON inspector is open: scrolling and closing. Like the help, this
+    // scrolls a page of text with no selected row, so only acceleration applies: there is
+    // nothing to center, and wrapping from the end back to the top would lose the reader.
+    fn on_inspector_key(&mut self, key: KeyCode) {
+        let action = self.keymap.action(key);
+        let step = match action {
+            Some(Action::Up | Action::Down) => self.step_rows(action == Some(Action::Down)),
+            _ => 0,
+        };
+        let Some(inspector) = &mut self.inspector else {
+            return;
+        };
+        let max_scroll = inspector.lines.len().saturating_sub(1);
+        match (key, action) {
+            (KeyCode::PageUp, _) => inspector.scroll = inspector.scroll.saturating_sub(10),
+            (KeyCode::PageDown, _) => inspector.scroll = (inspector.scroll + 10).min(max_scroll),
+            (KeyCode::Home, _) => inspector.scroll = 0,
+            (KeyCode::End, _) => inspector.scroll = max_scroll,
+            (_, Some(Action::Up | Action::Down)) => inspector.scroll = inspector.scroll.saturating_add_signed(step).min(max_scroll),
+            (_, Some(Action::Quit)) => self.should_quit = true,
+            (_, Some(Action::Inspect | Action::Close)) => self.inspector = None,
+            _ => {}
+        }
+    }
+
+    // Keys while the help overlay is open: scrolling, the search filter, and closing.
+    // Scrolling takes acceleration but not wrapping or centering, as in the inspector.
+    fn on_help_key(&mut self, key: KeyCode) {
+        if self.help_filtering {
+            match key {
+                KeyCode::Char(c) => self.help_filter.push(c),
+                KeyCode::Backspace => {
+                    self.help_filter.pop();
+                }
+                KeyCode::Enter => self.help_filtering = false,
+                KeyCode::Esc => {
+                    self.help_filtering = false;
+                    self.help_filter.clear();
+                }
+                _ => {}
+            }
+            self.help_scroll = 0;
+            return;
+        }
+        let max_scroll = self.help_lines().len().saturating_sub(1);
+        match (key, self.keymap.action(key)) {
+            (KeyCode::Char('/'), _) => self.help_filtering = true,
+            (KeyCode::PageUp, _) => self.help_scroll = self.help_scroll.saturating_sub(10),
+            (KeyCode::PageDown, _) => self.help_scroll = (self.help_scroll + 10).min(max_scroll),
+            (KeyCode::Home, _) => self.help_scroll = 0,
+            (_, Some(action @ (Action::Up | Action::Down))) => {
+                let step = self.step_rows(action == Action::Down);
+                self.help_scroll = self.help_scroll.saturating_add_signed(step).min(max_scroll);
+            }
+            (_, Some(Action::Quit)) => self.should_quit = true,
+            (_, Some(Action::ToggleHelp | Action::Close)) => {
+                self.show_help = false;
+                self.help_filter.clear();
+                self.help_scroll = 0;
+            }
+            _ => {}
+        }
+    }
+
+    /// Help overlay content generated from the keymap, narrowed by the search filter.
+    /// Sections without matching keys are left out.
+    pub fn help_lines(&self) -> Vec<HelpLine> {
+        let filter = self.help_filter.to_lowercase();
+        let matches = |keys: &str, description: &str| {
+            filter.is_empty() || keys.to_lowercase().contains(&filter) || description.to_lowercase().contains(&filter)
+        };
+        let mut lines = Vec::new();
+        for (section, actions) in HELP_SECTIONS {
+            let bound = actions.iter().map(|a| (self.keymap.label(*a), a.description_key()));
+            let fixed = FIXED_BINDINGS
+                .iter()
+                .filter(|(s, _, _)| s == section)
+                .map(|(_, keys, description)| (keys.to_string(), *description));
+            let entries: Vec<HelpLine> = bound
+                .chain(fixed)
+                .filter(|(keys, description)| !keys.is_empty() && matches(keys, t(description)))
+                .map(|(keys, description)| HelpLine::Key(keys, description))
+                .collect();
+            if !entries.is_empty() {
+                lines.push(HelpLine::Section(section));
+                lines.extend(entries);
+            }
+        }
+        lines
+    }
+
+    pub fn on_key(&mut self, key: KeyCode) {
+        // Handle input mode first
+        match self.input_mode {
+            InputMode::Normal => {
+                if self.inspector.is_some() {
+                    self.on_inspector_key(key);
+                } else if self.show_help {
+                    self.on_help_key(key);
+                } else if let Some(action) = self.keymap.action(key) {
+                    self.perform(action);
+                }
+            },
+            InputMode::EditingDay => {
+                match key {
+                    // Typed digits move the cursor straight to that day
+                    KeyCode::Char(c) if c.is_ascii_digit() => {
+                        if self.input_buffer.len() >= 2 {
+                            self.input_buffer.clear();
+                        }
+                        self.input_buffer.push(c);
+                        match self.input_buffer.parse::<u8>() {
+                            Ok(day) if (1..=self.last_day()).contains(&day) => {
+                                self.day_cursor = day;
+                                self.input_error = None;
+                            }
+                            // Lower divisions' bouts end earlier than the grid
+                            Ok(day) if (1..=BASHO_DAYS).contains(&day) => {
+                                let division = crate::i18n::division_name(&self.division);
+                                self.input_error = Some(tf("input.day_range", &[&division, &self.last_day()]));
+                            }
+                            _ => self.input_error = Some(t("input.invalid_day").to_string()),
+                        }
+                    },
+                    KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down => {
+                        let step: i8 = match key {
+                            KeyCode::Left => -1,
+                            KeyCode::Right => 1,
+                            KeyCode::Up => -(DAY_GRID_COLUMNS as i8),
+                            _ => DAY_GRID_COLUMNS as i8,
+                        };
+                        let mut day = self.day_cursor as i8 + step;
+                        // Down or on from the last row of the grid reaches the playoff below it
+                        if step > 0 && day > BASHO_DAYS as i8 && self.day_cursor <= BASHO_DAYS && self.last_day() > BASHO_DAYS {
+                            day = self.last_day() as i8;
+                        }
+                        if (1..=self.last_day() as i8).contains(&day) {
+                            self.day_cursor = day as u8;
+                        }
+                        self.input_buffer.clear();
+                        self.input_error = None;
+                    },
+                    // The loaded day needs no check; others load once their torikumi is found
+                    KeyCode::Enter if self.day_cursor == self.day && self.torikumi.as_ref().is_some_and(|t| !t.is_empty()) => {
+                        self.pick_day(self.day);
+                    },
+                    KeyCode::Enter => {
+                        self.checking_day = Some(self.day_cursor);
+                        self.requested_day_check = Some(self.day_cursor);
+                        self.input_error = None;
+                    },
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                        self.input_error = None;
+                        self.checking_day = None;
+                    },
+                    _ => {}
+                }
+            },
+            InputMode::SelectingDivision => {
+                match key {
+                    KeyCode::Up | KeyCode::Down => {
+                        self.division_selector_index = self.step_index(self.division_selector_index, DIVISIONS.len(), key == KeyCode::Down);
+                    },
+                    KeyCode::Enter => {
+                        self.division = DIVISIONS[self.division_selector_index].to_string();
+                        self.needs_reload = true;
+                        self.input_mode = InputMode::Normal;
+                        self.input_error = None;
+                    },
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.input_error = None;
+                    },
+                    _ => {}
+                }
+            },
+            InputMode::EditingBasho => {
+                match key {
+                    KeyCode::Char(c) if c.is_ascii_digit() && self.input_buffer.len() < 6 => {
+                        self.input_buffer.push(c);
+                        self.input_error = None;
+                    },
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                        self.input_error = None;
+                    },
+                    KeyCode::Enter => {
+                        let mut valid = false;
+                        if self.input_buffer.len() == 6
+                            && let Ok(year) = self.input_buffer[0..4].parse::<i32>()
+                            && let Ok(month) = self.input_buffer[4..6].parse::<u32>()
+                            && year >= 1958
+                            && (1..=12).contains(&month)
+                            && month % 2 == 1
+                        {
+                            self.basho_id = self.input_buffer.clone();
+                            self.basho_changed = true;
+                            self.needs_reload = true;
+                            self.input_mode = InputMode::Normal;
+                            self.input_buffer.clear();
+                            self.input_error = None;
+                            valid = true;
+                        }
+                        if !valid {
+                            self.input_error = Some(t("input.invalid").to_string());
+                        }
+                    },
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                        self.input_error = None;
+                    },
+                    _ => {}
+                }
+            },
+            InputMode::EditingSpoilerDay => {
+                match key {
+                    KeyCode::Char(c) if c.is_ascii_digit() && self.input_buffer.len() < 2 => {
+                        self.input_buffer.push(c);
+                        self.input_error = None;
+                    },
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                        self.input_error = None;
+                    },
+                    KeyCode::Enter => {
+                        let max_day = self.last_day();
+                        if self.input_buffer.is_empty() {
+                            self.set_spoiler_from(None);
+                            self.status_message = Some(t("status.spoiler_off").to_string());
+                        } else if let Ok(day) = self.input_buffer.parse::<u8>()
+                            && (1..=max_day).contains(&day)
+                        {
+                            self.set_spoiler_from(Some(day));
+                            self.status_message = Some(tf("status.spoiler_on", &[&day]));
+                        } else {
+                            self.input_error = Some(t("input.invalid_day").to_string());
+                            return;
+                        }
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                    },
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                        self.input_error = None;
+                    },
+                    _ => {}
+                }
+            },
+            InputMode::EnteringCommand => {
+                match key {
+                    KeyCode::Char(c) => {
+                        self.input_buffer.push(c);
+                        self.input_error = None;
+                    },
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                        self.input_error = None;
+                    },
+                    KeyCode::Enter => {
+                        let name = self.input_buffer.trim();
+                        if self.script_commands.iter().any(|c| c == name) {
+                            self.command_requested = Some(name.to_string());
+                            self.input_mode = InputMode::Normal;
+                            self.input_buffer.clear();
+                        } else {
+                            self.input_error = Some(t("input.unknown_command").to_string());
+                        }
+                    },
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                        self.input_error = None;
+                    },
+                    _ => {}
+                }
+            },
+            InputMode::JumpingToRank => {
+                match key {
+                    KeyCode::Char(c) if c.is_ascii_alphanumeric() || c == ' ' => {
+                        self.input_buffer.push(c);
+                        self.input_error = None;
+                    },
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                        self.input_error = None;
+                    },
+                    KeyCode::Enter => {
+                        let banzuke = self.banzuke.as_deref().unwrap_or_default();
+                        match rank::Rank::parse(&self.input_buffer).and_then(|target| rank_index(banzuke, &target)) {
+                            Some(index) => {
+                                self.select(index);
+                                self.input_mode = InputMode::Normal;
+                                self.input_buffer.clear();
+                            }
+                            None => self.input_error = Some(t("input.unknown_rank").to_string()),
+                        }
+                    },
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                        self.input_error = None;
+                    },
+                    _ => {}
+                }
+            },
+            InputMode::FindingBout => {
+                match key {
+                    KeyCode::Char(c) => {
+                        self.input_buffer.push(c);
+                        self.input_error = None;
+                    },
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                        self.input_error = None;
+                    },
+                    KeyCode::Enter if !self.input_buffer.trim().is_empty() => {
+                        let name = self.input_buffer.trim().to_string();
+                        self.status_message = Some(tf("status.finding_bout", &[&name, &self.day]));
+                        self.find_bout_requested = Some(name);
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                    },
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                        self.input_error = None;
+                    },
+                    _ => {}
+                }
+            },
+            InputMode::EnteringGoto => {
+                match key {
+                    KeyCode::Char(c) => {
+                        self.input_buffer.push(c);
+                        self.input_error = None;
+                    },
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                        self.input_error = None;
+                    },
+                    KeyCode::Enter => {
+                        let year = self.basho_id.get(..4).and_then(|y| y.parse().ok()).unwrap_or(2000);
+                        match crate::goto::parse(&self.input_buffer, year) {
+                            Ok(goto) => self.go_to(goto),
+                            Err(e) => self.input_error = Some(e.to_string()),
+                        }
+                    },
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                        self.input_error = None;
+                    },
+                    _ => {}
+                }
+            },
+            InputMode::Switching => {
+                match key {
+                    KeyCode::Char(c) => {
+                        self.input_buffer.push(c);
+                        self.switcher_index = 0;
+                        self.input_error = None;
+                    },
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                        self.switcher_index = 0;
+                        self.input_error = None;
+                    },
+                    KeyCode::Up | KeyCode::Down => {
+                        let len = self.switcher_matches().len().min(switcher::SHOWN);
+                        self.switcher_index = self.step_index(self.switcher_index, len, key == KeyCode::Down);
+                    },
+                    KeyCode::Enter => self.switch_to_selected(),
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                        self.switcher.clear();
+                        self.input_error = None;
+                    },
+                    _ => {}
+                }
+            },
+            InputMode::WritingNote => {
+                match key {
+                    KeyCode::Char(c) => self.input_buffer.push(c),
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                    },
+                    KeyCode::Enter if !self.input_buffer.trim().is_empty() => {
+                        let text = std::mem::take(&mut self.input_buffer);
+                        self.add_note(&text);
+                        self.input_mode = InputMode::Normal;
+                    },
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.input_buffer.clear();
+                    },
+                    _ => {}
+                }
+            },
+            InputMode::BindingKey => match key {
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                key => self.bind_key(key),
+            },
+        }
+    }
+}
+
+// Sort bouts for display. Rank order goes by the higher-ranked rikishi of each bout,
+// then the other one; unparsable ranks sort last.
+fn sort_torikumi(torikumi: &mut [TorikumiEntry], order: TorikumiOrder) {
+    match order {
+        TorikumiOrder::Match => torikumi.sort_by_key(|b| b.match_no),
+        TorikumiOrder::Reverse => torikumi.sort_by_key(|b| std::cmp::Reverse(b.match_no)),
+        TorikumiOrder::Rank => torikumi.sort_by_key(|b| {
+            let ordinal = |r: &str| rank::Rank::parse(r).map_or(u32::MAX, |r| r.ordinal());
+            let (east, west) = (ordinal(&b.east_rank), ordinal(&b.west_rank));
+            (east.min(west), east.max(west), std::cmp::Reverse(b.match_no))
+        }),
+    }
+}
+
+// Whether `filter` lets `bout` through, `decided` when its result is shown. Favorites are
+// matched by shikona, in any case.
+fn bout_shown(bout: &TorikumiEntry, decided: bool, filter: TorikumiFilter, favorites: &[String]) -> bool {
+    let tier = |r: &str| rank::Rank::parse(r).map(|r| r.tier);
+    let sanyaku = |r: &str| {
+        tier(r).is_some_and(|t| matches!(t, rank::Tier::Yokozuna | rank::Tier::Ozeki | rank::Tier::Sekiwake | rank::Tier::Komusubi))
+    };
+    let favorite = |s: &str| favorites.iter().any(|f| f.eq_ignore_ascii_case(s));
+    match filter {
+        TorikumiFilter::All => true,
+        TorikumiFilter::Sanyaku => sanyaku(&bout.east_rank) || sanyaku(&bout.west_rank),
+        TorikumiFilter::Maegashira => [&bout.east_rank, &bout.west_rank].iter().all(|r| tier(r) == Some(rank::Tier::Maegashira)),
+        TorikumiFilter::Favorites => favorite(&bout.east_shikona) || favorite(&bout.west_shikona),
+        TorikumiFilter::Unfinished => !decided,
+    }
+}
+
+// Rows of a table taken up by its borders and header row
+const TABLE_CHROME: u16 = 3;
+
+/// Table rows visible in a terminal `height` rows tall: the layout margin, header and
+/// footer bars, and the table's own borders and header take the rest.
+pub fn viewport_rows(height: u16) -> usize {
+    height.saturating_sub(2 + 3 + 3 + TABLE_CHROME) as usize
+}
+
+pub fn ui(f: &mut Frame, app: &mut App) {
+    let theme = app.theme;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Main content
+            Constraint::Length(3), // Footer
+        ])
+        .split(f.area());
+
+    // Keep the selection on screen at the current size
+    app.set_viewport_rows(chunks[1].height.saturating_sub(TABLE_CHROME) as usize);
+    app.refresh_rows();
+
+    // Header
+    let basho_date = crate::dates::basho_date(&app.basho_id);
+    let basho_month: u32 = app.basho_id[4..6].parse().unwrap_or(9);
+    let basho_name = crate::i18n::basho_name(basho_month);
+    let division = crate::i18n::division_name(&app.division);
+    
+    let header_text = if let Some(replay) = &app.replay {
+        let replay_day = tf("header.replay", &[&app.day, &app.last_day()]);
+        if replay.auto_advance {
+            format!("{} - {} {} - {} {}", basho_name, basho_date, division, replay_day, t("header.auto"))
+        } else {
+            format!("{} - {} {} - {}", basho_name, basho_date, division, replay_day)
+        }
+    } else if app.day > crate::max_day_for_division(&app.division) {
+        format!("{} - {} {} - {}", basho_name, basho_date, division, t("header.playoff"))
+    } else if basho_has_started(app) {
+        format!(
+            "{} - {} {} - {}",
+            basho_name, basho_date, division,
+            tf("header.day", &[&app.day, &crate::max_day_for_division(&app.division)])
+        )
+    } else {
+        format!(
+            "{} - {} {} {}",
+            basho_name, basho_date, division, t("header.upcoming")
+        )
+    };
+
+    let header_text = if app.live {
+        format!("{}  {}", t("header.live"), header_text)
+    } else {
+        header_text
+    };
+
+    let header_text = match app.spoiler_from {
+        Some(from) => format!("{} {}", header_text, tf("header.spoilers", &[&from])),
+        None => header_text,
+    };
+
+    let mut header_line = Line::from(header_text);
+    if app.connection == Some(Health::Offline) {
+        header_line.spans.insert(0, Span::styled(format!("{}  ", t("header.offline")), theme.negative));
+    }
+
+    let mut header_block = Block::default().borders(Borders::ALL).title(t("app.title"));
+    if let Some(banner) = adjustment_banner(app) {
+        header_block = header_block.title_bottom(Line::styled(format!(" ⚠ {} ", banner), theme.label).centered());
+    }
+    let header = Paragraph::new(header_line)
+    .style(theme.heading)
+    .alignment(Alignment::Center)
+    .block(header_block);
+
+    f.render_widget(header, chunks[0]);
+
+    // Main content
+    match app.current_view {
+        AppView::Torikumi => app.torikumi_table.render(f, chunks[1], app),
+        AppView::Banzuke => app.banzuke_panel.render(f, chunks[1], app),
+        AppView::BashoInfo => render_basho_info(f, chunks[1], app),
+        AppView::Compare => render_compare(f, chunks[1], app),
+        AppView::Stats => render_stats(f, chunks[1], app),
+        AppView::Records => render_records(f, chunks[1], app),
+        AppView::Schedule => render_schedule(f, chunks[1], app),
+        AppView::Journal => render_journal(f, chunks[1], app),
+        AppView::Dashboard => render_dashboard(f, chunks[1], app),
+        AppView::Matchups => render_matchups(f, chunks[1], app),
+        AppView::YushoHistory => render_yusho_history(f, chunks[1], app),
+        AppView::Settings => render_settings(f, chunks[1], app),
+    }
+
+    // Footer, built from the keymap so remapped keys show up
+    let footer_items = [
+        (Action::Quit, "footer.quit"),
+        (Action::ViewTorikumi, "footer.torikumi"),
+        (Action::ViewBanzuke, "footer.banzuke"),
+        (Action::ViewInfo, "footer.info"),
+        (Action::ChangeDay, "footer.day"),
+        (Action::ChangeDivision, "footer.division"),
+        (Action::ChangeBasho, "footer.basho"),
+        (Action::ToggleHelp, "footer.help"),
+    ];
+    let footer_text = footer_items
+        .iter()
+        .filter_map(|(action, label)| {
+            let key = app.keymap.keys_for(*action).into_iter().next()?;
+            Some(format!("{}: {}", key_name(key), t(label)))
+        })
+        .collect::<Vec<_>>()
+        .join(" | ");
+    let mut footer_lines = vec![Line::from(footer_text)];
+    if let Some(status) = &app.status_message {
+        footer_lines.push(Line::from(status.clone()));
+    }
+
+    let mut footer_block = Block::default().borders(Borders::ALL);
+    if let Some(health) = app.connection {
+        let (label, style) = match health {
+            Health::Online => ("footer.online", theme.positive),
+            Health::Degraded => ("footer.degraded", theme.label),
+            Health::Offline => ("footer.offline", theme.negative),
+        };
+        footer_block = footer_block.title_bottom(Line::styled(format!(" ● {} ", t(label)), style).left_aligned());
+    }
+    if let Some(version) = &app.update_available {
+        footer_block = footer_block.title_bottom(
+            Line::styled(tf("footer.update", &[&version, &env!("CARGO_PKG_VERSION")]), theme.positive).right_aligned(),
+        );
+    }
+    let footer = Paragraph::new(footer_lines)
+        .style(theme.accent)
+        .alignment(Alignment::Center)
+        .block(footer_block);
+
+    f.render_widget(footer, chunks[2]);
+
+    PopupStack::open(app).render(f, app);
+}
+
+// Title of the torikumi table with the selected position, naming the filter when one
+// hides bouts and counting the decided bouts while the day is in progress
+fn torikumi_title(app: &App) -> String {
+    let title = match app.torikumi_filter {
+        TorikumiFilter::All => t("torikumi.title").to_string(),
+        filter => format!("{} · {}", t("torikumi.title"), t(filter.label_key())),
+    };
+    let title = position_title(&title, app.torikumi_table.selection.index, app.torikumi_table.shown().len());
+    let total = app.torikumi.as_ref().map_or(0, |t| t.len());
+    match app.bouts_remaining() {
+        Some(left) if left > 0 => format!("{} · {}", title, tf("torikumi.decided", &[&(total - left), &total])),
+        _ => title,
+    }
+}
+
+// Why the loaded day differs from the one asked for, kept on the header until the next load
+fn adjustment_banner(app: &App) -> Option<String> {
+    let (requested, reason) = app.day_adjustment?;
+    Some(match reason {
+        DayAdjustment::Future => tf("adjusted.future", &[&requested, &app.day]),
+        DayAdjustment::Finished => tf("adjusted.finished", &[&requested, &app.day]),
+        DayAdjustment::DivisionDays => {
+            tf("adjusted.division", &[&requested, &crate::i18n::division_name(&app.division), &app.last_day()])
+        }
+    })
+}
+
+// Shown under the torikumi once every bout of the loaded day is decided
+fn remaining_title(app: &App) -> Line<'static> {
+    match app.bouts_remaining() {
+        Some(0) => Line::from(Span::styled(t("torikumi.complete"), app.theme.positive)).right_aligned(),
+        _ => Line::default(),
+    }
+}
+
+// Cell texts of one torikumi row, in column order
+fn torikumi_cells(app: &App, columns: &[TorikumiColumn], match_entry: &TorikumiEntry) -> Vec<CachedCell> {
+    let hidden = app.bout_hidden(match_entry);
+    let winner_id = match_entry.winner_id.filter(|_| !hidden);
+    let series = app.bout_series.as_ref().filter(|(key, _)| *key == app.bout_series_key()).map(|(_, series)| series);
+
+    columns.iter().map(|column| match column {
+        TorikumiColumn::East | TorikumiColumn::West => {
+            // Compose "Name (Rank) (W-L)" and highlight the winner if present
+            let (name, id, rank) = if *column == TorikumiColumn::East {
+                (&match_entry.east_shikona, match_entry.east_id, &match_entry.east_rank)
+            } else {
+                (&match_entry.west_shikona, match_entry.west_id, &match_entry.west_rank)
+            };
+            let (w, l) = app.record_map.get(&id).copied().unwrap_or((0, 0));
+            CachedCell {
+                text: format!("{} ({}) ({}-{})", romanize(name), abbr_rank(rank), w, l),
+                winner: winner_id == Some(id),
+                style: app.highlight(id, name, rank),
+            }
+        }
+        TorikumiColumn::Kimarite => {
+            let mut category = None;
+            let kimarite = if hidden {
+                t("torikumi.hidden").to_string()
+            } else if match_entry.winner_id.is_none()
+                && let Some(estimate) = bout_estimate(app, match_entry)
+            {
+                estimate
+            } else {
+                category = match_entry.kimarite.as_deref().and_then(kimarite::category);
+                match_entry.kimarite.clone().unwrap_or_else(|| "N/A".to_string())
+            };
+            // Capitalize first letter of kimarite
+            let mut chars = kimarite.chars();
+            let text = match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => kimarite,
+            };
+            CachedCell { text, winner: false, style: app.kimarite_style(category) }
+        }
+        TorikumiColumn::MatchNo => CachedCell::plain(match_entry.match_no.to_string()),
+        TorikumiColumn::RankDiff => {
+            let text = rank::rank_difference(&match_entry.east_rank, &match_entry.west_rank)
+                .map(|d| d.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            CachedCell::plain(text)
+        }
+        TorikumiColumn::WinProb => {
+            // Show the favorite's side and chance, e.g. "E 62%"
+            let text = match rank::win_probability(&match_entry.east_rank, &match_entry.west_rank) {
+                Some(p) if p >= 0.5 => format!("{} {:.0}%", t("torikumi.east_short"), p * 100.0),
+                Some(p) => format!("{} {:.0}%", t("torikumi.west_short"), (1.0 - p) * 100.0),
+                None => "-".to_string(),
+            };
+            CachedCell::plain(text)
+        }
+        TorikumiColumn::HeadToHead => CachedCell::plain(match series {
+            Some(series) => series.get(&match_entry.id).map_or_else(|| "-".to_string(), |(east, west)| format!("{}-{}", east, west)),
+            None => "…".to_string(),
+        }),
+        // Points at the side leading the career series, until the bout is decided
+        TorikumiColumn::Edge => CachedCell::plain(match (series, winner_id) {
+            (_, Some(_)) => String::new(),
+            (None, None) => "…".to_string(),
+            (Some(series), None) => match series.get(&match_entry.id) {
+                Some(&(east, west)) if east > west => format!("◀ +{}", east - west),
+                Some(&(east, west)) if west > east => format!("+{} ▶", west - east),
+                Some(&(east, _)) if east > 0 => "=".to_string(),
+                _ => "-".to_string(),
+            },
+        }),
+    }).collect()
+}
+
+// When an undecided bout should start, e.g. "~17:40 JST (09:40)" with the local time
+// when it differs from JST
+fn bout_estimate(app: &App, bout: &TorikumiEntry) -> Option<String> {
+    let date = app.basho.as_ref()?.start_date_naive()? + chrono::Duration::days(i64::from(bout.day) - 1);
+    let last = app.torikumi.iter().flatten().map(|b| b.match_no).max()?;
+    let start = crate::live::estimated_start(&app.division, date, bout.match_no, last)?;
+    let jst = start.format("%H:%M").to_string();
+    let local = start.with_timezone(&chrono::Local);
+    Some(if local.offset().local_minus_utc() == start.offset().local_minus_utc() {
+        tf("torikumi.estimate", &[&jst])
+    } else {
+        tf("torikumi.estimate_local", &[&jst, &local.format("%H:%M")])
+    })
+}
 
 // Convert a rank string to a compact abbreviation, e.g.:
 // "Maegashira 7 East" -> "M7", "M7e" -> "M7", "Ozeki" -> "O", "Yokozuna" -> "Y"
@@ -619,179 +2679,786 @@ fn abbr_rank(rank: &str) -> String {
     }
 }
 
-fn render_banzuke(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
-    if let Some(banzuke) = &app.banzuke {
-        let visible_height = area.height.saturating_sub(3) as usize; // Account for borders and header
-        let start_index = app.scroll_offset;
-        let end_index = (start_index + visible_height).min(banzuke.len());
-        
-        // Determine total days based on division
-        // Makuuchi and Juryo have 15 days, Makushita and below have 7 days
-        let total_days = if app.division.to_lowercase().contains("makuuchi") 
-            || app.division.to_lowercase().contains("juryo") {
-            15u8
-        } else {
-            7u8
+// Banzuke index of `target` ("M10", "Ms12w", "Ozeki"), matching its number and side only
+// when given. A rank nobody holds lands on the next one down.
+fn rank_index(banzuke: &[BanzukeEntry], target: &rank::Rank) -> Option<usize> {
+    let ranks: Vec<Option<rank::Rank>> = banzuke.iter().map(|e| rank::Rank::parse(&e.rank)).collect();
+    let exact = ranks.iter().position(|r| r.is_some_and(|r| r.matches(target)));
+    exact.or_else(|| ranks.iter().position(|r| r.is_some_and(|r| r.ordinal() >= target.ordinal())))
+}
+
+// Where each run of ranks in the same tier starts, e.g. the first Ozeki. Ranks that
+// can't be parsed form a section without a name.
+fn banzuke_sections(banzuke: &[BanzukeEntry]) -> Vec<(usize, Option<rank::Tier>)> {
+    let mut sections: Vec<(usize, Option<rank::Tier>)> = Vec::new();
+    for (i, entry) in banzuke.iter().enumerate() {
+        let tier = rank::Rank::parse(&entry.rank).map(|r| r.tier);
+        if sections.last().is_none_or(|(_, last)| *last != tier) {
+            sections.push((i, tier));
+        }
+    }
+    sections
+}
+
+// Cell texts of one banzuke row, in column order. Records count the first `total_days`
+// days (7 below Juryo, fewer in replay mode).
+fn banzuke_cells(app: &App, columns: &[BanzukeColumn], entry: &BanzukeEntry, total_days: u8, today: chrono::NaiveDate) -> Vec<String> {
+    let profile = app.rikishi_profiles.get(&entry.rikishi_id);
+    let previous_ranks = app.previous_ranks.as_ref().map(|(_, ranks)| ranks);
+    let recent_records = app.recent_records.as_ref().map(|(_, records)| records);
+
+    columns.iter().map(|column| match column {
+        BanzukeColumn::Rank => app.rank_text(&entry.rank),
+        BanzukeColumn::Shikona => romanize(&entry.shikona_en).into_owned(),
+        BanzukeColumn::ShikonaJp => entry.shikona_jp.clone().unwrap_or_default(),
+        BanzukeColumn::Record => {
+            // Calculate W-L-Absent from the record
+            let (wins, losses, absent) = entry.win_loss_absent(total_days);
+            format!("{}-{}-{}", wins, losses, absent)
+        }
+        BanzukeColumn::Streak => match entry.streak(total_days) {
+            Some((true, n)) => tf("banzuke.streak_wins", &[&n]),
+            Some((false, n)) => tf("banzuke.streak_losses", &[&n]),
+            None => "-".to_string(),
+        },
+        BanzukeColumn::Heya => profile.and_then(|p| p.heya.clone()).unwrap_or_default(),
+        BanzukeColumn::Shusshin => profile.and_then(|p| p.shusshin.clone()).unwrap_or_default(),
+        BanzukeColumn::Age => profile
+            .and_then(|p| p.birth_date.as_deref())
+            .and_then(|b| chrono::NaiveDate::parse_from_str(b.get(..10)?, "%Y-%m-%d").ok())
+            .and_then(|date| today.years_since(date))
+            .map(|age| age.to_string())
+            .unwrap_or_default(),
+        BanzukeColumn::RankChange => {
+            let previous = previous_ranks.and_then(|ranks| ranks.get(&entry.rikishi_id));
+            match previous.and_then(|p| Some((rank::Rank::parse(p)?, rank::Rank::parse(&entry.rank)?))) {
+                Some((before, now)) if before.ordinal() > now.ordinal() => format!("▲{}", before.ordinal() - now.ordinal()),
+                Some((before, now)) if before.ordinal() < now.ordinal() => format!("▼{}", now.ordinal() - before.ordinal()),
+                Some(_) => "=".to_string(),
+                None => "-".to_string(),
+            }
+        }
+        BanzukeColumn::Recent => match recent_records {
+            Some(records) => records.get(&entry.rikishi_id).map_or_else(|| "-".to_string(), |r| crate::recent::strip(r)),
+            None => "…".to_string(),
+        },
+    }).collect()
+}
+
+fn render_basho_info(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let theme = app.theme;
+    if let Some(basho) = &app.basho {
+
+        let mut text = vec![
+            // Line::from(vec![
+            //     Span::styled("Location: ", theme.label),
+            //     Span::raw(basho.location.as_deref().unwrap_or("Unknown")),
+            // ]), TODO: Fix unknown location
+            Line::from(vec![
+                Span::styled(t("info.start"), theme.label),
+                Span::raw(basho.start_date.as_deref().map(crate::dates::date).unwrap_or_else(|| t("info.unknown").to_string())),
+            ]),
+            Line::from(vec![
+                Span::styled(t("info.end"), theme.label),
+                Span::raw(basho.end_date.as_deref().map(crate::dates::date).unwrap_or_else(|| t("info.unknown").to_string())),
+            ]),
+        ];
+
+        if let Some(yusho_list) = &basho.yusho
+            && app.spoiler_from.is_none()
+            && app.replay.is_none()
+        {
+            text.push(Line::from(""));
+            text.push(Line::from(vec![
+                Span::styled(t("info.yusho"), theme.positive.add_modifier(Modifier::BOLD)),
+            ]));
+            
+            for yusho in yusho_list {
+                text.push(Line::from(vec![
+                    Span::styled(t("info.division"), theme.positive),
+                    Span::raw(crate::i18n::division_name(&yusho.division)),
+                ]));
+                text.push(Line::from(vec![
+                    Span::styled(t("info.winner"), theme.positive),
+                    Span::raw(romanize(&yusho.shikona_en)),
+                ]));
+                text.push(Line::from(""));
+            }
+        }
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(t("info.title")))
+            .wrap(ratatui::widgets::Wrap { trim: true });
+
+        f.render_widget(paragraph, area);
+    } else {
+        let paragraph = Paragraph::new(t("info.loading"))
+            .block(Block::default().borders(Borders::ALL).title(t("info.title")))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+    }
+}
+
+fn render_compare(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let theme = app.theme;
+    let block = Block::default().borders(Borders::ALL).title(t("compare.title"));
+    if app.compare_ids.is_empty() {
+        let hint = tf("compare.empty", &[&MAX_COMPARED, &app.keymap.label(Action::ToggleCompare)]);
+        let paragraph = Paragraph::new(hint).block(block).alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let (names, rows) = compare_rows(app, Utc::now().date_naive());
+    let constraints: Vec<Constraint> = std::iter::once(Constraint::Fill(1))
+        .chain(names.iter().map(|_| Constraint::Fill(1)))
+        .collect();
+    let header = Row::new(std::iter::once(String::new()).chain(names)).style(theme.heading);
+    let rows = rows.into_iter().map(|(label, cells)| {
+        Row::new(std::iter::once(Cell::from(Span::styled(label, theme.label))).chain(cells.into_iter().map(Cell::from)))
+    });
+    let table = Table::new(rows, constraints).header(header).block(block);
+    f.render_widget(table, area);
+}
+
+// Column headings (one per compared rikishi) and labelled rows of the comparison table.
+// Values not fetched yet, or not in the loaded banzuke, show as "-".
+fn compare_rows(app: &App, today: chrono::NaiveDate) -> (Vec<String>, Vec<(String, Vec<String>)>) {
+    let ids = &app.compare_ids;
+    let comparison = &app.comparison;
+    let entry = |id: u32| app.banzuke.iter().flatten().find(|e| e.rikishi_id == id);
+    let name = |id: u32| match (entry(id), comparison.profiles.get(&id)) {
+        (Some(e), _) => romanize(&e.shikona_en).into_owned(),
+        (None, Some(p)) => romanize(&p.shikona_en).into_owned(),
+        (None, None) => id.to_string(),
+    };
+    let dash = || "-".to_string();
+    let row = |label: &str, cell: &dyn Fn(u32) -> Option<String>| {
+        (label.to_string(), ids.iter().map(|id| cell(*id).unwrap_or_else(dash)).collect::<Vec<_>>())
+    };
+    let mut rows = vec![
+        row(t("compare.rank"), &|id| {
+            let rank = entry(id).map(|e| e.rank.as_str()).or_else(|| comparison.profiles.get(&id)?.current_rank.as_deref())?;
+            Some(romanize(rank).into_owned())
+        }),
+        row(t("compare.record"), &|id| {
+            let entry = entry(id)?;
+            let (w, l, a) = entry.win_loss_absent(app.revealed_for(entry));
+            Some(format!("{}-{}-{}", w, l, a))
+        }),
+        row(t("compare.heya"), &|id| comparison.profiles.get(&id)?.heya.clone()),
+        row(t("compare.age"), &|id| {
+            let birth = comparison.profiles.get(&id)?.birth_date.as_deref()?;
+            let date = chrono::NaiveDate::parse_from_str(birth.get(..10)?, "%Y-%m-%d").ok()?;
+            Some(today.years_since(date)?.to_string())
+        }),
+        row(t("compare.height"), &|id| Some(height_text(app.units, comparison.profiles.get(&id)?.height?))),
+        row(t("compare.weight"), &|id| Some(weight_text(app.units, comparison.profiles.get(&id)?.weight?))),
+        row(t("compare.career"), &|id| {
+            let stats = app.career_stats.get(&id)?;
+            Some(format!("{}-{}-{}", stats.total_wins, stats.total_losses, stats.total_absences))
+        }),
+        row(t("compare.basho"), &|id| Some(app.career_stats.get(&id)?.basho.to_string())),
+        row(t("compare.yusho"), &|id| Some(app.career_stats.get(&id)?.yusho.to_string())),
+    ];
+
+    // Bouts of the loaded basho whose results are hidden don't count
+    let shown = |m: &crate::api::HeadToHeadMatch| !(m.basho_id == app.basho_id && app.results_hidden(m.day));
+    for &opponent in ids {
+        rows.push(row(&tf("compare.versus", &[&name(opponent)]), &|id| {
+            if id == opponent {
+                return None;
+            }
+            let (wins, losses) = comparison.record_against(id, opponent, shown)?;
+            Some(format!("{}-{}", wins, losses))
+        }));
+    }
+
+    (ids.iter().map(|id| name(*id)).collect(), rows)
+}
+
+fn height_text(units: Units, cm: u32) -> String {
+    match units {
+        Units::Metric => format!("{} cm", cm),
+        Units::Imperial => {
+            let total_inches = (cm as f64 / 2.54).round() as u32;
+            format!("{}' {}\"", total_inches / 12, total_inches % 12)
+        }
+    }
+}
+
+fn weight_text(units: Units, kg: u32) -> String {
+    match units {
+        Units::Metric => format!("{} kg", kg),
+        Units::Imperial => format!("{} lbs", (kg as f64 * 2.20462).round() as u32),
+    }
+}
+
+fn render_stats(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let theme = app.theme;
+    let title = tf("stats.title", &[&crate::i18n::division_name(&app.division)]);
+    let Some(banzuke) = &app.banzuke else {
+        let paragraph = Paragraph::new(t("banzuke.loading"))
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    };
+    let stats = DivisionStats::compute(banzuke, &app.rikishi_profiles, |e| app.revealed_for(e), Utc::now().date_naive());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(4), Constraint::Min(0)])
+        .split(area);
+
+    let unknown = || "-".to_string();
+    let summary = vec![
+        Line::from(vec![
+            Span::styled(t("stats.rikishi"), theme.label),
+            Span::raw(stats.rikishi.to_string()),
+            Span::raw("    "),
+            Span::styled(t("stats.average_age"), theme.label),
+            Span::raw(stats.average_age.map(|age| format!("{:.1}", age)).unwrap_or_else(unknown)),
+        ]),
+        Line::from(vec![
+            Span::styled(t("stats.average_height"), theme.label),
+            Span::raw(stats.average_height.map(|h| height_text(app.units, h.round() as u32)).unwrap_or_else(unknown)),
+            Span::raw("    "),
+            Span::styled(t("stats.average_weight"), theme.label),
+            Span::raw(stats.average_weight.map(|w| weight_text(app.units, w.round() as u32)).unwrap_or_else(unknown)),
+        ]),
+    ];
+    let hint = Line::styled(tf("stats.show_extremes", &[&app.keymap.label(Action::Select)]), theme.accent).right_aligned();
+    let summary = Paragraph::new(summary).block(Block::default().borders(Borders::ALL).title(title).title_bottom(hint));
+    f.render_widget(summary, chunks[0]);
+
+    if app.show_extremes {
+        render_extremes(f, chunks[1], app, &stats.extremes);
+        return;
+    }
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 4); 4])
+        .split(chunks[1]);
+    // Each panel's rows as (name, count, style of the name)
+    let plain = |counts: &[(String, usize)]| counts.iter().map(|(name, n)| (name.clone(), *n, Style::default())).collect::<Vec<_>>();
+    let kimarite: Vec<_> = stats
+        .kimarite
+        .iter()
+        .map(|(name, n)| (name.clone(), *n, app.kimarite_style(kimarite::category(name))))
+        .collect();
+    let categories: Vec<_> = stats
+        .kimarite_categories
+        .iter()
+        .map(|(category, n)| (t(category.label_key()).to_string(), *n, app.kimarite_style(Some(*category))))
+        .collect();
+    let records: Vec<(String, usize)> = stats.records.iter().map(|((w, l), n)| (format!("{}-{}", w, l), *n)).collect();
+    let panels = [
+        (t("stats.heya"), plain(&stats.heya)),
+        (t("stats.kimarite_categories"), categories),
+        (t("stats.kimarite"), kimarite),
+        (t("stats.records"), plain(&records)),
+    ];
+    for ((heading, counts), area) in panels.into_iter().zip(columns.iter()) {
+        let rows = counts.iter().map(|(name, count, style)| Row::new([Cell::from(Span::styled(name.as_str(), *style)), Cell::from(count.to_string())]));
+        let table = Table::new(rows, [Constraint::Fill(1), Constraint::Length(4)])
+            .block(Block::default().borders(Borders::ALL).title(Span::styled(heading, theme.heading)));
+        f.render_widget(table, *area);
+    }
+}
+
+fn render_extremes(f: &mut Frame, area: ratatui::layout::Rect, app: &App, extremes: &Extremes) {
+    let theme = app.theme;
+    let units = app.units;
+    let show = |superlative: &Option<Superlative>, format: &dyn Fn(u32) -> String| {
+        superlative.as_ref().map(|s| (romanize(&s.shikona).into_owned(), format(s.value)))
+    };
+    let age = |years: u32| tf("stats.years", &[&years]);
+    let entries = [
+        (t("stats.heaviest"), show(&extremes.heaviest, &|kg| weight_text(units, kg))),
+        (t("stats.lightest"), show(&extremes.lightest, &|kg| weight_text(units, kg))),
+        (t("stats.tallest"), show(&extremes.tallest, &|cm| height_text(units, cm))),
+        (t("stats.shortest"), show(&extremes.shortest, &|cm| height_text(units, cm))),
+        (t("stats.oldest"), show(&extremes.oldest, &age)),
+        (t("stats.youngest"), show(&extremes.youngest, &age)),
+        (t("stats.longest_serving"), show(&extremes.longest_serving, &|d| format!("{}-{:02}", d / 100, d % 100))),
+    ];
+    let rows = entries.into_iter().map(|(label, shown)| {
+        let (shikona, value) = shown.unwrap_or_else(|| ("-".to_string(), String::new()));
+        Row::new([Cell::from(Span::styled(label, theme.label)), Cell::from(shikona), Cell::from(value)])
+    });
+    let title = tf("stats.extremes", &[&app.keymap.label(Action::Select)]);
+    let table = Table::new(rows, [Constraint::Fill(1), Constraint::Fill(1), Constraint::Fill(1)])
+        .block(Block::default().borders(Borders::ALL).title(Span::styled(title, theme.heading)));
+    f.render_widget(table, area);
+}
+
+fn render_records(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let theme = app.theme;
+    let division = crate::i18n::division_name(&app.division);
+    let Some(banzuke) = &app.banzuke else {
+        let paragraph = Paragraph::new(t("banzuke.loading"))
+            .block(Block::default().borders(Borders::ALL).title(tf("records.title", &[&division])))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    };
+    let records = CareerRecords::compute(banzuke, &app.career_stats, &app.career_bouts, app.last_visible_day().map(|day| (app.basho_id.as_str(), day)));
+    let loaded = banzuke
+        .iter()
+        .filter(|e| app.career_stats.contains_key(&e.rikishi_id) && app.career_bouts.contains_key(&e.rikishi_id))
+        .count();
+    let title = if loaded < banzuke.len() {
+        tf("records.title_loading", &[&division, &loaded, &banzuke.len()])
+    } else {
+        tf("records.title", &[&division])
+    };
+    let outer = Block::default().borders(Borders::ALL).title(title);
+    let inner = outer.inner(area);
+    f.render_widget(outer, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(inner);
+    let split = |area, parts| {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, parts); parts as usize])
+            .split(area)
+    };
+    let (top, bottom) = (split(rows[0], 3), split(rows[1], 2));
+
+    let counts = |list: &[(String, u32)]| list.iter().map(|(name, n)| (romanize(name).into_owned(), n.to_string())).collect::<Vec<_>>();
+    let panels = [
+        (t("records.yusho"), counts(&records.most_yusho), top[0]),
+        (t("records.wins"), counts(&records.most_wins), top[1]),
+        (t("records.kinboshi"), counts(&records.most_kinboshi), top[2]),
+        (
+            t("records.win_rate"),
+            records.best_win_rate.iter().map(|(name, rate)| (romanize(name).into_owned(), format!("{:.1}%", rate * 100.0))).collect(),
+            bottom[0],
+        ),
+        (t("records.streak"), counts(&records.longest_streaks), bottom[1]),
+    ];
+    for (heading, list, area) in panels {
+        let rows = list.into_iter().enumerate().map(|(i, (name, value))| {
+            Row::new([Cell::from(format!("{}.", i + 1)), Cell::from(name), Cell::from(value)])
+        });
+        let table = Table::new(rows, [Constraint::Length(4), Constraint::Fill(1), Constraint::Length(7)])
+            .block(Block::default().borders(Borders::ALL).title(Span::styled(heading, theme.heading)));
+        f.render_widget(table, area);
+    }
+}
+
+fn render_schedule(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let theme = app.theme;
+    let banzuke = app.banzuke.as_deref().unwrap_or_default();
+    let entry = banzuke.iter().find(|e| Some(e.rikishi_id) == app.schedule_rikishi);
+    let Some(entry) = entry else {
+        let paragraph = Paragraph::new(t("schedule.missing"))
+            .block(Block::default().borders(Borders::ALL).title(t("schedule.heading")))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let days = crate::max_day_for_division(&app.division);
+    let revealed = app.revealed_for(entry);
+    let torikumi = app.torikumi.as_deref().unwrap_or_default();
+    let rows = schedule::rows(banzuke, torikumi, entry.rikishi_id, days, revealed);
+    let (wins, losses, absent) = entry.win_loss_absent(revealed);
+    let title = tf("schedule.title", &[&romanize(&entry.shikona_en), &romanize(&entry.rank), &format!("{}-{}-{}", wins, losses, absent)]);
+
+    let table_rows = rows.iter().map(|row| {
+        let (outcome, style) = match &row.outcome {
+            Outcome::Win => (t("schedule.win").to_string(), theme.positive),
+            Outcome::Loss => (t("schedule.loss").to_string(), theme.negative),
+            Outcome::FusenWin => (t("schedule.fusen_win").to_string(), theme.positive),
+            Outcome::FusenLoss => (t("schedule.fusen_loss").to_string(), theme.negative),
+            Outcome::Hidden => (t("torikumi.hidden").to_string(), theme.muted),
+            Outcome::Scheduled => (t("schedule.scheduled").to_string(), theme.accent),
+            Outcome::Other(result) => (result.clone(), Style::default()),
+        };
+        let day = match row.day {
+            Some(day) => day.to_string(),
+            None => format!("#{}", row.bout),
+        };
+        Row::new([
+            Cell::from(day),
+            Cell::from(romanize(&row.opponent).into_owned()),
+            Cell::from(row.opponent_rank.as_deref().map_or_else(|| "-".to_string(), |rank| romanize(rank).into_owned())),
+            Cell::from(Span::styled(outcome, style)),
+            Cell::from(row.kimarite.clone().unwrap_or_default()),
+        ])
+    });
+    let header = Row::new([
+        t("schedule.day"),
+        t("schedule.opponent"),
+        t("schedule.rank"),
+        t("schedule.result"),
+        t("torikumi.kimarite"),
+    ])
+    .style(theme.heading);
+    let constraints = [Constraint::Length(5), Constraint::Fill(2), Constraint::Fill(2), Constraint::Fill(1), Constraint::Fill(2)];
+    let table = Table::new(table_rows, constraints)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(table, area);
+}
+
+fn render_journal(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let theme = app.theme;
+    let basho = format!("{} {}", crate::i18n::basho_name(app.basho_id[4..6].parse().unwrap_or(1)), crate::dates::basho_date(&app.basho_id));
+    let entries = app.journal.for_basho(&app.basho_id);
+    if entries.is_empty() {
+        let key = app.keymap.keys_for(Action::AddNote).into_iter().next().map(key_name).unwrap_or_default();
+        let paragraph = Paragraph::new(tf("journal.empty", &[&key]))
+            .block(Block::default().borders(Borders::ALL).title(tf("journal.title", &[&basho])))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+    let rows = entries.iter().enumerate().skip(app.selection().scroll).take(app.viewport_rows).map(|(i, entry)| {
+        let style = if i == app.selection().index { theme.selected } else { Style::default() };
+        Row::new([
+            Cell::from(tf("journal.day", &[&entry.day])),
+            Cell::from(Span::styled(entry.written.format("%Y-%m-%d %H:%M").to_string(), theme.muted)),
+            Cell::from(entry.text.clone()),
+        ])
+        .style(style)
+    });
+    let header = Row::new([t("journal.day_heading"), t("journal.written"), t("journal.note")]).style(theme.heading);
+    let table = Table::new(rows, [Constraint::Length(8), Constraint::Length(17), Constraint::Fill(1)])
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(position_title(&tf("journal.title", &[&basho]), app.selection().index, entries.len())));
+    f.render_widget(table, area);
+}
+
+fn render_yusho_history(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let theme = app.theme;
+    let history = app.yusho_history.as_deref().unwrap_or_default();
+    let title = tf("yusho_history.title", &[&crate::i18n::division_name(&app.yusho_division)]);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(position_title(&title, app.selection().index, history.len()))
+        .title_bottom(Line::styled(tf("yusho_history.hint", &[&app.keymap.label(Action::Select)]), theme.muted).centered());
+    if app.yusho_history.is_none() {
+        f.render_widget(Paragraph::new(t("yusho_history.loading")).block(block).alignment(Alignment::Center), area);
+        return;
+    }
+    // The loaded basho's winners would give away results the screen holds back
+    let limited = app.replay.is_some() || app.spoiler_from.is_some();
+    let rows = history.iter().enumerate().skip(app.selection().scroll).take(app.viewport_rows).map(|(i, (basho_id, yusho))| {
+        let style = if i == app.selection().index { theme.selected } else { Style::default() };
+        let winner = yusho.as_deref().and_then(|y| yusho_history::winner(y, &app.yusho_division));
+        let (shikona, shikona_jp) = match (yusho, winner) {
+            _ if limited && *basho_id == app.basho_id => (Span::styled(t("torikumi.hidden"), theme.muted), String::new()),
+            (None, _) => (Span::styled(t("yusho_history.unavailable"), theme.negative), String::new()),
+            (Some(_), None) => (Span::styled(t("yusho_history.none"), theme.muted), String::new()),
+            (Some(_), Some(winner)) => (Span::raw(romanize(&winner.shikona_en).into_owned()), winner.shikona_jp.clone()),
         };
-        
-        let rows: Vec<Row> = banzuke
-            .iter()
-            .enumerate()
-            .skip(start_index)
-            .take(end_index - start_index)
-            .map(|(i, entry)| {
-                let style = if i == app.selected_index {
-                    Style::default().bg(Color::Yellow).fg(Color::Black)
-                } else {
-                    Style::default()
-                };
+        Row::new([Cell::from(crate::dates::basho_date(basho_id)), Cell::from(shikona), Cell::from(shikona_jp)]).style(style)
+    });
+    let table = Table::new(rows, [Constraint::Fill(1), Constraint::Fill(1), Constraint::Fill(1)])
+        .header(Row::new([t("yusho_history.basho"), t("banzuke.wrestler"), t("banzuke.shikona_jp")]).style(theme.heading))
+        .block(block);
+    f.render_widget(table, area);
+}
 
-                // Calculate W-L-Absent from the record
-                let (wins, losses, absent) = if let Some(records) = &entry.record {
-                    let mut w = 0;
-                    let mut l = 0;
-                    for r in records {
-                        match r.result.as_str() {
-                            "win" => w += 1,
-                            "loss" => l += 1,
-                            _ => {}, // fusen-loss, fusen-win, or other - don't count as absent
-                        }
-                    }
-                    // Calculate absent as total days minus wins and losses
-                    let a = total_days.saturating_sub(w).saturating_sub(l);
-                    (w, l, a)
+fn render_settings(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let theme = app.theme;
+    let all = Setting::all();
+    let rows = all.iter().enumerate().skip(app.selection().scroll).take(app.viewport_rows).map(|(i, setting)| {
+        let style = if i == app.selection().index { theme.selected } else { Style::default() };
+        let (label, value) = match setting {
+            Setting::Theme => (t("settings.theme").to_string(), t(app.theme_name.label_key()).to_string()),
+            Setting::Units => (t("settings.units").to_string(), settings::units_value(app.units).to_string()),
+            Setting::RefreshInterval => (t("settings.refresh").to_string(), tf("settings.seconds", &[&app.refresh_interval_secs])),
+            Setting::Division => (t("settings.division").to_string(), crate::i18n::division_name(&app.default_division)),
+            Setting::Key(action) => {
+                let keys = if i == app.selection().index && app.input_mode == InputMode::BindingKey {
+                    t("settings.press_key").to_string()
                 } else {
-                    (0, 0, 0)
+                    app.keymap.label(*action)
                 };
-                
-                let result_str = format!("{}-{}-{}", wins, losses, absent);
-
-                Row::new(vec![
-                    Cell::from(entry.rank.clone()),
-                    Cell::from(entry.shikona_en.clone()),
-                    Cell::from(result_str),
-                ]).style(style)
-            })
-            .collect();
-
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Percentage(40),  // Rank
-                Constraint::Percentage(40),  // Wrestler name
-                Constraint::Percentage(20),  // Result (W-L-A)
-            ],
-        )
-        .header(
-            Row::new(vec!["Rank", "Wrestler", "Result"])
-                .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
-        )
-        .block(Block::default().borders(Borders::ALL).title("Banzuke"));
+                (tf("settings.key", &[&t(action.description_key())]), keys)
+            }
+        };
+        Row::new([Cell::from(Span::styled(label, theme.label)), Cell::from(Span::styled(value, theme.accent))]).style(style)
+    });
+    let file = app.config_path.as_ref().map_or_else(|| t("settings.no_file").to_string(), |p| p.display().to_string());
+    let table = Table::new(rows, [Constraint::Fill(2), Constraint::Fill(1)])
+        .header(Row::new([t("settings.setting"), t("settings.value")]).style(theme.heading))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(position_title(&tf("settings.title", &[&file]), app.selection().index, all.len()))
+                .title_bottom(Line::styled(tf("settings.hint", &[&app.keymap.label(Action::Select)]), theme.muted).centered()),
+        );
+    f.render_widget(table, area);
+}
 
-        f.render_widget(table, area);
+fn render_dashboard(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let theme = app.theme;
+    let tracked = app.dashboard.as_deref().unwrap_or_default();
+    let title = position_title(&tf("dashboard.title", &[&app.day]), app.selection().index, tracked.len());
+    let block = Block::default().borders(Borders::ALL).title(title);
+    let message = if app.favorites.is_empty() {
+        Some(t("dashboard.no_favorites"))
+    } else if app.dashboard.is_none() {
+        Some(t("dashboard.loading"))
     } else {
-        let paragraph = Paragraph::new("Loading banzuke data...")
-            .block(Block::default().borders(Borders::ALL).title("Banzuke"))
-            .alignment(Alignment::Center);
-        f.render_widget(paragraph, area);
+        None
+    };
+    if let Some(message) = message {
+        f.render_widget(Paragraph::new(message).block(block).alignment(Alignment::Center), area);
+        return;
     }
+    // Replay and spoiler-free mode limit the record; otherwise each division's own length does
+    let limited = app.replay.is_some() || app.spoiler_from.is_some();
+    let rows = tracked.iter().enumerate().skip(app.selection().scroll).take(app.viewport_rows).map(|(i, tracked)| {
+        let style = if i == app.selection().index { theme.selected } else { Style::default() };
+        let Some((division, entry)) = &tracked.placing else {
+            return Row::new([
+                Cell::from(romanize(&tracked.name).into_owned()),
+                Cell::from(Span::styled(t("dashboard.not_ranked"), theme.muted)),
+            ])
+            .style(style);
+        };
+        let days = crate::max_day_for_division(division);
+        // Lower-division bouts can only be placed on days from the loaded division's torikumi
+        let revealed = if !limited {
+            days
+        } else if division.eq_ignore_ascii_case(&app.division) {
+            app.revealed_for(entry)
+        } else if days < BASHO_DAYS {
+            0
+        } else {
+            app.revealed_days()
+        };
+        let (wins, losses, absent) = entry.win_loss_absent(revealed);
+        let record = if absent > 0 { format!("{}-{}-{}", wins, losses, absent) } else { format!("{}-{}", wins, losses) };
+        let streak = match entry.streak(revealed) {
+            Some((true, n)) => tf("banzuke.streak_wins", &[&n]),
+            Some((false, n)) => tf("banzuke.streak_losses", &[&n]),
+            None => String::new(),
+        };
+        let opponent = match tracked.opponent() {
+            Some((shikona, rank)) => format!("{} ({})", romanize(shikona), romanize(rank)),
+            None => t("dashboard.no_bout").to_string(),
+        };
+        let result = match (&tracked.bout, tracked.won()) {
+            (Some(bout), _) if app.bout_hidden(bout) => Span::styled(t("torikumi.hidden"), theme.muted),
+            (_, Some(true)) => Span::styled(t("schedule.win"), theme.positive),
+            (_, Some(false)) => Span::styled(t("schedule.loss"), theme.negative),
+            (Some(_), None) => Span::styled(t("schedule.scheduled"), theme.accent),
+            (None, None) => Span::raw(""),
+        };
+        Row::new([
+            Cell::from(romanize(&entry.shikona_en).into_owned()),
+            Cell::from(crate::i18n::division_name(division)),
+            Cell::from(romanize(&entry.rank).into_owned()),
+            Cell::from(opponent),
+            Cell::from(result),
+            Cell::from(record),
+            Cell::from(streak),
+        ])
+        .style(style)
+    });
+    let header = Row::new([
+        t("banzuke.wrestler"),
+        t("dashboard.division"),
+        t("banzuke.rank"),
+        t("dashboard.opponent"),
+        t("schedule.result"),
+        t("banzuke.result"),
+        t("banzuke.streak"),
+    ])
+    .style(theme.heading);
+    let constraints = [
+        Constraint::Fill(2),
+        Constraint::Fill(1),
+        Constraint::Fill(2),
+        Constraint::Fill(3),
+        Constraint::Fill(1),
+        Constraint::Length(7),
+        Constraint::Length(8),
+    ];
+    let table = Table::new(rows, constraints).header(header).block(block);
+    f.render_widget(table, area);
 }
 
-fn render_basho_info(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
-    if let Some(basho) = &app.basho {
-        // Helper function to format date without timestamp
-        let format_date = |date_str: &str| -> String {
-            if let Some(date_part) = date_str.split('T').next() {
-                date_part.to_string()
-            } else {
-                date_str.to_string()
-            }
-        };
+// Width of a matchup row's "12 Shikona" label
+const MATCHUP_LABEL_WIDTH: usize = 14;
 
-        let mut text = vec![
-            // Line::from(vec![
-            //     Span::styled("Location: ", Style::default().fg(Color::Yellow)),
-            //     Span::raw(basho.location.as_deref().unwrap_or("Unknown")),
-            // ]), TODO: Fix unknown location
-            Line::from(vec![
-                Span::styled("Start Date: ", Style::default().fg(Color::Yellow)),
-                Span::raw(basho.start_date.as_deref().map(format_date).unwrap_or_else(|| "Unknown".to_string())),
-            ]),
-            Line::from(vec![
-                Span::styled("End Date: ", Style::default().fg(Color::Yellow)),
-                Span::raw(basho.end_date.as_deref().map(format_date).unwrap_or_else(|| "Unknown".to_string())),
-            ]),
-        ];
+fn render_matchups(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let theme = app.theme;
+    let division = crate::i18n::division_name(&app.division);
+    let banzuke = app.banzuke.as_deref().unwrap_or_default();
+    if banzuke.is_empty() {
+        let paragraph = Paragraph::new(t("banzuke.loading"))
+            .block(Block::default().borders(Borders::ALL).title(tf("matchups.heading", &[&division])))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+    let matrix = Matrix::compute(banzuke, app.torikumi.as_deref().unwrap_or_default(), |e| app.revealed_for(e));
+    let (met, unmet) = matrix.met_and_unmet(app.selection().index);
+    let selected_name = matrix.rikishi.get(app.selection().index).map(|(_, name)| romanize(name)).unwrap_or_default();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(tf("matchups.title", &[&division, &selected_name, &met, &unmet]))
+        .title_bottom(Line::styled(t("matchups.legend"), theme.muted).centered());
+    let inner = block.inner(area);
+    f.render_widget(block, area);
 
-        if let Some(yusho_list) = &basho.yusho {
-            text.push(Line::from(""));
-            text.push(Line::from(vec![
-                Span::styled("Yusho Winners:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
-            ]));
-            
-            for yusho in yusho_list {
-                text.push(Line::from(vec![
-                    Span::styled("  Division: ", Style::default().fg(Color::Green)),
-                    Span::raw(&yusho.division),
-                ]));
-                text.push(Line::from(vec![
-                    Span::styled("  Winner: ", Style::default().fg(Color::Green)),
-                    Span::raw(&yusho.shikona_en),
-                ]));
-                text.push(Line::from(""));
-            }
-        }
+    // Two characters per column; scroll sideways so the selected rikishi's column shows
+    let fit = (inner.width as usize).saturating_sub(MATCHUP_LABEL_WIDTH) / 2;
+    let first_column = (app.selection().index + 1).saturating_sub(fit);
+    let columns = first_column..matrix.rikishi.len().min(first_column + fit);
 
-        let paragraph = Paragraph::new(text)
-            .block(Block::default().borders(Borders::ALL).title("Basho Information"))
-            .wrap(ratatui::widgets::Wrap { trim: true });
+    let mut header = vec![Span::raw(" ".repeat(MATCHUP_LABEL_WIDTH))];
+    header.extend(columns.clone().map(|c| {
+        // Every tenth column shows its tens digit, the rest their ones digit
+        let number = c + 1;
+        let (digit, style) = if number % 10 == 0 { (number / 10, theme.accent) } else { (number % 10, theme.heading) };
+        let style = if c == app.selection().index { style.patch(theme.selected) } else { style };
+        Span::styled(format!("{:<2}", digit), style)
+    }));
+    let mut lines = vec![Line::from(header)];
 
-        f.render_widget(paragraph, area);
-    } else {
-        let paragraph = Paragraph::new("Loading basho information...")
-            .block(Block::default().borders(Borders::ALL).title("Basho Information"))
-            .alignment(Alignment::Center);
-        f.render_widget(paragraph, area);
+    for (row, (_, name)) in matrix.rikishi.iter().enumerate().skip(app.selection().scroll).take(app.viewport_rows) {
+        let label = pad_to_width(&truncate_to_width(&format!("{:>2} {}", row + 1, romanize(name)), MATCHUP_LABEL_WIDTH - 1), MATCHUP_LABEL_WIDTH);
+        let label_style = if row == app.selection().index { theme.selected } else { Style::default() };
+        let mut spans = vec![Span::styled(label, label_style)];
+        spans.extend(columns.clone().map(|c| {
+            let (mark, style) = match matrix.cells[row][c] {
+                _ if c == row => ("╲", theme.muted),
+                Some(Meeting::Win) => ("○", theme.positive),
+                Some(Meeting::Loss) => ("●", theme.negative),
+                Some(Meeting::FusenWin) => ("□", theme.positive),
+                Some(Meeting::FusenLoss) => ("■", theme.negative),
+                Some(Meeting::Hidden) => ("?", theme.muted),
+                Some(Meeting::Scheduled) => ("◇", theme.accent),
+                None => ("·", theme.muted),
+            };
+            Span::styled(format!("{} ", mark), style)
+        }));
+        lines.push(Line::from(spans));
     }
+    f.render_widget(Paragraph::new(lines), inner);
 }
 
-fn render_help_popup(f: &mut Frame) {
-    let area = centered_rect(70, 60, f.area());
+fn render_help_popup(f: &mut Frame, app: &App) {
+    let theme = app.theme;
+    let area = centered_rect(70, 80, f.area());
     f.render_widget(Clear, area);
 
-    let help_text = vec![
-        Line::from("Sumo TUI Help"),
-        Line::from(""),
-        Line::from("Navigation:"),
-        Line::from("  ↑/↓/w/s     - Navigate lists"),
-        Line::from("  ←/→/a/d     - Switch between pages"),
-        Line::from("  Enter       - View details (rikishi in banzuke, head-to-head in torikumi)"),
-        Line::from("  1           - View daily matches (torikumi)"),
-        Line::from("  2           - View rankings (banzuke)"),
-        Line::from("  3           - View basho information"),
-        Line::from(""),
-        Line::from("Switch Data:"),
-        Line::from("  c       - Change day (1-15)"),
-        Line::from("  v       - Change division"),
-        Line::from("  b       - Change basho (YYYYMM format)"),
-        Line::from(""),
-        Line::from("Other:"),
-        Line::from("  h/F1    - Toggle this help"),
-        Line::from("  q       - Quit application"),
-        Line::from("  Esc     - Close help/cancel input/close details"),
-        Line::from(""),
-        Line::from("Divisions: Makuuchi, Juryo, Makushita, Sandanme, Jonidan, Jonokuchi"),
-        Line::from("Basho months: 01, 03, 05, 07, 09, 11"),
-    ];
+    let mut help_text: Vec<Line> = Vec::new();
+    let help_lines = app.help_lines();
+    if help_lines.is_empty() {
+        help_text.push(Line::from(Span::styled(t("help.no_matches"), theme.muted)));
+    }
+    for (i, line) in help_lines.into_iter().enumerate() {
+        match line {
+            HelpLine::Section(section) => {
+                if i > 0 {
+                    help_text.push(Line::from(""));
+                }
+                help_text.push(Line::from(Span::styled(t(section), theme.label)));
+            }
+            HelpLine::Key(keys, description) => {
+                help_text.push(Line::from(format!("  {}- {}", pad_to_width(&keys, 12), t(description))));
+            }
+        }
+    }
+    if app.help_filter.is_empty() {
+        help_text.push(Line::from(""));
+        help_text.push(Line::from(t("help.divisions")));
+        help_text.push(Line::from(t("help.months")));
+    }
+
+    let title = if app.help_filtering || !app.help_filter.is_empty() {
+        let cursor = if app.help_filtering { "_" } else { "" };
+        tf("help.filter", &[&format!("{}{}", app.help_filter, cursor)])
+    } else {
+        t("help.heading").to_string()
+    };
 
     let paragraph = Paragraph::new(help_text)
-        .block(Block::default().borders(Borders::ALL).title("Help"))
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Span::styled(title, theme.heading))
+                .title_bottom(Line::from(t("help.search_hint")).centered()),
+        )
+        .scroll((app.help_scroll.min(u16::MAX as usize) as u16, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+// 1-15 grid for picking a day: ✓ marks days with results, days still to come are
+// dimmed, days past the division's last are struck out, and the loaded day is underlined
+fn render_day_grid(f: &mut Frame, app: &App) {
+    let theme = &app.theme;
+    let area = centered_rect(50, 40, f.area());
+    f.render_widget(Clear, area);
+
+    let today = Utc::now().date_naive();
+    let division = crate::i18n::division_name(&app.division);
+    let range = tf("input.day_range", &[&division, &crate::max_day_for_division(&app.division)]);
+    let mut text = vec![Line::from(t("input.day")), Line::from(Span::styled(range, theme.muted)), Line::from("")];
+    for row in 0..BASHO_DAYS / DAY_GRID_COLUMNS {
+        let mut spans = Vec::new();
+        for column in 0..DAY_GRID_COLUMNS {
+            let day = row * DAY_GRID_COLUMNS + column + 1;
+            let status = app.day_status(day, today);
+            let mark = if status == DayStatus::Results { "✓" } else { " " };
+            let mut style = match status {
+                _ if day > app.last_day() => theme.muted.add_modifier(Modifier::CROSSED_OUT),
+                DayStatus::Future => theme.muted,
+                _ => Style::default(),
+            };
+            if day == app.day {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            if day == app.day_cursor {
+                style = style.patch(theme.selected);
+            }
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(format!("{:>2}", day), style));
+            spans.push(Span::styled(mark, theme.positive));
+            spans.push(Span::raw(" "));
+        }
+        text.push(Line::from(spans));
+    }
+    // The playoff, on a row of its own below the fifteen days
+    if app.last_day() > BASHO_DAYS {
+        let day = app.last_day();
+        let mut style = if day == app.day { Style::default().add_modifier(Modifier::UNDERLINED) } else { Style::default() };
+        if day == app.day_cursor {
+            style = style.patch(theme.selected);
+        }
+        text.push(Line::from(vec![Span::raw(" "), Span::styled(format!("{:>2} {}", day, t("header.playoff")), style)]));
+    }
+    text.push(Line::from(""));
+    text.push(Line::from(t("input.day_grid_hint")));
+    if let Some(day) = app.checking_day {
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled(tf("input.checking_day", &[&day]), theme.muted)));
+    } else if let Some(err) = app.input_error.as_deref() {
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled(err, theme.negative)));
+    }
 
+    let paragraph = Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL).title(t("input.title")));
     f.render_widget(paragraph, area);
 }
 
-fn render_input_popup(f: &mut Frame, prompt: &str, input: &str, error: Option<&str>) {
+fn render_input_popup(f: &mut Frame, theme: &Theme, prompt: &str, input: &str, error: Option<&str>) {
     let area = centered_rect(50, 20, f.area());
     f.render_widget(Clear, area);
 
@@ -799,285 +3466,572 @@ fn render_input_popup(f: &mut Frame, prompt: &str, input: &str, error: Option<&s
         Line::from(prompt),
         Line::from(""),
         Line::from(vec![
-            Span::styled("> ", Style::default().fg(Color::Green)),
+            Span::styled("> ", theme.positive),
             Span::raw(input),
-            Span::styled("_", Style::default().fg(Color::Yellow)),
+            Span::styled("_", theme.label),
         ]),
         Line::from(""),
-        Line::from("Press Enter to confirm, Esc to cancel"),
+        Line::from(t("input.confirm")),
     ];
 
     if let Some(err) = error {
         text.push(Line::from(""));
-        text.push(Line::from(Span::styled(err, Style::default().fg(Color::Red))));
+        text.push(Line::from(Span::styled(err, theme.negative)));
     }
 
     let paragraph = Paragraph::new(text)
-        .block(Block::default().borders(Borders::ALL).title("Input"))
+        .block(Block::default().borders(Borders::ALL).title(t("input.title")))
         .alignment(Alignment::Left);
 
     f.render_widget(paragraph, area);
 }
 
-fn render_division_selector(f: &mut Frame, selected_index: usize) {
+fn render_switcher(f: &mut Frame, app: &App) {
+    let theme = &app.theme;
+    let area = centered_rect(60, 60, f.area());
+    f.render_widget(Clear, area);
+
+    let matches = app.switcher_matches();
+    let mut text = vec![
+        Line::from(vec![
+            Span::styled("> ", theme.positive),
+            Span::raw(app.input_buffer.as_str()),
+            Span::styled("_", theme.label),
+        ]),
+        Line::from(""),
+    ];
+    for (i, entry) in matches.iter().take(switcher::SHOWN).enumerate() {
+        let kind = Span::styled(pad_to_width(t(entry.kind), 10), theme.muted);
+        let line = if i == app.switcher_index {
+            Line::from(vec![Span::styled("> ", theme.positive.add_modifier(Modifier::BOLD)), kind, Span::styled(entry.label.as_str(), theme.heading)])
+        } else {
+            Line::from(vec![Span::raw("  "), kind, Span::raw(entry.label.as_str())])
+        };
+        text.push(line);
+    }
+    if matches.is_empty() {
+        text.push(Line::styled(t("switcher.no_match"), theme.muted));
+    }
+    text.push(Line::from(""));
+    text.push(Line::styled(tf("switcher.hint", &[&matches.len()]), theme.accent));
+
+    let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(t("switcher.title")));
+    f.render_widget(paragraph, area);
+}
+
+fn render_division_selector(f: &mut Frame, theme: &Theme, selected_index: usize) {
     let area = centered_rect(50, 50, f.area());
     f.render_widget(Clear, area);
 
     let mut text = vec![
-        Line::from("Select Division"),
+        Line::from(t("division.select")),
         Line::from(""),
     ];
 
     for (i, division) in DIVISIONS.iter().enumerate() {
+        let division = crate::i18n::division_name(division);
         let line = if i == selected_index {
             Line::from(vec![
-                Span::styled("> ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
-                Span::styled(*division, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled("> ", theme.positive.add_modifier(Modifier::BOLD)),
+                Span::styled(division, theme.heading),
             ])
         } else {
             Line::from(vec![
                 Span::raw("  "),
-                Span::raw(*division),
+                Span::raw(division),
             ])
         };
         text.push(line);
     }
 
     text.push(Line::from(""));
-    text.push(Line::from("Use ↑↓ to select, Enter to confirm, Esc to cancel"));
+    text.push(Line::from(t("division.hint")));
+
+    let paragraph = Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL).title(t("division.title")))
+        .alignment(Alignment::Left);
+
+    f.render_widget(paragraph, area);
+}
+
+fn render_rikishi_details(f: &mut Frame, app: &App, details: &RikishiDetails) {
+    let (theme, units) = (&app.theme, app.units);
+    let area = centered_rect(70, 70, f.area());
+    f.render_widget(Clear, area);
+
+    // Calculate age from birth date
+    let age_str = if let Some(birth_date) = &details.birth_date {
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(&birth_date[..10], "%Y-%m-%d") {
+            let now = chrono::Utc::now().date_naive();
+            let age = now.years_since(date).unwrap_or(0);
+            tf("rikishi.age", &[&age])
+        } else {
+            String::new()
+        }
+    } else {
+        String::new()
+    };
+
+    let mut text = vec![
+        Line::from(vec![
+            Span::styled(t("rikishi.heading"), theme.heading),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(t("rikishi.shikona_en"), theme.positive),
+            Span::raw(romanize(&details.shikona_en)),
+        ]),
+        Line::from(vec![
+            Span::styled(t("rikishi.shikona_jp"), theme.positive),
+            Span::raw(&details.shikona_jp),
+        ]),
+        Line::from(""),
+    ];
+
+    if let Some(rank) = &details.current_rank {
+        text.push(Line::from(vec![
+            Span::styled(t("rikishi.rank"), theme.accent),
+            Span::raw(app.rank_text(rank)),
+        ]));
+    }
+
+    if let Some(heya) = &details.heya {
+        text.push(Line::from(vec![
+            Span::styled(t("rikishi.heya"), theme.accent),
+            Span::raw(heya),
+        ]));
+    }
+
+    text.push(Line::from(""));
+
+    if let Some(birth_date) = &details.birth_date {
+        text.push(Line::from(vec![
+            Span::styled(t("rikishi.birth_date"), theme.detail),
+            Span::raw(crate::dates::date(birth_date)),
+            Span::raw(age_str),
+        ]));
+    }
+
+    if let Some(shusshin) = &details.shusshin {
+        text.push(Line::from(vec![
+            Span::styled(t("rikishi.birthplace"), theme.detail),
+            Span::raw(shusshin),
+        ]));
+    }
+
+    text.push(Line::from(""));
+
+    if let Some(height) = details.height {
+        // Convert cm to feet and inches
+        let total_inches = (height as f64) / 2.54;
+        let feet = (total_inches / 12.0).floor() as u32;
+        let inches = (total_inches % 12.0).round() as u32;
+        
+        text.push(Line::from(vec![
+            Span::styled(t("rikishi.height"), theme.label),
+            Span::raw(match units {
+                Units::Metric => format!("{} cm ({}' {}\")", height, feet, inches),
+                Units::Imperial => format!("{}' {}\" ({} cm)", feet, inches, height),
+            }),
+        ]));
+    }
+
+    if let Some(weight) = details.weight {
+        // Convert kg to lbs
+        let lbs = ((weight as f64) * 2.20462).round() as u32;
+        
+        text.push(Line::from(vec![
+            Span::styled(t("rikishi.weight"), theme.label),
+            Span::raw(match units {
+                Units::Metric => format!("{} kg ({} lbs)", weight, lbs),
+                Units::Imperial => format!("{} lbs ({} kg)", lbs, weight),
+            }),
+        ]));
+    }
+
+    text.push(Line::from(""));
+
+    if let Some(debut) = &details.debut {
+        // Format debut as YYYY-MM
+        let debut_formatted = if debut.len() == 6 {
+            format!("{}-{}", &debut[0..4], &debut[4..6])
+        } else {
+            debut.clone()
+        };
+        text.push(Line::from(vec![
+            Span::styled(t("rikishi.debut"), theme.positive),
+            Span::raw(debut_formatted),
+        ]));
+    }
+
+    text.push(Line::from(""));
+    text.extend(achievement_lines(app, details.id));
+
+    text.push(Line::from(""));
+    if app.show_previous_basho {
+        text.extend(previous_basho_lines(app, details.id));
+    } else {
+        text.extend(rival_lines(app, details.id));
+    }
+
+    text.push(Line::from(""));
+    let hint = if !app.show_previous_basho && app.rivals.as_ref().is_some_and(|(id, rivals)| *id == details.id && !rivals.is_empty()) {
+        tf("rikishi.rivals_hint", &[&app.keymap.label(Action::ToggleCompare)])
+    } else {
+        tf("rikishi.hint", &[&app.keymap.label(Action::ToggleCompare)])
+    };
+    text.push(Line::from(vec![
+        Span::styled(hint, theme.accent.add_modifier(Modifier::ITALIC)),
+    ]));
+
+    let paragraph = Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL).title(t("rikishi.title")))
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    f.render_widget(paragraph, area);
+}
+
+// The rikishi's most-faced opponents, the selected one highlighted
+fn rival_lines(app: &App, rikishi_id: u32) -> Vec<Line<'static>> {
+    let theme = &app.theme;
+    let mut text = vec![Line::from(Span::styled(t("rikishi.rivals"), theme.heading))];
+    match app.rivals.as_ref().filter(|(id, _)| *id == rikishi_id) {
+        None => text.push(Line::from(Span::styled(t("rikishi.rivals_loading"), theme.muted))),
+        Some((_, rivals)) if rivals.is_empty() => text.push(Line::from(Span::styled(t("rikishi.rivals_none"), theme.muted))),
+        Some((_, rivals)) => {
+            let selected = app.rival_index.min(rivals.len() - 1);
+            for (i, rival) in rivals.iter().enumerate() {
+                let style = if i == selected { theme.selected } else { Style::default() };
+                text.push(Line::from(vec![
+                    Span::styled(format!("  {:<16}", romanize(&rival.shikona)), style),
+                    Span::raw(" "),
+                    Span::raw(tf("rikishi.rival_record", &[&rival.wins, &rival.losses, &rival.bouts])),
+                ]));
+            }
+        }
+    }
+    text
+}
+
+// This basho next to the last one, both up to the loaded day
+fn previous_basho_lines(app: &App, rikishi_id: u32) -> Vec<Line<'static>> {
+    let theme = &app.theme;
+    let Some(previous_id) = crate::api::previous_basho_id(&app.basho_id) else {
+        return Vec::new();
+    };
+    let mut text = vec![Line::from(Span::styled(tf("rikishi.previous_heading", &[&app.day]), theme.heading))];
+    let Some((_, matches)) = app.bout_history.as_ref().filter(|(id, _)| *id == rikishi_id) else {
+        text.push(Line::from(Span::styled(t("rikishi.rivals_loading"), theme.muted)));
+        return text;
+    };
+    let forms = [
+        form::basho_form(rikishi_id, matches, &app.basho_id, app.day),
+        form::basho_form(rikishi_id, matches, &previous_id, app.day),
+    ];
+    const LABEL: usize = 12;
+    const COLUMN: usize = 26;
+    let row = |label: &str, cells: [String; 2]| {
+        Line::from(vec![
+            Span::styled(pad_to_width(&format!("  {}", label), LABEL), theme.label),
+            Span::raw(pad_to_width(&cells[0], COLUMN)),
+            Span::raw(cells[1].clone()),
+        ])
+    };
+    let absent = || t("rikishi.previous_absent").to_string();
+    text.push(row("", forms.each_ref().map(|form| crate::dates::basho_date(&form.basho_id))));
+    text.push(row(t("rikishi.previous_rank"), forms.each_ref().map(|form| form.rank.as_deref().map_or_else(absent, |rank| app.rank_text(rank)))));
+    text.push(row(
+        t("rikishi.previous_record"),
+        forms.each_ref().map(|form| if form.rank.is_some() { format!("{}-{}", form.wins, form.losses) } else { absent() }),
+    ));
+    text.push(row(
+        t("rikishi.previous_kimarite"),
+        forms.each_ref().map(|form| form.kimarite.iter().map(|(k, n)| format!("{} {}", k, n)).collect::<Vec<_>>().join(", ")),
+    ));
+    text
+}
+
+// Career honours in the details popup: yusho and sansho from the career stats, kinboshi
+// and jun-yusho from the bout history
+fn achievement_lines(app: &App, rikishi_id: u32) -> Vec<Line<'static>> {
+    let theme = &app.theme;
+    let mut lines = vec![Line::from(Span::styled(t("rikishi.achievements"), theme.heading))];
+    let stats = app.career_stats.get(&rikishi_id);
+    let achievements = app.achievements.as_ref().filter(|a| a.rikishi_id == rikishi_id);
+    if stats.is_none() && achievements.is_none() {
+        lines.push(Line::from(Span::styled(t("rikishi.achievements_loading"), theme.muted)));
+        return lines;
+    }
+    let line = |label: &str, value: String| Line::from(vec![Span::styled(format!("  {}", label), theme.label), Span::raw(value)]);
+    let none = || t("rikishi.none").to_string();
+    if let Some(stats) = stats {
+        let yusho: Vec<String> = DIVISIONS
+            .iter()
+            .filter_map(|division| {
+                let count = stats.yusho_by_division.iter().find(|(d, _)| d.eq_ignore_ascii_case(division))?.1;
+                (*count > 0).then(|| format!("{} {}", crate::i18n::division_name(division), count))
+            })
+            .collect();
+        lines.push(line(t("rikishi.yusho"), if yusho.is_empty() { none() } else { yusho.join(", ") }));
+        let mut sansho: Vec<(&String, &u32)> = stats.sansho.iter().filter(|(_, count)| **count > 0).collect();
+        sansho.sort();
+        let sansho: Vec<String> = sansho.iter().map(|(name, count)| format!("{} {}", romanize(name), count)).collect();
+        lines.push(line(t("rikishi.sansho"), if sansho.is_empty() { none() } else { sansho.join(", ") }));
+    }
+    if let Some(achievements) = achievements {
+        let (jun_yusho, checked) = achievements.jun_yusho;
+        lines.push(line(t("rikishi.jun_yusho"), tf("rikishi.jun_yusho_count", &[&jun_yusho, &checked])));
+        lines.push(line(t("rikishi.kinboshi"), achievements.kinboshi.to_string()));
+    }
+    lines
+}
 
-    let paragraph = Paragraph::new(text)
-        .block(Block::default().borders(Borders::ALL).title("Division"))
-        .alignment(Alignment::Left);
+fn render_inspector(f: &mut Frame, theme: &Theme, inspector: &Inspector) {
+    let area = centered_rect(80, 80, f.area());
+    f.render_widget(Clear, area);
+    let lines: Vec<Line> = inspector.lines.iter().map(|line| Line::from(line.as_str())).collect();
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Span::styled(inspector.title.clone(), theme.heading))
+                .title_bottom(Line::styled(t("inspect.hint"), theme.muted).centered()),
+        )
+        .scroll((inspector.scroll.min(u16::MAX as usize) as u16, 0));
+    f.render_widget(paragraph, area);
+}
 
+// A one-line notice in the top right corner, above the table border
+fn render_toast(f: &mut Frame, theme: &Theme, message: &str) {
+    let screen = f.area();
+    let width = (crate::text::display_width(message) as u16 + 4).min(screen.width);
+    let area = ratatui::layout::Rect { x: screen.width - width, y: 1, width, height: 3.min(screen.height) };
+    f.render_widget(Clear, area);
+    let paragraph = Paragraph::new(Span::styled(message.to_string(), theme.positive.add_modifier(Modifier::BOLD)))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).border_style(theme.positive));
     f.render_widget(paragraph, area);
 }
 
-fn render_rikishi_details(f: &mut Frame, details: &RikishiDetails) {
-    let area = centered_rect(70, 70, f.area());
+fn render_projection(f: &mut Frame, theme: &Theme, projection: &Projection) {
+    let area = centered_rect(70, 80, f.area());
     f.render_widget(Clear, area);
+    let block = Block::default().borders(Borders::ALL).title(tf("projection.title", &[&romanize(&projection.shikona)]));
+    if projection.candidates.is_empty() {
+        let paragraph = Paragraph::new(t("projection.none")).block(block).alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+    let rows = projection.candidates.iter().map(|candidate| {
+        let head_to_head = match projection.head_to_head(candidate.rikishi_id) {
+            Some(Some((0, 0))) => Span::styled(t("projection.never_met"), theme.muted),
+            Some(Some((wins, losses))) => Span::raw(format!("{}-{}", wins, losses)),
+            Some(None) => Span::styled("?", theme.muted),
+            None => Span::styled("…", theme.muted),
+        };
+        let reason = match (candidate.near_rank, candidate.in_race) {
+            (true, true) => tf("projection.both", &[&t("projection.rank"), &t("projection.race")]),
+            (true, false) => t("projection.rank").to_string(),
+            _ => t("projection.race").to_string(),
+        };
+        Row::new([
+            Cell::from(romanize(&candidate.shikona).into_owned()),
+            Cell::from(Span::styled(romanize(&candidate.rank).into_owned(), theme.detail)),
+            Cell::from(format!("{}-{}", candidate.wins, candidate.losses)),
+            Cell::from(head_to_head),
+            Cell::from(Span::styled(reason, theme.muted)),
+        ])
+    });
+    let header = Row::new([
+        t("banzuke.wrestler"),
+        t("banzuke.rank"),
+        t("banzuke.result"),
+        t("projection.head_to_head"),
+        t("projection.why"),
+    ])
+    .style(theme.heading);
+    let constraints = [Constraint::Fill(2), Constraint::Fill(2), Constraint::Length(6), Constraint::Length(8), Constraint::Fill(2)];
+    f.render_widget(Table::new(rows, constraints).header(header).block(block), area);
+}
 
-    // Helper function to format date
-    let format_date = |date_str: &str| -> String {
-        if let Some(date_part) = date_str.split('T').next() {
-            date_part.to_string()
-        } else {
-            date_str.to_string()
-        }
-    };
+fn render_day_summary(f: &mut Frame, theme: &Theme, summary: &DaySummary) {
+    let area = centered_rect(70, 80, f.area());
+    f.render_widget(Clear, area);
 
-    // Calculate age from birth date
-    let age_str = if let Some(birth_date) = &details.birth_date {
-        if let Ok(date) = chrono::NaiveDate::parse_from_str(&birth_date[..10], "%Y-%m-%d") {
-            let now = chrono::Utc::now().date_naive();
-            let age = now.years_since(date).unwrap_or(0);
-            format!(" (Age: {})", age)
-        } else {
-            String::new()
-        }
-    } else {
-        String::new()
-    };
+    let heading = |key: &'static str| Line::from(Span::styled(t(key).to_string(), theme.accent.add_modifier(Modifier::BOLD)));
+    let none = || Line::from(Span::styled(format!("  {}", t("summary.none")), theme.muted));
 
     let mut text = vec![
         Line::from(vec![
-            Span::styled("Rikishi Details", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("Shikona (English): ", Style::default().fg(Color::Green)),
-            Span::raw(&details.shikona_en),
-        ]),
-        Line::from(vec![
-            Span::styled("Shikona (Japanese): ", Style::default().fg(Color::Green)),
-            Span::raw(&details.shikona_jp),
+            Span::styled(t("summary.bouts"), theme.label),
+            Span::raw(summary.bouts.to_string()),
+            Span::styled(t("summary.fusen"), theme.label),
+            Span::raw(summary.fusen.to_string()),
         ]),
         Line::from(""),
+        heading("summary.upsets"),
     ];
-
-    if let Some(rank) = &details.current_rank {
-        text.push(Line::from(vec![
-            Span::styled("Current Rank: ", Style::default().fg(Color::Cyan)),
-            Span::raw(rank),
-        ]));
+    if summary.upsets.is_empty() {
+        text.push(none());
     }
-
-    if let Some(heya) = &details.heya {
+    for upset in &summary.upsets {
         text.push(Line::from(vec![
-            Span::styled("Heya: ", Style::default().fg(Color::Cyan)),
-            Span::raw(heya),
+            Span::raw("  "),
+            Span::styled(romanize(&upset.winner).into_owned(), theme.positive),
+            Span::styled(format!(" ({})", romanize(&upset.winner_rank)), theme.detail),
+            Span::raw(t("summary.beat").to_string()),
+            Span::styled(romanize(&upset.loser).into_owned(), theme.negative),
+            Span::styled(format!(" ({})", romanize(&upset.loser_rank)), theme.detail),
         ]));
     }
 
     text.push(Line::from(""));
-
-    if let Some(birth_date) = &details.birth_date {
-        text.push(Line::from(vec![
-            Span::styled("Birth Date: ", Style::default().fg(Color::Magenta)),
-            Span::raw(format_date(birth_date)),
-            Span::raw(age_str),
-        ]));
+    text.push(heading("summary.kinboshi"));
+    if summary.kinboshi.is_empty() {
+        text.push(none());
     }
-
-    if let Some(shusshin) = &details.shusshin {
+    for (winner, yokozuna) in &summary.kinboshi {
         text.push(Line::from(vec![
-            Span::styled("Birthplace: ", Style::default().fg(Color::Magenta)),
-            Span::raw(shusshin),
+            Span::raw("  "),
+            Span::styled(winner.clone(), theme.winner),
+            Span::raw(t("summary.beat").to_string()),
+            Span::raw(yokozuna.clone()),
         ]));
     }
 
     text.push(Line::from(""));
-
-    if let Some(height) = details.height {
-        // Convert cm to feet and inches
-        let total_inches = (height as f64) / 2.54;
-        let feet = (total_inches / 12.0).floor() as u32;
-        let inches = (total_inches % 12.0).round() as u32;
-        
-        text.push(Line::from(vec![
-            Span::styled("Height: ", Style::default().fg(Color::Yellow)),
-            Span::raw(format!("{} cm ({}' {}\")", height, feet, inches)),
-        ]));
+    text.push(heading("summary.kimarite"));
+    if summary.kimarite.is_empty() {
+        text.push(none());
     }
-
-    if let Some(weight) = details.weight {
-        // Convert kg to lbs
-        let lbs = ((weight as f64) * 2.20462).round() as u32;
-        
-        text.push(Line::from(vec![
-            Span::styled("Weight: ", Style::default().fg(Color::Yellow)),
-            Span::raw(format!("{} kg ({} lbs)", weight, lbs)),
-        ]));
+    for (kimarite, count) in &summary.kimarite {
+        text.push(Line::from(format!("  {}: {}", kimarite, count)));
     }
 
     text.push(Line::from(""));
-
-    if let Some(debut) = &details.debut {
-        // Format debut as YYYY-MM
-        let debut_formatted = if debut.len() == 6 {
-            format!("{}-{}", &debut[0..4], &debut[4..6])
-        } else {
-            debut.clone()
-        };
+    text.push(heading("summary.race"));
+    if summary.race.is_empty() {
+        text.push(none());
+    }
+    let leader_losses = summary.race.first().map(|c| c.losses);
+    for contender in &summary.race {
+        let style = if Some(contender.losses) == leader_losses { theme.heading } else { Style::default() };
         text.push(Line::from(vec![
-            Span::styled("Debut: ", Style::default().fg(Color::Green)),
-            Span::raw(debut_formatted),
+            Span::raw("  "),
+            Span::styled(format!("{}-{}", contender.wins, contender.losses), style),
+            Span::raw("  "),
+            Span::styled(romanize(&contender.shikona).into_owned(), style),
         ]));
     }
 
-    text.push(Line::from(""));
-    text.push(Line::from(vec![
-        Span::styled("Press Esc to close", Style::default().fg(Color::Cyan).add_modifier(Modifier::ITALIC)),
-    ]));
-
     let paragraph = Paragraph::new(text)
-        .block(Block::default().borders(Borders::ALL).title("Rikishi Information"))
+        .block(Block::default().borders(Borders::ALL).title(tf("summary.title", &[&summary.day])))
         .wrap(ratatui::widgets::Wrap { trim: true });
-
     f.render_widget(paragraph, area);
 }
 
-fn render_head_to_head(f: &mut Frame, h2h: &HeadToHeadResponse) {
+// Shikona of `rikishi_id` and their opponent in a bout between them
+fn head_to_head_names(m: &HeadToHeadMatch, rikishi_id: u32) -> (&str, &str) {
+    if m.west_id == rikishi_id { (&m.west_shikona, &m.east_shikona) } else { (&m.east_shikona, &m.west_shikona) }
+}
+
+fn render_head_to_head(f: &mut Frame, theme: &Theme, rikishi_id: u32, h2h: &HeadToHeadResponse) {
     let area = centered_rect(80, 80, f.area());
     f.render_widget(Clear, area);
 
     let mut text = vec![
         Line::from(vec![
-            Span::styled("Head-to-Head Record", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled(t("h2h.heading"), theme.heading),
         ]),
         Line::from(""),
     ];
 
     // Overall record
     if !h2h.matches.is_empty() {
-        let first_match = &h2h.matches[0];
-        let rikishi_name = if first_match.winner_id == Some(first_match.east_id) || first_match.east_id != first_match.east_id {
-            &first_match.east_shikona
-        } else {
-            &first_match.east_shikona
-        };
-        let opponent_name = if first_match.east_id == first_match.east_id {
-            &first_match.west_shikona
-        } else {
-            &first_match.west_shikona
-        };
+        let (rikishi_name, opponent_name) = head_to_head_names(&h2h.matches[0], rikishi_id);
+        let (rikishi_name, opponent_name) = (&romanize(rikishi_name), &romanize(opponent_name));
 
         text.push(Line::from(vec![
-            Span::styled("Total Matches: ", Style::default().fg(Color::Cyan)),
+            Span::styled(t("h2h.total"), theme.accent),
             Span::raw(format!("{}", h2h.total)),
         ]));
         text.push(Line::from(vec![
-            Span::styled(format!("{} Wins: ", rikishi_name), Style::default().fg(Color::Green)),
+            Span::styled(tf("h2h.wins", &[rikishi_name]), theme.positive),
             Span::raw(format!("{}", h2h.rikishi_wins)),
         ]));
         text.push(Line::from(vec![
-            Span::styled(format!("{} Wins: ", opponent_name), Style::default().fg(Color::Red)),
+            Span::styled(tf("h2h.wins", &[opponent_name]), theme.negative),
             Span::raw(format!("{}", h2h.opponent_wins)),
         ]));
         text.push(Line::from(""));
     }
 
     // Kimarite wins
-    if let Some(wins) = &h2h.kimarite_wins {
-        if !wins.is_empty() {
+    if let Some(wins) = &h2h.kimarite_wins
+        && !wins.is_empty()
+    {
+        text.push(Line::from(vec![
+            Span::styled(t("h2h.winning"), theme.positive.add_modifier(Modifier::BOLD)),
+        ]));
+        for (technique, count) in wins {
+            // Capitalize first letter
+            let capitalized = if !technique.is_empty() {
+                let mut chars: Vec<char> = technique.chars().collect();
+                chars[0] = chars[0].to_uppercase().next().unwrap_or(chars[0]);
+                chars.into_iter().collect()
+            } else {
+                technique.clone()
+            };
+            
             text.push(Line::from(vec![
-                Span::styled("Winning Techniques:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::raw("  "),
+                Span::styled(capitalized, theme.positive),
+                Span::raw(format!(": {}", count)),
             ]));
-            for (technique, count) in wins {
-                // Capitalize first letter
-                let capitalized = if !technique.is_empty() {
-                    let mut chars: Vec<char> = technique.chars().collect();
-                    chars[0] = chars[0].to_uppercase().next().unwrap_or(chars[0]);
-                    chars.into_iter().collect()
-                } else {
-                    technique.clone()
-                };
-                
-                text.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(capitalized, Style::default().fg(Color::Green)),
-                    Span::raw(format!(": {}", count)),
-                ]));
-            }
-            text.push(Line::from(""));
         }
+        text.push(Line::from(""));
     }
 
     // Kimarite losses
-    if let Some(losses) = &h2h.kimarite_losses {
-        if !losses.is_empty() {
+    if let Some(losses) = &h2h.kimarite_losses
+        && !losses.is_empty()
+    {
+        text.push(Line::from(vec![
+            Span::styled(t("h2h.losing"), theme.negative.add_modifier(Modifier::BOLD)),
+        ]));
+        for (technique, count) in losses {
+            // Capitalize first letter
+            let capitalized = if !technique.is_empty() {
+                let mut chars: Vec<char> = technique.chars().collect();
+                chars[0] = chars[0].to_uppercase().next().unwrap_or(chars[0]);
+                chars.into_iter().collect()
+            } else {
+                technique.clone()
+            };
+            
             text.push(Line::from(vec![
-                Span::styled("Losing Techniques:", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::raw("  "),
+                Span::styled(capitalized, theme.negative),
+                Span::raw(format!(": {}", count)),
             ]));
-            for (technique, count) in losses {
-                // Capitalize first letter
-                let capitalized = if !technique.is_empty() {
-                    let mut chars: Vec<char> = technique.chars().collect();
-                    chars[0] = chars[0].to_uppercase().next().unwrap_or(chars[0]);
-                    chars.into_iter().collect()
-                } else {
-                    technique.clone()
-                };
-                
-                text.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(capitalized, Style::default().fg(Color::Red)),
-                    Span::raw(format!(": {}", count)),
-                ]));
-            }
-            text.push(Line::from(""));
         }
+        text.push(Line::from(""));
     }
 
     // Match history (show most recent 10)
     text.push(Line::from(vec![
-        Span::styled("Recent Matches:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Span::styled(t("h2h.recent"), theme.heading),
     ]));
     text.push(Line::from(""));
 
     for (i, match_entry) in h2h.matches.iter().take(10).enumerate() {
-        let basho_date = crate::api::SumoApi::format_basho_date(&match_entry.basho_id);
-        let winner = match_entry.winner_en.as_deref().unwrap_or("N/A");
+        let basho_date = crate::dates::basho_date(&match_entry.basho_id);
+        let winner = match_entry
+            .winner_id
+            .and_then(|id| match_entry.shikona_of(id))
+            .or(match_entry.winner_en.as_deref())
+            .map_or("N/A".into(), romanize);
+        let winner_style = if match_entry.won_by(rikishi_id) == Some(false) { theme.negative } else { theme.positive };
         let kimarite_raw = match_entry.kimarite.as_deref().unwrap_or("N/A");
         
         // Capitalize first letter of kimarite
@@ -1090,42 +4044,73 @@ fn render_head_to_head(f: &mut Frame, h2h: &HeadToHeadResponse) {
         };
 
         text.push(Line::from(vec![
-            Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::DarkGray)),
-            Span::raw(format!("{} Day {}: ", basho_date, match_entry.day)),
-            Span::styled(winner, Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
-            Span::raw(" by "),
-            Span::styled(kimarite, Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{}. ", i + 1), theme.muted),
+            Span::raw(tf("h2h.match_day", &[&basho_date, &match_entry.day])),
+            Span::styled(winner, winner_style.add_modifier(Modifier::BOLD)),
+            Span::raw(t("h2h.by")),
+            Span::styled(kimarite, theme.accent),
         ]));
     }
 
     if h2h.matches.len() > 10 {
         text.push(Line::from(""));
         text.push(Line::from(vec![
-            Span::styled(format!("... and {} more", h2h.matches.len() - 10), Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC)),
+            Span::styled(tf("h2h.more", &[&(h2h.matches.len() - 10)]), theme.muted.add_modifier(Modifier::ITALIC)),
         ]));
     }
 
     text.push(Line::from(""));
     text.push(Line::from(vec![
-        Span::styled("Press Esc to close", Style::default().fg(Color::Cyan).add_modifier(Modifier::ITALIC)),
+        Span::styled(t("common.close"), theme.accent.add_modifier(Modifier::ITALIC)),
     ]));
 
     let paragraph = Paragraph::new(text)
-        .block(Block::default().borders(Borders::ALL).title("Match History"))
+        .block(Block::default().borders(Borders::ALL).title(t("h2h.title")))
         .wrap(ratatui::widgets::Wrap { trim: true });
 
     f.render_widget(paragraph, area);
 }
 
 fn basho_has_started(app: &App) -> bool {
-    if let Some(basho) = &app.basho {
-        if let Some(start) = basho.start_date_naive() {
-            return Utc::now().date_naive() >= start;
-        }
+    if let Some(basho) = &app.basho
+        && let Some(start) = basho.start_date_naive()
+    {
+        return Utc::now().date_naive() >= start;
     }
     true
 }
 
+fn basho_has_finished(app: &App) -> bool {
+    let today = Utc::now().date_naive();
+    if let Some(end) = app.basho.as_ref().and_then(|b| b.end_date_naive()) {
+        return today > end;
+    }
+    crate::parse_basho_year_month(&app.basho_id)
+        .map(|ym| ym < (today.year(), today.month()))
+        .unwrap_or(false)
+}
+
+// Block title with the selection's position, e.g. "Banzuke (12/42)"
+fn position_title(title: &str, selected: usize, len: usize) -> String {
+    if len == 0 {
+        title.to_string()
+    } else {
+        format!("{} ({}/{})", title, selected.min(len - 1) + 1, len)
+    }
+}
+
+// Display widths of table columns inside a bordered block, matching the layout
+// ratatui's Table uses (one column of spacing between cells)
+fn column_widths(area: ratatui::layout::Rect, constraints: &[Constraint]) -> Vec<usize> {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    Layout::horizontal(constraints.iter().copied())
+        .spacing(1)
+        .split(inner)
+        .iter()
+        .map(|r| r.width as usize)
+        .collect()
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: ratatui::layout::Rect) -> ratatui::layout::Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -1156,6 +4141,27 @@ pub fn setup_terminal() -> anyhow::Result<Terminal<CrosstermBackend<io::Stdout>>
     Ok(terminal)
 }
 
+/// Leave the TUI and stop the process. Once it is continued (`fg`) the SIGCONT handler
+/// sets the TUI up again.
+#[cfg(unix)]
+pub fn suspend_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> anyhow::Result<()> {
+    restore_terminal(terminal)?;
+    // SIGSTOP can't be caught, so this returns only after SIGCONT, whose handler takes
+    // the terminal back with resume_terminal
+    unsafe {
+        libc::raise(libc::SIGSTOP);
+    }
+    Ok(())
+}
+
+/// Re-enter raw mode and the alternate screen, forcing a full redraw.
+pub fn resume_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> anyhow::Result<()> {
+    enable_raw_mode()?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen, EnableMouseCapture)?;
+    terminal.clear()?;
+    Ok(())
+}
+
 pub fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> anyhow::Result<()> {
     disable_raw_mode()?;
     execute!(
@@ -1166,3 +4172,790 @@ pub fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -
     terminal.show_cursor()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{App, Pending, Popup, PopupStack};
+    use crate::api::TorikumiEntry;
+    use std::time::Duration;
+
+    fn bout(day: u8, match_no: u8) -> TorikumiEntry {
+        TorikumiEntry {
+            id: format!("202501-{}-{}", day, match_no),
+            basho_id: "202501".to_string(),
+            division: "Makuuchi".to_string(),
+            day,
+            match_no,
+            east_id: 1,
+            east_shikona: "Hoshoryu".into(),
+            east_rank: "Yokozuna 1 East".to_string(),
+            west_id: 2,
+            west_shikona: "Onosato".into(),
+            west_rank: "Ozeki 1 West".to_string(),
+            kimarite: Some("yorikiri".to_string()),
+            winner_id: Some(1),
+            winner_en: Some("Hoshoryu".to_string()),
+            winner_jp: None,
+        }
+    }
+
+    #[test]
+    fn rivals_skip_hidden_bouts_and_open_the_head_to_head() {
+        use crate::api::HeadToHeadMatch;
+        use crate::keymap::Action;
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.set_spoiler_from(Some(3));
+        let played = |basho_id: &str, day: u8, opponent_id: u32, winner_id: u32| HeadToHeadMatch {
+            basho_id: basho_id.to_string(),
+            division: "Makuuchi".to_string(),
+            day,
+            match_no: 1,
+            east_id: 1,
+            east_shikona: "Hoshoryu".to_string(),
+            east_rank: String::new(),
+            west_id: opponent_id,
+            west_shikona: format!("Rikishi {}", opponent_id),
+            west_rank: String::new(),
+            kimarite: None,
+            winner_id: Some(winner_id),
+            winner_en: None,
+            winner_jp: None,
+        };
+        let matches = vec![
+            played("202501", 3, 2, 1),
+            played("202501", 4, 2, 1),
+            played("202411", 5, 2, 2),
+            played("202411", 6, 3, 3),
+        ];
+        app.rikishi_details = Some(crate::api::RikishiDetails {
+            id: 1,
+            sumodb_id: None,
+            nsk_id: None,
+            shikona_en: "Hoshoryu".to_string(),
+            shikona_jp: String::new(),
+            current_rank: None,
+            heya: None,
+            birth_date: None,
+            shusshin: None,
+            height: None,
+            weight: None,
+            debut: None,
+        });
+        app.show_rikishi_details = true;
+        app.set_bout_history(1, matches, (0, 0));
+
+        // Days 3 and 4 of this basho are hidden, leaving one bout against each
+        let rivals = &app.rivals.as_ref().unwrap().1;
+        assert_eq!(rivals.iter().map(|r| (r.opponent_id, r.bouts, r.losses)).collect::<Vec<_>>(), [(2, 1, 1), (3, 1, 1)]);
+        app.perform(Action::Down);
+        // The rivals list moves like the views', wrapping when that is on
+        app.navigation.wrap = true;
+        app.perform(Action::Down);
+        assert_eq!(app.rival_index, 0);
+        app.perform(Action::Up);
+        app.perform(Action::Select);
+        assert_eq!(app.requested_head_to_head, Some((1, 3)));
+
+        // The compare key swaps the rivals for this basho against the last
+        app.perform(Action::ToggleCompare);
+        assert!(app.show_previous_basho);
+        assert!(app.compare_ids.is_empty());
+        let history = &app.bout_history.as_ref().unwrap().1;
+        assert_eq!(crate::form::basho_form(1, history, "202411", 15).losses, 2);
+
+        // Hidden bouts come off the totals of whoever won them, going by ID
+        let h2h = crate::api::HeadToHeadResponse {
+            kimarite_losses: None,
+            kimarite_wins: None,
+            matches: vec![played("202501", 3, 2, 2), played("202501", 3, 2, 1), played("202501", 4, 2, 9), played("202411", 5, 2, 2)],
+            opponent_wins: 2,
+            rikishi_wins: 1,
+            total: 4,
+        };
+        app.set_head_to_head(2, h2h);
+        let (rikishi_id, h2h) = app.head_to_head_data.as_ref().unwrap();
+        assert_eq!((*rikishi_id, h2h.total, h2h.rikishi_wins, h2h.opponent_wins), (2, 1, 0, 1));
+    }
+
+    #[test]
+    fn torikumi_winner_goes_by_id() {
+        use super::{torikumi_cells, TorikumiColumn};
+        let app = App::new("202501".to_string(), "Makuuchi".to_string(), 1);
+        let spelled = TorikumiEntry { winner_en: Some("Hōshōryū".to_string()), ..bout(1, 1) };
+        let cells = torikumi_cells(&app, &[TorikumiColumn::East, TorikumiColumn::West], &spelled);
+        assert_eq!(cells.iter().map(|c| c.winner).collect::<Vec<_>>(), [true, false]);
+    }
+
+    #[test]
+    fn yusho_history_cycles_divisions_and_loads_once() {
+        use crate::keymap::Action;
+        let mut app = App::new("202501".to_string(), "Juryo".to_string(), 15);
+        app.perform(Action::ViewYushoHistory);
+        assert!(std::mem::take(&mut app.yusho_history_requested));
+        assert_eq!(app.yusho_division, "Juryo");
+        app.yusho_history = Some(vec![("202501".to_string(), Some(Vec::new())), ("202411".to_string(), None)]);
+        assert_eq!(app.current_list_len(), 2);
+
+        app.perform(Action::Select);
+        assert_eq!(app.yusho_division, "Makushita");
+        app.perform(Action::NextPage);
+        assert!(app.current_view == super::AppView::Settings);
+        app.perform(Action::PrevPage);
+        assert!(app.current_view == super::AppView::YushoHistory);
+        assert!(!app.yusho_history_requested);
+    }
+
+    #[test]
+    fn inspector_shows_the_selected_bout() {
+        use crate::keymap::Action;
+        use crossterm::event::KeyCode;
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.set_torikumi(vec![bout(3, 1), bout(3, 2)]);
+        app.select(1);
+        app.perform(Action::Inspect);
+        let inspector = app.inspector.as_ref().unwrap();
+        assert_eq!(inspector.title, "API data: 202501-3-2");
+        assert!(inspector.lines.iter().any(|line| line.trim() == r#""eastShikona": "Hoshoryu","#));
+        app.on_key(KeyCode::End);
+        assert_eq!(app.inspector.as_ref().unwrap().scroll, app.inspector.as_ref().unwrap().lines.len() - 1);
+        app.on_key(KeyCode::Esc);
+        assert!(app.inspector.is_none());
+
+        // The raw data has the winner, so not while the day's results are hidden
+        app.set_spoiler_from(Some(3));
+        app.perform(Action::Inspect);
+        assert!(app.inspector.is_none());
+        assert_eq!(app.status_message.as_deref(), Some("Not shown while results are hidden"));
+    }
+
+    #[test]
+    fn settings_are_applied_and_written_to_the_config() {
+        use crate::keymap::Action;
+        use crate::settings::Setting;
+        use crossterm::event::KeyCode;
+        let path = std::env::temp_dir().join(format!("sumo-settings-test-{}.toml", std::process::id()));
+        std::fs::write(&path, "# mine\nfavorites = [\"Onosato\"]\n").unwrap();
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.config_path = Some(path.clone());
+
+        app.perform(Action::ViewSettings);
+        app.perform(Action::Select);
+        app.select(Setting::all().iter().position(|s| *s == Setting::Key(Action::Quit)).unwrap());
+        app.perform(Action::Select);
+        app.on_key(KeyCode::Char('Q'));
+        assert_eq!(app.keymap.action(KeyCode::Char('Q')), Some(Action::Quit));
+        assert_eq!(app.keymap.action(KeyCode::Char('q')), None);
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written, "# mine\nfavorites = [\"Onosato\"]\ntheme = \"high_contrast\"\n\n[keys]\nquit = [\"Q\"]\n");
+    }
+
+    #[test]
+    fn favorite_jumps_to_bout() {
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.favorites = vec!["onosato".to_string()];
+        app.set_torikumi(vec![bout(3, 1)]);
+        app.jump_to_favorite(1);
+        assert!(app.current_view == super::AppView::Torikumi);
+        assert_eq!(app.selection().index, 0);
+        app.jump_to_favorite(2);
+        assert_eq!(app.status_message.as_deref(), Some("No favorite in slot 2"));
+
+        // The jump scrolls as far as the rows that fit on screen
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        let mut bouts: Vec<_> = (1..=20).map(|n| TorikumiEntry { west_shikona: "Ura".into(), ..bout(3, n) }).collect();
+        bouts[19].west_shikona = "Onosato".into();
+        app.set_torikumi(bouts);
+        app.set_viewport_rows(4);
+        app.on_key_event(KeyEvent::new(KeyCode::Char('1'), KeyModifiers::ALT));
+        let selection = app.selection();
+        assert_eq!(selection.index, 19);
+        assert!((selection.scroll..selection.scroll + 4).contains(&19), "scrolled to {}", selection.scroll);
+    }
+
+    #[test]
+    fn unfinished_bouts_are_filtered_and_jumped_to() {
+        use crate::keymap::Action;
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        let undecided = |match_no| TorikumiEntry { winner_id: None, winner_en: None, ..bout(3, match_no) };
+        app.set_torikumi(vec![bout(3, 1), undecided(2), bout(3, 3), undecided(4)]);
+        assert_eq!(super::torikumi_title(&app), "Daily Matches (1/4) · 2/4 decided");
+
+        app.perform(Action::JumpToUnfinished);
+        assert_eq!(app.selection().index, 1);
+        app.set_torikumi_filter(super::TorikumiFilter::Unfinished);
+        assert_eq!((app.shown_bouts().map(|b| b.match_no).collect::<Vec<_>>(), app.selection().index), (vec![2, 4], 0));
+        // Results hidden by spoiler-free mode count as unfinished
+        app.set_spoiler_from(Some(3));
+        assert_eq!(app.shown_bouts().count(), 4);
+        app.perform(Action::RevealAll);
+        app.set_torikumi(vec![bout(3, 1)]);
+        app.perform(Action::JumpToUnfinished);
+        assert_eq!(app.status_message.as_deref(), Some("All bouts decided"));
+    }
+
+    #[test]
+    fn counts_remaining_bouts_unless_hidden() {
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        assert_eq!(app.bouts_remaining(), None);
+        let mut undecided = bout(3, 2);
+        undecided.winner_id = None;
+        app.set_torikumi(vec![bout(3, 1), undecided]);
+        assert_eq!(app.bouts_remaining(), Some(1));
+        app.set_spoiler_from(Some(3));
+        assert_eq!(app.bouts_remaining(), None);
+
+        app.show_toast("Day 3 is complete".to_string());
+        app.on_tick();
+        assert!(app.toast.is_some());
+        app.toast.as_mut().unwrap().1 -= super::TOAST_DURATION;
+        app.on_tick();
+        assert!(app.toast.is_none());
+
+        app.reduced_motion = true;
+        app.show_toast("Day 3 is complete".to_string());
+        assert!(app.toast.is_none());
+        assert_eq!(app.status_message.as_deref(), Some("Day 3 is complete"));
+    }
+
+    #[test]
+    fn notes_open_their_day_from_the_journal() {
+        use crossterm::event::KeyCode;
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 8);
+        app.on_key(KeyCode::Char('n'));
+        "Onosato injured?".chars().for_each(|c| app.on_key(KeyCode::Char(c)));
+        app.on_key(KeyCode::Enter);
+        assert_eq!(app.journal.for_basho("202501")[0].text, "Onosato injured?");
+
+        app.day = 3;
+        app.on_key(KeyCode::Char('7'));
+        assert_eq!(app.current_list_len(), 1);
+        app.on_key(KeyCode::Enter);
+        assert!(app.current_view == super::AppView::Torikumi);
+        assert_eq!((app.day, app.needs_reload), (8, true));
+    }
+
+    #[test]
+    fn torikumi_order_keeps_the_selected_bout() {
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        let mut low = bout(3, 3);
+        low.east_rank = "Maegashira 15 East".to_string();
+        low.west_rank = "Maegashira 16 West".to_string();
+        let mut mid = bout(3, 2);
+        mid.west_rank = "Maegashira 2 West".to_string();
+        app.set_torikumi(vec![low, mid, bout(3, 1)]);
+        let order = |app: &App| app.torikumi.iter().flatten().map(|b| b.match_no).collect::<Vec<_>>();
+        assert_eq!(order(&app), vec![1, 2, 3]);
+
+        app.selection_mut().index = 2;
+        app.set_torikumi_order(super::TorikumiOrder::Reverse);
+        assert_eq!((order(&app), app.selection().index), (vec![3, 2, 1], 0));
+        // Yokozuna-Ozeki first, then the Yokozuna against a maegashira
+        app.set_torikumi_order(super::TorikumiOrder::Rank);
+        assert_eq!((order(&app), app.selection().index), (vec![1, 2, 3], 2));
+    }
+
+    #[test]
+    fn torikumi_filters_keep_the_selection_on_shown_bouts() {
+        use crate::keymap::Action;
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.favorites = vec!["ura".to_string()];
+        let mut low = bout(3, 1);
+        (low.east_id, low.east_shikona, low.east_rank) = (3, "Ura".into(), "Maegashira 15 East".to_string());
+        (low.west_id, low.west_rank) = (4, "Maegashira 16 West".to_string());
+        let mut mid = bout(3, 2);
+        mid.west_rank = "Maegashira 2 West".to_string();
+        app.set_torikumi(vec![low, mid, bout(3, 3)]);
+        let shown = |app: &App| app.shown_bouts().map(|b| b.match_no).collect::<Vec<_>>();
+
+        app.selection_mut().index = 2;
+        app.perform(Action::CycleFilter);
+        assert_eq!((shown(&app), app.selection().index), (vec![2, 3], 1));
+        app.perform(Action::CycleFilter);
+        assert_eq!((shown(&app), app.selection().index), (vec![1], 0));
+        app.perform(Action::CycleFilter);
+        assert_eq!(shown(&app), vec![1]);
+        // Jumping to a bout the filter hides shows them all again
+        assert!(app.focus_bout(2));
+        assert_eq!((app.torikumi_filter, shown(&app), app.selection().index), (super::TorikumiFilter::All, vec![1, 2, 3], 1));
+    }
+
+    #[test]
+    fn quick_switcher_jumps_to_rikishi_and_divisions() {
+        use super::InputMode;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.set_torikumi(vec![bout(3, 1), bout(3, 2)]);
+        app.set_banzuke(vec![crate::api::BanzukeEntry {
+            side: "West".to_string(),
+            rikishi_id: 2,
+            shikona_en: "Onosato".into(),
+            shikona_jp: Some("大の里".to_string()),
+            rank_value: 2,
+            rank: "Ozeki 1 West".to_string(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: None,
+        }]);
+        let type_text = |app: &mut App, text: &str| text.chars().for_each(|c| app.on_key(KeyCode::Char(c)));
+
+        app.current_view = super::AppView::Stats;
+        app.open_switcher();
+        type_text(&mut app, "onos");
+        app.on_key(KeyCode::Enter);
+        assert!(app.current_view == super::AppView::Torikumi);
+        assert_eq!(app.selection().index, 0);
+
+        app.on_key_event(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        assert!(app.input_mode == InputMode::Switching);
+        type_text(&mut app, "jury");
+        assert_eq!(app.switcher_matches()[0].target, super::Target::Division("Juryo".to_string()));
+        app.on_key(KeyCode::Enter);
+        assert!(app.input_mode == InputMode::Normal);
+        assert_eq!((app.division.as_str(), app.needs_reload), ("Juryo", true));
+
+        app.open_switcher();
+        type_text(&mut app, "view banzuke");
+        app.on_key(KeyCode::Enter);
+        assert!(app.current_view == super::AppView::Banzuke);
+
+        app.open_switcher();
+        type_text(&mut app, "zzzz");
+        app.on_key(KeyCode::Enter);
+        assert!(app.input_mode == InputMode::Switching);
+        assert_eq!(app.input_error.as_deref(), Some("Nothing matches"));
+    }
+
+    #[test]
+    fn goto_prompt_sets_basho_division_and_day() {
+        use super::InputMode;
+        use crate::keymap::Action;
+        use crossterm::event::KeyCode;
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        let submit = |app: &mut App, text: &str| {
+            app.perform(Action::Goto);
+            text.chars().for_each(|c| app.on_key(KeyCode::Char(c)));
+            app.on_key(KeyCode::Enter);
+        };
+
+        // Makushita has seven bouts a basho
+        submit(&mut app, "kyushu 2024 ms d9");
+        assert!(app.input_mode == InputMode::EnteringGoto);
+        assert_eq!((app.input_error.as_deref(), app.needs_reload), (Some("Invalid day"), false));
+        app.on_key(KeyCode::Esc);
+
+        submit(&mut app, "kyushu 2024 juryo d9");
+        assert!(app.input_mode == InputMode::Normal);
+        assert_eq!((app.basho_id.as_str(), app.division.as_str(), app.day), ("202411", "Juryo", 9));
+        assert!(app.basho_changed && app.needs_reload);
+
+        // A basho name without a year stays in the loaded basho's year
+        submit(&mut app, "hatsu");
+        assert_eq!((app.basho_id.as_str(), app.day), ("202401", 9));
+    }
+
+    #[test]
+    fn jumps_between_banzuke_tiers() {
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        let ranks = ["Yokozuna 1 East", "Ozeki 1 East", "Ozeki 1 West", "Maegashira 1 East", "Maegashira 1 West"];
+        app.set_banzuke(ranks.iter().enumerate().map(|(i, rank)| crate::api::BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: i as u32,
+            shikona_en: "A".into(),
+            shikona_jp: None,
+            rank_value: i as u32,
+            rank: rank.to_string(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: None,
+        }).collect());
+        assert_eq!(super::banzuke_sections(app.banzuke.as_deref().unwrap()).len(), 3);
+
+        app.current_view = super::AppView::Banzuke;
+        app.perform(super::Action::NextSection);
+        assert_eq!(app.selection().index, 1);
+        app.perform(super::Action::NextSection);
+        app.perform(super::Action::NextSection);
+        assert_eq!(app.selection().index, 3);
+        app.selection_mut().index = 4;
+        app.perform(super::Action::PrevSection);
+        assert_eq!(app.selection().index, 3);
+        app.perform(super::Action::PrevSection);
+        assert_eq!(app.selection().index, 1);
+
+        let banzuke = app.banzuke.as_deref().unwrap();
+        let index = |rank: &str| super::rank_index(banzuke, &crate::rank::Rank::parse(rank).unwrap());
+        assert_eq!(index("O1w"), Some(2));
+        assert_eq!(index("m1"), Some(3));
+        // Nobody at Sekiwake: the next rank down
+        assert_eq!(index("S"), Some(3));
+        assert_eq!(index("J3"), None);
+    }
+
+    #[test]
+    fn day_grid_marks_results_and_moves_the_cursor() {
+        use super::{DayStatus, InputMode};
+        use crossterm::event::KeyCode;
+
+        let mut app = App::new("202501".to_string(), "Makushita".to_string(), 3);
+        app.basho = Some(crate::api::Basho {
+            date: Some("202501".to_string()),
+            location: None,
+            start_date: Some("2025-01-12T00:00:00Z".to_string()),
+            end_date: Some("2025-01-26T00:00:00Z".to_string()),
+            yusho: None,
+            sansho: None,
+        });
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 1, 16).unwrap();
+        assert_eq!(app.day_status(4, today), DayStatus::Results);
+        assert_eq!(app.day_status(5, today), DayStatus::Pending);
+        assert_eq!(app.day_status(6, today), DayStatus::Future);
+
+        // Makushita bouts end on day 7, so later days can't be picked
+        app.perform(super::Action::ChangeDay);
+        assert_eq!((app.input_mode == InputMode::EditingDay, app.day_cursor), (true, 3));
+        app.on_key(KeyCode::Down);
+        assert_eq!(app.day_cursor, 3);
+        app.on_key(KeyCode::Char('1'));
+        app.on_key(KeyCode::Char('2'));
+        assert_eq!(app.input_error.as_deref(), Some("Makushita bouts run on days 1-7"));
+        app.on_key(KeyCode::Esc);
+
+        app.division = "Juryo".to_string();
+        app.perform(super::Action::ChangeDay);
+        app.on_key(KeyCode::Down);
+        app.on_key(KeyCode::Right);
+        assert_eq!(app.day_cursor, 9);
+        app.on_key(KeyCode::Down);
+        app.on_key(KeyCode::Down);
+        assert_eq!(app.day_cursor, 14);
+        app.on_key(KeyCode::Char('1'));
+        app.on_key(KeyCode::Char('2'));
+        // The day loads once its torikumi turns out to exist
+        app.on_key(KeyCode::Enter);
+        assert_eq!((app.requested_day_check.take(), app.checking_day, app.needs_reload), (Some(12), Some(12), false));
+        app.settle_day_check(12, Some(false));
+        assert_eq!(app.input_error.as_deref(), Some("No Juryo torikumi for day 12 yet"));
+        app.on_key(KeyCode::Enter);
+        app.settle_day_check(12, Some(true));
+        assert_eq!((app.day, app.needs_reload, app.input_mode == InputMode::Normal), (12, true, true));
+    }
+
+    #[test]
+    fn playoff_is_a_day_after_the_last() {
+        use super::Action;
+        use crossterm::event::KeyCode;
+
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 13);
+        assert_eq!(app.last_day(), 15);
+        app.set_playoff(true);
+        assert_eq!((app.playoff(), app.last_day()), (Some(true), 16));
+        app.perform(Action::ChangeDay);
+        app.on_key(KeyCode::Down);
+        assert_eq!(app.day_cursor, 16);
+        app.on_key(KeyCode::Up);
+        app.on_key(KeyCode::Char('1'));
+        app.on_key(KeyCode::Char('6'));
+        app.on_key(KeyCode::Enter);
+        app.settle_day_check(16, None);
+        assert_eq!(app.day, 16);
+
+        // Only known for the division it was checked in
+        app.division = "Juryo".to_string();
+        assert_eq!((app.playoff(), app.last_day()), (None, 15));
+    }
+
+    #[test]
+    fn recent_records_are_fetched_once_per_banzuke() {
+        use crate::config::BanzukeColumn;
+        let mut app = App::new("202501".to_string(), "Juryo".to_string(), 5);
+        app.banzuke = Some(Vec::new());
+        assert_eq!(app.request_recent_records(), None);
+        app.banzuke_columns = vec![BanzukeColumn::Shikona, BanzukeColumn::Recent];
+        assert_eq!(app.request_recent_records().as_deref(), Some("202501/Juryo"));
+        assert_eq!(app.request_recent_records(), None);
+        app.division = "Makushita".to_string();
+        assert_eq!(app.request_recent_records().as_deref(), Some("202501/Makushita"));
+    }
+
+    #[test]
+    fn head_to_head_column_shows_the_series() {
+        use super::torikumi_cells;
+        use crate::config::TorikumiColumn;
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 5);
+        app.set_torikumi(vec![bout(5, 1)]);
+        assert_eq!(app.request_bout_series(), None);
+        app.torikumi_columns = vec![TorikumiColumn::East, TorikumiColumn::West, TorikumiColumn::HeadToHead];
+        assert_eq!(app.request_bout_series().as_deref(), Some("202501/Makuuchi/5"));
+        assert_eq!(app.request_bout_series(), None);
+
+        let cell = |app: &App| torikumi_cells(app, &[TorikumiColumn::HeadToHead], &bout(5, 1))[0].text.clone();
+        assert_eq!(cell(&app), "…");
+        let id = bout(5, 1).id;
+        app.bout_series = Some(("202501/Makuuchi/5".to_string(), [(id, (7, 3))].into()));
+        assert_eq!(cell(&app), "7-3");
+        // Another day's series isn't shown
+        app.day = 6;
+        assert_eq!(cell(&app), "…");
+    }
+
+    #[test]
+    fn edge_points_at_the_series_leader_until_decided() {
+        use super::torikumi_cells;
+        use crate::config::TorikumiColumn;
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 5);
+        let mut undecided = bout(5, 2);
+        undecided.winner_id = None;
+        app.set_torikumi(vec![bout(5, 1), undecided.clone()]);
+        app.torikumi_columns = vec![TorikumiColumn::Edge];
+        assert_eq!(app.request_bout_series().as_deref(), Some("202501/Makuuchi/5"));
+
+        let cell = |app: &App, bout: &TorikumiEntry| torikumi_cells(app, &[TorikumiColumn::Edge], bout)[0].text.clone();
+        app.bout_series = Some(("202501/Makuuchi/5".to_string(), [(bout(5, 1).id, (2, 5)), (undecided.id.clone(), (2, 5))].into()));
+        assert_eq!(cell(&app, &undecided), "+3 ▶");
+        assert_eq!(cell(&app, &bout(5, 1)), "");
+        app.bout_series = Some(("202501/Makuuchi/5".to_string(), [(undecided.id.clone(), (4, 4))].into()));
+        assert_eq!(cell(&app, &undecided), "=");
+        app.bout_series = Some(("202501/Makuuchi/5".to_string(), [(undecided.id.clone(), (0, 0))].into()));
+        assert_eq!(cell(&app, &undecided), "-");
+    }
+
+    #[test]
+    fn adjusted_days_are_explained() {
+        use super::{adjustment_banner, DayAdjustment};
+        let mut app = App::new("202501".to_string(), "Juryo".to_string(), 15);
+        assert_eq!(adjustment_banner(&app), None);
+        app.day_adjustment = Some((16, DayAdjustment::DivisionDays));
+        assert_eq!(adjustment_banner(&app).as_deref(), Some("Day 16 requested: Juryo runs to day 15"));
+        app.day_adjustment = Some((9, DayAdjustment::Finished));
+        assert_eq!(adjustment_banner(&app).as_deref(), Some("Day 9 requested: basho is over, showing day 15"));
+    }
+
+    #[test]
+    fn spoiler_free_hides_from_day() {
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 5);
+        app.set_spoiler_from(Some(5));
+        assert!(!app.results_hidden(4));
+        assert!(app.results_hidden(5));
+        assert_eq!(app.revealed_days(), 4);
+    }
+
+    #[test]
+    fn revealing_every_bout_completes_replay_day() {
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.start_replay(Duration::from_secs(10), false);
+        app.set_torikumi(vec![bout(3, 1), bout(3, 2)]);
+        assert!(app.bout_hidden(&bout(3, 1)));
+        assert_eq!(app.revealed_days(), 2);
+
+        app.reveal_bouts(false);
+        assert!(!app.bout_hidden(&bout(3, 1)));
+        assert!(app.bout_hidden(&bout(3, 2)));
+
+        app.reveal_bouts(true);
+        assert_eq!(app.revealed_days(), 3);
+    }
+
+    // Makushita 1 East with `results` as his bouts so far
+    fn makushita_entry(results: &[&str]) -> crate::api::BanzukeEntry {
+        let result = |result: &&str| crate::api::MatchRecord {
+            result: result.to_string(),
+            opponent_shikona_en: "Onosato".to_string(),
+            opponent_shikona_jp: String::new(),
+            kimarite: None,
+        };
+        crate::api::BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: 1,
+            shikona_en: "Hoshoryu".into(),
+            shikona_jp: None,
+            rank_value: 1,
+            rank: "Makushita 1 East".to_string(),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: Some(results.iter().map(result).collect()),
+        }
+    }
+
+    #[test]
+    fn lower_division_replay_maps_bouts_to_days() {
+        let mut app = App::new("202501".to_string(), "Makushita".to_string(), 6);
+        app.set_banzuke(vec![makushita_entry(&["win", "loss", "win"])]);
+        app.start_replay(Duration::from_secs(10), false);
+        assert_eq!(app.request_bout_days().as_deref(), Some("202501/Makushita"));
+        assert_eq!(app.request_bout_days(), None);
+        // Nothing is revealed until the bouts are placed on their days
+        assert_eq!(app.record_map[&1], (0, 0));
+
+        // Bouts on days 2, 4 and 6: only two are in before the replay day
+        app.set_bout_days("202501/Makushita".to_string(), [(1, vec![2, 4, 6])].into());
+        assert_eq!(app.record_map[&1], (1, 1));
+        app.set_torikumi(vec![bout(6, 1)]);
+        app.reveal_bouts(true);
+        assert_eq!(app.record_map[&1], (2, 1));
+    }
+
+    #[test]
+    fn lower_division_spoiler_free_counts_bouts_before_the_hidden_day() {
+        let mut app = App::new("202501".to_string(), "Makushita".to_string(), 7);
+        app.set_banzuke(vec![makushita_entry(&["win", "win", "loss", "win", "win", "loss", "win"])]);
+        // Without hidden results the whole record counts
+        assert_eq!(app.record_map[&1], (5, 2));
+
+        app.set_spoiler_from(Some(12));
+        assert_eq!(app.revealed_days(), 11);
+        assert_eq!(app.request_bout_days().as_deref(), Some("202501/Makushita"));
+        app.set_bout_days("202501/Makushita".to_string(), [(1, vec![1, 3, 5, 7, 9, 11, 13])].into());
+        // Six bouts fall on days 1-11, the seventh on day 13
+        assert_eq!(app.revealed_for(&makushita_entry(&[])), 6);
+        assert_eq!(app.record_map[&1], (4, 2));
+    }
+
+    #[test]
+    fn last_visible_day_is_a_day_in_every_division() {
+        let mut app = App::new("202501".to_string(), "Makushita".to_string(), 7);
+        assert_eq!(app.last_visible_day(), None);
+        app.set_spoiler_from(Some(12));
+        assert_eq!(app.last_visible_day(), Some(11));
+        app.set_spoiler_from(None);
+        app.start_replay(Duration::from_secs(10), false);
+        assert_eq!(app.last_visible_day(), Some(6));
+    }
+
+    #[test]
+    fn shrinking_keeps_selection_visible() {
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.set_torikumi((1..=20).map(|n| bout(3, n)).collect());
+        app.set_viewport_rows(10);
+        app.select(9);
+        app.set_viewport_rows(4);
+        assert_eq!(app.selection().scroll, 6);
+
+        // Growing again shows as many rows as fit instead of blank space
+        app.set_viewport_rows(30);
+        assert_eq!(app.selection().scroll, 0);
+        assert_eq!(app.selection().index, 9);
+    }
+
+    #[test]
+    fn reloads_keep_the_selected_rikishi() {
+        let card = |day: u8, pairs: &[(u32, u32)]| {
+            let bouts = pairs.iter().enumerate().map(|(i, (east, west))| TorikumiEntry { east_id: *east, west_id: *west, ..bout(day, i as u8 + 1) });
+            bouts.collect::<Vec<_>>()
+        };
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.set_torikumi(card(3, &[(1, 2), (3, 4), (5, 6)]));
+        app.select(1);
+        app.clear_torikumi();
+        assert_eq!(app.followed.bout, [3, 4]);
+
+        // The next day, 3 fights last
+        app.set_torikumi(card(4, &[(4, 5), (6, 1), (2, 3)]));
+        let followed = std::mem::take(&mut app.followed);
+        app.follow(&followed);
+        assert_eq!(app.selection().index, 2);
+        // Neither of them on the card: the first row, as before
+        app.clear_torikumi();
+        app.set_torikumi(card(5, &[(1, 4), (5, 6)]));
+        let followed = std::mem::take(&mut app.followed);
+        app.follow(&followed);
+        assert_eq!(app.selection().index, 0);
+    }
+
+    #[test]
+    fn views_keep_their_own_selection() {
+        use crate::keymap::Action;
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.set_torikumi((1..=100).map(|n| bout(3, n)).collect());
+        app.set_viewport_rows(10);
+        app.select(40);
+        app.perform(Action::ViewSettings);
+        assert_eq!(app.selection().index, 0);
+        app.perform(Action::Down);
+        app.perform(Action::Down);
+        app.perform(Action::ViewBanzuke);
+        app.perform(Action::ViewTorikumi);
+        assert_eq!(app.selection(), super::Selection { index: 40, scroll: 31 });
+
+        // Back in a shorter window, still on screen
+        app.set_viewport_rows(4);
+        app.perform(Action::ViewSettings);
+        assert_eq!(app.selection().index, 2);
+        app.perform(Action::ViewTorikumi);
+        assert_eq!(app.selection(), super::Selection { index: 40, scroll: 37 });
+    }
+
+    #[test]
+    fn help_search_filters_generated_entries() {
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        let all = app.help_lines();
+        assert!(all.contains(&super::HelpLine::Key("h/F1".to_string(), "help.toggle_help")));
+
+        app.help_filter = "replay".to_string();
+        let filtered = app.help_lines();
+        assert!(filtered.len() < all.len());
+        assert_eq!(filtered[0], super::HelpLine::Section("help.replay"));
+        assert!(filtered.iter().all(|l| !matches!(l, super::HelpLine::Section("help.navigation"))));
+    }
+
+    #[test]
+    fn marking_rikishi_for_comparison() {
+        use crate::api::BanzukeEntry;
+        use crate::keymap::Action;
+        let entry = |id: u32| BanzukeEntry {
+            side: "East".to_string(),
+            rikishi_id: id,
+            shikona_en: format!("Rikishi{}", id).into(),
+            shikona_jp: None,
+            rank_value: id,
+            rank: format!("Maegashira {} East", id),
+            wins: None,
+            losses: None,
+            absences: None,
+            record: None,
+        };
+        let mut app = App::new("202501".to_string(), "Makuuchi".to_string(), 3);
+        app.set_banzuke((1..=5).map(entry).collect());
+        app.perform(Action::ViewBanzuke);
+        for index in 0..5 {
+            app.select(index);
+            app.perform(Action::ToggleCompare);
+        }
+        assert_eq!(app.compare_ids, vec![1, 2, 3, 4]);
+        assert_eq!(app.status_message.as_deref(), Some("Up to 4 rikishi can be compared"));
+        app.select(1);
+        app.perform(Action::ToggleCompare);
+        assert_eq!(app.compare_ids, vec![1, 3, 4]);
+
+        app.perform(Action::ViewCompare);
+        assert!(app.compare_requested);
+        let (names, rows) = super::compare_rows(&app, chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+        assert_eq!(names, vec!["Rikishi1", "Rikishi3", "Rikishi4"]);
+        assert_eq!(rows[0].1, vec!["Maegashira 1 East", "Maegashira 3 East", "Maegashira 4 East"]);
+        // Head-to-heads not fetched yet
+        assert_eq!(rows.last().unwrap().1, vec!["-", "-", "-"]);
+    }
+
+    #[test]
+    fn detail_popups_load_in_place_and_retry() {
+        use crate::keymap::Action;
+        let mut app = App::new("202501".into(), "Makuuchi".into(), 1);
+        app.open_head_to_head(1, 2);
+        assert_eq!(app.requested_head_to_head.take(), Some((1, 2)));
+        assert_eq!(PopupStack::open(&app).top(), Some(Popup::Pending));
+
+        assert!(app.settle_pending(&Pending::HeadToHead(1, 2), Some("timed out".to_string())));
+        app.perform(Action::Select);
+        assert_eq!(app.requested_head_to_head, Some((1, 2)));
+        assert_eq!(app.pending, Some((Pending::HeadToHead(1, 2), None)));
+
+        // Closed before the data came in, so it isn't shown after all
+        app.perform(Action::Close);
+        assert!(!app.settle_pending(&Pending::HeadToHead(1, 2), None));
+        assert_eq!(PopupStack::open(&app).top(), None);
+    }
+}
@@ -0,0 +1,712 @@
+use super::{
+    App, CachedCell, InputMode, Pending, basho_has_started, centered_rect, column_widths,
+    position_title, remaining_title, render_day_grid, render_day_summary, render_division_selector,
+    render_head_to_head, render_help_popup, render_input_popup, render_inspector,
+    render_projection, render_rikishi_details, render_switcher, render_toast, torikumi_title,
+};
+use crate::config::{BanzukeColumn, NavigationConfig, ScrollMode, TorikumiColumn};
+use crate::i18n::{t, tf};
+use crate::keymap::Action;
+use crate::rank;
+use crate::text::truncate_to_width;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+};
+use std::time::{Duration, Instant};
+
+/// Selected row of a scrolling list and the first row on screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Selection {
+    pub index: usize,
+    pub scroll: usize,
+}
+
+impl Selection {
+    /// Select `index`, scrolling just far enough that it is one of `rows` rows on screen.
+    pub fn select(&mut self, index: usize, rows: usize) {
+        self.index = index;
+        if index < self.scroll {
+            self.scroll = index;
+        } else if index >= self.scroll + rows {
+            self.scroll = index + 1 - rows;
+        }
+    }
+
+    /// Select `index` of a list of `len` with `rows` on screen, scrolled as `mode` keeps it.
+    pub fn scroll_to(&mut self, index: usize, len: usize, rows: usize, mode: ScrollMode) {
+        match mode {
+            ScrollMode::Edge => self.select(index, rows),
+            ScrollMode::Center => {
+                self.index = index;
+                self.scroll = index.saturating_sub(rows / 2).min(len.saturating_sub(rows));
+            }
+        }
+    }
+
+    /// Move `by` rows down (up when negative) a list of `len` with `rows` on screen. A
+    /// move past either end stops there, and with `wrap` the next one carries on from the
+    /// other end.
+    pub fn step(&mut self, by: isize, len: usize, rows: usize, navigation: &NavigationConfig) {
+        if len == 0 {
+            return;
+        }
+        let last = len - 1;
+        // The list may have shrunk since the row was selected
+        let index = self.index.min(last);
+        let target = match index.checked_add_signed(by) {
+            Some(target) if target <= last => target,
+            Some(_) if navigation.wrap && index == last => 0,
+            Some(_) => last,
+            None if navigation.wrap && index == 0 => last,
+            None => 0,
+        };
+        self.scroll_to(target, len, rows, navigation.scroll);
+    }
+
+    /// Keep the selection inside a list of `len`, scrolled no further than the last row.
+    pub fn clamp(&mut self, len: usize) {
+        self.index = self.index.min(len.saturating_sub(1));
+        self.scroll = self.scroll.min(self.index);
+    }
+
+    /// Fit the selection to `rows` rows on screen, without blank rows at the bottom when
+    /// the window grows.
+    pub fn fit(&mut self, len: usize, rows: usize, mode: ScrollMode) {
+        if len == 0 {
+            *self = Self::default();
+            return;
+        }
+        self.index = self.index.min(len - 1);
+        self.scroll = self.scroll.min(len.saturating_sub(rows));
+        self.scroll_to(self.index, len, rows, mode);
+    }
+}
+
+// Presses of a movement key closer together than this come from holding it down
+const REPEAT_GAP: Duration = Duration::from_millis(80);
+// Held repeats before the rows moved per press double, and how often they double at most
+const REPEATS_PER_DOUBLING: u32 = 8;
+const MAX_DOUBLINGS: u32 = 3;
+
+/// Speeds up the selection while a movement key is held down, telling held keys from
+/// separate presses by how quickly they repeat.
+#[derive(Debug, Default)]
+pub struct Acceleration {
+    // When the last move was made and whether it was down
+    last: Option<(Instant, bool)>,
+    repeats: u32,
+}
+
+impl Acceleration {
+    /// Rows to move for a press moving down (or up) at `now`: one, then doubling up to
+    /// eight the longer the key is held.
+    pub fn rows(&mut self, down: bool, now: Instant) -> usize {
+        let held = self.last.is_some_and(|(at, was_down)| {
+            was_down == down && now.saturating_duration_since(at) < REPEAT_GAP
+        });
+        self.repeats = if held { self.repeats + 1 } else { 0 };
+        self.last = Some((now, down));
+        1 << (self.repeats / REPEATS_PER_DOUBLING).min(MAX_DOUBLINGS)
+    }
+}
+
+/// The torikumi table: which bouts the filter shows, their cells and the selected one.
+#[derive(Default)]
+pub struct TorikumiTable {
+    pub selection: Selection,
+    // Indices into the torikumi of the bouts the filter lets through, in display order;
+    // the selection indexes this
+    shown: Vec<usize>,
+    // Pre-formatted cells of the shown bouts, so drawing a frame only has to truncate
+    // the visible ones
+    rows: Vec<Vec<CachedCell>>,
+}
+
+impl TorikumiTable {
+    /// Indices into the torikumi of the shown bouts, in display order.
+    pub fn shown(&self) -> &[usize] {
+        &self.shown
+    }
+
+    pub fn set_shown(&mut self, shown: Vec<usize>) {
+        self.shown = shown;
+        self.selection.clamp(self.shown.len());
+    }
+
+    pub fn set_rows(&mut self, rows: Vec<Vec<CachedCell>>) {
+        self.rows = rows;
+    }
+
+    /// Index into the torikumi of the selected bout.
+    pub fn selected(&self) -> Option<usize> {
+        self.shown.get(self.selection.index).copied()
+    }
+
+    /// Row of the bout at `index` in the torikumi, if it is shown.
+    pub fn row_of(&self, index: usize) -> Option<usize> {
+        self.shown.iter().position(|i| *i == index)
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, app: &App) {
+        let theme = app.theme;
+        let Some(torikumi) = &app.torikumi else {
+            let paragraph = Paragraph::new(t("torikumi.loading"))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(t("torikumi.title")),
+                )
+                .alignment(Alignment::Center);
+            f.render_widget(paragraph, area);
+            return;
+        };
+        if torikumi.is_empty() {
+            let message = if basho_has_started(app) {
+                t("torikumi.none")
+            } else {
+                t("torikumi.not_started")
+            };
+
+            let paragraph = Paragraph::new(message)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(t("torikumi.title")),
+                )
+                .alignment(Alignment::Center);
+
+            f.render_widget(paragraph, area);
+            return;
+        }
+
+        let visible_height = area.height.saturating_sub(3) as usize; // Account for borders and header
+        let columns = app.torikumi_columns();
+        let constraints: Vec<Constraint> = columns
+            .iter()
+            .map(|c| match c {
+                TorikumiColumn::East | TorikumiColumn::West => Constraint::Fill(2),
+                TorikumiColumn::Kimarite => Constraint::Fill(1),
+                TorikumiColumn::MatchNo => Constraint::Length(4),
+                TorikumiColumn::RankDiff => Constraint::Length(9),
+                TorikumiColumn::WinProb => Constraint::Length(9),
+                TorikumiColumn::HeadToHead => Constraint::Length(7),
+                TorikumiColumn::Edge => Constraint::Length(6),
+            })
+            .collect();
+        let widths = column_widths(area, &constraints);
+
+        let rows: Vec<Row> = self
+            .rows
+            .iter()
+            .enumerate()
+            .skip(self.selection.scroll)
+            .take(visible_height)
+            .map(|(i, cells)| {
+                let style = if i == self.selection.index {
+                    theme.selected
+                } else {
+                    Style::default()
+                };
+                let cells = cells.iter().zip(&widths).map(|(cell, &width)| {
+                    let text = truncate_to_width(&cell.text, width);
+                    if cell.winner {
+                        Cell::from(Line::from(Span::styled(text, theme.winner)))
+                    } else {
+                        Cell::from(Line::from(Span::styled(text, cell.style)))
+                    }
+                });
+                Row::new(cells).style(style)
+            })
+            .collect();
+
+        let header: Vec<&str> = columns
+            .iter()
+            .map(|c| match c {
+                TorikumiColumn::East => t("torikumi.east"),
+                TorikumiColumn::West => t("torikumi.west"),
+                TorikumiColumn::Kimarite => t("torikumi.kimarite"),
+                TorikumiColumn::MatchNo => t("torikumi.match_no"),
+                TorikumiColumn::RankDiff => t("torikumi.rank_diff"),
+                TorikumiColumn::WinProb => t("torikumi.win_prob"),
+                TorikumiColumn::HeadToHead => t("torikumi.head_to_head"),
+                TorikumiColumn::Edge => t("torikumi.edge"),
+            })
+            .collect();
+
+        let table = Table::new(rows, constraints)
+            .header(Row::new(header).style(theme.heading))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(torikumi_title(app))
+                    .title_bottom(remaining_title(app)),
+            );
+
+        f.render_widget(table, area);
+    }
+}
+
+/// The banzuke table with a header row above each rank tier.
+#[derive(Default)]
+pub struct BanzukePanel {
+    pub selection: Selection,
+    // Pre-formatted cells of every entry
+    rows: Vec<Vec<String>>,
+    // Style of each row from the user's highlight rules
+    styles: Vec<Style>,
+    // Banzuke index where each rank tier starts
+    sections: Vec<(usize, Option<rank::Tier>)>,
+}
+
+impl BanzukePanel {
+    pub fn set_rows(
+        &mut self,
+        rows: Vec<Vec<String>>,
+        styles: Vec<Style>,
+        sections: Vec<(usize, Option<rank::Tier>)>,
+    ) {
+        self.rows = rows;
+        self.styles = styles;
+        self.sections = sections;
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, app: &App) {
+        let theme = app.theme;
+        let Some(banzuke) = &app.banzuke else {
+            let paragraph = Paragraph::new(t("banzuke.loading"))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(t("banzuke.title")),
+                )
+                .alignment(Alignment::Center);
+            f.render_widget(paragraph, area);
+            return;
+        };
+        let visible_height = area.height.saturating_sub(3) as usize; // Account for borders and header
+        let Selection {
+            index: selected,
+            scroll,
+        } = self.selection;
+
+        let columns = app.banzuke_columns();
+        let constraints: Vec<Constraint> = columns
+            .iter()
+            .map(|c| match c {
+                BanzukeColumn::Rank | BanzukeColumn::Shikona | BanzukeColumn::ShikonaJp => {
+                    Constraint::Fill(2)
+                }
+                BanzukeColumn::Heya | BanzukeColumn::Shusshin => Constraint::Fill(2),
+                BanzukeColumn::Record => Constraint::Fill(1),
+                BanzukeColumn::Recent => Constraint::Fill(2),
+                BanzukeColumn::Streak | BanzukeColumn::RankChange => Constraint::Length(6),
+                BanzukeColumn::Age => Constraint::Length(4),
+            })
+            .collect();
+        let widths = column_widths(area, &constraints);
+
+        // Section headers are extra rows between the entries: scroll by entry, then
+        // slide the window so the selected entry stays visible below the headers
+        let sections = &self.sections;
+        let display_index =
+            |i: usize| i + sections.iter().take_while(|(start, _)| *start <= i).count();
+        let mut first_row = display_index(scroll);
+        if sections.iter().any(|(start, _)| *start == scroll) {
+            first_row -= 1;
+        }
+        let selected_row = display_index(selected);
+        if selected_row >= first_row + visible_height {
+            first_row = selected_row + 1 - visible_height;
+        }
+
+        let mut headers = sections.iter().peekable();
+        let rows: Vec<Row> = self
+            .rows
+            .iter()
+            .enumerate()
+            .flat_map(|(i, cells)| {
+                let header = headers.next_if(|(start, _)| *start == i).map(|(_, tier)| {
+                    let name = tier.map_or("", |tier| t(tier.label_key()));
+                    Row::new([Cell::from(Span::styled(
+                        format!("── {} ──", name),
+                        theme.accent.add_modifier(Modifier::BOLD),
+                    ))])
+                });
+                let style = if i == selected {
+                    theme.selected
+                } else {
+                    self.styles.get(i).copied().unwrap_or_default()
+                };
+                let cells = cells
+                    .iter()
+                    .zip(&widths)
+                    .map(|(text, &width)| Cell::from(truncate_to_width(text, width)));
+                header.into_iter().chain([Row::new(cells).style(style)])
+            })
+            .skip(first_row)
+            .take(visible_height)
+            .collect();
+
+        let header: Vec<&str> = columns
+            .iter()
+            .map(|c| match c {
+                BanzukeColumn::Rank => t("banzuke.rank"),
+                BanzukeColumn::Shikona => t("banzuke.wrestler"),
+                BanzukeColumn::ShikonaJp => t("banzuke.shikona_jp"),
+                BanzukeColumn::Record => t("banzuke.result"),
+                BanzukeColumn::Streak => t("banzuke.streak"),
+                BanzukeColumn::Heya => t("banzuke.heya"),
+                BanzukeColumn::Shusshin => t("banzuke.shusshin"),
+                BanzukeColumn::Age => t("banzuke.age"),
+                BanzukeColumn::RankChange => t("banzuke.rank_change"),
+                BanzukeColumn::Recent => t("banzuke.recent"),
+            })
+            .collect();
+
+        let table = Table::new(rows, constraints)
+            .header(Row::new(header).style(theme.heading))
+            .block(Block::default().borders(Borders::ALL).title(position_title(
+                t("banzuke.title"),
+                selected,
+                banzuke.len(),
+            )));
+
+        f.render_widget(table, area);
+    }
+}
+
+/// Something drawn over the current view.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Popup {
+    Help,
+    /// A prompt, the day grid, the division selector or the quick switcher
+    Input,
+    RikishiDetails,
+    HeadToHead,
+    /// The frame of a detail popup still loading, or whose fetch failed
+    Pending,
+    DaySummary,
+    Projection,
+    Inspector,
+    Toast,
+    Loading,
+}
+
+impl Popup {
+    /// Name of the popup as headless runs report it.
+    pub fn name(self) -> &'static str {
+        match self {
+            Popup::Help => "help",
+            Popup::Input => "input",
+            Popup::RikishiDetails => "rikishi_details",
+            Popup::HeadToHead => "head_to_head",
+            Popup::Pending => "pending",
+            Popup::DaySummary => "day_summary",
+            Popup::Projection => "projection",
+            Popup::Inspector => "inspector",
+            Popup::Toast => "toast",
+            Popup::Loading => "loading",
+        }
+    }
+}
+
+/// The popups open over the view, bottom first.
+pub struct PopupStack(Vec<Popup>);
+
+impl PopupStack {
+    pub fn open(app: &App) -> Self {
+        let open = [
+            (Popup::Help, app.show_help),
+            (
+                Popup::Input,
+                !matches!(app.input_mode, InputMode::Normal | InputMode::BindingKey),
+            ),
+            (
+                Popup::RikishiDetails,
+                app.show_rikishi_details && app.rikishi_details.is_some(),
+            ),
+            (
+                Popup::HeadToHead,
+                app.show_head_to_head && app.head_to_head_data.is_some(),
+            ),
+            (Popup::Pending, app.pending.is_some()),
+            (Popup::DaySummary, app.day_summary.is_some()),
+            (Popup::Projection, app.projection.is_some()),
+            (Popup::Inspector, app.inspector.is_some()),
+            (Popup::Toast, app.toast.is_some()),
+            (Popup::Loading, app.loading_overlay.is_some()),
+        ];
+        Self(
+            open.into_iter()
+                .filter(|(_, open)| *open)
+                .map(|(popup, _)| popup)
+                .collect(),
+        )
+    }
+
+    /// The popup drawn over all the others.
+    pub fn top(&self) -> Option<Popup> {
+        self.0.last().copied()
+    }
+
+    pub fn render(&self, f: &mut Frame, app: &App) {
+        for popup in &self.0 {
+            match popup {
+                Popup::Help => render_help_popup(f, app),
+                Popup::Input => render_input(f, app),
+                Popup::RikishiDetails => {
+                    if let Some(details) = &app.rikishi_details {
+                        render_rikishi_details(f, app, details);
+                    }
+                }
+                Popup::HeadToHead => {
+                    if let Some((rikishi_id, h2h)) = &app.head_to_head_data {
+                        render_head_to_head(f, &app.theme, *rikishi_id, h2h);
+                    }
+                }
+                Popup::Pending => {
+                    if let Some((request, error)) = &app.pending {
+                        render_pending(f, app, request, error.as_deref());
+                    }
+                }
+                Popup::DaySummary => {
+                    if let Some(summary) = &app.day_summary {
+                        render_day_summary(f, &app.theme, summary);
+                    }
+                }
+                Popup::Projection => {
+                    if let Some(projection) = &app.projection {
+                        render_projection(f, &app.theme, projection);
+                    }
+                }
+                Popup::Inspector => {
+                    if let Some(inspector) = &app.inspector {
+                        render_inspector(f, &app.theme, inspector);
+                    }
+                }
+                Popup::Toast => {
+                    if let Some((message, _)) = &app.toast {
+                        render_toast(f, &app.theme, message);
+                    }
+                }
+                Popup::Loading => {
+                    if let Some(message) = &app.loading_overlay {
+                        let area = centered_rect(50, 20, f.area());
+                        f.render_widget(Clear, area);
+
+                        let paragraph = Paragraph::new(message.clone())
+                            .style(app.theme.heading)
+                            .alignment(Alignment::Center)
+                            .block(
+                                Block::default()
+                                    .borders(Borders::ALL)
+                                    .title(t("loading.title")),
+                            );
+
+                        f.render_widget(paragraph, area);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// The frame of the popup being loaded, sized and titled like it
+fn render_pending(f: &mut Frame, app: &App, request: &Pending, error: Option<&str>) {
+    let (area, title, failed) = match request {
+        Pending::Rikishi(_) => (
+            centered_rect(70, 70, f.area()),
+            t("rikishi.title"),
+            "status.rikishi_failed",
+        ),
+        Pending::HeadToHead(..) => (
+            centered_rect(80, 80, f.area()),
+            t("h2h.title"),
+            "status.h2h_failed",
+        ),
+    };
+    f.render_widget(Clear, area);
+    let text = match error {
+        Some(error) => vec![
+            Line::from(Span::styled(tf(failed, &[&error]), app.theme.negative)),
+            Line::from(""),
+            Line::from(tf(
+                "loading.retry",
+                &[
+                    &app.keymap.label(Action::Select),
+                    &app.keymap.label(Action::Close),
+                ],
+            )),
+        ],
+        None => vec![Line::from(Span::styled(
+            t("loading.details"),
+            app.theme.heading,
+        ))],
+    };
+    let paragraph = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(paragraph, area);
+}
+
+// The popup of the open prompt
+fn render_input(f: &mut Frame, app: &App) {
+    let error = app.input_error.as_deref();
+    match app.input_mode {
+        InputMode::EditingDay => render_day_grid(f, app),
+        InputMode::SelectingDivision => {
+            render_division_selector(f, &app.theme, app.division_selector_index)
+        }
+        InputMode::EditingBasho => {
+            render_input_popup(f, &app.theme, t("input.basho"), &app.input_buffer, error)
+        }
+        InputMode::EditingSpoilerDay => render_input_popup(
+            f,
+            &app.theme,
+            t("input.spoiler_day"),
+            &app.input_buffer,
+            error,
+        ),
+        InputMode::EnteringCommand => {
+            let prompt = tf("input.command", &[&app.script_commands.join(", ")]);
+            render_input_popup(f, &app.theme, &prompt, &app.input_buffer, error)
+        }
+        InputMode::EnteringGoto => {
+            render_input_popup(f, &app.theme, t("input.goto"), &app.input_buffer, error)
+        }
+        InputMode::JumpingToRank => {
+            render_input_popup(f, &app.theme, t("input.rank"), &app.input_buffer, error)
+        }
+        InputMode::FindingBout => render_input_popup(
+            f,
+            &app.theme,
+            t("input.find_bout"),
+            &app.input_buffer,
+            error,
+        ),
+        InputMode::WritingNote => {
+            let prompt = tf("input.note", &[&app.day]);
+            render_input_popup(f, &app.theme, &prompt, &app.input_buffer, None)
+        }
+        InputMode::Switching => render_switcher(f, app),
+        InputMode::Normal | InputMode::BindingKey => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Acceleration, Action, NavigationConfig, Popup, PopupStack, ScrollMode, Selection};
+    use crate::tui::App;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn selection_scrolls_to_stay_on_screen() {
+        let edge = NavigationConfig::default();
+        let mut selection = Selection::default();
+        for _ in 0..6 {
+            selection.step(1, 10, 4, &edge);
+        }
+        assert_eq!(
+            selection,
+            Selection {
+                index: 6,
+                scroll: 3
+            }
+        );
+        selection.step(1, 7, 4, &edge);
+        assert_eq!(selection.index, 6);
+        // A taller window shows the rows above instead of blank ones below
+        selection.fit(7, 6, ScrollMode::Edge);
+        assert_eq!(
+            selection,
+            Selection {
+                index: 6,
+                scroll: 1
+            }
+        );
+        selection.clamp(3);
+        assert_eq!(
+            selection,
+            Selection {
+                index: 2,
+                scroll: 1
+            }
+        );
+        selection.step(-1, 3, 6, &edge);
+        selection.step(-1, 3, 6, &edge);
+        assert_eq!(
+            selection,
+            Selection {
+                index: 0,
+                scroll: 0
+            }
+        );
+    }
+
+    #[test]
+    fn navigation_wraps_centers_and_accelerates() {
+        let navigation = NavigationConfig {
+            wrap: true,
+            accelerate: true,
+            scroll: ScrollMode::Center,
+        };
+        let mut selection = Selection::default();
+        selection.step(5, 20, 6, &navigation);
+        assert_eq!(
+            selection,
+            Selection {
+                index: 5,
+                scroll: 2
+            }
+        );
+        // A long jump stops at the end; only the next move wraps
+        selection.step(30, 20, 6, &navigation);
+        assert_eq!(
+            selection,
+            Selection {
+                index: 19,
+                scroll: 14
+            }
+        );
+        selection.step(1, 20, 6, &navigation);
+        assert_eq!(
+            selection,
+            Selection {
+                index: 0,
+                scroll: 0
+            }
+        );
+        selection.step(-1, 20, 6, &navigation);
+        assert_eq!(selection.index, 19);
+
+        let mut acceleration = Acceleration::default();
+        let start = Instant::now();
+        let held: Vec<usize> = (0..20)
+            .map(|i| acceleration.rows(true, start + Duration::from_millis(30 * i)))
+            .collect();
+        assert_eq!(&held[..9], [1, 1, 1, 1, 1, 1, 1, 1, 2]);
+        assert_eq!(held[19], 4);
+        // Pressed again after a pause, or the other way, it starts over
+        assert_eq!(acceleration.rows(true, start + Duration::from_secs(5)), 1);
+        assert_eq!(
+            acceleration.rows(false, start + Duration::from_millis(5030)),
+            1
+        );
+    }
+
+    #[test]
+    fn popups_stack_in_drawing_order() {
+        let mut app = App::new("202501".into(), "Makuuchi".into(), 1);
+        assert_eq!(PopupStack::open(&app).top(), None);
+        app.perform(Action::ToggleHelp);
+        app.perform(Action::ChangeBasho);
+        assert_eq!(PopupStack::open(&app).0, vec![Popup::Help, Popup::Input]);
+        app.show_toast("Saved".to_string());
+        assert_eq!(PopupStack::open(&app).top(), Some(Popup::Toast));
+    }
+}
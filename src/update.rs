@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How long a looked-up version is trusted before crates.io is asked again.
+const CHECK_INTERVAL_SECS: i64 = 24 * 60 * 60;
+
+const CRATE_URL: &str = "https://crates.io/api/v1/crates/sumo";
+
+/// The last version lookup, kept in the state directory so startup doesn't hit
+/// crates.io more than once a day.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct UpdateCache {
+    /// Unix time of the last lookup, successful or not
+    checked_at: i64,
+    /// Newest stable version found, if the lookup succeeded
+    latest: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CrateResponse {
+    #[serde(rename = "crate")]
+    krate: CrateInfo,
+}
+
+#[derive(Deserialize)]
+struct CrateInfo {
+    max_stable_version: Option<String>,
+}
+
+fn cache_path() -> Option<PathBuf> {
+    Some(crate::paths::state_dir()?.join("update.toml"))
+}
+
+/// The newest published version if it is newer than this build. Uses the cached
+/// result when it is less than a day old; failures are silent.
+pub async fn check() -> Option<String> {
+    let path = cache_path();
+    let now = chrono::Utc::now().timestamp();
+    let cached = path.as_deref().and_then(load_cache);
+    let latest = match cached {
+        Some(cache) if now - cache.checked_at < CHECK_INTERVAL_SECS => cache.latest,
+        previous => {
+            // Record failed lookups too, so an offline machine isn't retried on every start
+            let latest = fetch_latest().await.ok().or(previous.and_then(|c| c.latest));
+            if let Some(path) = &path {
+                save_cache(path, &UpdateCache { checked_at: now, latest: latest.clone() });
+            }
+            latest
+        }
+    }?;
+    is_newer(&latest, env!("CARGO_PKG_VERSION")).then_some(latest)
+}
+
+async fn fetch_latest() -> anyhow::Result<String> {
+    // crates.io rejects requests without a descriptive User-Agent
+    let client = reqwest::Client::builder()
+        .user_agent(crate::config::DEFAULT_USER_AGENT)
+        .timeout(Duration::from_secs(10))
+        .build()?;
+    let response = client.get(CRATE_URL).send().await?.error_for_status()?;
+    let info = response.json::<CrateResponse>().await?;
+    info.krate.max_stable_version.ok_or_else(|| anyhow::anyhow!("no stable version published"))
+}
+
+fn load_cache(path: &Path) -> Option<UpdateCache> {
+    toml::from_str(&std::fs::read_to_string(path).ok()?).ok()
+}
+
+fn save_cache(path: &Path, cache: &UpdateCache) {
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if let Ok(contents) = toml::to_string(cache) {
+        let _ = std::fs::write(path, contents);
+    }
+}
+
+/// Whether `candidate` is a later `major.minor.patch` version than `current`.
+/// Pre-release and build suffixes are ignored.
+pub fn is_newer(candidate: &str, current: &str) -> bool {
+    fn parse(version: &str) -> Option<(u64, u64, u64)> {
+        let core = version.trim().trim_start_matches('v').split(['-', '+']).next()?;
+        let mut parts = core.split('.').map(|p| p.parse::<u64>());
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+        let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+        Some((major, minor, patch))
+    }
+    match (parse(candidate), parse(current)) {
+        (Some(candidate), Some(current)) => candidate > current,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_newer, UpdateCache};
+
+    #[test]
+    fn compares_versions_numerically() {
+        assert!(is_newer("0.1.10", "0.1.9"));
+        assert!(is_newer("1.0", "0.9.9"));
+        assert!(!is_newer("0.1.1", "0.1.1"));
+        assert!(!is_newer("0.1.0", "0.1.1"));
+        assert!(!is_newer("garbage", "0.1.1"));
+    }
+
+    #[test]
+    fn cache_round_trips_without_a_version() {
+        let cache = UpdateCache { checked_at: 1_760_000_000, latest: None };
+        let parsed: UpdateCache = toml::from_str(&toml::to_string(&cache).unwrap()).unwrap();
+        assert_eq!(parsed, cache);
+    }
+}
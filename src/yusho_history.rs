@@ -0,0 +1,60 @@
+use crate::api::{self, Basho, SumoApi, YushoEntry};
+use futures::stream::{self, StreamExt};
+
+/// Basho listed in the yusho history: the last five years.
+pub const HISTORY_BASHO: usize = 30;
+// Basho requested at once when they aren't cached
+const CONCURRENT_REQUESTS: usize = 4;
+
+/// Yusho winners of each basho, newest first; `None` where the basho couldn't be loaded.
+pub type History = Vec<(String, Option<Vec<YushoEntry>>)>;
+
+/// The `count` basho IDs up to and including `latest`, newest first.
+pub fn basho_ids(latest: &str, count: usize) -> Vec<String> {
+    std::iter::successors(Some(latest.to_string()), |id| api::previous_basho_id(id)).take(count).collect()
+}
+
+/// The winner of `division` among a basho's yusho.
+pub fn winner<'a>(yusho: &'a [YushoEntry], division: &str) -> Option<&'a YushoEntry> {
+    yusho.iter().find(|y| y.division.eq_ignore_ascii_case(division))
+}
+
+/// Yusho winners of the `count` basho up to `latest`. Basho with winners in the cache are
+/// read from it, since a finished basho never changes; the rest are requested.
+pub async fn fetch(api: &SumoApi, latest: &str, count: usize) -> History {
+    stream::iter(basho_ids(latest, count))
+        .map(|id| async move {
+            let cached = api
+                .get_cached::<Basho>(&api::basho_path(&id))
+                .filter(|basho| basho.yusho.as_ref().is_some_and(|y| !y.is_empty()));
+            let basho = match cached {
+                Some(basho) => Some(basho),
+                None => api.get_basho(&id).await.ok(),
+            };
+            (id, basho.map(|b| b.yusho.unwrap_or_default()))
+        })
+        .buffered(CONCURRENT_REQUESTS)
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{basho_ids, winner};
+    use crate::api::YushoEntry;
+
+    #[test]
+    fn lists_basho_back_across_years() {
+        assert_eq!(basho_ids("202503", 4), ["202503", "202501", "202411", "202409"]);
+
+        let yusho = |division: &str, shikona: &str| YushoEntry {
+            division: division.to_string(),
+            rikishi_id: 1,
+            shikona_en: shikona.to_string(),
+            shikona_jp: String::new(),
+        };
+        let yusho = [yusho("Makuuchi", "Hoshoryu"), yusho("Sandanme", "Asahakuryu")];
+        assert_eq!(winner(&yusho, "sandanme").map(|y| y.shikona_en.as_str()), Some("Asahakuryu"));
+        assert!(winner(&yusho, "Jonidan").is_none());
+    }
+}